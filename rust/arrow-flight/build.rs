@@ -23,32 +23,37 @@ use std::{
 };
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
-    // avoid rerunning build if the file has not changed
+    // avoid rerunning build if the files have not changed
     println!("cargo:rerun-if-changed=../../format/Flight.proto");
+    println!("cargo:rerun-if-changed=../../format/FlightSql.proto");
 
     // override the build location, in order to check in the changes to proto files
     env::set_var("OUT_DIR", "src");
 
+    compile_proto("../../format/Flight.proto", "src/arrow.flight.protocol.rs")?;
+    compile_proto(
+        "../../format/FlightSql.proto",
+        "src/arrow.flight.protocol.sql.rs",
+    )?;
+
+    // As the proto files are checked in, the build should not fail if they are not found
+    Ok(())
+}
+
+fn compile_proto(proto: &str, output: &str) -> Result<(), Box<dyn std::error::Error>> {
     // The current working directory can vary depending on how the project is being
     // built or released so we build an absolute path to the proto file
-    let path = Path::new("../../format/Flight.proto");
+    let path = Path::new(proto);
     if path.exists() {
-        tonic_build::compile_protos("../../format/Flight.proto")?;
+        tonic_build::compile_protos(proto)?;
         // read file contents to string
-        let mut file = OpenOptions::new()
-            .read(true)
-            .open("src/arrow.flight.protocol.rs")?;
+        let mut file = OpenOptions::new().read(true).open(output)?;
         let mut buffer = String::new();
         file.read_to_string(&mut buffer)?;
         // append warning that file was auto-generate
-        let mut file = OpenOptions::new()
-            .write(true)
-            .truncate(true)
-            .open("src/arrow.flight.protocol.rs")?;
+        let mut file = OpenOptions::new().write(true).truncate(true).open(output)?;
         file.write_all("// This file was automatically generated through the build.rs script, and should not be edited.\n\n".as_bytes())?;
         file.write_all(buffer.as_bytes())?;
     }
-
-    // As the proto file is checked in, the build should not fail if the file is not found
     Ok(())
 }
@@ -0,0 +1,171 @@
+// This file was automatically generated through the build.rs script, and should not be edited.
+
+//
+// Flight SQL defines a set of commands, exchanged over the Flight protocol,
+// for executing SQL against a service and for inspecting its catalog.
+//
+// Commands are serialized as the `value` of a `google.protobuf.Any` and the
+// resulting bytes are sent as the `cmd` of a `FlightDescriptor` (for
+// GetFlightInfo, GetSchema and DoPut), as the `ticket` of a `Ticket` (for
+// DoGet) or as the `body` of an `Action` (for DoAction).
+//
+// Fields holding an optional filter treat an empty string as "no filter".
+
+///
+/// Represents a request to list the catalogs of the service.
+///
+/// The returned Arrow schema will be:
+/// <
+///   catalog_name: utf8 not null
+/// >
+/// The returned data should be ordered by catalog_name.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandGetCatalogs {}
+///
+/// Represents a request to list the database schemas of the service.
+///
+/// The returned Arrow schema will be:
+/// <
+///   catalog_name: utf8,
+///   db_schema_name: utf8 not null
+/// >
+/// The returned data should be ordered by catalog_name, then db_schema_name.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandGetDbSchemas {
+    /// Restrict the results to this catalog.
+    #[prost(string, tag = "1")]
+    pub catalog: std::string::String,
+    /// A SQL LIKE pattern restricting the schemas returned.
+    #[prost(string, tag = "2")]
+    pub db_schema_filter_pattern: std::string::String,
+}
+///
+/// Represents a request to list the tables of the service.
+///
+/// The returned Arrow schema will be:
+/// <
+///   catalog_name: utf8,
+///   db_schema_name: utf8,
+///   table_name: utf8 not null,
+///   table_type: utf8 not null,
+///   table_schema: bytes not null (only present if include_schema is set)
+/// >
+/// The returned data should be ordered by catalog_name, db_schema_name,
+/// table_name, then table_type. `table_schema` holds the table's Arrow schema
+/// encoded as an IPC message.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandGetTables {
+    /// Restrict the results to this catalog.
+    #[prost(string, tag = "1")]
+    pub catalog: std::string::String,
+    /// A SQL LIKE pattern restricting the schemas searched.
+    #[prost(string, tag = "2")]
+    pub db_schema_filter_pattern: std::string::String,
+    /// A SQL LIKE pattern restricting the tables returned.
+    #[prost(string, tag = "3")]
+    pub table_name_filter_pattern: std::string::String,
+    /// Restrict the results to tables of these types.
+    #[prost(string, repeated, tag = "4")]
+    pub table_types: ::std::vec::Vec<std::string::String>,
+    /// Whether to include the schema of each table in the results.
+    #[prost(bool, tag = "5")]
+    pub include_schema: bool,
+}
+///
+/// Represents a request to list the table types supported by the service.
+///
+/// The returned Arrow schema will be:
+/// <
+///   table_type: utf8 not null
+/// >
+/// The returned data should be ordered by table_type.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandGetTableTypes {}
+///
+/// Request to create a prepared statement, sent as the body of a
+/// "CreatePreparedStatement" action.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActionCreatePreparedStatementRequest {
+    /// The SQL query to prepare.
+    #[prost(string, tag = "1")]
+    pub query: std::string::String,
+}
+///
+/// The result of a "CreatePreparedStatement" action, returned as the body of
+/// the action's single result.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActionCreatePreparedStatementResult {
+    /// Opaque handle identifying the prepared statement on the service.
+    #[prost(bytes, tag = "1")]
+    pub prepared_statement_handle: std::vec::Vec<u8>,
+    /// The schema of the statement's results, encoded as an IPC message,
+    /// if known.
+    #[prost(bytes, tag = "2")]
+    pub dataset_schema: std::vec::Vec<u8>,
+    /// The schema of the statement's parameters, encoded as an IPC message,
+    /// if it has any.
+    #[prost(bytes, tag = "3")]
+    pub parameter_schema: std::vec::Vec<u8>,
+}
+///
+/// Request to release a prepared statement, sent as the body of a
+/// "ClosePreparedStatement" action.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActionClosePreparedStatementRequest {
+    /// Opaque handle identifying the prepared statement on the service.
+    #[prost(bytes, tag = "1")]
+    pub prepared_statement_handle: std::vec::Vec<u8>,
+}
+///
+/// Represents a SQL query. Used in GetFlightInfo to plan the query; the
+/// returned endpoints carry a TicketStatementQuery to fetch the results.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandStatementQuery {
+    /// The SQL query.
+    #[prost(string, tag = "1")]
+    pub query: std::string::String,
+}
+///
+/// Represents a ticket fetching the results of a planned CommandStatementQuery.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TicketStatementQuery {
+    /// Opaque handle identifying the planned statement on the service.
+    #[prost(bytes, tag = "1")]
+    pub statement_handle: std::vec::Vec<u8>,
+}
+///
+/// Represents the execution of a prepared statement. Used in GetFlightInfo to
+/// plan the execution and as the ticket used by DoGet to fetch its results.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandPreparedStatementQuery {
+    /// Opaque handle identifying the prepared statement on the service.
+    #[prost(bytes, tag = "1")]
+    pub prepared_statement_handle: std::vec::Vec<u8>,
+}
+///
+/// Represents a SQL update (INSERT, UPDATE, DELETE, DDL) executed with DoPut.
+/// The service replies with a single DoPutUpdateResult.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandStatementUpdate {
+    /// The SQL statement.
+    #[prost(string, tag = "1")]
+    pub query: std::string::String,
+}
+///
+/// Represents the execution of a prepared update with DoPut. The batches sent
+/// by the client, if any, bind the statement's parameters.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CommandPreparedStatementUpdate {
+    /// Opaque handle identifying the prepared statement on the service.
+    #[prost(bytes, tag = "1")]
+    pub prepared_statement_handle: std::vec::Vec<u8>,
+}
+///
+/// The result of an update, returned as the `app_metadata` of the single
+/// PutResult of a DoPut.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DoPutUpdateResult {
+    /// The number of records affected, or -1 if unknown.
+    #[prost(int64, tag = "1")]
+    pub record_count: i64,
+}
@@ -0,0 +1,297 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! An Arrow-native client for Flight services
+
+use std::convert::{TryFrom, TryInto};
+use std::fmt;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use futures::{future, Stream, StreamExt, TryStreamExt};
+use tonic::codegen::StdError;
+use tonic::transport::{Channel, Endpoint};
+use tonic::{Status, Streaming};
+
+use arrow::datatypes::{Schema, SchemaRef};
+use arrow::error::{ArrowError, Result};
+use arrow::ipc::writer::IpcWriteOptions;
+use arrow::record_batch::RecordBatch;
+
+use crate::flight_service_client::FlightServiceClient;
+use crate::utils::{
+    FlightDataDecoder, FlightDataDecoderStream, FlightDataEncoder,
+    FlightDataEncoderStream, DEFAULT_MAX_FLIGHT_DATA_SIZE,
+};
+use crate::{
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
+    PutResult, SchemaResult, Ticket,
+};
+
+/// A client for a Flight service that sends and receives Arrow record batches
+/// rather than raw `FlightData` messages.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn example() -> arrow::error::Result<()> {
+/// use arrow_flight::{client::FlightClient, Ticket};
+/// use futures::TryStreamExt;
+///
+/// let mut client = FlightClient::connect("http://localhost:50051").await?;
+/// let stream = client.do_get(Ticket { ticket: b"query".to_vec() }).await?;
+/// let batches: Vec<_> = stream.try_collect().await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FlightClient {
+    inner: FlightServiceClient<Channel>,
+    options: IpcWriteOptions,
+    max_flight_data_size: usize,
+}
+
+impl FlightClient {
+    /// Connect to a Flight service at the given endpoint
+    pub async fn connect<D>(dst: D) -> Result<Self>
+    where
+        D: TryInto<Endpoint>,
+        D::Error: Into<StdError>,
+    {
+        let inner = FlightServiceClient::connect(dst)
+            .await
+            .map_err(|e| ArrowError::ExternalError(Box::new(e)))?;
+        Ok(Self::new(inner))
+    }
+
+    /// Create a client from an existing generated Flight client
+    pub fn new(inner: FlightServiceClient<Channel>) -> Self {
+        Self {
+            inner,
+            options: IpcWriteOptions::default(),
+            max_flight_data_size: DEFAULT_MAX_FLIGHT_DATA_SIZE,
+        }
+    }
+
+    /// Set the IPC options used to encode batches sent with `do_put`
+    pub fn with_options(mut self, options: IpcWriteOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Set the target maximum size of the messages sent with `do_put`, see
+    /// [`FlightDataEncoder::with_max_flight_data_size`]
+    pub fn with_max_flight_data_size(mut self, max_flight_data_size: usize) -> Self {
+        self.max_flight_data_size = max_flight_data_size;
+        self
+    }
+
+    /// Access the underlying generated client, e.g. to call `handshake`
+    pub fn inner(&mut self) -> &mut FlightServiceClient<Channel> {
+        &mut self.inner
+    }
+
+    /// Get information about how to consume the flight described by `descriptor`
+    pub async fn get_flight_info(
+        &mut self,
+        descriptor: FlightDescriptor,
+    ) -> Result<FlightInfo> {
+        let response = self
+            .inner
+            .get_flight_info(descriptor)
+            .await
+            .map_err(status_to_arrow_error)?;
+        Ok(response.into_inner())
+    }
+
+    /// List the flights available on the service that match `criteria`
+    pub async fn list_flights(&mut self, criteria: Criteria) -> Result<Vec<FlightInfo>> {
+        let response = self
+            .inner
+            .list_flights(criteria)
+            .await
+            .map_err(status_to_arrow_error)?;
+        response
+            .into_inner()
+            .map_err(status_to_arrow_error)
+            .try_collect()
+            .await
+    }
+
+    /// Get the schema of the flight described by `descriptor`
+    pub async fn get_schema(&mut self, descriptor: FlightDescriptor) -> Result<Schema> {
+        let response = self
+            .inner
+            .get_schema(descriptor)
+            .await
+            .map_err(status_to_arrow_error)?;
+        let result: SchemaResult = response.into_inner();
+        Schema::try_from(&result)
+    }
+
+    /// Perform a custom action, returning its results
+    pub async fn do_action(&mut self, action: Action) -> Result<Vec<crate::Result>> {
+        let response = self
+            .inner
+            .do_action(action)
+            .await
+            .map_err(status_to_arrow_error)?;
+        response
+            .into_inner()
+            .map_err(status_to_arrow_error)
+            .try_collect()
+            .await
+    }
+
+    /// List the custom actions supported by the service
+    pub async fn list_actions(&mut self) -> Result<Vec<ActionType>> {
+        let response = self
+            .inner
+            .list_actions(Empty {})
+            .await
+            .map_err(status_to_arrow_error)?;
+        response
+            .into_inner()
+            .map_err(status_to_arrow_error)
+            .try_collect()
+            .await
+    }
+
+    /// Retrieve the stream of record batches identified by `ticket`.
+    ///
+    /// The schema of the stream is read before returning, and is available
+    /// from [`FlightRecordBatchStream::schema`].
+    pub async fn do_get(&mut self, ticket: Ticket) -> Result<FlightRecordBatchStream> {
+        let response = self
+            .inner
+            .do_get(ticket)
+            .await
+            .map_err(status_to_arrow_error)?;
+        FlightRecordBatchStream::try_new(response.into_inner()).await
+    }
+
+    /// Upload a stream of record batches with the given schema to the flight
+    /// described by `descriptor`, returning the results sent by the service.
+    pub async fn do_put<S>(
+        &mut self,
+        descriptor: FlightDescriptor,
+        schema: SchemaRef,
+        batches: S,
+    ) -> Result<Vec<PutResult>>
+    where
+        S: Stream<Item = RecordBatch> + Send + Sync + 'static,
+    {
+        let encoder = FlightDataEncoder::new(self.options.clone())
+            .with_max_flight_data_size(self.max_flight_data_size);
+        let data =
+            FlightDataEncoderStream::new(&schema, Box::pin(batches.map(Ok)), encoder)
+                .with_flight_descriptor(descriptor);
+
+        // an encoding error ends the upload; it is reported once the service
+        // has responded to the truncated stream
+        let encode_error = Arc::new(Mutex::new(None));
+        let stream_error = encode_error.clone();
+        let request = data.scan((), move |_, data| {
+            future::ready(match data {
+                Ok(data) => Some(data),
+                Err(e) => {
+                    *stream_error.lock().unwrap() = Some(e);
+                    None
+                }
+            })
+        });
+
+        let response = self
+            .inner
+            .do_put(request)
+            .await
+            .map_err(status_to_arrow_error)?;
+        let results = response
+            .into_inner()
+            .map_err(status_to_arrow_error)
+            .try_collect()
+            .await?;
+
+        let encode_error = encode_error.lock().unwrap().take();
+        match encode_error {
+            Some(e) => Err(e),
+            None => Ok(results),
+        }
+    }
+}
+
+type FlightDataStream = Pin<Box<dyn Stream<Item = Result<FlightData>> + Send + Sync>>;
+
+/// A stream of record batches decoded from a stream of `FlightData` messages
+pub struct FlightRecordBatchStream {
+    inner: FlightDataDecoderStream<FlightDataStream>,
+}
+
+impl FlightRecordBatchStream {
+    /// Create a stream from messages whose schema has already been read
+    pub fn new(inner: Streaming<FlightData>, schema: SchemaRef) -> Self {
+        Self {
+            inner: FlightDataDecoderStream::with_decoder(
+                boxed_flight_data(inner),
+                FlightDataDecoder::with_schema(schema),
+            ),
+        }
+    }
+
+    /// Create a stream by reading its schema from the first messages of `inner`
+    pub async fn try_new(inner: Streaming<FlightData>) -> Result<Self> {
+        let mut inner = FlightDataDecoderStream::new(boxed_flight_data(inner));
+        inner.read_schema().await?;
+        Ok(Self { inner })
+    }
+
+    /// The schema of the record batches in this stream
+    pub fn schema(&self) -> SchemaRef {
+        self.inner
+            .schema()
+            .cloned()
+            .expect("FlightRecordBatchStream is always created with a schema")
+    }
+}
+
+impl fmt::Debug for FlightRecordBatchStream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FlightRecordBatchStream")
+            .field("schema", &self.schema())
+            .finish()
+    }
+}
+
+impl Stream for FlightRecordBatchStream {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.inner).poll_next(cx)
+    }
+}
+
+fn boxed_flight_data(inner: Streaming<FlightData>) -> FlightDataStream {
+    Box::pin(inner.map_err(status_to_arrow_error))
+}
+
+fn status_to_arrow_error(status: Status) -> ArrowError {
+    ArrowError::ExternalError(Box::new(status))
+}
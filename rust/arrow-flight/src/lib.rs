@@ -17,4 +17,7 @@
 
 include!("arrow.flight.protocol.rs");
 
+pub mod client;
+pub mod server;
+pub mod sql;
 pub mod utils;
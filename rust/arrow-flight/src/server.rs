@@ -0,0 +1,280 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers to implement Flight services in terms of Arrow schemas and batches
+
+use std::convert::TryFrom;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use futures::{stream, Stream, StreamExt};
+use tonic::{Request, Response, Status, Streaming};
+
+use arrow::datatypes::{Schema, SchemaRef};
+use arrow::error::{ArrowError, Result as ArrowResult};
+use arrow::ipc::writer::IpcWriteOptions;
+use arrow::record_batch::RecordBatch;
+
+use crate::client::FlightRecordBatchStream;
+use crate::flight_service_server::{FlightService, FlightServiceServer};
+use crate::utils::{
+    flight_schema_from_arrow_schema, FlightDataEncoder, FlightDataEncoderStream,
+    DEFAULT_MAX_FLIGHT_DATA_SIZE,
+};
+use crate::{
+    Action, ActionType, Criteria, Empty, FlightData, FlightDescriptor, FlightInfo,
+    HandshakeRequest, HandshakeResponse, PutResult, SchemaResult, Ticket,
+};
+
+/// A boxed stream of gRPC responses, as required by the generated `FlightService`
+pub type BoxedFlightStream<T> =
+    Pin<Box<dyn Stream<Item = Result<T, Status>> + Send + Sync + 'static>>;
+
+/// A boxed stream of record batches returned by [`ArrowFlightService::do_get`]
+pub type SendableRecordBatchStream =
+    Pin<Box<dyn Stream<Item = ArrowResult<RecordBatch>> + Send + Sync + 'static>>;
+
+/// A Flight service implemented in terms of Arrow schemas and record batches.
+///
+/// Wrap an implementation in an [`ArrowFlightServer`] to serve it with tonic;
+/// the adapter takes care of encoding schemas, dictionaries and batches as
+/// `FlightData`. Handlers that are not overridden return `UNIMPLEMENTED`.
+#[tonic::async_trait]
+pub trait ArrowFlightService: Send + Sync + 'static {
+    /// List the flights that match `criteria`
+    async fn list_flights(&self, _criteria: Criteria) -> Result<Vec<FlightInfo>, Status> {
+        Err(Status::unimplemented("list_flights is not implemented"))
+    }
+
+    /// Describe how to consume the flight identified by `descriptor`
+    async fn get_flight_info(
+        &self,
+        _descriptor: FlightDescriptor,
+    ) -> Result<FlightInfo, Status> {
+        Err(Status::unimplemented("get_flight_info is not implemented"))
+    }
+
+    /// Return the schema of the flight identified by `descriptor`
+    async fn get_schema(
+        &self,
+        _descriptor: FlightDescriptor,
+    ) -> Result<SchemaRef, Status> {
+        Err(Status::unimplemented("get_schema is not implemented"))
+    }
+
+    /// Return the schema and record batches of the stream identified by `ticket`
+    async fn do_get(
+        &self,
+        _ticket: Ticket,
+    ) -> Result<(SchemaRef, SendableRecordBatchStream), Status> {
+        Err(Status::unimplemented("do_get is not implemented"))
+    }
+
+    /// Consume the record batches uploaded by a client to the flight
+    /// identified by `descriptor`
+    async fn do_put(
+        &self,
+        _descriptor: FlightDescriptor,
+        _batches: FlightRecordBatchStream,
+    ) -> Result<Vec<PutResult>, Status> {
+        Err(Status::unimplemented("do_put is not implemented"))
+    }
+
+    /// Perform a custom action
+    async fn do_action(&self, _action: Action) -> Result<Vec<crate::Result>, Status> {
+        Err(Status::unimplemented("do_action is not implemented"))
+    }
+
+    /// List the custom actions supported by this service
+    async fn list_actions(&self) -> Result<Vec<ActionType>, Status> {
+        Ok(vec![])
+    }
+}
+
+/// Adapts an [`ArrowFlightService`] to the generated `FlightService` trait.
+///
+/// # Example
+///
+/// ```no_run
+/// # use arrow_flight::server::{ArrowFlightServer, ArrowFlightService};
+/// # struct MyService {}
+/// # impl ArrowFlightService for MyService {}
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let server = ArrowFlightServer::new(MyService {});
+/// tonic::transport::Server::builder()
+///     .add_service(server.into_service())
+///     .serve("[::1]:50051".parse()?)
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ArrowFlightServer<T> {
+    service: Arc<T>,
+    options: IpcWriteOptions,
+    max_flight_data_size: usize,
+}
+
+impl<T: ArrowFlightService> ArrowFlightServer<T> {
+    /// Create a new adapter for `service`
+    pub fn new(service: T) -> Self {
+        Self {
+            service: Arc::new(service),
+            options: IpcWriteOptions::default(),
+            max_flight_data_size: DEFAULT_MAX_FLIGHT_DATA_SIZE,
+        }
+    }
+
+    /// Set the IPC options used to encode schemas and batches
+    pub fn with_options(mut self, options: IpcWriteOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Set the target maximum size of the messages sent by `do_get`, see
+    /// [`FlightDataEncoder::with_max_flight_data_size`]
+    pub fn with_max_flight_data_size(mut self, max_flight_data_size: usize) -> Self {
+        self.max_flight_data_size = max_flight_data_size;
+        self
+    }
+
+    /// Wrap the adapter in a generated server, ready to add to a tonic `Router`
+    pub fn into_service(self) -> FlightServiceServer<Self> {
+        FlightServiceServer::new(self)
+    }
+}
+
+impl<T> Clone for ArrowFlightServer<T> {
+    fn clone(&self) -> Self {
+        Self {
+            service: self.service.clone(),
+            options: self.options.clone(),
+            max_flight_data_size: self.max_flight_data_size,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl<T: ArrowFlightService> FlightService for ArrowFlightServer<T> {
+    type HandshakeStream = BoxedFlightStream<HandshakeResponse>;
+    type ListFlightsStream = BoxedFlightStream<FlightInfo>;
+    type DoGetStream = BoxedFlightStream<FlightData>;
+    type DoPutStream = BoxedFlightStream<PutResult>;
+    type DoActionStream = BoxedFlightStream<crate::Result>;
+    type ListActionsStream = BoxedFlightStream<ActionType>;
+    type DoExchangeStream = BoxedFlightStream<FlightData>;
+
+    async fn handshake(
+        &self,
+        _request: Request<Streaming<HandshakeRequest>>,
+    ) -> Result<Response<Self::HandshakeStream>, Status> {
+        Err(Status::unimplemented("handshake is not implemented"))
+    }
+
+    async fn list_flights(
+        &self,
+        request: Request<Criteria>,
+    ) -> Result<Response<Self::ListFlightsStream>, Status> {
+        let flights = self.service.list_flights(request.into_inner()).await?;
+        Ok(Response::new(iter_stream(flights)))
+    }
+
+    async fn get_flight_info(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<FlightInfo>, Status> {
+        let info = self.service.get_flight_info(request.into_inner()).await?;
+        Ok(Response::new(info))
+    }
+
+    async fn get_schema(
+        &self,
+        request: Request<FlightDescriptor>,
+    ) -> Result<Response<SchemaResult>, Status> {
+        let schema = self.service.get_schema(request.into_inner()).await?;
+        Ok(Response::new(flight_schema_from_arrow_schema(
+            &schema,
+            &self.options,
+        )))
+    }
+
+    async fn do_get(
+        &self,
+        request: Request<Ticket>,
+    ) -> Result<Response<Self::DoGetStream>, Status> {
+        let (schema, batches) = self.service.do_get(request.into_inner()).await?;
+
+        let encoder = FlightDataEncoder::new(self.options.clone())
+            .with_max_flight_data_size(self.max_flight_data_size);
+        let output = FlightDataEncoderStream::new(&schema, batches, encoder)
+            .map(|data| data.map_err(arrow_error_to_status));
+
+        Ok(Response::new(Box::pin(output)))
+    }
+
+    async fn do_put(
+        &self,
+        request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoPutStream>, Status> {
+        let mut input = request.into_inner();
+        let first = input
+            .message()
+            .await?
+            .ok_or_else(|| Status::invalid_argument("do_put stream is empty"))?;
+        let descriptor = first.flight_descriptor.clone().ok_or_else(|| {
+            Status::invalid_argument(
+                "The first do_put message must contain a flight descriptor",
+            )
+        })?;
+        let schema = Schema::try_from(&first).map_err(arrow_error_to_status)?;
+
+        let batches = FlightRecordBatchStream::new(input, Arc::new(schema));
+        let results = self.service.do_put(descriptor, batches).await?;
+        Ok(Response::new(iter_stream(results)))
+    }
+
+    async fn do_action(
+        &self,
+        request: Request<Action>,
+    ) -> Result<Response<Self::DoActionStream>, Status> {
+        let results = self.service.do_action(request.into_inner()).await?;
+        Ok(Response::new(iter_stream(results)))
+    }
+
+    async fn list_actions(
+        &self,
+        _request: Request<Empty>,
+    ) -> Result<Response<Self::ListActionsStream>, Status> {
+        let actions = self.service.list_actions().await?;
+        Ok(Response::new(iter_stream(actions)))
+    }
+
+    async fn do_exchange(
+        &self,
+        _request: Request<Streaming<FlightData>>,
+    ) -> Result<Response<Self::DoExchangeStream>, Status> {
+        Err(Status::unimplemented("do_exchange is not implemented"))
+    }
+}
+
+fn iter_stream<T: Send + Sync + 'static>(items: Vec<T>) -> BoxedFlightStream<T> {
+    Box::pin(stream::iter(items.into_iter().map(Ok)))
+}
+
+fn arrow_error_to_status(e: ArrowError) -> Status {
+    Status::internal(e.to_string())
+}
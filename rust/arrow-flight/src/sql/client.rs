@@ -0,0 +1,267 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! A typed client for Flight SQL services
+
+use std::convert::TryInto;
+use std::sync::Arc;
+
+use futures::stream;
+use prost::Message;
+use tonic::codegen::StdError;
+use tonic::transport::Endpoint;
+
+use arrow::datatypes::Schema;
+use arrow::error::{ArrowError, Result};
+
+use super::{
+    ActionClosePreparedStatementRequest, ActionCreatePreparedStatementRequest,
+    ActionCreatePreparedStatementResult, Any, CommandGetCatalogs, CommandGetDbSchemas,
+    CommandGetTableTypes, CommandGetTables, CommandPreparedStatementQuery,
+    CommandPreparedStatementUpdate, CommandStatementQuery, CommandStatementUpdate,
+    DoPutUpdateResult, ProstMessageExt, CLOSE_PREPARED_STATEMENT,
+    CREATE_PREPARED_STATEMENT,
+};
+use crate::client::{FlightClient, FlightRecordBatchStream};
+use crate::flight_descriptor::DescriptorType;
+use crate::utils::schema_from_ipc_message;
+use crate::{Action, FlightDescriptor, FlightInfo, Ticket};
+
+/// A client for Flight SQL services.
+///
+/// Queries and metadata requests return a `FlightInfo` whose endpoints carry
+/// the tickets to pass to [`FlightSqlClient::do_get`] to fetch the results.
+///
+/// # Example
+///
+/// ```no_run
+/// # async fn example() -> arrow::error::Result<()> {
+/// use arrow_flight::sql::client::FlightSqlClient;
+/// use futures::TryStreamExt;
+///
+/// let mut client = FlightSqlClient::connect("http://localhost:50051").await?;
+/// let info = client.execute("SELECT 1".to_string()).await?;
+/// for endpoint in info.endpoint {
+///     let ticket = endpoint.ticket.expect("endpoint without ticket");
+///     let batches: Vec<_> = client.do_get(ticket).await?.try_collect().await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct FlightSqlClient {
+    client: FlightClient,
+}
+
+impl FlightSqlClient {
+    /// Connect to a Flight SQL service at the given endpoint
+    pub async fn connect<D>(dst: D) -> Result<Self>
+    where
+        D: TryInto<Endpoint>,
+        D::Error: Into<StdError>,
+    {
+        Ok(Self::new(FlightClient::connect(dst).await?))
+    }
+
+    /// Create a Flight SQL client on top of a Flight client
+    pub fn new(client: FlightClient) -> Self {
+        Self { client }
+    }
+
+    /// Access the underlying Flight client
+    pub fn inner(&mut self) -> &mut FlightClient {
+        &mut self.client
+    }
+
+    /// Plan the execution of a SQL query
+    pub async fn execute(&mut self, query: String) -> Result<FlightInfo> {
+        self.get_flight_info_for_command(CommandStatementQuery { query })
+            .await
+    }
+
+    /// Execute a SQL update, returning the number of records affected or -1
+    /// if unknown
+    pub async fn execute_update(&mut self, query: String) -> Result<i64> {
+        self.do_put_update(CommandStatementUpdate { query }).await
+    }
+
+    /// Request the list of catalogs
+    pub async fn get_catalogs(&mut self) -> Result<FlightInfo> {
+        self.get_flight_info_for_command(CommandGetCatalogs {})
+            .await
+    }
+
+    /// Request the list of database schemas
+    pub async fn get_db_schemas(
+        &mut self,
+        request: CommandGetDbSchemas,
+    ) -> Result<FlightInfo> {
+        self.get_flight_info_for_command(request).await
+    }
+
+    /// Request the list of tables
+    pub async fn get_tables(&mut self, request: CommandGetTables) -> Result<FlightInfo> {
+        self.get_flight_info_for_command(request).await
+    }
+
+    /// Request the list of table types
+    pub async fn get_table_types(&mut self) -> Result<FlightInfo> {
+        self.get_flight_info_for_command(CommandGetTableTypes {})
+            .await
+    }
+
+    /// Create a prepared statement for a SQL query
+    pub async fn prepare(&mut self, query: String) -> Result<PreparedStatement> {
+        let request = ActionCreatePreparedStatementRequest { query };
+        let results = self
+            .do_action(CREATE_PREPARED_STATEMENT, request.as_any())
+            .await?;
+        let result = results.first().ok_or_else(|| {
+            ArrowError::ParseError(
+                "CreatePreparedStatement returned no result".to_string(),
+            )
+        })?;
+        let result: ActionCreatePreparedStatementResult =
+            Any::decode_from(&result.body)?.unpack()?.ok_or_else(|| {
+                ArrowError::ParseError(
+                    "CreatePreparedStatement returned an unexpected result".to_string(),
+                )
+            })?;
+        PreparedStatement::try_new(result)
+    }
+
+    /// Fetch the record batches identified by a ticket of a `FlightInfo`
+    pub async fn do_get(&mut self, ticket: Ticket) -> Result<FlightRecordBatchStream> {
+        self.client.do_get(ticket).await
+    }
+
+    async fn get_flight_info_for_command<M: ProstMessageExt>(
+        &mut self,
+        cmd: M,
+    ) -> Result<FlightInfo> {
+        self.client.get_flight_info(descriptor(&cmd)).await
+    }
+
+    async fn do_put_update<M: ProstMessageExt>(&mut self, cmd: M) -> Result<i64> {
+        let results = self
+            .client
+            .do_put(descriptor(&cmd), Arc::new(Schema::empty()), stream::empty())
+            .await?;
+        let result = results.first().ok_or_else(|| {
+            ArrowError::ParseError("Update returned no result".to_string())
+        })?;
+        let result =
+            DoPutUpdateResult::decode(&result.app_metadata[..]).map_err(|e| {
+                ArrowError::ParseError(format!(
+                    "Unable to decode DoPutUpdateResult: {}",
+                    e
+                ))
+            })?;
+        Ok(result.record_count)
+    }
+
+    async fn do_action(
+        &mut self,
+        action_type: &str,
+        body: Any,
+    ) -> Result<Vec<crate::Result>> {
+        self.client
+            .do_action(Action {
+                r#type: action_type.to_string(),
+                body: body.encode_to_vec(),
+            })
+            .await
+    }
+}
+
+/// A prepared statement created by [`FlightSqlClient::prepare`]
+#[derive(Debug, Clone)]
+pub struct PreparedStatement {
+    handle: Vec<u8>,
+    dataset_schema: Option<Schema>,
+    parameter_schema: Option<Schema>,
+}
+
+impl PreparedStatement {
+    fn try_new(result: ActionCreatePreparedStatementResult) -> Result<Self> {
+        let read_schema = |bytes: &[u8]| {
+            if bytes.is_empty() {
+                Ok(None)
+            } else {
+                schema_from_ipc_message(bytes).map(Some)
+            }
+        };
+        Ok(Self {
+            dataset_schema: read_schema(&result.dataset_schema)?,
+            parameter_schema: read_schema(&result.parameter_schema)?,
+            handle: result.prepared_statement_handle,
+        })
+    }
+
+    /// The opaque handle identifying this statement on the service
+    pub fn handle(&self) -> &[u8] {
+        &self.handle
+    }
+
+    /// The schema of the statement's results, if known
+    pub fn dataset_schema(&self) -> Option<&Schema> {
+        self.dataset_schema.as_ref()
+    }
+
+    /// The schema of the statement's parameters, if it has any
+    pub fn parameter_schema(&self) -> Option<&Schema> {
+        self.parameter_schema.as_ref()
+    }
+
+    /// Plan the execution of this statement as a query
+    pub async fn execute(&self, client: &mut FlightSqlClient) -> Result<FlightInfo> {
+        client
+            .get_flight_info_for_command(CommandPreparedStatementQuery {
+                prepared_statement_handle: self.handle.clone(),
+            })
+            .await
+    }
+
+    /// Execute this statement as an update, returning the number of records
+    /// affected or -1 if unknown
+    pub async fn execute_update(&self, client: &mut FlightSqlClient) -> Result<i64> {
+        client
+            .do_put_update(CommandPreparedStatementUpdate {
+                prepared_statement_handle: self.handle.clone(),
+            })
+            .await
+    }
+
+    /// Release this statement on the service
+    pub async fn close(self, client: &mut FlightSqlClient) -> Result<()> {
+        let request = ActionClosePreparedStatementRequest {
+            prepared_statement_handle: self.handle,
+        };
+        client
+            .do_action(CLOSE_PREPARED_STATEMENT, request.as_any())
+            .await?;
+        Ok(())
+    }
+}
+
+fn descriptor<M: ProstMessageExt>(cmd: &M) -> FlightDescriptor {
+    FlightDescriptor {
+        r#type: DescriptorType::Cmd as i32,
+        cmd: cmd.as_any().encode_to_vec(),
+        path: vec![],
+    }
+}
@@ -0,0 +1,283 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Support for [Flight SQL](https://arrow.apache.org/docs/format/FlightSql.html),
+//! a protocol for executing SQL over Arrow Flight.
+//!
+//! Flight SQL commands are packed in a `google.protobuf.Any` and sent as the
+//! command of a `FlightDescriptor`, the content of a `Ticket` or the body of
+//! an `Action`. [`client::FlightSqlClient`] sends these commands and
+//! [`server::FlightSqlService`] lets a service answer them.
+
+use std::convert::TryFrom;
+
+use prost::Message;
+
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::error::{ArrowError, Result};
+
+include!("../arrow.flight.protocol.sql.rs");
+
+pub mod client;
+pub mod server;
+
+/// The type of the action that creates a prepared statement
+pub const CREATE_PREPARED_STATEMENT: &str = "CreatePreparedStatement";
+
+/// The type of the action that closes a prepared statement
+pub const CLOSE_PREPARED_STATEMENT: &str = "ClosePreparedStatement";
+
+/// A protobuf message packed with the URL of its type, equivalent to
+/// `google.protobuf.Any`
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Any {
+    /// The URL identifying the type of the packed message
+    #[prost(string, tag = "1")]
+    pub type_url: String,
+    /// The encoded message
+    #[prost(bytes, tag = "2")]
+    pub value: Vec<u8>,
+}
+
+impl Any {
+    /// Pack `message` into an `Any`
+    pub fn pack<M: ProstMessageExt>(message: &M) -> Any {
+        Any {
+            type_url: M::type_url().to_string(),
+            value: encode_message(message),
+        }
+    }
+
+    /// Whether this `Any` contains a message of type `M`
+    pub fn is<M: ProstMessageExt>(&self) -> bool {
+        self.type_url == M::type_url()
+    }
+
+    /// Unpack the message, returning `None` if it is not of type `M`
+    pub fn unpack<M: ProstMessageExt>(&self) -> Result<Option<M>> {
+        if !self.is::<M>() {
+            return Ok(None);
+        }
+        M::decode(&self.value[..]).map(Some).map_err(|e| {
+            ArrowError::ParseError(format!("Unable to decode {}: {}", self.type_url, e))
+        })
+    }
+
+    /// Decode an `Any` from its protobuf encoding
+    pub fn decode_from(bytes: &[u8]) -> Result<Any> {
+        Any::decode(bytes).map_err(|e| {
+            ArrowError::ParseError(format!("Unable to decode Any message: {}", e))
+        })
+    }
+
+    /// Encode this `Any` as protobuf
+    pub fn encode_to_vec(&self) -> Vec<u8> {
+        encode_message(self)
+    }
+}
+
+/// Extension of the Flight SQL messages to pack them in an [`Any`]
+pub trait ProstMessageExt: Message + Default + Sized {
+    /// The URL identifying this message type in an [`Any`]
+    fn type_url() -> &'static str;
+
+    /// Pack this message in an [`Any`]
+    fn as_any(&self) -> Any {
+        Any::pack(self)
+    }
+}
+
+macro_rules! prost_message_ext {
+    ($($name:ident),*) => {
+        $(
+            impl ProstMessageExt for $name {
+                fn type_url() -> &'static str {
+                    concat!(
+                        "type.googleapis.com/arrow.flight.protocol.sql.",
+                        stringify!($name)
+                    )
+                }
+            }
+        )*
+    };
+}
+
+prost_message_ext!(
+    CommandGetCatalogs,
+    CommandGetDbSchemas,
+    CommandGetTables,
+    CommandGetTableTypes,
+    ActionCreatePreparedStatementRequest,
+    ActionCreatePreparedStatementResult,
+    ActionClosePreparedStatementRequest,
+    CommandStatementQuery,
+    TicketStatementQuery,
+    CommandPreparedStatementQuery,
+    CommandStatementUpdate,
+    CommandPreparedStatementUpdate,
+    DoPutUpdateResult
+);
+
+/// A Flight SQL command, as found in a `FlightDescriptor` or a `Ticket`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Command {
+    GetCatalogs(CommandGetCatalogs),
+    GetDbSchemas(CommandGetDbSchemas),
+    GetTables(CommandGetTables),
+    GetTableTypes(CommandGetTableTypes),
+    StatementQuery(CommandStatementQuery),
+    TicketStatementQuery(TicketStatementQuery),
+    PreparedStatementQuery(CommandPreparedStatementQuery),
+    StatementUpdate(CommandStatementUpdate),
+    PreparedStatementUpdate(CommandPreparedStatementUpdate),
+}
+
+impl Command {
+    /// Pack this command in an [`Any`]
+    pub fn into_any(self) -> Any {
+        match self {
+            Command::GetCatalogs(cmd) => cmd.as_any(),
+            Command::GetDbSchemas(cmd) => cmd.as_any(),
+            Command::GetTables(cmd) => cmd.as_any(),
+            Command::GetTableTypes(cmd) => cmd.as_any(),
+            Command::StatementQuery(cmd) => cmd.as_any(),
+            Command::TicketStatementQuery(cmd) => cmd.as_any(),
+            Command::PreparedStatementQuery(cmd) => cmd.as_any(),
+            Command::StatementUpdate(cmd) => cmd.as_any(),
+            Command::PreparedStatementUpdate(cmd) => cmd.as_any(),
+        }
+    }
+}
+
+impl TryFrom<&Any> for Command {
+    type Error = ArrowError;
+
+    fn try_from(any: &Any) -> Result<Self> {
+        macro_rules! unpack {
+            ($($variant:ident),*) => {
+                $(
+                    if let Some(cmd) = any.unpack()? {
+                        return Ok(Command::$variant(cmd));
+                    }
+                )*
+            };
+        }
+        unpack!(
+            GetCatalogs,
+            GetDbSchemas,
+            GetTables,
+            GetTableTypes,
+            StatementQuery,
+            TicketStatementQuery,
+            PreparedStatementQuery,
+            StatementUpdate,
+            PreparedStatementUpdate
+        );
+        Err(ArrowError::ParseError(format!(
+            "Unsupported Flight SQL command: {}",
+            any.type_url
+        )))
+    }
+}
+
+impl TryFrom<&[u8]> for Command {
+    type Error = ArrowError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Command::try_from(&Any::decode_from(bytes)?)
+    }
+}
+
+impl CommandGetCatalogs {
+    /// The schema of the results of this command
+    pub fn schema() -> Schema {
+        Schema::new(vec![Field::new("catalog_name", DataType::Utf8, false)])
+    }
+}
+
+impl CommandGetDbSchemas {
+    /// The schema of the results of this command
+    pub fn schema() -> Schema {
+        Schema::new(vec![
+            Field::new("catalog_name", DataType::Utf8, true),
+            Field::new("db_schema_name", DataType::Utf8, false),
+        ])
+    }
+}
+
+impl CommandGetTables {
+    /// The schema of the results of this command, which depends on whether
+    /// the table schemas were requested
+    pub fn schema(&self) -> Schema {
+        let mut fields = vec![
+            Field::new("catalog_name", DataType::Utf8, true),
+            Field::new("db_schema_name", DataType::Utf8, true),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("table_type", DataType::Utf8, false),
+        ];
+        if self.include_schema {
+            fields.push(Field::new("table_schema", DataType::Binary, false));
+        }
+        Schema::new(fields)
+    }
+}
+
+impl CommandGetTableTypes {
+    /// The schema of the results of this command
+    pub fn schema() -> Schema {
+        Schema::new(vec![Field::new("table_type", DataType::Utf8, false)])
+    }
+}
+
+fn encode_message<M: Message>(message: &M) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(message.encoded_len());
+    message
+        .encode(&mut buf)
+        .expect("a Vec has enough capacity to encode any message");
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_any_round_trip() {
+        let query = CommandStatementQuery {
+            query: "SELECT 1".to_string(),
+        };
+        let any = Any::decode_from(&query.as_any().encode_to_vec()).unwrap();
+        assert!(any.is::<CommandStatementQuery>());
+        assert!(!any.is::<CommandStatementUpdate>());
+        assert_eq!(any.unpack::<CommandStatementUpdate>().unwrap(), None);
+        assert_eq!(any.unpack().unwrap(), Some(query.clone()));
+        assert_eq!(
+            Command::try_from(&any).unwrap(),
+            Command::StatementQuery(query)
+        );
+    }
+
+    #[test]
+    fn test_unknown_command() {
+        let any = Any {
+            type_url: "type.googleapis.com/unknown".to_string(),
+            value: vec![],
+        };
+        let err = Command::try_from(&any).unwrap_err();
+        assert!(err.to_string().contains("type.googleapis.com/unknown"));
+    }
+}
@@ -0,0 +1,397 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Helpers to implement Flight SQL services
+
+use std::convert::TryFrom;
+
+use arrow::datatypes::{Schema, SchemaRef};
+use arrow::ipc::writer::IpcWriteOptions;
+use tonic::Status;
+
+use super::{
+    ActionClosePreparedStatementRequest, ActionCreatePreparedStatementRequest,
+    ActionCreatePreparedStatementResult, Any, Command, CommandGetCatalogs,
+    CommandGetDbSchemas, CommandGetTableTypes, CommandGetTables,
+    CommandPreparedStatementQuery, CommandPreparedStatementUpdate, CommandStatementQuery,
+    CommandStatementUpdate, DoPutUpdateResult, ProstMessageExt, TicketStatementQuery,
+    CLOSE_PREPARED_STATEMENT, CREATE_PREPARED_STATEMENT,
+};
+use crate::client::FlightRecordBatchStream;
+use crate::flight_service_server::FlightServiceServer;
+use crate::server::{ArrowFlightServer, ArrowFlightService, SendableRecordBatchStream};
+use crate::utils::ipc_message_from_arrow_schema;
+use crate::{
+    Action, ActionType, FlightDescriptor, FlightEndpoint, FlightInfo, PutResult, Ticket,
+};
+
+/// A Flight SQL service, implemented in terms of Flight SQL commands and
+/// Arrow record batches.
+///
+/// Wrap an implementation in a [`FlightSqlServer`] to serve it. Queries are
+/// planned by the `get_flight_info_*` handlers, whose `FlightInfo` endpoints
+/// carry the tickets that are then passed to the matching `do_get_*`
+/// handlers. The metadata requests (catalogs, schemas, tables and table
+/// types) have default `get_flight_info_*` handlers returning a single
+/// endpoint whose ticket is the command itself. All other handlers return
+/// `UNIMPLEMENTED` unless overridden.
+#[tonic::async_trait]
+pub trait FlightSqlService: Send + Sync + 'static {
+    /// Plan the execution of a SQL query
+    async fn get_flight_info_statement(
+        &self,
+        _query: CommandStatementQuery,
+        _descriptor: FlightDescriptor,
+    ) -> Result<FlightInfo, Status> {
+        Err(Status::unimplemented(
+            "get_flight_info_statement is not implemented",
+        ))
+    }
+
+    /// Plan the execution of a prepared statement
+    async fn get_flight_info_prepared_statement(
+        &self,
+        _query: CommandPreparedStatementQuery,
+        _descriptor: FlightDescriptor,
+    ) -> Result<FlightInfo, Status> {
+        Err(Status::unimplemented(
+            "get_flight_info_prepared_statement is not implemented",
+        ))
+    }
+
+    /// Describe the results of a request for the list of catalogs
+    async fn get_flight_info_catalogs(
+        &self,
+        query: CommandGetCatalogs,
+        descriptor: FlightDescriptor,
+    ) -> Result<FlightInfo, Status> {
+        metadata_flight_info(&CommandGetCatalogs::schema(), &query, descriptor)
+    }
+
+    /// Describe the results of a request for the list of database schemas
+    async fn get_flight_info_schemas(
+        &self,
+        query: CommandGetDbSchemas,
+        descriptor: FlightDescriptor,
+    ) -> Result<FlightInfo, Status> {
+        metadata_flight_info(&CommandGetDbSchemas::schema(), &query, descriptor)
+    }
+
+    /// Describe the results of a request for the list of tables
+    async fn get_flight_info_tables(
+        &self,
+        query: CommandGetTables,
+        descriptor: FlightDescriptor,
+    ) -> Result<FlightInfo, Status> {
+        metadata_flight_info(&query.schema(), &query, descriptor)
+    }
+
+    /// Describe the results of a request for the list of table types
+    async fn get_flight_info_table_types(
+        &self,
+        query: CommandGetTableTypes,
+        descriptor: FlightDescriptor,
+    ) -> Result<FlightInfo, Status> {
+        metadata_flight_info(&CommandGetTableTypes::schema(), &query, descriptor)
+    }
+
+    /// Fetch the results of a query planned by `get_flight_info_statement`
+    async fn do_get_statement(
+        &self,
+        _ticket: TicketStatementQuery,
+    ) -> Result<(SchemaRef, SendableRecordBatchStream), Status> {
+        Err(Status::unimplemented("do_get_statement is not implemented"))
+    }
+
+    /// Fetch the results of a prepared statement
+    async fn do_get_prepared_statement(
+        &self,
+        _query: CommandPreparedStatementQuery,
+    ) -> Result<(SchemaRef, SendableRecordBatchStream), Status> {
+        Err(Status::unimplemented(
+            "do_get_prepared_statement is not implemented",
+        ))
+    }
+
+    /// Fetch the list of catalogs
+    async fn do_get_catalogs(
+        &self,
+        _query: CommandGetCatalogs,
+    ) -> Result<(SchemaRef, SendableRecordBatchStream), Status> {
+        Err(Status::unimplemented("do_get_catalogs is not implemented"))
+    }
+
+    /// Fetch the list of database schemas
+    async fn do_get_schemas(
+        &self,
+        _query: CommandGetDbSchemas,
+    ) -> Result<(SchemaRef, SendableRecordBatchStream), Status> {
+        Err(Status::unimplemented("do_get_schemas is not implemented"))
+    }
+
+    /// Fetch the list of tables
+    async fn do_get_tables(
+        &self,
+        _query: CommandGetTables,
+    ) -> Result<(SchemaRef, SendableRecordBatchStream), Status> {
+        Err(Status::unimplemented("do_get_tables is not implemented"))
+    }
+
+    /// Fetch the list of table types
+    async fn do_get_table_types(
+        &self,
+        _query: CommandGetTableTypes,
+    ) -> Result<(SchemaRef, SendableRecordBatchStream), Status> {
+        Err(Status::unimplemented(
+            "do_get_table_types is not implemented",
+        ))
+    }
+
+    /// Execute a SQL update, returning the number of records affected or -1
+    /// if unknown
+    async fn do_put_statement_update(
+        &self,
+        _command: CommandStatementUpdate,
+    ) -> Result<i64, Status> {
+        Err(Status::unimplemented(
+            "do_put_statement_update is not implemented",
+        ))
+    }
+
+    /// Execute a prepared update, with parameters bound by `batches`
+    async fn do_put_prepared_statement_update(
+        &self,
+        _command: CommandPreparedStatementUpdate,
+        _batches: FlightRecordBatchStream,
+    ) -> Result<i64, Status> {
+        Err(Status::unimplemented(
+            "do_put_prepared_statement_update is not implemented",
+        ))
+    }
+
+    /// Create a prepared statement
+    async fn create_prepared_statement(
+        &self,
+        _request: ActionCreatePreparedStatementRequest,
+    ) -> Result<ActionCreatePreparedStatementResult, Status> {
+        Err(Status::unimplemented(
+            "create_prepared_statement is not implemented",
+        ))
+    }
+
+    /// Release a prepared statement
+    async fn close_prepared_statement(
+        &self,
+        _request: ActionClosePreparedStatementRequest,
+    ) -> Result<(), Status> {
+        Err(Status::unimplemented(
+            "close_prepared_statement is not implemented",
+        ))
+    }
+}
+
+/// Adapts a [`FlightSqlService`] to an [`ArrowFlightService`], decoding the
+/// Flight SQL commands of each request and dispatching them to the service.
+#[derive(Debug, Clone)]
+pub struct FlightSqlServer<T> {
+    service: T,
+}
+
+impl<T: FlightSqlService> FlightSqlServer<T> {
+    /// Create a new adapter for `service`
+    pub fn new(service: T) -> Self {
+        Self { service }
+    }
+
+    /// Wrap the adapter in a generated server, ready to add to a tonic `Router`
+    pub fn into_service(self) -> FlightServiceServer<ArrowFlightServer<Self>> {
+        ArrowFlightServer::new(self).into_service()
+    }
+}
+
+#[tonic::async_trait]
+impl<T: FlightSqlService> ArrowFlightService for FlightSqlServer<T> {
+    async fn get_flight_info(
+        &self,
+        descriptor: FlightDescriptor,
+    ) -> Result<FlightInfo, Status> {
+        let service = &self.service;
+        match decode_command(&descriptor.cmd)? {
+            Command::StatementQuery(cmd) => {
+                service.get_flight_info_statement(cmd, descriptor).await
+            }
+            Command::PreparedStatementQuery(cmd) => {
+                service
+                    .get_flight_info_prepared_statement(cmd, descriptor)
+                    .await
+            }
+            Command::GetCatalogs(cmd) => {
+                service.get_flight_info_catalogs(cmd, descriptor).await
+            }
+            Command::GetDbSchemas(cmd) => {
+                service.get_flight_info_schemas(cmd, descriptor).await
+            }
+            Command::GetTables(cmd) => {
+                service.get_flight_info_tables(cmd, descriptor).await
+            }
+            Command::GetTableTypes(cmd) => {
+                service.get_flight_info_table_types(cmd, descriptor).await
+            }
+            cmd => Err(Status::invalid_argument(format!(
+                "{:?} is not a valid GetFlightInfo command",
+                cmd
+            ))),
+        }
+    }
+
+    async fn get_schema(
+        &self,
+        descriptor: FlightDescriptor,
+    ) -> Result<SchemaRef, Status> {
+        let info = self.get_flight_info(descriptor).await?;
+        let schema = Schema::try_from(&info).map_err(|e| {
+            Status::internal(format!("Invalid schema in FlightInfo: {}", e))
+        })?;
+        Ok(SchemaRef::new(schema))
+    }
+
+    async fn do_get(
+        &self,
+        ticket: Ticket,
+    ) -> Result<(SchemaRef, SendableRecordBatchStream), Status> {
+        let service = &self.service;
+        match decode_command(&ticket.ticket)? {
+            Command::TicketStatementQuery(cmd) => service.do_get_statement(cmd).await,
+            Command::PreparedStatementQuery(cmd) => {
+                service.do_get_prepared_statement(cmd).await
+            }
+            Command::GetCatalogs(cmd) => service.do_get_catalogs(cmd).await,
+            Command::GetDbSchemas(cmd) => service.do_get_schemas(cmd).await,
+            Command::GetTables(cmd) => service.do_get_tables(cmd).await,
+            Command::GetTableTypes(cmd) => service.do_get_table_types(cmd).await,
+            cmd => Err(Status::invalid_argument(format!(
+                "{:?} is not a valid DoGet ticket",
+                cmd
+            ))),
+        }
+    }
+
+    async fn do_put(
+        &self,
+        descriptor: FlightDescriptor,
+        batches: FlightRecordBatchStream,
+    ) -> Result<Vec<PutResult>, Status> {
+        let record_count = match decode_command(&descriptor.cmd)? {
+            Command::StatementUpdate(cmd) => {
+                self.service.do_put_statement_update(cmd).await?
+            }
+            Command::PreparedStatementUpdate(cmd) => {
+                self.service
+                    .do_put_prepared_statement_update(cmd, batches)
+                    .await?
+            }
+            cmd => {
+                return Err(Status::invalid_argument(format!(
+                    "{:?} is not a valid DoPut command",
+                    cmd
+                )))
+            }
+        };
+        let result = DoPutUpdateResult { record_count };
+        Ok(vec![PutResult {
+            app_metadata: super::encode_message(&result),
+        }])
+    }
+
+    async fn do_action(&self, action: Action) -> Result<Vec<crate::Result>, Status> {
+        match action.r#type.as_str() {
+            CREATE_PREPARED_STATEMENT => {
+                let request = unpack_action_body(&action.body)?;
+                let result = self.service.create_prepared_statement(request).await?;
+                Ok(vec![crate::Result {
+                    body: result.as_any().encode_to_vec(),
+                }])
+            }
+            CLOSE_PREPARED_STATEMENT => {
+                let request = unpack_action_body(&action.body)?;
+                self.service.close_prepared_statement(request).await?;
+                Ok(vec![])
+            }
+            other => Err(Status::invalid_argument(format!(
+                "Unsupported action type: {}",
+                other
+            ))),
+        }
+    }
+
+    async fn list_actions(&self) -> Result<Vec<ActionType>, Status> {
+        Ok(vec![
+            ActionType {
+                r#type: CREATE_PREPARED_STATEMENT.to_string(),
+                description: "Creates a reusable prepared statement resource on the \
+                              server.\nRequest Message: \
+                              ActionCreatePreparedStatementRequest\nResponse Message: \
+                              ActionCreatePreparedStatementResult"
+                    .to_string(),
+            },
+            ActionType {
+                r#type: CLOSE_PREPARED_STATEMENT.to_string(),
+                description: "Closes a reusable prepared statement resource on the \
+                              server.\nRequest Message: \
+                              ActionClosePreparedStatementRequest\nResponse Message: N/A"
+                    .to_string(),
+            },
+        ])
+    }
+}
+
+/// Build the `FlightInfo` of a Flight SQL command whose results are fetched
+/// by passing the command itself as the ticket.
+///
+/// This is useful to implement the `get_flight_info_*` handlers of requests
+/// that need no planning.
+pub fn metadata_flight_info<M: ProstMessageExt>(
+    schema: &Schema,
+    command: &M,
+    descriptor: FlightDescriptor,
+) -> Result<FlightInfo, Status> {
+    let schema = ipc_message_from_arrow_schema(schema, &IpcWriteOptions::default())
+        .map_err(|e| Status::internal(e.to_string()))?;
+    Ok(FlightInfo {
+        schema,
+        flight_descriptor: Some(descriptor),
+        endpoint: vec![FlightEndpoint {
+            ticket: Some(Ticket {
+                ticket: command.as_any().encode_to_vec(),
+            }),
+            location: vec![],
+        }],
+        total_records: -1,
+        total_bytes: -1,
+    })
+}
+
+fn decode_command(bytes: &[u8]) -> Result<Command, Status> {
+    Command::try_from(bytes).map_err(|e| Status::invalid_argument(e.to_string()))
+}
+
+fn unpack_action_body<M: ProstMessageExt>(body: &[u8]) -> Result<M, Status> {
+    Any::decode_from(body)
+        .and_then(|any| any.unpack())
+        .map_err(|e| Status::invalid_argument(e.to_string()))?
+        .ok_or_else(|| Status::invalid_argument("Unexpected action body"))
+}
@@ -17,18 +17,30 @@
 
 //! Utilities to assist with reading and writing Arrow data as Flight messages
 
+use std::collections::VecDeque;
 use std::convert::TryFrom;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
 
-use crate::{FlightData, SchemaResult};
+use futures::{Stream, StreamExt};
 
-use arrow::array::ArrayRef;
+use crate::{FlightData, FlightDescriptor, FlightInfo, SchemaResult};
+
+use arrow::array::{make_array, ArrayRef, MutableArrayData};
 use arrow::datatypes::{Schema, SchemaRef};
 use arrow::error::{ArrowError, Result};
-use arrow::ipc::{convert, reader, writer, writer::EncodedData, writer::IpcWriteOptions};
+use arrow::ipc::{
+    convert, reader, writer, writer::EncodedData, writer::IpcWriteOptions, MessageHeader,
+};
 use arrow::record_batch::RecordBatch;
 
 /// Convert a `RecordBatch` to a vector of `FlightData` representing the bytes of the dictionaries
 /// and a `FlightData` representing the bytes of the batch's values
+///
+/// All the dictionaries of the batch are returned on every call; use a
+/// [`FlightDataEncoder`] to only send the dictionaries that changed, and to
+/// split large batches.
 pub fn flight_data_from_arrow_batch(
     batch: &RecordBatch,
     options: &IpcWriteOptions,
@@ -136,7 +148,42 @@ impl TryFrom<&SchemaResult> for Schema {
     }
 }
 
+/// Try convert the schema of a `FlightInfo` into an Arrow Schema
+///
+/// Returns an error if `FlightInfo.schema` is not a valid IPC schema message
+impl TryFrom<&FlightInfo> for Schema {
+    type Error = ArrowError;
+    fn try_from(info: &FlightInfo) -> Result<Self> {
+        schema_from_ipc_message(&info.schema)
+    }
+}
+
+/// Read a `Schema` from an encapsulated IPC message, in the format written by
+/// [`ipc_message_from_arrow_schema`]
+pub fn schema_from_ipc_message(bytes: &[u8]) -> Result<Schema> {
+    // skip the optional continuation marker and the message length
+    let offset = if bytes.len() >= 4 && bytes[..4] == [0xff; 4] {
+        8
+    } else {
+        4
+    };
+    if bytes.len() < offset {
+        return Err(ArrowError::ParseError(
+            "Unable to read Arrow schema from IPC message: message too short".to_string(),
+        ));
+    }
+    convert::schema_from_bytes(&bytes[offset..]).map_err(|err| {
+        ArrowError::ParseError(format!(
+            "Unable to read Arrow schema from IPC message: {}",
+            err
+        ))
+    })
+}
+
 /// Convert a FlightData message to a RecordBatch
+///
+/// The dictionaries of the batch must already be decoded; a
+/// [`FlightDataDecoder`] applies dictionary messages as they are received.
 pub fn flight_data_to_arrow_batch(
     data: &FlightData,
     schema: SchemaRef,
@@ -164,4 +211,558 @@ pub fn flight_data_to_arrow_batch(
         })?
 }
 
+/// The default maximum size of the `FlightData` messages produced by a
+/// [`FlightDataEncoder`], which is below the 4MB default message size limit
+/// of gRPC implementations
+pub const DEFAULT_MAX_FLIGHT_DATA_SIZE: usize = 2 * 1024 * 1024;
+
+/// Stateful encoder of Arrow data into a sequence of `FlightData` messages.
+///
+/// Unlike [`flight_data_from_arrow_batch`], the encoder keeps track of the
+/// dictionaries it has already sent, so that a dictionary shared by several
+/// batches of a stream is only transmitted once. Batches larger than the
+/// maximum message size are split into several record batch messages.
+#[derive(Debug)]
+pub struct FlightDataEncoder {
+    data_gen: writer::IpcDataGenerator,
+    dictionary_tracker: writer::DictionaryTracker,
+    options: IpcWriteOptions,
+    max_flight_data_size: usize,
+}
+
+impl FlightDataEncoder {
+    /// Create a new encoder with the given IPC write options
+    pub fn new(options: IpcWriteOptions) -> Self {
+        Self {
+            data_gen: writer::IpcDataGenerator::default(),
+            dictionary_tracker: writer::DictionaryTracker::new(false),
+            options,
+            max_flight_data_size: DEFAULT_MAX_FLIGHT_DATA_SIZE,
+        }
+    }
+
+    /// Set the target maximum size of the record batch messages.
+    ///
+    /// This is a best effort limit: a batch is split into slices of equal
+    /// numbers of rows, and a single row or dictionary may exceed it.
+    pub fn with_max_flight_data_size(mut self, max_flight_data_size: usize) -> Self {
+        self.max_flight_data_size = max_flight_data_size;
+        self
+    }
+
+    /// Encode the schema message that starts every Flight data stream
+    pub fn encode_schema(&self, schema: &Schema) -> FlightData {
+        flight_data_from_arrow_schema(schema, &self.options)
+    }
+
+    /// Encode a record batch, preceded by any dictionaries that have not yet
+    /// been sent by this encoder
+    pub fn encode_batch(&mut self, batch: &RecordBatch) -> Result<Vec<FlightData>> {
+        let mut messages = vec![];
+        for batch in split_batch_for_grpc_response(batch, self.max_flight_data_size) {
+            let (encoded_dictionaries, encoded_batch) = self.data_gen.encoded_batch(
+                &batch,
+                &mut self.dictionary_tracker,
+                &self.options,
+            )?;
+            messages.extend(encoded_dictionaries.into_iter().map(Into::into));
+            messages.push(encoded_batch.into());
+        }
+        Ok(messages)
+    }
+}
+
+impl Default for FlightDataEncoder {
+    fn default() -> Self {
+        Self::new(IpcWriteOptions::default())
+    }
+}
+
+/// Split a record batch into batches whose in-memory size is about
+/// `max_flight_data_size` bytes or less.
+///
+/// The returned batches do not share buffers with `batch` at an offset, as
+/// the IPC writer can only encode arrays that start at offset zero.
+pub fn split_batch_for_grpc_response(
+    batch: &RecordBatch,
+    max_flight_data_size: usize,
+) -> Vec<RecordBatch> {
+    let size: usize = batch
+        .columns()
+        .iter()
+        .map(|column| column.get_array_memory_size())
+        .sum();
+    let num_rows = batch.num_rows();
+    let num_batches = (size / max_flight_data_size.max(1) + 1).min(num_rows.max(1));
+    if num_batches == 1 {
+        return vec![compact_batch(batch, 0, num_rows)];
+    }
+
+    let rows_per_batch = (num_rows + num_batches - 1) / num_batches;
+    (0..num_rows)
+        .step_by(rows_per_batch)
+        .map(|offset| compact_batch(batch, offset, rows_per_batch.min(num_rows - offset)))
+        .collect()
+}
+
+/// Return the rows `offset..offset + length` of `batch`, copying any column
+/// that does not start at offset zero
+fn compact_batch(batch: &RecordBatch, offset: usize, length: usize) -> RecordBatch {
+    if offset == 0
+        && length == batch.num_rows()
+        && batch.columns().iter().all(|column| column.offset() == 0)
+    {
+        return batch.clone();
+    }
+    let columns = batch
+        .columns()
+        .iter()
+        .map(|column| {
+            let data = column.data();
+            let mut mutable = MutableArrayData::new(vec![data.as_ref()], false, length);
+            mutable.extend(0, offset, offset + length);
+            make_array(Arc::new(mutable.freeze()))
+        })
+        .collect();
+    RecordBatch::try_new(batch.schema(), columns)
+        .expect("slicing a valid record batch cannot fail")
+}
+
+/// Stateful decoder of a stream of `FlightData` messages into record batches.
+///
+/// The first message of a stream must be a schema; dictionary batches update
+/// the decoder's state and record batch messages are decoded against it.
+#[derive(Debug, Default)]
+pub struct FlightDataDecoder {
+    schema: Option<SchemaRef>,
+    dictionaries_by_field: Vec<Option<ArrayRef>>,
+}
+
+impl FlightDataDecoder {
+    /// Create a new decoder that expects a schema message first
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a decoder for a stream whose schema is already known
+    pub fn with_schema(schema: SchemaRef) -> Self {
+        Self {
+            dictionaries_by_field: vec![None; schema.fields().len()],
+            schema: Some(schema),
+        }
+    }
+
+    /// The schema of the stream, if a schema message has been decoded
+    pub fn schema(&self) -> Option<&SchemaRef> {
+        self.schema.as_ref()
+    }
+
+    /// Decode a single message, returning a record batch if the message
+    /// contained one.
+    ///
+    /// Schema and dictionary messages only update the decoder's state and
+    /// return `None`.
+    pub fn decode(&mut self, data: &FlightData) -> Result<Option<RecordBatch>> {
+        let message =
+            arrow::ipc::root_as_message(&data.data_header[..]).map_err(|err| {
+                ArrowError::ParseError(format!(
+                    "Unable to get root as message: {:?}",
+                    err
+                ))
+            })?;
+
+        match message.header_type() {
+            MessageHeader::Schema => {
+                let schema = Arc::new(Schema::try_from(data)?);
+                *self = Self::with_schema(schema);
+                Ok(None)
+            }
+            MessageHeader::DictionaryBatch => {
+                let schema = self.schema.as_ref().ok_or_else(|| {
+                    ArrowError::ParseError(
+                        "Received a dictionary batch before the schema".to_string(),
+                    )
+                })?;
+                let batch = message.header_as_dictionary_batch().ok_or_else(|| {
+                    ArrowError::ParseError(
+                        "Unable to convert flight data header to a dictionary batch"
+                            .to_string(),
+                    )
+                })?;
+                reader::read_dictionary(
+                    &data.data_body,
+                    batch,
+                    schema,
+                    &mut self.dictionaries_by_field,
+                )?;
+                Ok(None)
+            }
+            MessageHeader::RecordBatch => {
+                let schema = self.schema.clone().ok_or_else(|| {
+                    ArrowError::ParseError(
+                        "Received a record batch before the schema".to_string(),
+                    )
+                })?;
+                flight_data_to_arrow_batch(data, schema, &self.dictionaries_by_field)
+                    .map(Some)
+            }
+            MessageHeader::NONE => Ok(None),
+            other => Err(ArrowError::ParseError(format!(
+                "Unsupported flight message type {:?}",
+                other
+            ))),
+        }
+    }
+}
+
+/// Adapts a stream of record batches into the stream of `FlightData`
+/// messages encoding them, starting with the schema.
+///
+/// Dictionaries are sent before the first batch that uses them and again
+/// whenever they change. The stream ends after the first error.
+pub struct FlightDataEncoderStream<S> {
+    inner: S,
+    encoder: FlightDataEncoder,
+    queue: VecDeque<FlightData>,
+    done: bool,
+}
+
+impl<S> FlightDataEncoderStream<S>
+where
+    S: Stream<Item = Result<RecordBatch>> + Unpin,
+{
+    /// Create a stream encoding `batches`, whose schema is `schema`
+    pub fn new(schema: &Schema, batches: S, encoder: FlightDataEncoder) -> Self {
+        let mut queue = VecDeque::new();
+        queue.push_back(encoder.encode_schema(schema));
+        Self {
+            inner: batches,
+            encoder,
+            queue,
+            done: false,
+        }
+    }
+
+    /// Attach a flight descriptor to the schema message, as expected by
+    /// `DoPut`
+    pub fn with_flight_descriptor(mut self, descriptor: FlightDescriptor) -> Self {
+        if let Some(schema) = self.queue.front_mut() {
+            schema.flight_descriptor = Some(descriptor);
+        }
+        self
+    }
+}
+
+impl<S> Stream for FlightDataEncoderStream<S>
+where
+    S: Stream<Item = Result<RecordBatch>> + Unpin,
+{
+    type Item = Result<FlightData>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            if let Some(data) = self.queue.pop_front() {
+                return Poll::Ready(Some(Ok(data)));
+            }
+            if self.done {
+                return Poll::Ready(None);
+            }
+            let batch = match Pin::new(&mut self.inner).poll_next(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(None) => {
+                    self.done = true;
+                    continue;
+                }
+                Poll::Ready(Some(batch)) => batch,
+            };
+            match batch.and_then(|batch| self.encoder.encode_batch(&batch)) {
+                Ok(messages) => self.queue.extend(messages),
+                Err(e) => {
+                    self.done = true;
+                    return Poll::Ready(Some(Err(e)));
+                }
+            }
+        }
+    }
+}
+
+/// Adapts a stream of `FlightData` messages into the record batches they
+/// encode.
+///
+/// Schema and dictionary messages update the state of the stream and do not
+/// produce a batch.
+pub struct FlightDataDecoderStream<S> {
+    inner: S,
+    decoder: FlightDataDecoder,
+}
+
+impl<S> FlightDataDecoderStream<S>
+where
+    S: Stream<Item = Result<FlightData>> + Unpin,
+{
+    /// Create a stream decoding `messages`, the first of which must be the
+    /// schema
+    pub fn new(messages: S) -> Self {
+        Self::with_decoder(messages, FlightDataDecoder::new())
+    }
+
+    /// Create a stream decoding `messages` with an existing decoder, for
+    /// example one created for a known schema
+    pub fn with_decoder(messages: S, decoder: FlightDataDecoder) -> Self {
+        Self {
+            inner: messages,
+            decoder,
+        }
+    }
+
+    /// The schema of the stream, if it has been received
+    pub fn schema(&self) -> Option<&SchemaRef> {
+        self.decoder.schema()
+    }
+
+    /// Read messages until the schema is known, returning an error if the
+    /// stream ends first
+    pub async fn read_schema(&mut self) -> Result<SchemaRef> {
+        while self.decoder.schema().is_none() {
+            let data = self.inner.next().await.ok_or_else(|| {
+                ArrowError::ParseError(
+                    "Flight data stream ended before its schema".to_string(),
+                )
+            })??;
+            // a record batch received before the schema is an error
+            self.decoder.decode(&data)?;
+        }
+        Ok(self.decoder.schema().cloned().unwrap())
+    }
+}
+
+impl<S> Stream for FlightDataDecoderStream<S>
+where
+    S: Stream<Item = Result<FlightData>> + Unpin,
+{
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            let data = match Pin::new(&mut self.inner).poll_next(cx) {
+                Poll::Pending => return Poll::Pending,
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(Some(Ok(data))) => data,
+            };
+            match self.decoder.decode(&data) {
+                Ok(None) => continue,
+                Ok(Some(batch)) => return Poll::Ready(Some(Ok(batch))),
+                Err(e) => return Poll::Ready(Some(Err(e))),
+            }
+        }
+    }
+}
+
 // TODO: add more explicit conversion that exposes flight descriptor and metadata options
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::collections::HashMap;
+
+    use arrow::array::{DictionaryArray, Int32Array, StringArray};
+    use arrow::datatypes::{DataType, Field, Int32Type};
+    use futures::{stream, TryStreamExt};
+
+    fn header_type(data: &FlightData) -> MessageHeader {
+        arrow::ipc::root_as_message(&data.data_header[..])
+            .unwrap()
+            .header_type()
+    }
+
+    async fn round_trip(
+        schema: &Schema,
+        batches: Vec<RecordBatch>,
+        encoder: FlightDataEncoder,
+    ) -> (Vec<FlightData>, SchemaRef, Vec<RecordBatch>) {
+        let messages: Vec<FlightData> = FlightDataEncoderStream::new(
+            schema,
+            stream::iter(batches.into_iter().map(Ok)),
+            encoder,
+        )
+        .try_collect()
+        .await
+        .unwrap();
+
+        let mut decoder = FlightDataDecoderStream::new(stream::iter(
+            messages.clone().into_iter().map(Ok),
+        ));
+        let schema = decoder.read_schema().await.unwrap();
+        let decoded = decoder.try_collect().await.unwrap();
+        (messages, schema, decoded)
+    }
+
+    fn assert_batches_eq(expected: &[RecordBatch], actual: &[RecordBatch]) {
+        assert_eq!(expected.len(), actual.len());
+        for (expected, actual) in expected.iter().zip(actual) {
+            // array equality does not support null bitmaps at an offset
+            let expected = compact_batch(expected, 0, expected.num_rows());
+            assert_eq!(expected.schema(), actual.schema());
+            for (expected, actual) in expected.columns().iter().zip(actual.columns()) {
+                assert_eq!(expected.data(), actual.data());
+            }
+        }
+    }
+
+    fn dictionary_batch(schema: &SchemaRef, keys: Vec<&str>) -> RecordBatch {
+        let dictionary: DictionaryArray<Int32Type> = keys.into_iter().collect();
+        RecordBatch::try_new(schema.clone(), vec![Arc::new(dictionary)]).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_dictionary_round_trip() {
+        let schema = Arc::new(Schema::new(vec![Field::new_dict(
+            "dict",
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+            true,
+            0,
+            false,
+        )]));
+        let batches = vec![
+            dictionary_batch(&schema, vec!["a", "b", "a"]),
+            dictionary_batch(&schema, vec!["a", "b", "b"]),
+            dictionary_batch(&schema, vec!["c", "c"]),
+        ];
+
+        let (messages, decoded_schema, decoded) =
+            round_trip(&schema, batches.clone(), FlightDataEncoder::default()).await;
+
+        // the second batch has the same dictionary as the first
+        let headers: Vec<_> = messages.iter().map(header_type).collect();
+        assert_eq!(
+            headers,
+            vec![
+                MessageHeader::Schema,
+                MessageHeader::DictionaryBatch,
+                MessageHeader::RecordBatch,
+                MessageHeader::RecordBatch,
+                MessageHeader::DictionaryBatch,
+                MessageHeader::RecordBatch,
+            ]
+        );
+        assert_eq!(decoded_schema, schema);
+        assert_batches_eq(&batches, &decoded);
+    }
+
+    #[tokio::test]
+    async fn test_split_large_batch() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let values: Vec<i32> = (0..10_000).collect();
+        let strings: Vec<Option<String>> = values
+            .iter()
+            .map(|v| {
+                if v % 3 == 0 {
+                    None
+                } else {
+                    Some(v.to_string())
+                }
+            })
+            .collect();
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(values)),
+                Arc::new(StringArray::from(
+                    strings.iter().map(|s| s.as_deref()).collect::<Vec<_>>(),
+                )),
+            ],
+        )
+        .unwrap();
+
+        let encoder = FlightDataEncoder::default().with_max_flight_data_size(16 * 1024);
+        let (messages, _, decoded) =
+            round_trip(&schema, vec![batch.clone()], encoder).await;
+
+        assert!(decoded.len() > 1);
+        for data in &messages[1..] {
+            assert!(
+                data.data_body.len() <= 16 * 1024,
+                "{}",
+                data.data_body.len()
+            );
+        }
+        let rows: usize = decoded.iter().map(|b| b.num_rows()).sum();
+        assert_eq!(rows, batch.num_rows());
+        let mut offset = 0;
+        for decoded in &decoded {
+            assert_batches_eq(
+                &[batch.slice(offset, decoded.num_rows())],
+                std::slice::from_ref(decoded),
+            );
+            offset += decoded.num_rows();
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sliced_batch() {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(vec![
+                Some(1),
+                None,
+                Some(3),
+                Some(4),
+                None,
+            ]))],
+        )
+        .unwrap();
+        let sliced = batch.slice(1, 3);
+
+        let (_, _, decoded) =
+            round_trip(&schema, vec![sliced.clone()], FlightDataEncoder::default()).await;
+        assert_batches_eq(&[sliced], &decoded);
+    }
+
+    #[tokio::test]
+    async fn test_schema_metadata() {
+        let mut field_metadata = std::collections::BTreeMap::new();
+        field_metadata.insert("unit".to_string(), "seconds".to_string());
+        let mut field = Field::new("a", DataType::Int32, false);
+        field.set_metadata(Some(field_metadata));
+        let mut metadata = HashMap::new();
+        metadata.insert("source".to_string(), "test".to_string());
+        let schema = Arc::new(Schema::new_with_metadata(vec![field], metadata));
+
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(Int32Array::from(vec![1, 2]))],
+        )
+        .unwrap();
+        let (_, decoded_schema, decoded) =
+            round_trip(&schema, vec![batch], FlightDataEncoder::default()).await;
+
+        assert_eq!(decoded_schema, schema);
+        assert_eq!(decoded[0].schema(), schema);
+    }
+
+    #[test]
+    fn test_decode_batch_before_schema() {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, false)]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![Arc::new(Int32Array::from(vec![1]))],
+        )
+        .unwrap();
+        let messages = FlightDataEncoder::default().encode_batch(&batch).unwrap();
+
+        let err = FlightDataDecoder::new().decode(&messages[0]).unwrap_err();
+        assert!(err.to_string().contains("before the schema"));
+    }
+}
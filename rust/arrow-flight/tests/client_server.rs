@@ -0,0 +1,331 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Round-trip tests of `FlightClient` against an in-process `ArrowFlightServer`
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+
+use futures::{stream, StreamExt, TryStreamExt};
+use tonic::transport::Server;
+use tonic::Status;
+
+use arrow::array::{ArrayRef, DictionaryArray, Int32Array, StringArray};
+use arrow::datatypes::{DataType, Field, Int32Type, Schema, SchemaRef};
+use arrow::ipc::writer::IpcWriteOptions;
+use arrow::record_batch::RecordBatch;
+
+use arrow_flight::client::{FlightClient, FlightRecordBatchStream};
+use arrow_flight::flight_descriptor::DescriptorType;
+use arrow_flight::server::{
+    ArrowFlightServer, ArrowFlightService, SendableRecordBatchStream,
+};
+use arrow_flight::utils::ipc_message_from_arrow_schema;
+use arrow_flight::{
+    Criteria, FlightDescriptor, FlightEndpoint, FlightInfo, PutResult, Ticket,
+};
+
+/// A service that stores uploaded flights in memory, keyed by descriptor path
+#[derive(Default)]
+struct InMemoryService {
+    flights: Mutex<HashMap<String, (SchemaRef, Vec<RecordBatch>)>>,
+}
+
+impl InMemoryService {
+    fn flight_info(
+        &self,
+        name: &str,
+        schema: &Schema,
+        batches: &[RecordBatch],
+    ) -> FlightInfo {
+        FlightInfo {
+            schema: ipc_message_from_arrow_schema(schema, &IpcWriteOptions::default())
+                .unwrap(),
+            flight_descriptor: Some(descriptor(name)),
+            endpoint: vec![FlightEndpoint {
+                ticket: Some(Ticket {
+                    ticket: name.as_bytes().to_vec(),
+                }),
+                location: vec![],
+            }],
+            total_records: batches.iter().map(|b| b.num_rows() as i64).sum(),
+            total_bytes: -1,
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl ArrowFlightService for InMemoryService {
+    async fn list_flights(&self, _criteria: Criteria) -> Result<Vec<FlightInfo>, Status> {
+        let flights = self.flights.lock().unwrap();
+        let mut names: Vec<_> = flights.keys().cloned().collect();
+        names.sort();
+        Ok(names
+            .iter()
+            .map(|name| {
+                let (schema, batches) = &flights[name];
+                self.flight_info(name, schema, batches)
+            })
+            .collect())
+    }
+
+    async fn get_flight_info(
+        &self,
+        descriptor: FlightDescriptor,
+    ) -> Result<FlightInfo, Status> {
+        let name = descriptor.path.join("/");
+        let flights = self.flights.lock().unwrap();
+        let (schema, batches) = flights
+            .get(&name)
+            .ok_or_else(|| Status::not_found(name.clone()))?;
+        Ok(self.flight_info(&name, schema, batches))
+    }
+
+    async fn get_schema(
+        &self,
+        descriptor: FlightDescriptor,
+    ) -> Result<SchemaRef, Status> {
+        let name = descriptor.path.join("/");
+        let flights = self.flights.lock().unwrap();
+        let (schema, _) = flights.get(&name).ok_or_else(|| Status::not_found(name))?;
+        Ok(schema.clone())
+    }
+
+    async fn do_get(
+        &self,
+        ticket: Ticket,
+    ) -> Result<(SchemaRef, SendableRecordBatchStream), Status> {
+        let name = String::from_utf8(ticket.ticket).unwrap();
+        let flights = self.flights.lock().unwrap();
+        let (schema, batches) = flights
+            .get(&name)
+            .cloned()
+            .ok_or_else(|| Status::not_found(name))?;
+        Ok((schema, Box::pin(stream::iter(batches.into_iter().map(Ok)))))
+    }
+
+    async fn do_put(
+        &self,
+        descriptor: FlightDescriptor,
+        batches: FlightRecordBatchStream,
+    ) -> Result<Vec<PutResult>, Status> {
+        let schema = batches.schema();
+        let batches: Vec<RecordBatch> = batches
+            .try_collect()
+            .await
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+        let results = batches
+            .iter()
+            .map(|batch| PutResult {
+                app_metadata: batch.num_rows().to_string().into_bytes(),
+            })
+            .collect();
+        self.flights
+            .lock()
+            .unwrap()
+            .insert(descriptor.path.join("/"), (schema, batches));
+        Ok(results)
+    }
+}
+
+fn descriptor(name: &str) -> FlightDescriptor {
+    FlightDescriptor {
+        r#type: DescriptorType::Path as i32,
+        cmd: vec![],
+        path: name.split('/').map(|s| s.to_string()).collect(),
+    }
+}
+
+fn test_batches() -> (SchemaRef, Vec<RecordBatch>) {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int32, false),
+        Field::new("name", DataType::Utf8, true),
+        Field::new_dict(
+            "category",
+            DataType::Dictionary(Box::new(DataType::Int32), Box::new(DataType::Utf8)),
+            true,
+            0,
+            false,
+        ),
+    ]));
+
+    let batch = |ids: Vec<i32>, names: Vec<Option<&str>>, categories: Vec<&str>| {
+        let categories: DictionaryArray<Int32Type> = categories.into_iter().collect();
+        RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(ids)) as ArrayRef,
+                Arc::new(StringArray::from(names)),
+                Arc::new(categories),
+            ],
+        )
+        .unwrap()
+    };
+
+    let batches = vec![
+        batch(
+            vec![1, 2, 3],
+            vec![Some("a"), None, Some("c")],
+            vec!["x", "y", "x"],
+        ),
+        batch(vec![4, 5], vec![Some("d"), Some("e")], vec!["z", "z"]),
+    ];
+    (schema, batches)
+}
+
+async fn start_server() -> FlightClient {
+    let mut listener =
+        tokio::net::TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+            .await
+            .unwrap();
+    let addr = listener.local_addr().unwrap();
+    let service = ArrowFlightServer::new(InMemoryService::default()).into_service();
+    tokio::spawn(async move {
+        let incoming = listener.incoming();
+        Server::builder()
+            .add_service(service)
+            .serve_with_incoming(incoming)
+            .await
+            .unwrap();
+    });
+
+    FlightClient::connect(format!("http://{}", addr))
+        .await
+        .unwrap()
+}
+
+fn assert_batches_eq(expected: &[RecordBatch], actual: &[RecordBatch]) {
+    assert_eq!(expected.len(), actual.len());
+    for (expected, actual) in expected.iter().zip(actual) {
+        assert_eq!(expected.schema(), actual.schema());
+        for i in 0..expected.num_columns() {
+            assert_eq!(
+                expected.column(i).data(),
+                actual.column(i).data(),
+                "column {} differs",
+                i
+            );
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_put_then_get() {
+    let mut client = start_server().await;
+    let (schema, batches) = test_batches();
+
+    let results = client
+        .do_put(
+            descriptor("data/test"),
+            schema.clone(),
+            stream::iter(batches.clone()),
+        )
+        .await
+        .unwrap();
+    let rows: Vec<_> = results
+        .iter()
+        .map(|r| String::from_utf8(r.app_metadata.clone()).unwrap())
+        .collect();
+    assert_eq!(rows, vec!["3", "2"]);
+
+    let info = client
+        .get_flight_info(descriptor("data/test"))
+        .await
+        .unwrap();
+    assert_eq!(info.total_records, 5);
+    let ticket = info.endpoint[0].ticket.clone().unwrap();
+
+    let stream = client.do_get(ticket).await.unwrap();
+    assert_eq!(stream.schema(), schema);
+    let received: Vec<RecordBatch> = stream.try_collect().await.unwrap();
+    assert_batches_eq(&batches, &received);
+
+    let dictionary = received[1]
+        .column(2)
+        .as_any()
+        .downcast_ref::<DictionaryArray<Int32Type>>()
+        .unwrap();
+    let values = dictionary.values();
+    let values = values.as_any().downcast_ref::<StringArray>().unwrap();
+    assert_eq!(values.value(0), "z");
+
+    let fetched = client.get_schema(descriptor("data/test")).await.unwrap();
+    assert_eq!(&fetched, schema.as_ref());
+}
+
+#[tokio::test]
+async fn test_list_flights() {
+    let mut client = start_server().await;
+    let (schema, batches) = test_batches();
+
+    for name in &["b", "a"] {
+        client
+            .do_put(
+                descriptor(name),
+                schema.clone(),
+                stream::iter(batches.clone()),
+            )
+            .await
+            .unwrap();
+    }
+
+    let flights = client.list_flights(Criteria::default()).await.unwrap();
+    let paths: Vec<_> = flights
+        .iter()
+        .map(|f| f.flight_descriptor.clone().unwrap().path.join("/"))
+        .collect();
+    assert_eq!(paths, vec!["a", "b"]);
+    let schema_from_info = Schema::try_from(&flights[0]).unwrap();
+    assert_eq!(&schema_from_info, schema.as_ref());
+}
+
+#[tokio::test]
+async fn test_empty_put_and_errors() {
+    let mut client = start_server().await;
+    let (schema, _) = test_batches();
+
+    let results = client
+        .do_put(descriptor("empty"), schema.clone(), stream::empty())
+        .await
+        .unwrap();
+    assert!(results.is_empty());
+
+    let stream = client
+        .do_get(Ticket {
+            ticket: b"empty".to_vec(),
+        })
+        .await
+        .unwrap();
+    assert_eq!(stream.schema(), schema);
+    assert_eq!(stream.count().await, 0);
+
+    let err = client
+        .get_flight_info(descriptor("missing"))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("missing"));
+
+    let err = client
+        .do_get(Ticket {
+            ticket: b"missing".to_vec(),
+        })
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("missing"));
+}
@@ -28,8 +28,10 @@ use pyo3::{libc::uintptr_t, prelude::*};
 
 use arrow::array::{make_array_from_raw, ArrayRef, Int64Array};
 use arrow::compute::kernels;
-use arrow::error::ArrowError;
+use arrow::datatypes::SchemaRef;
+use arrow::error::{ArrowError, Result as ArrowResult};
 use arrow::ffi;
+use arrow::record_batch::{RecordBatch, RecordBatchReader};
 
 /// an error that bridges ArrowError with a Python error
 #[derive(Debug)]
@@ -167,11 +169,79 @@ fn concatenate(array: PyObject, py: Python) -> PyResult<PyObject> {
     to_py(array, py)
 }
 
+/// Returns the array, after passing it through Rust
+#[pyfunction]
+fn round_trip(array: PyObject, py: Python) -> PyResult<PyObject> {
+    // import
+    let array = to_rust(array, py)?;
+
+    // export
+    to_py(array, py)
+}
+
+/// a `RecordBatchReader` over batches that were already read
+struct BatchesReader {
+    schema: SchemaRef,
+    batches: std::vec::IntoIter<RecordBatch>,
+}
+
+impl Iterator for BatchesReader {
+    type Item = ArrowResult<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.batches.next().map(Ok)
+    }
+}
+
+impl RecordBatchReader for BatchesReader {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+/// Returns a `pyarrow.RecordBatchReader` with the batches of `reader`, after reading them in Rust
+#[pyfunction]
+fn round_trip_record_batch_reader(reader: PyObject, py: Python) -> PyResult<PyObject> {
+    // prepare a pointer to receive the stream
+    let stream = Box::into_raw(Box::new(ffi::FFI_ArrowArrayStream::empty()));
+
+    // make the conversion through PyArrow's private API
+    reader.call_method1(py, "_export_to_c", (stream as uintptr_t,))?;
+
+    let reader = unsafe { ffi::ArrowArrayStreamReader::from_raw(stream) };
+    unsafe { drop(Box::from_raw(stream)) };
+    let reader = reader.map_err(|e| PyO3ArrowError::from(e))?;
+
+    // import all batches
+    let schema = reader.schema();
+    let batches = reader
+        .collect::<ArrowResult<Vec<_>>>()
+        .map_err(|e| PyO3ArrowError::from(e))?;
+
+    // export them back
+    let reader = BatchesReader {
+        schema,
+        batches: batches.into_iter(),
+    };
+    let stream = Box::into_raw(Box::new(ffi::FFI_ArrowArrayStream::empty()));
+    unsafe { ffi::export_reader_into_raw(Box::new(reader), stream) };
+
+    let pa = py.import("pyarrow")?;
+    let reader = pa
+        .getattr("RecordBatchReader")?
+        .call_method1("_import_from_c", (stream as uintptr_t,));
+    // pyarrow moved the stream out of the pointer, which is now released
+    unsafe { drop(Box::from_raw(stream)) };
+    Ok(reader?.to_object(py))
+}
+
 #[pymodule]
 fn arrow_pyarrow_integration_testing(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_wrapped(wrap_pyfunction!(double))?;
     m.add_wrapped(wrap_pyfunction!(double_py))?;
     m.add_wrapped(wrap_pyfunction!(substring))?;
     m.add_wrapped(wrap_pyfunction!(concatenate))?;
+    m.add_wrapped(wrap_pyfunction!(round_trip))?;
+    m.add_wrapped(wrap_pyfunction!(round_trip_record_batch_reader))?;
     Ok(())
 }
@@ -16,6 +16,8 @@
 # specific language governing permissions and limitations
 # under the License.
 
+import datetime
+import decimal
 import unittest
 
 import pyarrow
@@ -78,3 +80,60 @@ class TestCase(unittest.TestCase):
         del expected
         # No leak of C++ memory
         self.assertEqual(old_allocated, pyarrow.total_allocated_bytes())
+
+    def test_nested_and_temporal_round_trip(self):
+        """
+        Python -> Rust -> Python
+        """
+        old_allocated = pyarrow.total_allocated_bytes()
+        arrays = [
+            pyarrow.array([[1, 2], None, [3]], pyarrow.list_(pyarrow.int64())),
+            pyarrow.array([["a", "b"], None], pyarrow.large_list(pyarrow.string())),
+            pyarrow.array([[1, 2], None], pyarrow.list_(pyarrow.int32(), 2)),
+            pyarrow.array(
+                [{"a": 1, "b": "x"}, None, {"a": None, "b": "z"}],
+                pyarrow.struct([("a", pyarrow.int32()), ("b", pyarrow.string())]),
+            ),
+            pyarrow.array(["a", None, "b", "a"]).dictionary_encode(),
+            pyarrow.array(
+                [datetime.datetime(2020, 1, 1), None], pyarrow.timestamp("ms", "UTC")
+            ),
+            pyarrow.array([1, None, 3], pyarrow.duration("us")),
+            pyarrow.array([decimal.Decimal("1.23"), None], pyarrow.decimal128(5, 2)),
+            pyarrow.array([b"ab", None, b"cd"], pyarrow.binary(2)),
+        ]
+        for a in arrays:
+            b = arrow_pyarrow_integration_testing.round_trip(a)
+            self.assertEqual(b.type, a.type)
+            self.assertEqual(b, a)
+            del b
+        del a
+        del arrays
+        # No leak of C++ memory
+        self.assertEqual(old_allocated, pyarrow.total_allocated_bytes())
+
+    def test_record_batch_reader_round_trip(self):
+        """
+        Python -> Rust -> Python
+        """
+        old_allocated = pyarrow.total_allocated_bytes()
+        schema = pyarrow.schema(
+            [("a", pyarrow.int64()), ("b", pyarrow.list_(pyarrow.string()))],
+            metadata={b"key": b"value"},
+        )
+        batches = [
+            pyarrow.record_batch(
+                [pyarrow.array([1, None]), pyarrow.array([["x"], None])], schema=schema
+            ),
+            pyarrow.record_batch(
+                [pyarrow.array([3]), pyarrow.array([["y", "z"]])], schema=schema
+            ),
+        ]
+        reader = pyarrow.RecordBatchReader.from_batches(schema, batches)
+        reader = arrow_pyarrow_integration_testing.round_trip_record_batch_reader(reader)
+        self.assertEqual(reader.schema, schema)
+        self.assertEqual(reader.read_all(), pyarrow.Table.from_batches(batches))
+        del reader
+        del batches
+        # No leak of C++ memory
+        self.assertEqual(old_allocated, pyarrow.total_allocated_bytes())
@@ -0,0 +1,126 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+#[macro_use]
+extern crate criterion;
+use criterion::Criterion;
+
+use rand::distributions::{Alphanumeric, Distribution, Standard};
+use rand::Rng;
+
+use std::sync::Arc;
+
+extern crate arrow;
+
+use arrow::array::*;
+use arrow::compute::{zip, zip_falsy_scalar};
+use arrow::datatypes::*;
+use arrow::util::test_util::seedable_rng;
+
+fn create_primitive<T>(size: usize, null_density: f32) -> ArrayRef
+where
+    T: ArrowPrimitiveType,
+    Standard: Distribution<T::Native>,
+{
+    let mut rng = seedable_rng();
+
+    let array: PrimitiveArray<T> = seedable_rng()
+        .sample_iter(&Standard)
+        .take(size)
+        .map(|value| {
+            if rng.gen::<f32>() < null_density {
+                None
+            } else {
+                Some(value)
+            }
+        })
+        .collect();
+
+    Arc::new(array) as ArrayRef
+}
+
+fn create_strings(size: usize, null_density: f32) -> ArrayRef {
+    let rng = &mut seedable_rng();
+
+    let mut builder = StringBuilder::new(size);
+    for _ in 0..size {
+        if rng.gen::<f32>() < null_density {
+            builder.append_null().unwrap()
+        } else {
+            let value = rng.sample_iter(&Alphanumeric).take(4).collect::<String>();
+            builder.append_value(&value).unwrap();
+        }
+    }
+    Arc::new(builder.finish())
+}
+
+fn create_mask(size: usize, true_density: f32) -> BooleanArray {
+    let mut rng = seedable_rng();
+    (0..size)
+        .map(|_| Some(rng.gen::<f32>() < true_density))
+        .collect()
+}
+
+fn bench_zip(mask: &BooleanArray, truthy: &ArrayRef, falsy: &ArrayRef) {
+    criterion::black_box(zip(mask, truthy.as_ref(), falsy.as_ref()).unwrap());
+}
+
+fn bench_zip_falsy_scalar(mask: &BooleanArray, truthy: &ArrayRef, falsy: &ArrayRef) {
+    criterion::black_box(
+        zip_falsy_scalar(mask, truthy.as_ref(), falsy.as_ref()).unwrap(),
+    );
+}
+
+fn add_benchmark(c: &mut Criterion) {
+    let size = 4096;
+    let sparse_mask = create_mask(size, 0.1);
+    let dense_mask = create_mask(size, 0.9);
+
+    let truthy = create_primitive::<Int32Type>(size, 0.0);
+    let falsy = create_primitive::<Int32Type>(size, 0.0);
+    c.bench_function("zip i32 sparse mask 4096", |b| {
+        b.iter(|| bench_zip(&sparse_mask, &truthy, &falsy))
+    });
+    c.bench_function("zip i32 dense mask 4096", |b| {
+        b.iter(|| bench_zip(&dense_mask, &truthy, &falsy))
+    });
+
+    let truthy = create_primitive::<Int32Type>(size, 0.5);
+    let falsy = create_primitive::<Int32Type>(size, 0.5);
+    c.bench_function("zip i32 nulls 4096", |b| {
+        b.iter(|| bench_zip(&sparse_mask, &truthy, &falsy))
+    });
+
+    let scalar = create_primitive::<Int32Type>(1, 0.0);
+    c.bench_function("zip i32 falsy scalar 4096", |b| {
+        b.iter(|| bench_zip_falsy_scalar(&dense_mask, &truthy, &scalar))
+    });
+
+    let truthy = create_strings(size, 0.0);
+    let falsy = create_strings(size, 0.0);
+    c.bench_function("zip str 4096", |b| {
+        b.iter(|| bench_zip(&sparse_mask, &truthy, &falsy))
+    });
+
+    let truthy = create_strings(size, 0.5);
+    let falsy = create_strings(size, 0.5);
+    c.bench_function("zip str nulls 4096", |b| {
+        b.iter(|| bench_zip(&sparse_mask, &truthy, &falsy))
+    });
+}
+
+criterion_group!(benches, add_benchmark);
+criterion_main!(benches);
@@ -22,8 +22,10 @@ use std::{any::Any, convert::TryFrom};
 use super::ArrayDataRef;
 use super::*;
 use crate::array::equal_json::JsonEqual;
+use crate::buffer::{Buffer, MutableBuffer};
 use crate::error::Result;
 use crate::ffi;
+use crate::util::bit_util;
 
 /// Trait for dealing with different types of array at runtime when the type of the
 /// array is not known in advance.
@@ -323,6 +325,108 @@ pub fn make_array(data: ArrayDataRef) -> ArrayRef {
     }
 }
 
+/// Creates a new array of `data_type` and `length` where every slot is null.
+///
+/// Nested types get child arrays of the matching length, e.g. a struct has a null
+/// child per field and a list has an empty child. Unions are created as sparse unions.
+pub fn new_null_array(data_type: &DataType, length: usize) -> ArrayRef {
+    make_array(Arc::new(new_null_data(data_type, length)))
+}
+
+fn new_null_data(data_type: &DataType, length: usize) -> ArrayData {
+    if let DataType::Null = data_type {
+        return ArrayData::new(
+            DataType::Null,
+            length,
+            Some(length),
+            None,
+            0,
+            vec![],
+            vec![],
+        );
+    }
+
+    let zeroed = |len: usize| Buffer::from(MutableBuffer::from_len_zeroed(len));
+    let (buffers, child_data) = match data_type {
+        DataType::Boolean => (vec![zeroed(bit_util::ceil(length, 8))], vec![]),
+        DataType::Utf8 | DataType::Binary => {
+            (vec![zeroed((length + 1) * 4), zeroed(0)], vec![])
+        }
+        DataType::LargeUtf8 | DataType::LargeBinary => {
+            (vec![zeroed((length + 1) * 8), zeroed(0)], vec![])
+        }
+        DataType::List(field) => (
+            vec![zeroed((length + 1) * 4)],
+            vec![Arc::new(new_null_data(field.data_type(), 0))],
+        ),
+        DataType::LargeList(field) => (
+            vec![zeroed((length + 1) * 8)],
+            vec![Arc::new(new_null_data(field.data_type(), 0))],
+        ),
+        DataType::FixedSizeList(field, size) => (
+            vec![],
+            vec![Arc::new(new_null_data(
+                field.data_type(),
+                length * *size as usize,
+            ))],
+        ),
+        DataType::Struct(fields) => (
+            vec![],
+            fields
+                .iter()
+                .map(|field| Arc::new(new_null_data(field.data_type(), length)))
+                .collect(),
+        ),
+        DataType::Union(fields) => (
+            vec![zeroed(length)],
+            fields
+                .iter()
+                .map(|field| Arc::new(new_null_data(field.data_type(), length)))
+                .collect(),
+        ),
+        DataType::Dictionary(key_type, value_type) => (
+            vec![zeroed(length * fixed_width(key_type))],
+            vec![Arc::new(new_null_data(value_type, 0))],
+        ),
+        _ => (vec![zeroed(length * fixed_width(data_type))], vec![]),
+    };
+
+    ArrayData::new(
+        data_type.clone(),
+        length,
+        Some(length),
+        Some(MutableBuffer::new_null(length).into()),
+        0,
+        buffers,
+        child_data,
+    )
+}
+
+/// Returns the width in bytes of a slot of a fixed-width `data_type`
+fn fixed_width(data_type: &DataType) -> usize {
+    match data_type {
+        DataType::Int8 | DataType::UInt8 => 1,
+        DataType::Int16 | DataType::UInt16 | DataType::Float16 => 2,
+        DataType::Int32
+        | DataType::UInt32
+        | DataType::Float32
+        | DataType::Date32(_)
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => 4,
+        DataType::Int64
+        | DataType::UInt64
+        | DataType::Float64
+        | DataType::Date64(_)
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_)
+        | DataType::Interval(IntervalUnit::DayTime) => 8,
+        DataType::Decimal(_, _) => 16,
+        DataType::FixedSizeBinary(size) => *size as usize,
+        dt => unreachable!("{:?} is not a fixed-width data type", dt),
+    }
+}
+
 /// Creates a new array from two FFI pointers. Used to import arrays from the C Data Interface
 /// # Safety
 /// Assumes that these pointers represent valid C Data Interfaces, both in memory
@@ -375,3 +479,48 @@ where
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_null_array() {
+        let array = new_null_array(&DataType::Int32, 9);
+        assert_eq!(array.len(), 9);
+        assert_eq!(array.null_count(), 9);
+        (0..9).for_each(|i| assert!(array.is_null(i)));
+
+        let array = new_null_array(&DataType::Utf8, 3);
+        let array = array.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(array.null_count(), 3);
+        assert_eq!(array.value_offset(3), 0);
+    }
+
+    #[test]
+    fn test_new_null_nested_array() {
+        let list = DataType::List(Box::new(Field::new("item", DataType::Int64, true)));
+        let data_type = DataType::Struct(vec![
+            Field::new("a", list, true),
+            Field::new("b", DataType::Boolean, true),
+        ]);
+        let array = new_null_array(&data_type, 4);
+        let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+        assert_eq!(array.null_count(), 4);
+
+        let a = array
+            .column(0)
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .unwrap();
+        assert_eq!(a.len(), 4);
+        assert_eq!(a.null_count(), 4);
+        assert_eq!(a.values().len(), 0);
+
+        let dictionary =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let array = new_null_array(&dictionary, 2);
+        assert_eq!(array.null_count(), 2);
+        assert_eq!(array.data().child_data()[0].len(), 0);
+    }
+}
@@ -106,6 +106,11 @@ impl<'a, K: ArrowPrimitiveType> DictionaryArray<K> {
         self.values.clone()
     }
 
+    /// Borrows the values, for callers that need them for the lifetime of `self`.
+    pub(crate) fn values_ref(&self) -> &ArrayRef {
+        &self.values
+    }
+
     /// Returns a clone of the value type of this list.
     pub fn value_type(&self) -> DataType {
         self.values.data_ref().data_type().clone()
@@ -94,7 +94,7 @@ where
     let right_keys = right.keys_array();
 
     let left_values = StringArray::from(left.values().data());
-    let right_values = StringArray::from(left.values().data());
+    let right_values = StringArray::from(right.values().data());
 
     Box::new(move |i: usize, j: usize| {
         let key_left = left_keys.value(i).to_usize().unwrap();
@@ -105,6 +105,168 @@ where
     })
 }
 
+fn compare_binary<'a, T>(left: &'a Array, right: &'a Array) -> DynComparator<'a>
+where
+    T: BinaryOffsetSizeTrait,
+{
+    let left = left
+        .as_any()
+        .downcast_ref::<GenericBinaryArray<T>>()
+        .unwrap();
+    let right = right
+        .as_any()
+        .downcast_ref::<GenericBinaryArray<T>>()
+        .unwrap();
+    Box::new(move |i, j| left.value(i).cmp(right.value(j)))
+}
+
+fn compare_fixed_size_binary<'a>(left: &'a Array, right: &'a Array) -> DynComparator<'a> {
+    let left = left
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap();
+    let right = right
+        .as_any()
+        .downcast_ref::<FixedSizeBinaryArray>()
+        .unwrap();
+    Box::new(move |i, j| left.value(i).cmp(right.value(j)))
+}
+
+/// Compares the slot `i` of `left` with the slot `j` of `right`, ordering nulls
+/// before any value. Used for the values nested in lists, structs and dictionaries.
+fn compare_nullable(
+    left: &Array,
+    i: usize,
+    right: &Array,
+    j: usize,
+    cmp: &DynComparator,
+) -> Ordering {
+    match (left.is_valid(i), right.is_valid(j)) {
+        (true, true) => cmp(i, j),
+        (false, true) => Ordering::Less,
+        (true, false) => Ordering::Greater,
+        (false, false) => Ordering::Equal,
+    }
+}
+
+/// Compares the ranges `left_start..left_start + left_len` of `left` and
+/// `right_start..right_start + right_len` of `right` lexicographically, where a
+/// range that is a prefix of the other is smaller. `cmp` compares the values of
+/// `left` and `right`.
+fn compare_ranges(
+    left: &Array,
+    left_start: usize,
+    left_len: usize,
+    right: &Array,
+    right_start: usize,
+    right_len: usize,
+    cmp: &DynComparator,
+) -> Ordering {
+    (0..left_len.min(right_len))
+        .map(|k| compare_nullable(left, left_start + k, right, right_start + k, cmp))
+        .find(|ordering| *ordering != Ordering::Equal)
+        .unwrap_or_else(|| left_len.cmp(&right_len))
+}
+
+fn compare_list<'a, T>(left: &'a Array, right: &'a Array) -> Result<DynComparator<'a>>
+where
+    T: OffsetSizeTrait,
+{
+    let left = left.as_any().downcast_ref::<GenericListArray<T>>().unwrap();
+    let right = right
+        .as_any()
+        .downcast_ref::<GenericListArray<T>>()
+        .unwrap();
+    let left_values = left.values_ref().as_ref();
+    let right_values = right.values_ref().as_ref();
+    let cmp = build_compare(left_values, right_values)?;
+
+    Ok(Box::new(move |i, j| {
+        compare_ranges(
+            left_values,
+            left.value_offset(i).to_usize().unwrap(),
+            left.value_length(i).to_usize().unwrap(),
+            right_values,
+            right.value_offset(j).to_usize().unwrap(),
+            right.value_length(j).to_usize().unwrap(),
+            &cmp,
+        )
+    }))
+}
+
+fn compare_fixed_size_list<'a>(
+    left: &'a Array,
+    right: &'a Array,
+) -> Result<DynComparator<'a>> {
+    let left = left.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let right = right.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+    let left_values = left.values_ref().as_ref();
+    let right_values = right.values_ref().as_ref();
+    let cmp = build_compare(left_values, right_values)?;
+
+    Ok(Box::new(move |i, j| {
+        compare_ranges(
+            left_values,
+            left.value_offset(i) as usize,
+            left.value_length() as usize,
+            right_values,
+            right.value_offset(j) as usize,
+            right.value_length() as usize,
+            &cmp,
+        )
+    }))
+}
+
+fn compare_struct<'a>(left: &'a Array, right: &'a Array) -> Result<DynComparator<'a>> {
+    let (left_offset, right_offset) = (left.offset(), right.offset());
+    let left = left.as_any().downcast_ref::<StructArray>().unwrap();
+    let right = right.as_any().downcast_ref::<StructArray>().unwrap();
+    let columns = left
+        .columns()
+        .into_iter()
+        .zip(right.columns())
+        .map(|(left, right)| {
+            let cmp = build_compare(left.as_ref(), right.as_ref())?;
+            Ok((left.as_ref(), right.as_ref(), cmp))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // the children are not sliced with the struct, hence the offsets
+    Ok(Box::new(move |i, j| {
+        columns
+            .iter()
+            .map(|(left, right, cmp)| {
+                compare_nullable(*left, left_offset + i, *right, right_offset + j, cmp)
+            })
+            .find(|ordering| *ordering != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    }))
+}
+
+fn compare_dict<'a, T>(left: &'a Array, right: &'a Array) -> Result<DynComparator<'a>>
+where
+    T: ArrowDictionaryKeyType,
+{
+    let left = left.as_any().downcast_ref::<DictionaryArray<T>>().unwrap();
+    let right = right.as_any().downcast_ref::<DictionaryArray<T>>().unwrap();
+    let (left_keys, right_keys) = (left.keys_array(), right.keys_array());
+    let left_values = left.values_ref().as_ref();
+    let right_values = right.values_ref().as_ref();
+    let cmp = build_compare(left_values, right_values)?;
+
+    Ok(Box::new(move |i, j| {
+        compare_ranges(
+            left_values,
+            left_keys.value(i).to_usize().unwrap(),
+            1,
+            right_values,
+            right_keys.value(j).to_usize().unwrap(),
+            1,
+            &cmp,
+        )
+    }))
+}
+
 /// returns a comparison function that compares two values at two different positions
 /// between the two arrays.
 /// The arrays' types must be equal.
@@ -192,6 +354,17 @@ pub fn build_compare<'a>(left: &'a Array, right: &'a Array) -> Result<DynCompara
         }
         (Utf8, Utf8) => compare_string::<i32>(left, right),
         (LargeUtf8, LargeUtf8) => compare_string::<i64>(left, right),
+        (Binary, Binary) => compare_binary::<i32>(left, right),
+        (LargeBinary, LargeBinary) => compare_binary::<i64>(left, right),
+        (FixedSizeBinary(_), FixedSizeBinary(_)) => {
+            compare_fixed_size_binary(left, right)
+        }
+        (List(_), List(_)) => compare_list::<i32>(left, right)?,
+        (LargeList(_), LargeList(_)) => compare_list::<i64>(left, right)?,
+        (FixedSizeList(_, _), FixedSizeList(_, _)) => {
+            compare_fixed_size_list(left, right)?
+        }
+        (Struct(_), Struct(_)) => compare_struct(left, right)?,
         (
             Dictionary(key_type_lhs, value_type_lhs),
             Dictionary(key_type_rhs, value_type_rhs),
@@ -199,10 +372,23 @@ pub fn build_compare<'a>(left: &'a Array, right: &'a Array) -> Result<DynCompara
             if value_type_lhs.as_ref() != &DataType::Utf8
                 || value_type_rhs.as_ref() != &DataType::Utf8
             {
-                return Err(ArrowError::InvalidArgumentError(
-                    "Arrow still does not support comparisons of non-string dictionary arrays"
-                        .to_string(),
-                ));
+                return match (key_type_lhs.as_ref(), key_type_rhs.as_ref()) {
+                    (a, b) if a != b => Err(ArrowError::InvalidArgumentError(
+                        "Can't compare arrays of different types".to_string(),
+                    )),
+                    (UInt8, UInt8) => compare_dict::<UInt8Type>(left, right),
+                    (UInt16, UInt16) => compare_dict::<UInt16Type>(left, right),
+                    (UInt32, UInt32) => compare_dict::<UInt32Type>(left, right),
+                    (UInt64, UInt64) => compare_dict::<UInt64Type>(left, right),
+                    (Int8, Int8) => compare_dict::<Int8Type>(left, right),
+                    (Int16, Int16) => compare_dict::<Int16Type>(left, right),
+                    (Int32, Int32) => compare_dict::<Int32Type>(left, right),
+                    (Int64, Int64) => compare_dict::<Int64Type>(left, right),
+                    (lhs, _) => Err(ArrowError::InvalidArgumentError(format!(
+                        "Dictionaries do not support keys of type {:?}",
+                        lhs
+                    ))),
+                };
             }
             match (key_type_lhs.as_ref(), key_type_rhs.as_ref()) {
                 (a, b) if a != b => {
@@ -18,8 +18,9 @@
 use crate::array::ArrayData;
 
 use super::{
-    Extend, _MutableArrayData,
+    _MutableArrayData,
     utils::{resize_for_bits, set_bits},
+    Extend,
 };
 
 pub(super) fn build_extend(array: &ArrayData) -> Extend {
@@ -0,0 +1,45 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use crate::{array::ArrayData, datatypes::ArrowNativeType};
+
+use super::{_MutableArrayData, Extend};
+
+/// Builds an [Extend] for the keys of a dictionary whose values were concatenated
+/// with the values of other dictionaries, starting at `key_offset`.
+pub(super) fn build_extend_with_offset<T: ArrowNativeType>(
+    array: &ArrayData,
+    key_offset: usize,
+) -> Extend {
+    let keys = array.buffer::<T>(0);
+    Box::new(
+        move |mutable: &mut _MutableArrayData, _, start: usize, len: usize| {
+            (start..start + len).for_each(|i| {
+                // keys of null slots are undefined and are not remapped
+                let key = if array.is_valid(i) {
+                    keys[i]
+                        .to_usize()
+                        .and_then(|key| T::from_usize(key + key_offset))
+                        .expect("dictionary key overflow when merging dictionaries")
+                } else {
+                    T::default()
+                };
+                mutable.buffer1.push(key);
+            })
+        },
+    )
+}
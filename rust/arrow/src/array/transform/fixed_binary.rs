@@ -17,11 +17,12 @@
 
 use crate::{array::ArrayData, datatypes::DataType};
 
-use super::{Extend, _MutableArrayData};
+use super::{_MutableArrayData, Extend};
 
 pub(super) fn build_extend(array: &ArrayData) -> Extend {
     let size = match array.data_type() {
         DataType::FixedSizeBinary(i) => *i as usize,
+        DataType::Decimal(_, _) => 16,
         _ => unreachable!(),
     };
 
@@ -57,6 +58,7 @@ pub(super) fn build_extend(array: &ArrayData) -> Extend {
 pub(super) fn extend_nulls(mutable: &mut _MutableArrayData, len: usize) {
     let size = match mutable.data_type {
         DataType::FixedSizeBinary(i) => i as usize,
+        DataType::Decimal(_, _) => 16,
         _ => unreachable!(),
     };
 
@@ -18,8 +18,9 @@
 use crate::array::{ArrayData, OffsetSizeTrait};
 
 use super::{
-    Extend, _MutableArrayData,
+    _MutableArrayData,
     utils::{extend_offsets, get_last_offset},
+    Extend,
 };
 
 pub(super) fn build_extend<T: OffsetSizeTrait>(array: &ArrayData) -> Extend {
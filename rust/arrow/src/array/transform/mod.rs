@@ -22,11 +22,14 @@ use crate::{buffer::MutableBuffer, datatypes::DataType, util::bit_util};
 use super::{ArrayData, ArrayDataRef};
 
 mod boolean;
+mod dictionary;
 mod fixed_binary;
+mod fixed_size_list;
 mod list;
 mod null;
 mod primitive;
 mod structure;
+mod union;
 mod utils;
 mod variable_size;
 
@@ -57,7 +60,9 @@ struct _MutableArrayData<'a> {
 impl<'a> _MutableArrayData<'a> {
     fn freeze(self, dictionary: Option<ArrayDataRef>) -> ArrayData {
         let buffers = match self.data_type {
-            DataType::Null | DataType::Struct(_) => vec![],
+            DataType::Null | DataType::Struct(_) | DataType::FixedSizeList(_, _) => {
+                vec![]
+            }
             DataType::Utf8
             | DataType::Binary
             | DataType::LargeUtf8
@@ -217,13 +222,36 @@ fn build_extend(array: &ArrayData) -> Extend {
             _ => unreachable!(),
         },
         DataType::Struct(_) => structure::build_extend(array),
-        DataType::FixedSizeBinary(_) => fixed_binary::build_extend(array),
+        DataType::FixedSizeBinary(_) | DataType::Decimal(_, _) => {
+            fixed_binary::build_extend(array)
+        }
+        DataType::FixedSizeList(_, _) => fixed_size_list::build_extend(array),
+        DataType::Union(_) => union::build_extend(array),
         DataType::Float16 => unreachable!(),
-        /*
-        DataType::FixedSizeList(_, _) => {}
-        DataType::Union(_) => {}
-        */
-        _ => todo!("Take and filter operations still not supported for this datatype"),
+    }
+}
+
+fn build_extend_dictionary<'a>(
+    key_type: &DataType,
+    array: &'a ArrayData,
+    key_offset: usize,
+) -> Extend<'a> {
+    match key_type {
+        DataType::UInt8 => dictionary::build_extend_with_offset::<u8>(array, key_offset),
+        DataType::UInt16 => {
+            dictionary::build_extend_with_offset::<u16>(array, key_offset)
+        }
+        DataType::UInt32 => {
+            dictionary::build_extend_with_offset::<u32>(array, key_offset)
+        }
+        DataType::UInt64 => {
+            dictionary::build_extend_with_offset::<u64>(array, key_offset)
+        }
+        DataType::Int8 => dictionary::build_extend_with_offset::<i8>(array, key_offset),
+        DataType::Int16 => dictionary::build_extend_with_offset::<i16>(array, key_offset),
+        DataType::Int32 => dictionary::build_extend_with_offset::<i32>(array, key_offset),
+        DataType::Int64 => dictionary::build_extend_with_offset::<i64>(array, key_offset),
+        _ => unreachable!(),
     }
 }
 
@@ -266,13 +294,12 @@ fn build_extend_nulls(data_type: &DataType) -> ExtendNulls {
             _ => unreachable!(),
         },
         DataType::Struct(_) => structure::extend_nulls,
-        DataType::FixedSizeBinary(_) => fixed_binary::extend_nulls,
+        DataType::FixedSizeBinary(_) | DataType::Decimal(_, _) => {
+            fixed_binary::extend_nulls
+        }
+        DataType::FixedSizeList(_, _) => fixed_size_list::extend_nulls,
+        DataType::Union(_) => union::extend_nulls,
         DataType::Float16 => unreachable!(),
-        /*
-        DataType::FixedSizeList(_, _) => {}
-        DataType::Union(_) => {}
-        */
-        _ => todo!("Take and filter operations still not supported for this datatype"),
     })
 }
 
@@ -418,11 +445,13 @@ impl<'a> MutableArrayData<'a> {
                 ],
                 _ => unreachable!(),
             },
+            DataType::Decimal(_, _) => [MutableBuffer::new(capacity * 16), empty_buffer],
             DataType::Float16 => unreachable!(),
-            DataType::Struct(_) => [empty_buffer, MutableBuffer::new(0)],
-            _ => {
-                todo!("Take and filter operations still not supported for this datatype")
+            DataType::Struct(_) | DataType::FixedSizeList(_, _) => {
+                [empty_buffer, MutableBuffer::new(0)]
             }
+            // the union is always built as sparse, with one byte per type id
+            DataType::Union(_) => [MutableBuffer::new(capacity), empty_buffer],
         };
 
         let child_data = match &data_type {
@@ -449,7 +478,8 @@ impl<'a> MutableArrayData<'a> {
             | DataType::LargeUtf8
             | DataType::LargeBinary
             | DataType::Interval(_)
-            | DataType::FixedSizeBinary(_) => vec![],
+            | DataType::FixedSizeBinary(_)
+            | DataType::Decimal(_, _) => vec![],
             DataType::List(_) | DataType::LargeList(_) => {
                 let childs = arrays
                     .iter()
@@ -469,14 +499,57 @@ impl<'a> MutableArrayData<'a> {
                     MutableArrayData::new(child_arrays, use_nulls, capacity)
                 })
                 .collect::<Vec<_>>(),
-            _ => {
-                todo!("Take and filter operations still not supported for this datatype")
+            DataType::FixedSizeList(_, size) => {
+                let childs = arrays
+                    .iter()
+                    .map(|array| array.child_data()[0].as_ref())
+                    .collect::<Vec<_>>();
+                vec![MutableArrayData::new(
+                    childs,
+                    use_nulls,
+                    capacity * *size as usize,
+                )]
             }
+            // every child of a sparse union receives nulls for the slots of other types
+            DataType::Union(fields) => (0..fields.len())
+                .map(|i| {
+                    let child_arrays = arrays
+                        .iter()
+                        .map(|array| array.child_data()[i].as_ref())
+                        .collect::<Vec<_>>();
+                    MutableArrayData::new(child_arrays, true, capacity)
+                })
+                .collect::<Vec<_>>(),
         };
 
-        let dictionary = match &data_type {
-            DataType::Dictionary(_, _) => Some(arrays[0].child_data()[0].clone()),
-            _ => None,
+        // arrays with different dictionaries get their values concatenated and their keys
+        // offset by the position of their values in the concatenated dictionary.
+        let (dictionary, key_offsets) = match &data_type {
+            DataType::Dictionary(_, _) => {
+                let first = &arrays[0].child_data()[0];
+                let same_dictionary = arrays.iter().skip(1).all(|array| {
+                    let other = &array.child_data()[0];
+                    Arc::ptr_eq(first, other) || first == other
+                });
+                if same_dictionary {
+                    (Some(first.clone()), None)
+                } else {
+                    let dictionaries = arrays
+                        .iter()
+                        .map(|array| array.child_data()[0].as_ref())
+                        .collect::<Vec<_>>();
+                    let mut key_offsets = Vec::with_capacity(dictionaries.len());
+                    let total_len = dictionaries.iter().map(|d| d.len()).sum();
+                    let mut values =
+                        MutableArrayData::new(dictionaries.clone(), false, total_len);
+                    dictionaries.iter().enumerate().for_each(|(i, d)| {
+                        key_offsets.push(values.data.len);
+                        values.extend(i, 0, d.len());
+                    });
+                    (Some(Arc::new(values.freeze())), Some(key_offsets))
+                }
+            }
+            _ => (None, None),
         };
 
         let extend_nulls = build_extend_nulls(data_type);
@@ -489,7 +562,16 @@ impl<'a> MutableArrayData<'a> {
         let null_bytes = bit_util::ceil(capacity, 8);
         let null_buffer = MutableBuffer::from_len_zeroed(null_bytes);
 
-        let extend_values = arrays.iter().map(|array| build_extend(array)).collect();
+        let extend_values = match (&data_type, key_offsets) {
+            (DataType::Dictionary(key_type, _), Some(key_offsets)) => arrays
+                .iter()
+                .zip(key_offsets)
+                .map(|(array, key_offset)| {
+                    build_extend_dictionary(key_type, array, key_offset)
+                })
+                .collect(),
+            _ => arrays.iter().map(|array| build_extend(array)).collect(),
+        };
 
         let data = _MutableArrayData {
             data_type: data_type.clone(),
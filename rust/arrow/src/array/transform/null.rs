@@ -17,7 +17,7 @@
 
 use crate::array::ArrayData;
 
-use super::{Extend, _MutableArrayData};
+use super::{_MutableArrayData, Extend};
 
 pub(super) fn build_extend(_: &ArrayData) -> Extend {
     Box::new(move |_, _, _, _| {})
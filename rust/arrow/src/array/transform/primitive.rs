@@ -19,7 +19,7 @@ use std::mem::size_of;
 
 use crate::{array::ArrayData, datatypes::ArrowNativeType};
 
-use super::{Extend, _MutableArrayData};
+use super::{_MutableArrayData, Extend};
 
 pub(super) fn build_extend<T: ArrowNativeType>(array: &ArrayData) -> Extend {
     let values = array.buffer::<T>(0);
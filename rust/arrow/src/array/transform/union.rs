@@ -0,0 +1,93 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+use crate::array::ArrayData;
+
+use super::{_MutableArrayData, Extend};
+
+// The result is always a sparse union: every child has a slot for every slot of the
+// union, which is null for the children that are not selected by the type id.
+
+pub(super) fn build_extend(array: &ArrayData) -> Extend {
+    let type_ids = &array.buffers()[0].as_slice()[array.offset()..];
+    // the child data is not sliced with the union, hence the offset
+    let offset = array.offset();
+
+    if array.buffers().len() == 1 {
+        // sparse: the slots of the children are aligned with the slots of the union
+        return Box::new(
+            move |mutable: &mut _MutableArrayData,
+                  index: usize,
+                  start: usize,
+                  len: usize| {
+                mutable
+                    .buffer1
+                    .extend_from_slice(&type_ids[start..start + len]);
+                mutable.child_data.iter_mut().for_each(|child| {
+                    child.extend(index, offset + start, offset + start + len)
+                })
+            },
+        );
+    }
+
+    // dense: the value offsets omit the null slots of the union
+    let mut valid_slots = Vec::with_capacity(array.len());
+    let mut valid_count = array
+        .null_buffer()
+        .map(|nulls| nulls.count_set_bits_offset(0, offset))
+        .unwrap_or(offset);
+    (0..array.len()).for_each(|i| {
+        valid_slots.push(valid_count);
+        if array.is_valid(i) {
+            valid_count += 1;
+        }
+    });
+    let value_offsets = unsafe { array.buffers()[1].typed_data::<i32>() };
+
+    Box::new(
+        move |mutable: &mut _MutableArrayData, index: usize, start: usize, len: usize| {
+            (start..start + len).for_each(|i| {
+                let type_id = type_ids[i];
+                mutable.buffer1.push(type_id);
+                if array.is_valid(i) {
+                    let value_offset = value_offsets[valid_slots[i]] as usize;
+                    mutable.child_data.iter_mut().enumerate().for_each(
+                        |(child_index, child)| {
+                            if child_index == type_id as usize {
+                                child.extend(index, value_offset, value_offset + 1)
+                            } else {
+                                child.extend_nulls(1)
+                            }
+                        },
+                    )
+                } else {
+                    mutable
+                        .child_data
+                        .iter_mut()
+                        .for_each(|child| child.extend_nulls(1))
+                }
+            })
+        },
+    )
+}
+
+pub(super) fn extend_nulls(mutable: &mut _MutableArrayData, len: usize) {
+    mutable.buffer1.extend(len);
+    mutable
+        .child_data
+        .iter_mut()
+        .for_each(|child| child.extend_nulls(len))
+}
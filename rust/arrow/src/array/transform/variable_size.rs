@@ -21,8 +21,9 @@ use crate::{
 };
 
 use super::{
-    Extend, _MutableArrayData,
+    _MutableArrayData,
     utils::{extend_offsets, get_last_offset},
+    Extend,
 };
 
 #[inline]
@@ -41,7 +42,7 @@ fn extend_offset_values<T: OffsetSizeTrait>(
 
 pub(super) fn build_extend<T: OffsetSizeTrait>(array: &ArrayData) -> Extend {
     let offsets = array.buffer::<T>(0);
-    let values = &array.buffers()[1].as_slice()[array.offset()..];
+    let values = array.buffers()[1].as_slice();
     if array.null_count() == 0 {
         // fast case where we can copy regions without null issues
         Box::new(
@@ -81,8 +82,7 @@ pub(super) fn build_extend<T: OffsetSizeTrait>(array: &ArrayData) -> Extend {
                         let length = length.to_usize().unwrap();
 
                         // append value
-                        let start = offsets[i].to_usize().unwrap()
-                            - offsets[0].to_usize().unwrap();
+                        let start = offsets[i].to_usize().unwrap();
                         let bytes = &values[start..(start + length)];
                         values_buffer.extend_from_slice(bytes);
                     }
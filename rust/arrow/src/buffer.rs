@@ -27,6 +27,8 @@ use crate::{
     ffi,
 };
 
+pub use crate::bytes::Allocation;
+
 use std::convert::AsRef;
 use std::fmt::Debug;
 use std::ops::{BitAnd, BitOr, Not};
@@ -112,6 +114,28 @@ impl Buffer {
         Buffer::build_with_arguments(ptr, len, Deallocation::Foreign(data))
     }
 
+    /// Creates a buffer from a memory region owned by `owner` (e.g. a memory-mapped file),
+    /// without copying it. This `Buffer` **does not** free this piece of memory when dropped,
+    /// but keeps `owner` alive for as long as the memory is in use.
+    ///
+    /// # Arguments
+    ///
+    /// * `ptr` - Pointer to raw parts
+    /// * `len` - Length of raw parts in **bytes**
+    /// * `owner` - The allocation that owns the memory region
+    ///
+    /// # Safety
+    ///
+    /// This function is unsafe as there is no guarantee that the given pointer is valid for `len`
+    /// bytes while `owner` is alive.
+    pub unsafe fn from_custom_allocation(
+        ptr: NonNull<u8>,
+        len: usize,
+        owner: Arc<dyn Allocation>,
+    ) -> Self {
+        Buffer::build_with_arguments(ptr, len, Deallocation::Custom(owner))
+    }
+
     /// Auxiliary method to create a new Buffer
     unsafe fn build_with_arguments(
         ptr: NonNull<u8>,
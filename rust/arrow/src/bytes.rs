@@ -26,12 +26,20 @@ use std::{fmt::Debug, fmt::Formatter};
 
 use crate::{ffi, memory};
 
+/// A memory region owned outside of Arrow (e.g. a memory-mapped file), that is kept
+/// alive for as long as a [`Bytes`] points into it.
+pub trait Allocation: Send + Sync {}
+
+impl<T: Send + Sync> Allocation for T {}
+
 /// Mode of deallocating memory regions
 pub enum Deallocation {
     /// Native deallocation, using Rust deallocator with Arrow-specific memory aligment
     Native(usize),
     /// Foreign interface, via a callback
     Foreign(Arc<ffi::FFI_ArrowArray>),
+    /// Custom allocation, deallocated when its last reference is dropped
+    Custom(Arc<dyn Allocation>),
 }
 
 impl Debug for Deallocation {
@@ -43,6 +51,9 @@ impl Debug for Deallocation {
             Deallocation::Foreign(_) => {
                 write!(f, "Deallocation::Foreign {{ capacity: unknown }}")
             }
+            Deallocation::Custom(_) => {
+                write!(f, "Deallocation::Custom {{ capacity: unknown }}")
+            }
         }
     }
 }
@@ -115,7 +126,7 @@ impl Bytes {
             Deallocation::Native(capacity) => capacity,
             // we cannot determine this in general,
             // and thus we state that this is externally-owned memory
-            Deallocation::Foreign(_) => 0,
+            Deallocation::Foreign(_) | Deallocation::Custom(_) => 0,
         }
     }
 }
@@ -129,6 +140,8 @@ impl Drop for Bytes {
             }
             // foreign interface knows how to deallocate itself.
             Deallocation::Foreign(_) => (),
+            // the allocation is released when its last reference is dropped.
+            Deallocation::Custom(_) => (),
         }
     }
 }
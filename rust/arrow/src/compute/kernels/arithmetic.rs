@@ -25,7 +25,10 @@
 use std::ops::{Add, Div, Mul, Neg, Sub};
 use std::sync::Arc;
 
-use num::{One, Zero};
+use num::traits::{WrappingAdd, WrappingMul, WrappingSub};
+use num::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Float, One, ToPrimitive, Zero,
+};
 
 use crate::buffer::Buffer;
 #[cfg(feature = "simd")]
@@ -226,6 +229,198 @@ where
     Ok(PrimitiveArray::<T>::from(Arc::new(data)))
 }
 
+/// Helper function to perform math lambda function on values from an array and a scalar.
+/// If the array value is null then the output value is also null, so `null + 1` is `null`.
+pub fn math_op_scalar<T, F>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    F: Fn(T::Native, T::Native) -> T::Native,
+{
+    let values = array
+        .values()
+        .iter()
+        .map(|v| op(*v, scalar))
+        .collect::<Vec<T::Native>>();
+
+    let data = ArrayData::new(
+        T::DATA_TYPE,
+        array.len(),
+        None,
+        null_buffer_of(array),
+        0,
+        vec![Buffer::from_slice_ref(&values)],
+        vec![],
+    );
+    Ok(PrimitiveArray::<T>::from(Arc::new(data)))
+}
+
+/// Helper function to perform a fallible math lambda function on values from two arrays.
+/// The function is only applied to slots where both values are valid, so that the values
+/// of null slots cannot cause an error.
+///
+/// # Errors
+///
+/// This function errors if the arrays have different lengths or if `op` errors.
+fn math_checked_op<T, F>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    F: Fn(T::Native, T::Native) -> Result<T::Native>,
+{
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform math operation on arrays of different length".to_string(),
+        ));
+    }
+
+    let null_bit_buffer =
+        combine_option_bitmap(left.data_ref(), right.data_ref(), left.len())?;
+
+    let values = match &null_bit_buffer {
+        Some(b) => left
+            .values()
+            .iter()
+            .zip(right.values().iter())
+            .enumerate()
+            .map(|(i, (l, r))| {
+                if unsafe { bit_util::get_bit_raw(b.as_ptr(), i) } {
+                    op(*l, *r)
+                } else {
+                    Ok(T::default_value())
+                }
+            })
+            .collect::<Result<Vec<T::Native>>>()?,
+        None => left
+            .values()
+            .iter()
+            .zip(right.values().iter())
+            .map(|(l, r)| op(*l, *r))
+            .collect::<Result<Vec<T::Native>>>()?,
+    };
+
+    let data = ArrayData::new(
+        T::DATA_TYPE,
+        left.len(),
+        None,
+        null_bit_buffer,
+        0,
+        vec![Buffer::from_slice_ref(&values)],
+        vec![],
+    );
+    Ok(PrimitiveArray::<T>::from(Arc::new(data)))
+}
+
+/// Helper function to perform a fallible math lambda function on values from an array and
+/// a scalar. The function is only applied to the valid slots of the array.
+fn math_checked_op_scalar<T, F>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+    op: F,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    F: Fn(T::Native, T::Native) -> Result<T::Native>,
+{
+    let values = array
+        .values()
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            if array.is_valid(i) {
+                op(*v, scalar)
+            } else {
+                Ok(T::default_value())
+            }
+        })
+        .collect::<Result<Vec<T::Native>>>()?;
+
+    let data = ArrayData::new(
+        T::DATA_TYPE,
+        array.len(),
+        None,
+        null_buffer_of(array),
+        0,
+        vec![Buffer::from_slice_ref(&values)],
+        vec![],
+    );
+    Ok(PrimitiveArray::<T>::from(Arc::new(data)))
+}
+
+/// Returns the validity of `array` as a buffer starting at bit 0.
+fn null_buffer_of<T: datatypes::ArrowPrimitiveType>(
+    array: &PrimitiveArray<T>,
+) -> Option<Buffer> {
+    array
+        .data_ref()
+        .null_buffer()
+        .map(|b| b.bit_slice(array.offset(), array.len()))
+}
+
+fn overflow_error<N: std::fmt::Debug>(op: &str, left: N, right: N) -> ArrowError {
+    ArrowError::ComputeError(format!(
+        "Overflow happened on: {:?} {} {:?}",
+        left, op, right
+    ))
+}
+
+/// SIMD vectorized version of `math_op_scalar` above.
+#[cfg(simd)]
+fn simd_math_op_scalar<T, SIMD_OP, SCALAR_OP>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+    simd_op: SIMD_OP,
+    scalar_op: SCALAR_OP,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    SIMD_OP: Fn(T::Simd, T::Simd) -> T::Simd,
+    SCALAR_OP: Fn(T::Native, T::Native) -> T::Native,
+{
+    let lanes = T::lanes();
+    let buffer_size = array.len() * std::mem::size_of::<T::Native>();
+    let mut result = MutableBuffer::new(buffer_size).with_bitset(buffer_size, false);
+
+    let mut result_chunks = result.typed_data_mut().chunks_exact_mut(lanes);
+    let mut array_chunks = array.values().chunks_exact(lanes);
+
+    let simd_right = T::init(scalar);
+    result_chunks
+        .borrow_mut()
+        .zip(array_chunks.borrow_mut())
+        .for_each(|(result_slice, input_slice)| {
+            let simd_left = T::load(input_slice);
+            let simd_result = T::bin_op(simd_left, simd_right, &simd_op);
+            T::write(simd_result, result_slice);
+        });
+
+    let result_remainder = result_chunks.into_remainder();
+    let array_remainder = array_chunks.remainder();
+
+    result_remainder.iter_mut().zip(array_remainder).for_each(
+        |(scalar_result, scalar_input)| {
+            *scalar_result = scalar_op(*scalar_input, scalar);
+        },
+    );
+
+    let data = ArrayData::new(
+        T::DATA_TYPE,
+        array.len(),
+        None,
+        null_buffer_of(array),
+        0,
+        vec![result.into()],
+        vec![],
+    );
+    Ok(PrimitiveArray::<T>::from(Arc::new(data)))
+}
+
 /// SIMD vectorized version of `math_op` above.
 #[cfg(simd)]
 fn simd_math_op<T, SIMD_OP, SCALAR_OP>(
@@ -570,6 +765,450 @@ where
     return math_divide(&left, &right);
 }
 
+/// Perform `left + scalar` operation on an array and a scalar. If the array value is null
+/// then the result is also null.
+pub fn add_scalar<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: Add<Output = T::Native>,
+{
+    #[cfg(simd)]
+    return simd_math_op_scalar(&array, scalar, |a, b| a + b, |a, b| a + b);
+    #[cfg(not(simd))]
+    return math_op_scalar(array, scalar, |a, b| a + b);
+}
+
+/// Perform `left - scalar` operation on an array and a scalar. If the array value is null
+/// then the result is also null.
+pub fn subtract_scalar<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: Sub<Output = T::Native>,
+{
+    #[cfg(simd)]
+    return simd_math_op_scalar(&array, scalar, |a, b| a - b, |a, b| a - b);
+    #[cfg(not(simd))]
+    return math_op_scalar(array, scalar, |a, b| a - b);
+}
+
+/// Perform `left * scalar` operation on an array and a scalar. If the array value is null
+/// then the result is also null.
+pub fn multiply_scalar<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: Mul<Output = T::Native>,
+{
+    #[cfg(simd)]
+    return simd_math_op_scalar(&array, scalar, |a, b| a * b, |a, b| a * b);
+    #[cfg(not(simd))]
+    return math_op_scalar(array, scalar, |a, b| a * b);
+}
+
+/// Perform `left / scalar` operation on an array and a scalar. If the array value is null
+/// then the result is also null. If the scalar is zero then the result of this operation
+/// will be `Err(ArrowError::DivideByZero)`.
+pub fn divide_scalar<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: Div<Output = T::Native> + Zero,
+{
+    if scalar.is_zero() {
+        return Err(ArrowError::DivideByZero);
+    }
+    #[cfg(simd)]
+    return simd_math_op_scalar(&array, scalar, |a, b| a / b, |a, b| a / b);
+    #[cfg(not(simd))]
+    return math_op_scalar(array, scalar, |a, b| a / b);
+}
+
+/// A remainder operation that returns `None` instead of panicking on overflow, which
+/// happens for the signed integer types on e.g. `i32::MIN % -1`.
+pub trait CheckedRemainder: Sized {
+    /// Returns `self % rhs`, or `None` if the operation overflows or `rhs` is zero.
+    fn checked_remainder(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! checked_remainder_integer {
+    ($($t:ty),*) => {
+        $(impl CheckedRemainder for $t {
+            fn checked_remainder(self, rhs: Self) -> Option<Self> {
+                self.checked_rem(rhs)
+            }
+        })*
+    };
+}
+
+checked_remainder_integer!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+impl CheckedRemainder for f32 {
+    fn checked_remainder(self, rhs: Self) -> Option<Self> {
+        Some(self % rhs)
+    }
+}
+
+impl CheckedRemainder for f64 {
+    fn checked_remainder(self, rhs: Self) -> Option<Self> {
+        Some(self % rhs)
+    }
+}
+
+/// Perform `left % right` operation on two arrays. If either left or right value is null
+/// then the result is also null. If any valid right hand value is zero then the result
+/// of this operation will be `Err(ArrowError::DivideByZero)`, and if any valid slot
+/// overflows, e.g. `i32::MIN % -1`, it will be `Err(ArrowError::ComputeError)`.
+pub fn modulus<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: CheckedRemainder + Zero,
+{
+    math_checked_op(left, right, checked_modulus_value)
+}
+
+/// Perform `left % scalar` operation on an array and a scalar. If the array value is null
+/// then the result is also null. If the scalar is zero then the result of this operation
+/// will be `Err(ArrowError::DivideByZero)`, and if any valid slot overflows it will be
+/// `Err(ArrowError::ComputeError)`.
+pub fn modulus_scalar<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: CheckedRemainder + Zero,
+{
+    if scalar.is_zero() {
+        return Err(ArrowError::DivideByZero);
+    }
+    math_checked_op_scalar(array, scalar, checked_modulus_value)
+}
+
+fn checked_modulus_value<N>(a: N, b: N) -> Result<N>
+where
+    N: CheckedRemainder + Zero + Copy + std::fmt::Debug,
+{
+    if b.is_zero() {
+        Err(ArrowError::DivideByZero)
+    } else {
+        a.checked_remainder(b)
+            .ok_or_else(|| overflow_error("%", a, b))
+    }
+}
+
+/// Perform `left + right` operation on two integer arrays, returning
+/// `Err(ArrowError::ComputeError)` if any valid slot overflows.
+pub fn add_checked<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: CheckedAdd,
+{
+    math_checked_op(left, right, |a, b| {
+        a.checked_add(&b).ok_or_else(|| overflow_error("+", a, b))
+    })
+}
+
+/// Perform `left + scalar` operation on an integer array and a scalar, returning
+/// `Err(ArrowError::ComputeError)` if any valid slot overflows.
+pub fn add_scalar_checked<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: CheckedAdd,
+{
+    math_checked_op_scalar(array, scalar, |a, b| {
+        a.checked_add(&b).ok_or_else(|| overflow_error("+", a, b))
+    })
+}
+
+/// Perform `left - right` operation on two integer arrays, returning
+/// `Err(ArrowError::ComputeError)` if any valid slot overflows.
+pub fn subtract_checked<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: CheckedSub,
+{
+    math_checked_op(left, right, |a, b| {
+        a.checked_sub(&b).ok_or_else(|| overflow_error("-", a, b))
+    })
+}
+
+/// Perform `left - scalar` operation on an integer array and a scalar, returning
+/// `Err(ArrowError::ComputeError)` if any valid slot overflows.
+pub fn subtract_scalar_checked<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: CheckedSub,
+{
+    math_checked_op_scalar(array, scalar, |a, b| {
+        a.checked_sub(&b).ok_or_else(|| overflow_error("-", a, b))
+    })
+}
+
+/// Perform `left * right` operation on two integer arrays, returning
+/// `Err(ArrowError::ComputeError)` if any valid slot overflows.
+pub fn multiply_checked<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: CheckedMul,
+{
+    math_checked_op(left, right, |a, b| {
+        a.checked_mul(&b).ok_or_else(|| overflow_error("*", a, b))
+    })
+}
+
+/// Perform `left * scalar` operation on an integer array and a scalar, returning
+/// `Err(ArrowError::ComputeError)` if any valid slot overflows.
+pub fn multiply_scalar_checked<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: CheckedMul,
+{
+    math_checked_op_scalar(array, scalar, |a, b| {
+        a.checked_mul(&b).ok_or_else(|| overflow_error("*", a, b))
+    })
+}
+
+/// Perform `left / right` operation on two integer arrays. Returns
+/// `Err(ArrowError::DivideByZero)` if any valid right hand value is zero and
+/// `Err(ArrowError::ComputeError)` if any valid slot overflows, e.g. `i32::MIN / -1`.
+pub fn divide_checked<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: CheckedDiv + Zero,
+{
+    math_checked_op(left, right, checked_divide_value)
+}
+
+/// Perform `left / scalar` operation on an integer array and a scalar. Returns
+/// `Err(ArrowError::DivideByZero)` if the scalar is zero and `Err(ArrowError::ComputeError)`
+/// if any valid slot overflows.
+pub fn divide_scalar_checked<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: CheckedDiv + Zero,
+{
+    if scalar.is_zero() {
+        return Err(ArrowError::DivideByZero);
+    }
+    math_checked_op_scalar(array, scalar, checked_divide_value)
+}
+
+fn checked_divide_value<N>(a: N, b: N) -> Result<N>
+where
+    N: CheckedDiv + Zero + std::fmt::Debug,
+{
+    if b.is_zero() {
+        Err(ArrowError::DivideByZero)
+    } else {
+        a.checked_div(&b).ok_or_else(|| overflow_error("/", a, b))
+    }
+}
+
+/// Perform `left + right` operation on two integer arrays, wrapping around at the
+/// boundary of the type on overflow.
+pub fn add_wrapping<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: WrappingAdd,
+{
+    math_op(left, right, |a, b| a.wrapping_add(&b))
+}
+
+/// Perform `left + scalar` operation on an integer array and a scalar, wrapping around
+/// at the boundary of the type on overflow.
+pub fn add_scalar_wrapping<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: WrappingAdd,
+{
+    math_op_scalar(array, scalar, |a, b| a.wrapping_add(&b))
+}
+
+/// Perform `left - right` operation on two integer arrays, wrapping around at the
+/// boundary of the type on overflow.
+pub fn subtract_wrapping<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: WrappingSub,
+{
+    math_op(left, right, |a, b| a.wrapping_sub(&b))
+}
+
+/// Perform `left - scalar` operation on an integer array and a scalar, wrapping around
+/// at the boundary of the type on overflow.
+pub fn subtract_scalar_wrapping<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: WrappingSub,
+{
+    math_op_scalar(array, scalar, |a, b| a.wrapping_sub(&b))
+}
+
+/// Perform `left * right` operation on two integer arrays, wrapping around at the
+/// boundary of the type on overflow.
+pub fn multiply_wrapping<T>(
+    left: &PrimitiveArray<T>,
+    right: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: WrappingMul,
+{
+    math_op(left, right, |a, b| a.wrapping_mul(&b))
+}
+
+/// Perform `left * scalar` operation on an integer array and a scalar, wrapping around
+/// at the boundary of the type on overflow.
+pub fn multiply_scalar_wrapping<T>(
+    array: &PrimitiveArray<T>,
+    scalar: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: WrappingMul,
+{
+    math_op_scalar(array, scalar, |a, b| a.wrapping_mul(&b))
+}
+
+/// Raise each value of a floating point array to the power of the corresponding value of
+/// `exponent`. If either value is null then the result is also null.
+pub fn powf<T>(
+    array: &PrimitiveArray<T>,
+    exponent: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: Float,
+{
+    math_op(array, exponent, |a, b| a.powf(b))
+}
+
+/// Raise each value of a floating point array to the power of `exponent`. If the value is
+/// null then the result is also null.
+pub fn powf_scalar<T>(
+    array: &PrimitiveArray<T>,
+    exponent: T::Native,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: Float,
+{
+    math_op_scalar(array, exponent, |a, b| a.powf(b))
+}
+
+/// Raise each value of an integer array to the power of the corresponding value of
+/// `exponent`. Returns `Err(ArrowError::ComputeError)` if any valid exponent is negative
+/// or if any valid slot overflows.
+pub fn pow_checked<T>(
+    array: &PrimitiveArray<T>,
+    exponent: &PrimitiveArray<T>,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: CheckedMul + One + ToPrimitive,
+{
+    math_checked_op(array, exponent, checked_pow_value)
+}
+
+/// Raise each value of an integer array to the power of `exponent`. Returns
+/// `Err(ArrowError::ComputeError)` if any valid slot overflows.
+pub fn pow_scalar_checked<T>(
+    array: &PrimitiveArray<T>,
+    exponent: usize,
+) -> Result<PrimitiveArray<T>>
+where
+    T: ArrowNumericType,
+    T::Native: CheckedMul + One,
+{
+    let values = array
+        .values()
+        .iter()
+        .enumerate()
+        .map(|(i, v)| {
+            if array.is_valid(i) {
+                num::checked_pow(*v, exponent).ok_or_else(|| {
+                    ArrowError::ComputeError(format!(
+                        "Overflow happened on: {:?} ^ {}",
+                        v, exponent
+                    ))
+                })
+            } else {
+                Ok(T::default_value())
+            }
+        })
+        .collect::<Result<Vec<T::Native>>>()?;
+
+    let data = ArrayData::new(
+        T::DATA_TYPE,
+        array.len(),
+        None,
+        null_buffer_of(array),
+        0,
+        vec![Buffer::from_slice_ref(&values)],
+        vec![],
+    );
+    Ok(PrimitiveArray::<T>::from(Arc::new(data)))
+}
+
+fn checked_pow_value<N>(a: N, b: N) -> Result<N>
+where
+    N: CheckedMul + One + ToPrimitive + Copy + std::fmt::Debug,
+{
+    let exponent = b
+        .to_usize()
+        .ok_or_else(|| ArrowError::ComputeError(format!("Invalid exponent: {:?}", b)))?;
+    num::checked_pow(a, exponent).ok_or_else(|| overflow_error("^", a, b))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -797,4 +1436,186 @@ mod tests {
             .collect();
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn test_primitive_array_scalar_ops() {
+        let a = Int32Array::from(vec![Some(5), None, Some(7), Some(-8)]);
+        let a = a.slice(1, 3);
+        let a = a.as_any().downcast_ref::<Int32Array>().unwrap();
+
+        let c = add_scalar(a, 3).unwrap();
+        assert_eq!(c, Int32Array::from(vec![None, Some(10), Some(-5)]));
+        let c = subtract_scalar(a, 3).unwrap();
+        assert_eq!(c, Int32Array::from(vec![None, Some(4), Some(-11)]));
+        let c = multiply_scalar(a, 3).unwrap();
+        assert_eq!(c, Int32Array::from(vec![None, Some(21), Some(-24)]));
+        let c = divide_scalar(a, 3).unwrap();
+        assert_eq!(c, Int32Array::from(vec![None, Some(2), Some(-2)]));
+        let c = modulus_scalar(a, 3).unwrap();
+        assert_eq!(c, Int32Array::from(vec![None, Some(1), Some(-2)]));
+    }
+
+    #[test]
+    fn test_primitive_array_scalar_divide_by_zero() {
+        let a = Int32Array::from(vec![15]);
+        assert!(matches!(
+            divide_scalar(&a, 0),
+            Err(ArrowError::DivideByZero)
+        ));
+        assert!(matches!(
+            modulus_scalar(&a, 0),
+            Err(ArrowError::DivideByZero)
+        ));
+    }
+
+    #[test]
+    fn test_primitive_array_modulus() {
+        let a = Int32Array::from(vec![Some(15), Some(15), Some(8), None, Some(-7)]);
+        let b = Int32Array::from(vec![Some(5), Some(6), Some(8), Some(9), Some(3)]);
+        let c = modulus(&a, &b).unwrap();
+        assert_eq!(
+            c,
+            Int32Array::from(vec![Some(0), Some(3), Some(0), None, Some(-1)])
+        );
+    }
+
+    #[test]
+    fn test_primitive_array_modulus_by_zero() {
+        let a = Int32Array::from(vec![15]);
+        let b = Int32Array::from(vec![0]);
+        assert!(matches!(modulus(&a, &b), Err(ArrowError::DivideByZero)));
+
+        // zero divisors in null slots are ignored
+        let a = Int32Array::from(vec![Some(15), None]);
+        let b = Int32Array::from(vec![Some(2), Some(0)]);
+        let c = modulus(&a, &b).unwrap();
+        assert_eq!(c, Int32Array::from(vec![Some(1), None]));
+    }
+
+    #[test]
+    fn test_primitive_array_modulus_overflow() {
+        let a = Int32Array::from(vec![i32::MIN]);
+        let b = Int32Array::from(vec![-1]);
+        assert!(matches!(modulus(&a, &b), Err(ArrowError::ComputeError(_))));
+        assert!(matches!(
+            modulus_scalar(&a, -1),
+            Err(ArrowError::ComputeError(_))
+        ));
+
+        // overflowing values in null slots are ignored
+        let a = Int64Array::from(
+            ArrayData::builder(datatypes::DataType::Int64)
+                .len(2)
+                .add_buffer(Buffer::from_slice_ref(&[7i64, i64::MIN]))
+                .null_bit_buffer(Buffer::from(vec![0b00000001]))
+                .build(),
+        );
+        let c = modulus_scalar(&a, -1).unwrap();
+        assert_eq!(c, Int64Array::from(vec![Some(0), None]));
+
+        let a = Float64Array::from(vec![5.5]);
+        let c = modulus_scalar(&a, -2.0).unwrap();
+        assert!((c.value(0) - 1.5).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_primitive_array_checked_overflow() {
+        let a = Int8Array::from(vec![Some(100), None, Some(-100)]);
+        let b = Int8Array::from(vec![Some(27), Some(100), Some(28)]);
+        let c = add_checked(&a, &b).unwrap();
+        assert_eq!(c, Int8Array::from(vec![Some(127), None, Some(-72)]));
+
+        let b = Int8Array::from(vec![Some(28), Some(100), Some(28)]);
+        assert!(matches!(
+            add_checked(&a, &b),
+            Err(ArrowError::ComputeError(_))
+        ));
+        assert!(subtract_checked(&b, &a).is_err());
+        assert!(multiply_checked(&a, &b).is_err());
+        assert!(add_scalar_checked(&a, 28).is_err());
+        assert!(subtract_scalar_checked(&a, 29).is_err());
+        assert!(multiply_scalar_checked(&a, 2).is_err());
+        assert_eq!(
+            subtract_scalar_checked(&a, 28).unwrap(),
+            Int8Array::from(vec![Some(72), None, Some(-128)])
+        );
+    }
+
+    #[test]
+    fn test_primitive_array_divide_checked() {
+        let a = Int8Array::from(vec![Some(-128), Some(10)]);
+        let b = Int8Array::from(vec![Some(-1), Some(2)]);
+        assert!(matches!(
+            divide_checked(&a, &b),
+            Err(ArrowError::ComputeError(_))
+        ));
+        let b = Int8Array::from(vec![Some(1), Some(0)]);
+        assert!(matches!(
+            divide_checked(&a, &b),
+            Err(ArrowError::DivideByZero)
+        ));
+        assert_eq!(
+            divide_scalar_checked(&a, 2).unwrap(),
+            Int8Array::from(vec![-64, 5])
+        );
+        assert!(divide_scalar_checked(&a, -1).is_err());
+    }
+
+    #[test]
+    fn test_primitive_array_wrapping() {
+        let a = UInt8Array::from(vec![Some(250), None, Some(3)]);
+        let b = UInt8Array::from(vec![Some(10), Some(1), Some(5)]);
+        assert_eq!(
+            add_wrapping(&a, &b).unwrap(),
+            UInt8Array::from(vec![Some(4), None, Some(8)])
+        );
+        assert_eq!(
+            subtract_wrapping(&a, &b).unwrap(),
+            UInt8Array::from(vec![Some(240), None, Some(254)])
+        );
+        assert_eq!(
+            multiply_wrapping(&a, &b).unwrap(),
+            UInt8Array::from(vec![Some(196), None, Some(15)])
+        );
+        assert_eq!(
+            add_scalar_wrapping(&a, 10).unwrap(),
+            UInt8Array::from(vec![Some(4), None, Some(13)])
+        );
+        assert_eq!(
+            subtract_scalar_wrapping(&a, 10).unwrap(),
+            UInt8Array::from(vec![Some(240), None, Some(249)])
+        );
+        assert_eq!(
+            multiply_scalar_wrapping(&a, 2).unwrap(),
+            UInt8Array::from(vec![Some(244), None, Some(6)])
+        );
+    }
+
+    #[test]
+    fn test_primitive_array_pow() {
+        let a = Int32Array::from(vec![Some(2), None, Some(-3)]);
+        let b = Int32Array::from(vec![Some(10), Some(2), Some(3)]);
+        assert_eq!(
+            pow_checked(&a, &b).unwrap(),
+            Int32Array::from(vec![Some(1024), None, Some(-27)])
+        );
+        assert_eq!(
+            pow_scalar_checked(&a, 2).unwrap(),
+            Int32Array::from(vec![Some(4), None, Some(9)])
+        );
+        assert!(pow_scalar_checked(&a, 31).is_err());
+        let b = Int32Array::from(vec![Some(-1), Some(2), Some(3)]);
+        assert!(pow_checked(&a, &b).is_err());
+
+        let a = Float64Array::from(vec![Some(4.0), None]);
+        assert_eq!(
+            powf_scalar(&a, 0.5).unwrap(),
+            Float64Array::from(vec![Some(2.0), None])
+        );
+        let b = Float64Array::from(vec![Some(2.0), Some(1.0)]);
+        assert_eq!(
+            powf(&a, &b).unwrap(),
+            Float64Array::from(vec![Some(16.0), None])
+        );
+    }
 }
@@ -74,6 +74,92 @@ where
     Ok(BooleanArray::from(Arc::new(data)))
 }
 
+/// Helper function to implement binary kernels with Kleene logic, where `op` receives
+/// 64-bit chunks of the left values, left validity, right values and right validity,
+/// and returns chunks of the result values and validity.
+fn binary_boolean_kleene_kernel<F>(
+    left: &BooleanArray,
+    right: &BooleanArray,
+    op: F,
+) -> Result<BooleanArray>
+where
+    F: Fn(u64, u64, u64, u64) -> (u64, u64),
+{
+    if left.len() != right.len() {
+        return Err(ArrowError::ComputeError(
+            "Cannot perform bitwise operation on arrays of different length".to_string(),
+        ));
+    }
+
+    let len = left.len();
+    let len_bytes = ceil(len, 8);
+
+    let left_data = left.data_ref();
+    let right_data = right.data_ref();
+
+    // arrays without a null bitmap are treated as all valid
+    let all_valid = || -> Buffer {
+        MutableBuffer::new(len_bytes)
+            .with_bitset(len_bytes, true)
+            .into()
+    };
+    let (left_valid, left_valid_offset) = match left_data.null_buffer() {
+        Some(buffer) => (buffer.clone(), left.offset()),
+        None => (all_valid(), 0),
+    };
+    let (right_valid, right_valid_offset) = match right_data.null_buffer() {
+        Some(buffer) => (buffer.clone(), right.offset()),
+        None => (all_valid(), 0),
+    };
+
+    let left_values = left_data.buffers()[0].bit_chunks(left.offset(), len);
+    let left_valid = left_valid.bit_chunks(left_valid_offset, len);
+    let right_values = right_data.buffers()[0].bit_chunks(right.offset(), len);
+    let right_valid = right_valid.bit_chunks(right_valid_offset, len);
+
+    // reserve capacity and set length so we can get a typed view of u64 chunks
+    let mut values = MutableBuffer::new(len_bytes).with_bitset(len / 64 * 8, false);
+    let mut valid = MutableBuffer::new(len_bytes).with_bitset(len / 64 * 8, false);
+
+    values
+        .typed_data_mut::<u64>()
+        .iter_mut()
+        .zip(valid.typed_data_mut::<u64>().iter_mut())
+        .zip(
+            left_values
+                .iter()
+                .zip(left_valid.iter())
+                .zip(right_values.iter().zip(right_valid.iter())),
+        )
+        .for_each(|((value, valid), ((l, l_valid), (r, r_valid)))| {
+            let (v, n) = op(l, l_valid, r, r_valid);
+            *value = v;
+            *valid = n;
+        });
+
+    let remainder_bytes = ceil(left_values.remainder_len(), 8);
+    let (v, n) = op(
+        left_values.remainder_bits(),
+        left_valid.remainder_bits(),
+        right_values.remainder_bits(),
+        right_valid.remainder_bits(),
+    );
+    // we are counting its starting from the least significant bit, to to_le_bytes should be correct
+    values.extend_from_slice(&v.to_le_bytes()[0..remainder_bytes]);
+    valid.extend_from_slice(&n.to_le_bytes()[0..remainder_bytes]);
+
+    let data = ArrayData::new(
+        DataType::Boolean,
+        len,
+        None,
+        Some(valid.into()),
+        0,
+        vec![values.into()],
+        vec![],
+    );
+    Ok(BooleanArray::from(Arc::new(data)))
+}
+
 /// Performs `AND` operation on two arrays. If either left or right value is null then the
 /// result is also null.
 /// # Error
@@ -116,6 +202,64 @@ pub fn or(left: &BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
     binary_boolean_kernel(&left, &right, buffer_bin_or)
 }
 
+/// Performs `AND` operation on two arrays using
+/// [Kleene logic](https://en.wikipedia.org/wiki/Three-valued_logic#Kleene_and_Priest_logics),
+/// as used by SQL: `false AND null` is `false`, while `true AND null` is null.
+/// # Error
+/// This function errors when the arrays have different lengths.
+/// # Example
+/// ```rust
+/// use arrow::array::BooleanArray;
+/// use arrow::error::Result;
+/// use arrow::compute::kernels::boolean::and_kleene;
+/// # fn main() -> Result<()> {
+/// let a = BooleanArray::from(vec![Some(true), Some(false), None]);
+/// let b = BooleanArray::from(vec![None, None, None]);
+/// let and_ab = and_kleene(&a, &b)?;
+/// assert_eq!(and_ab, BooleanArray::from(vec![None, Some(false), None]));
+/// # Ok(())
+/// # }
+/// ```
+pub fn and_kleene(left: &BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
+    if left.null_count() == 0 && right.null_count() == 0 {
+        return and(left, right);
+    }
+    binary_boolean_kleene_kernel(left, right, |l, l_valid, r, r_valid| {
+        // the result is known if both sides are, or if either side is a valid false
+        let valid = (l_valid & r_valid) | (l_valid & !l) | (r_valid & !r);
+        (l & r, valid)
+    })
+}
+
+/// Performs `OR` operation on two arrays using
+/// [Kleene logic](https://en.wikipedia.org/wiki/Three-valued_logic#Kleene_and_Priest_logics),
+/// as used by SQL: `true OR null` is `true`, while `false OR null` is null.
+/// # Error
+/// This function errors when the arrays have different lengths.
+/// # Example
+/// ```rust
+/// use arrow::array::BooleanArray;
+/// use arrow::error::Result;
+/// use arrow::compute::kernels::boolean::or_kleene;
+/// # fn main() -> Result<()> {
+/// let a = BooleanArray::from(vec![Some(true), Some(false), None]);
+/// let b = BooleanArray::from(vec![None, None, None]);
+/// let or_ab = or_kleene(&a, &b)?;
+/// assert_eq!(or_ab, BooleanArray::from(vec![Some(true), None, None]));
+/// # Ok(())
+/// # }
+/// ```
+pub fn or_kleene(left: &BooleanArray, right: &BooleanArray) -> Result<BooleanArray> {
+    if left.null_count() == 0 && right.null_count() == 0 {
+        return or(left, right);
+    }
+    binary_boolean_kleene_kernel(left, right, |l, l_valid, r, r_valid| {
+        // the result is known if both sides are, or if either side is a valid true
+        let valid = (l_valid & r_valid) | (l_valid & l) | (r_valid & r);
+        (l | r, valid)
+    })
+}
+
 /// Performs unary `NOT` operation on an arrays. If value is null then the result is also
 /// null.
 /// # Error
@@ -540,6 +684,149 @@ mod tests {
         assert_eq!(expected, c);
     }
 
+    #[test]
+    fn test_bool_array_and_kleene_nulls() {
+        let a = BooleanArray::from(vec![
+            None,
+            None,
+            None,
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(true),
+            Some(true),
+            Some(true),
+        ]);
+        let b = BooleanArray::from(vec![
+            None,
+            Some(false),
+            Some(true),
+            None,
+            Some(false),
+            Some(true),
+            None,
+            Some(false),
+            Some(true),
+        ]);
+        let c = and_kleene(&a, &b).unwrap();
+
+        let expected = BooleanArray::from(vec![
+            None,
+            Some(false),
+            None,
+            Some(false),
+            Some(false),
+            Some(false),
+            None,
+            Some(false),
+            Some(true),
+        ]);
+
+        assert_eq!(c, expected);
+    }
+
+    #[test]
+    fn test_bool_array_or_kleene_nulls() {
+        let a = BooleanArray::from(vec![
+            None,
+            None,
+            None,
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(true),
+            Some(true),
+            Some(true),
+        ]);
+        let b = BooleanArray::from(vec![
+            None,
+            Some(false),
+            Some(true),
+            None,
+            Some(false),
+            Some(true),
+            None,
+            Some(false),
+            Some(true),
+        ]);
+        let c = or_kleene(&a, &b).unwrap();
+
+        let expected = BooleanArray::from(vec![
+            None,
+            None,
+            Some(true),
+            None,
+            Some(false),
+            Some(true),
+            Some(true),
+            Some(true),
+            Some(true),
+        ]);
+
+        assert_eq!(c, expected);
+    }
+
+    #[test]
+    fn test_bool_array_kleene_one_side_nullable() {
+        let a = BooleanArray::from(vec![false, true, false, true]);
+        let b = BooleanArray::from(vec![None, None, Some(true), Some(false)]);
+
+        let c = and_kleene(&a, &b).unwrap();
+        let expected =
+            BooleanArray::from(vec![Some(false), None, Some(false), Some(false)]);
+        assert_eq!(c, expected);
+
+        let c = or_kleene(&b, &a).unwrap();
+        let expected = BooleanArray::from(vec![None, Some(true), Some(true), Some(true)]);
+        assert_eq!(c, expected);
+    }
+
+    #[test]
+    fn test_bool_array_kleene_sliced() {
+        // longer than 64 values so that both the chunks and the remainder are used
+        let a: Vec<Option<bool>> = (0..200)
+            .map(|i| match i % 3 {
+                0 => None,
+                1 => Some(false),
+                _ => Some(true),
+            })
+            .collect();
+        let b: Vec<Option<bool>> = (0..200)
+            .map(|i| match i % 5 {
+                0 | 1 => None,
+                2 => Some(false),
+                _ => Some(true),
+            })
+            .collect();
+        let a_array = BooleanArray::from(a.clone());
+        let a_array = a_array.slice(3, 150);
+        let a_array = a_array.as_any().downcast_ref::<BooleanArray>().unwrap();
+        let b_array = BooleanArray::from(b.clone());
+        let b_array = b_array.slice(37, 150);
+        let b_array = b_array.as_any().downcast_ref::<BooleanArray>().unwrap();
+
+        let pairs = || a[3..153].iter().zip(b[37..187].iter());
+        let expected_and: Vec<Option<bool>> = pairs()
+            .map(|(l, r)| match (l, r) {
+                (Some(false), _) | (_, Some(false)) => Some(false),
+                (Some(true), Some(true)) => Some(true),
+                _ => None,
+            })
+            .collect();
+        let expected_or: Vec<Option<bool>> = pairs()
+            .map(|(l, r)| match (l, r) {
+                (Some(true), _) | (_, Some(true)) => Some(true),
+                (Some(false), Some(false)) => Some(false),
+                _ => None,
+            })
+            .collect();
+
+        let c = and_kleene(a_array, b_array).unwrap();
+        assert_eq!(c, BooleanArray::from(expected_and));
+        let c = or_kleene(a_array, b_array).unwrap();
+        assert_eq!(c, BooleanArray::from(expected_or));
+    }
+
     #[test]
     fn test_nonnull_array_is_null() {
         let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3, 4]));
@@ -254,4 +254,44 @@ mod tests {
 
         Ok(())
     }
+
+    fn collect_string_dictionary(
+        array: &DictionaryArray<Int16Type>,
+    ) -> Vec<Option<String>> {
+        let values = array.values();
+        let values = values.as_any().downcast_ref::<StringArray>().unwrap();
+        array
+            .keys()
+            .iter()
+            .map(|key| key.map(|key| values.value(key as usize).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_concat_string_dictionaries_with_different_values() -> Result<()> {
+        let input_1: DictionaryArray<Int16Type> =
+            vec!["a", "b", "a"].into_iter().collect();
+        let input_2: DictionaryArray<Int16Type> =
+            vec![Some("c"), None, Some("a")].into_iter().collect();
+
+        let arr = concat(&[&input_1, &input_2])?;
+        let arr = arr
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int16Type>>()
+            .unwrap();
+
+        assert_eq!(arr.null_count(), 1);
+        assert_eq!(
+            collect_string_dictionary(arr),
+            vec![
+                Some("a".to_string()),
+                Some("b".to_string()),
+                Some("a".to_string()),
+                Some("c".to_string()),
+                None,
+                Some("a".to_string()),
+            ]
+        );
+        Ok(())
+    }
 }
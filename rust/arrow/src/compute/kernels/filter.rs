@@ -251,7 +251,7 @@ mod tests {
     use super::*;
     use crate::{
         buffer::Buffer,
-        datatypes::{DataType, Field},
+        datatypes::{DataType, Field, Float64Type, Int32Type},
     };
 
     macro_rules! def_temporal_test {
@@ -581,4 +581,51 @@ mod tests {
         assert_eq!(chunks, vec![(1, 62), (63, 124), (125, 130)]);
         assert_eq!(filter_count, 61 + 61 + 5);
     }
+
+    #[test]
+    fn test_filter_fixed_size_list() {
+        let value_data = Int32Array::from(vec![0, 1, 2, 3, 4, 5, 6, 7]).data();
+        let list_data_type = DataType::FixedSizeList(
+            Box::new(Field::new("item", DataType::Int32, false)),
+            2,
+        );
+        let list_data = ArrayData::builder(list_data_type)
+            .len(4)
+            .add_child_data(value_data)
+            .build();
+        let list_array = FixedSizeListArray::from(list_data);
+        let list_array = list_array.slice(1, 3);
+
+        let predicate = BooleanArray::from(vec![true, false, true]);
+        let result = filter(list_array.as_ref(), &predicate).unwrap();
+        let result = result
+            .as_any()
+            .downcast_ref::<FixedSizeListArray>()
+            .unwrap();
+
+        assert_eq!(result.len(), 2);
+        let values = result.values();
+        let values = values.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(values, &Int32Array::from(vec![2, 3, 6, 7]));
+    }
+
+    #[test]
+    fn test_filter_union() {
+        let mut builder = UnionBuilder::new_sparse(3);
+        builder.append::<Int32Type>("a", 1).unwrap();
+        builder.append::<Float64Type>("b", 3.2).unwrap();
+        builder.append::<Int32Type>("a", 34).unwrap();
+        let array = builder.build().unwrap();
+
+        let predicate = BooleanArray::from(vec![false, true, true]);
+        let result = filter(&array, &predicate).unwrap();
+        let result = result.as_any().downcast_ref::<UnionArray>().unwrap();
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result.type_id(0), 1);
+        assert_eq!(result.type_id(1), 0);
+        let value = result.value(1);
+        let value = value.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(value.value(0), 34);
+    }
 }
@@ -0,0 +1,785 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! Defines kernels to hash arrays, to find their distinct values and to test them
+//! for membership in a set of values.
+//!
+//! Example:
+//!
+//! ```
+//! use arrow::array::{Array, Int32Array, StructArray, UInt64Array};
+//! use arrow::compute::{unique, value_counts};
+//!
+//! let array = Int32Array::from(vec![Some(1), Some(2), Some(1), None]);
+//!
+//! let distinct = unique(&array).unwrap();
+//! let distinct = distinct.as_any().downcast_ref::<Int32Array>().unwrap();
+//! assert_eq!(distinct, &Int32Array::from(vec![Some(1), Some(2), None]));
+//!
+//! let counts = value_counts(&array).unwrap();
+//! let counts = counts.column(1);
+//! let counts = counts.as_any().downcast_ref::<UInt64Array>().unwrap();
+//! assert_eq!(counts, &UInt64Array::from(vec![2, 1, 1]));
+//! ```
+
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, BuildHasherDefault, Hasher};
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::compute::kernels::cast::cast;
+use crate::datatypes::*;
+use crate::error::{ArrowError, Result};
+
+/// The hash of a null slot
+const NULL_HASH: u64 = 0;
+
+/// Combines two hashes into one hash
+fn combine_hashes(l: u64, r: u64) -> u64 {
+    let hash = (17 * 37u64).wrapping_add(l);
+    hash.wrapping_mul(37).wrapping_add(r)
+}
+
+fn hash_bytes<S: BuildHasher>(state: &S, bytes: &[u8]) -> u64 {
+    let mut hasher = state.build_hasher();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+/// Returns the number of bytes of a slot of a fixed width type, where the hash of
+/// a slot is the hash of its bytes.
+fn fixed_width(data_type: &DataType) -> Option<usize> {
+    match data_type {
+        DataType::Int8 | DataType::UInt8 => Some(1),
+        DataType::Int16 | DataType::UInt16 | DataType::Float16 => Some(2),
+        DataType::Int32
+        | DataType::UInt32
+        | DataType::Float32
+        | DataType::Date32(_)
+        | DataType::Time32(_)
+        | DataType::Interval(IntervalUnit::YearMonth) => Some(4),
+        DataType::Int64
+        | DataType::UInt64
+        | DataType::Float64
+        | DataType::Date64(_)
+        | DataType::Time64(_)
+        | DataType::Timestamp(_, _)
+        | DataType::Duration(_)
+        | DataType::Interval(IntervalUnit::DayTime) => Some(8),
+        DataType::Decimal(_, _) => Some(16),
+        DataType::FixedSizeBinary(width) => Some(*width as usize),
+        _ => None,
+    }
+}
+
+fn hash_list<S: BuildHasher, O: OffsetSizeTrait>(
+    array: &Array,
+    state: &S,
+) -> Result<Vec<u64>> {
+    let array = array
+        .as_any()
+        .downcast_ref::<GenericListArray<O>>()
+        .unwrap();
+    let values = hash_values(array.values().as_ref(), state)?;
+    Ok((0..array.len())
+        .map(|i| {
+            if array.is_null(i) {
+                return NULL_HASH;
+            }
+            let start = array.value_offset(i).to_usize().unwrap();
+            let end = start + array.value_length(i).to_usize().unwrap();
+            values[start..end]
+                .iter()
+                .fold(combine_hashes(0, (end - start) as u64), |acc, hash| {
+                    combine_hashes(acc, *hash)
+                })
+        })
+        .collect())
+}
+
+fn hash_variable_size<'a, S, I>(state: &S, values: I) -> Vec<u64>
+where
+    S: BuildHasher,
+    I: Iterator<Item = Option<&'a [u8]>>,
+{
+    values
+        .map(|value| match value {
+            Some(value) => hash_bytes(state, value),
+            None => NULL_HASH,
+        })
+        .collect()
+}
+
+fn hash_dictionary<S: BuildHasher, K: ArrowPrimitiveType>(
+    array: &Array,
+    state: &S,
+) -> Result<Vec<u64>> {
+    let array = array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let values = hash_values(array.values().as_ref(), state)?;
+    Ok(array
+        .keys()
+        .iter()
+        .map(|key| match key {
+            Some(key) => values[key.to_usize().unwrap()],
+            None => NULL_HASH,
+        })
+        .collect())
+}
+
+/// Hashes every slot of `array`, independently of the representation of its values:
+/// dictionary arrays hash like their decoded values and `Utf8`, `LargeUtf8`,
+/// `Binary` and `LargeBinary` slots with the same bytes hash alike.
+fn hash_values<S: BuildHasher>(array: &Array, state: &S) -> Result<Vec<u64>> {
+    let data = array.data_ref();
+    if let Some(width) = fixed_width(data.data_type()) {
+        let values = data.buffers()[0].as_slice();
+        return Ok((0..array.len())
+            .map(|i| {
+                if array.is_null(i) {
+                    NULL_HASH
+                } else {
+                    let start = (data.offset() + i) * width;
+                    hash_bytes(state, &values[start..start + width])
+                }
+            })
+            .collect());
+    }
+
+    let hashes = match data.data_type() {
+        DataType::Null => vec![NULL_HASH; array.len()],
+        DataType::Boolean => {
+            let array = array.as_any().downcast_ref::<BooleanArray>().unwrap();
+            array
+                .iter()
+                .map(|value| match value {
+                    Some(value) => hash_bytes(state, &[value as u8]),
+                    None => NULL_HASH,
+                })
+                .collect()
+        }
+        DataType::Utf8 => {
+            let values = array.as_any().downcast_ref::<StringArray>().unwrap();
+            hash_variable_size(state, values.iter().map(|v| v.map(str::as_bytes)))
+        }
+        DataType::LargeUtf8 => {
+            let values = array.as_any().downcast_ref::<LargeStringArray>().unwrap();
+            hash_variable_size(state, values.iter().map(|v| v.map(str::as_bytes)))
+        }
+        DataType::Binary => {
+            let values = array.as_any().downcast_ref::<BinaryArray>().unwrap();
+            hash_variable_size(state, values.iter())
+        }
+        DataType::LargeBinary => {
+            let values = array.as_any().downcast_ref::<LargeBinaryArray>().unwrap();
+            hash_variable_size(state, values.iter())
+        }
+        DataType::List(_) => hash_list::<S, i32>(array, state)?,
+        DataType::LargeList(_) => hash_list::<S, i64>(array, state)?,
+        DataType::FixedSizeList(_, size) => {
+            let size = *size as usize;
+            let array = array.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let values = hash_values(array.values().as_ref(), state)?;
+            (0..array.len())
+                .map(|i| {
+                    if array.is_null(i) {
+                        return NULL_HASH;
+                    }
+                    let start = array.value_offset(i) as usize;
+                    values[start..start + size]
+                        .iter()
+                        .fold(combine_hashes(0, size as u64), |acc, hash| {
+                            combine_hashes(acc, *hash)
+                        })
+                })
+                .collect()
+        }
+        DataType::Struct(_) => {
+            // the children are not sliced with the struct, hence the offset
+            let array = array.as_any().downcast_ref::<StructArray>().unwrap();
+            let mut hashes = vec![0; array.len()];
+            for column in array.columns() {
+                let values = hash_values(column.as_ref(), state)?;
+                hashes.iter_mut().enumerate().for_each(|(i, hash)| {
+                    *hash = combine_hashes(*hash, values[data.offset() + i])
+                });
+            }
+            hashes
+                .into_iter()
+                .enumerate()
+                .map(|(i, hash)| if array.is_null(i) { NULL_HASH } else { hash })
+                .collect()
+        }
+        DataType::Dictionary(key_type, _) => match key_type.as_ref() {
+            DataType::Int8 => hash_dictionary::<S, Int8Type>(array, state)?,
+            DataType::Int16 => hash_dictionary::<S, Int16Type>(array, state)?,
+            DataType::Int32 => hash_dictionary::<S, Int32Type>(array, state)?,
+            DataType::Int64 => hash_dictionary::<S, Int64Type>(array, state)?,
+            DataType::UInt8 => hash_dictionary::<S, UInt8Type>(array, state)?,
+            DataType::UInt16 => hash_dictionary::<S, UInt16Type>(array, state)?,
+            DataType::UInt32 => hash_dictionary::<S, UInt32Type>(array, state)?,
+            DataType::UInt64 => hash_dictionary::<S, UInt64Type>(array, state)?,
+            t => {
+                return Err(ArrowError::ComputeError(format!(
+                    "Hash not supported for dictionary key type {:?}",
+                    t
+                )))
+            }
+        },
+        t => {
+            return Err(ArrowError::ComputeError(format!(
+                "Hash not supported for data type {:?}",
+                t
+            )))
+        }
+    };
+    Ok(hashes)
+}
+
+/// Hashes every slot of `array` into a [UInt64Array] using a [DefaultHasher] with
+/// fixed keys, so that equal values hash alike across arrays and calls.
+///
+/// Null slots hash to 0. Dictionary arrays hash like their decoded values, string
+/// and binary slots with the same bytes hash alike regardless of their offset size,
+/// and lists and structs combine the hashes of their children.
+pub fn hash(array: &Array) -> Result<UInt64Array> {
+    hash_with_state(array, &BuildHasherDefault::<DefaultHasher>::default())
+}
+
+/// Like [hash], but builds the hasher of every slot from `state`.
+pub fn hash_with_state<S: BuildHasher>(array: &Array, state: &S) -> Result<UInt64Array> {
+    Ok(UInt64Array::from(hash_values(array, state)?))
+}
+
+fn list_slots_equal<O: OffsetSizeTrait>(
+    left: &Array,
+    i: usize,
+    right: &Array,
+    j: usize,
+) -> bool {
+    let left = left.as_any().downcast_ref::<GenericListArray<O>>().unwrap();
+    let right = right
+        .as_any()
+        .downcast_ref::<GenericListArray<O>>()
+        .unwrap();
+    let len = left.value_length(i);
+    let (i_start, j_start) = (
+        left.value_offset(i).to_usize().unwrap(),
+        right.value_offset(j).to_usize().unwrap(),
+    );
+    len == right.value_length(j)
+        && (0..len.to_usize().unwrap()).all(|k| {
+            slots_equal(
+                left.values().as_ref(),
+                i_start + k,
+                right.values().as_ref(),
+                j_start + k,
+            )
+        })
+}
+
+fn dictionary_slots_equal<K: ArrowPrimitiveType>(
+    left: &Array,
+    i: usize,
+    right: &Array,
+    j: usize,
+) -> bool {
+    let left = left.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let right = right.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    slots_equal(
+        left.values().as_ref(),
+        left.keys().value(i).to_usize().unwrap(),
+        right.values().as_ref(),
+        right.keys().value(j).to_usize().unwrap(),
+    )
+}
+
+/// Returns whether the slot `i` of `left` and the slot `j` of `right` hold equal
+/// values, where two nulls are equal. Both arrays must have the same data type, one
+/// supported by [hash_values].
+fn slots_equal(left: &Array, i: usize, right: &Array, j: usize) -> bool {
+    match (left.is_null(i), right.is_null(j)) {
+        (true, true) => return true,
+        (false, false) => {}
+        _ => return false,
+    }
+    let (left_data, right_data) = (left.data_ref(), right.data_ref());
+    if let Some(width) = fixed_width(left_data.data_type()) {
+        let i = (left_data.offset() + i) * width;
+        let j = (right_data.offset() + j) * width;
+        return left_data.buffers()[0].as_slice()[i..i + width]
+            == right_data.buffers()[0].as_slice()[j..j + width];
+    }
+
+    macro_rules! value_equal {
+        ($array_type:ty) => {{
+            let left = left.as_any().downcast_ref::<$array_type>().unwrap();
+            let right = right.as_any().downcast_ref::<$array_type>().unwrap();
+            left.value(i) == right.value(j)
+        }};
+    }
+
+    match left_data.data_type() {
+        DataType::Null => true,
+        DataType::Boolean => value_equal!(BooleanArray),
+        DataType::Utf8 => value_equal!(StringArray),
+        DataType::LargeUtf8 => value_equal!(LargeStringArray),
+        DataType::Binary => value_equal!(BinaryArray),
+        DataType::LargeBinary => value_equal!(LargeBinaryArray),
+        DataType::List(_) => list_slots_equal::<i32>(left, i, right, j),
+        DataType::LargeList(_) => list_slots_equal::<i64>(left, i, right, j),
+        DataType::FixedSizeList(_, size) => {
+            let left = left.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let right = right.as_any().downcast_ref::<FixedSizeListArray>().unwrap();
+            let (i_start, j_start) = (left.value_offset(i), right.value_offset(j));
+            (0..*size).all(|k| {
+                slots_equal(
+                    left.values().as_ref(),
+                    (i_start + k) as usize,
+                    right.values().as_ref(),
+                    (j_start + k) as usize,
+                )
+            })
+        }
+        DataType::Struct(_) => {
+            // the children are not sliced with the struct, hence the offsets
+            let left = left.as_any().downcast_ref::<StructArray>().unwrap();
+            let right = right.as_any().downcast_ref::<StructArray>().unwrap();
+            left.columns()
+                .iter()
+                .zip(right.columns())
+                .all(|(left, right)| {
+                    slots_equal(
+                        left.as_ref(),
+                        left_data.offset() + i,
+                        right.as_ref(),
+                        right_data.offset() + j,
+                    )
+                })
+        }
+        DataType::Dictionary(key_type, _) => match key_type.as_ref() {
+            DataType::Int8 => dictionary_slots_equal::<Int8Type>(left, i, right, j),
+            DataType::Int16 => dictionary_slots_equal::<Int16Type>(left, i, right, j),
+            DataType::Int32 => dictionary_slots_equal::<Int32Type>(left, i, right, j),
+            DataType::Int64 => dictionary_slots_equal::<Int64Type>(left, i, right, j),
+            DataType::UInt8 => dictionary_slots_equal::<UInt8Type>(left, i, right, j),
+            DataType::UInt16 => dictionary_slots_equal::<UInt16Type>(left, i, right, j),
+            DataType::UInt32 => dictionary_slots_equal::<UInt32Type>(left, i, right, j),
+            DataType::UInt64 => dictionary_slots_equal::<UInt64Type>(left, i, right, j),
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    }
+}
+
+/// Returns the index of the first occurrence of every distinct value of `array`, in
+/// order of appearance, and the number of occurrences of each.
+fn distinct(array: &Array) -> Result<(Vec<usize>, Vec<u64>)> {
+    let hashes = hash_values(array, &BuildHasherDefault::<DefaultHasher>::default())?;
+
+    let mut first_indices: Vec<usize> = vec![];
+    let mut counts: Vec<u64> = vec![];
+    // maps a hash to the positions in `first_indices` of the values with that hash
+    let mut seen: HashMap<u64, Vec<usize>> = HashMap::new();
+    for (i, hash) in hashes.into_iter().enumerate() {
+        let candidates = seen.entry(hash).or_default();
+        let position = candidates
+            .iter()
+            .find(|position| slots_equal(array, first_indices[**position], array, i));
+        match position {
+            Some(position) => counts[*position] += 1,
+            None => {
+                candidates.push(first_indices.len());
+                first_indices.push(i);
+                counts.push(1);
+            }
+        }
+    }
+    Ok((first_indices, counts))
+}
+
+/// Copies the slots of `array` at `indices` into a new array
+fn take_slots(array: &Array, indices: &[usize]) -> ArrayRef {
+    let mut mutable =
+        MutableArrayData::new(vec![array.data_ref().as_ref()], false, indices.len());
+    indices.iter().for_each(|i| mutable.extend(0, *i, *i + 1));
+    make_array(Arc::new(mutable.freeze()))
+}
+
+/// Returns the distinct values of `array` in order of first appearance. Null counts
+/// as a value, so the result contains at most one null.
+pub fn unique(array: &Array) -> Result<ArrayRef> {
+    let (indices, _) = distinct(array)?;
+    Ok(take_slots(array, &indices))
+}
+
+/// Returns a [StructArray] with a `values` column holding the distinct values of
+/// `array` in order of first appearance and a `counts` column of type `UInt64`
+/// holding how often each of them occurs. Nulls are counted as a value.
+pub fn value_counts(array: &Array) -> Result<StructArray> {
+    let (indices, counts) = distinct(array)?;
+    let values = take_slots(array, &indices);
+    let counts = Arc::new(UInt64Array::from(counts)) as ArrayRef;
+    Ok(StructArray::from(vec![
+        (
+            Field::new("values", array.data_type().clone(), true),
+            values,
+        ),
+        (Field::new("counts", DataType::UInt64, false), counts),
+    ]))
+}
+
+/// A set of values built once from an array of candidates, to test arrays for
+/// membership with [is_in].
+#[derive(Debug)]
+pub struct ValueSet {
+    /// the candidates, with dictionaries unpacked
+    values: ArrayRef,
+    /// maps a hash to the slots of `values` with that hash, without duplicates
+    slots: HashMap<u64, Vec<usize>>,
+    contains_null: bool,
+}
+
+impl ValueSet {
+    /// Builds a set of the values of `values`. A dictionary array is unpacked into
+    /// its value type, and a null in `values` makes [is_in] return null instead of
+    /// false for slots that are not found.
+    pub fn try_new(values: &Array) -> Result<Self> {
+        let values = match values.data_type() {
+            DataType::Dictionary(_, value_type) => {
+                let values = make_array(values.data());
+                cast(&values, value_type)?
+            }
+            _ => make_array(values.data()),
+        };
+        let hashes = hash_values(
+            values.as_ref(),
+            &BuildHasherDefault::<DefaultHasher>::default(),
+        )?;
+
+        let mut slots: HashMap<u64, Vec<usize>> = HashMap::new();
+        let mut contains_null = false;
+        for (i, hash) in hashes.into_iter().enumerate() {
+            if values.is_null(i) {
+                contains_null = true;
+                continue;
+            }
+            let candidates = slots.entry(hash).or_default();
+            if !candidates
+                .iter()
+                .any(|j| slots_equal(values.as_ref(), *j, values.as_ref(), i))
+            {
+                candidates.push(i);
+            }
+        }
+        Ok(Self {
+            values,
+            slots,
+            contains_null,
+        })
+    }
+
+    /// Returns the data type of the values of this set
+    pub fn data_type(&self) -> &DataType {
+        self.values.data_type()
+    }
+
+    /// Returns the number of distinct non-null values in this set
+    pub fn len(&self) -> usize {
+        self.slots.values().map(|slots| slots.len()).sum()
+    }
+
+    /// Returns whether this set has no non-null values
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+
+    /// Returns whether this set contains a null
+    pub fn contains_null(&self) -> bool {
+        self.contains_null
+    }
+
+    fn contains(&self, array: &Array, i: usize, hash: u64) -> bool {
+        match self.slots.get(&hash) {
+            Some(slots) => slots
+                .iter()
+                .any(|j| slots_equal(array, i, self.values.as_ref(), *j)),
+            None => false,
+        }
+    }
+}
+
+fn dictionary_is_in<K: ArrowPrimitiveType>(
+    array: &Array,
+    value_set: &ValueSet,
+) -> Result<BooleanArray> {
+    let array = array.as_any().downcast_ref::<DictionaryArray<K>>().unwrap();
+    let values = is_in(array.values().as_ref(), value_set)?;
+    Ok(array
+        .keys()
+        .iter()
+        .map(|key| {
+            key.and_then(|key| {
+                let key = key.to_usize().unwrap();
+                if values.is_null(key) {
+                    None
+                } else {
+                    Some(values.value(key))
+                }
+            })
+        })
+        .collect())
+}
+
+/// Returns whether each slot of `array` is one of the values of `value_set`, with
+/// the null semantics of SQL's `IN`: a null slot is null, and a slot that is not
+/// found is null if the set contains a null and false otherwise.
+///
+/// `array` must have the data type of the set, or be a dictionary array whose value
+/// type is the data type of the set.
+///
+/// Example:
+///
+/// ```
+/// use arrow::array::{BooleanArray, StringArray};
+/// use arrow::compute::{is_in, ValueSet};
+///
+/// let value_set = ValueSet::try_new(&StringArray::from(vec!["a", "c"])).unwrap();
+/// let array = StringArray::from(vec![Some("a"), Some("b"), None]);
+/// let result = is_in(&array, &value_set).unwrap();
+/// assert_eq!(result, BooleanArray::from(vec![Some(true), Some(false), None]));
+/// ```
+pub fn is_in(array: &Array, value_set: &ValueSet) -> Result<BooleanArray> {
+    if array.data_type() != value_set.data_type() {
+        return match array.data_type() {
+            DataType::Dictionary(key_type, value_type)
+                if value_type.as_ref() == value_set.data_type() =>
+            {
+                match key_type.as_ref() {
+                    DataType::Int8 => dictionary_is_in::<Int8Type>(array, value_set),
+                    DataType::Int16 => dictionary_is_in::<Int16Type>(array, value_set),
+                    DataType::Int32 => dictionary_is_in::<Int32Type>(array, value_set),
+                    DataType::Int64 => dictionary_is_in::<Int64Type>(array, value_set),
+                    DataType::UInt8 => dictionary_is_in::<UInt8Type>(array, value_set),
+                    DataType::UInt16 => dictionary_is_in::<UInt16Type>(array, value_set),
+                    DataType::UInt32 => dictionary_is_in::<UInt32Type>(array, value_set),
+                    DataType::UInt64 => dictionary_is_in::<UInt64Type>(array, value_set),
+                    t => Err(ArrowError::ComputeError(format!(
+                        "is_in not supported for dictionary key type {:?}",
+                        t
+                    ))),
+                }
+            }
+            t => Err(ArrowError::ComputeError(format!(
+                "is_in requires an array of type {:?}, got {:?}",
+                value_set.data_type(),
+                t
+            ))),
+        };
+    }
+
+    let hashes = hash_values(array, &BuildHasherDefault::<DefaultHasher>::default())?;
+    Ok(hashes
+        .into_iter()
+        .enumerate()
+        .map(|(i, hash)| {
+            if array.is_null(i) {
+                None
+            } else if value_set.contains(array, i, hash) {
+                Some(true)
+            } else if value_set.contains_null() {
+                None
+            } else {
+                Some(false)
+            }
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_consistent_across_types() -> Result<()> {
+        let strings = StringArray::from(vec![Some("a"), None, Some("bc"), Some("a")]);
+        let large = LargeStringArray::from(vec![Some("a"), None, Some("bc"), Some("a")]);
+        let binary = BinaryArray::from(vec![
+            Some("a".as_bytes()),
+            None,
+            Some("bc".as_bytes()),
+            Some("a".as_bytes()),
+        ]);
+        let dictionary: DictionaryArray<Int8Type> =
+            vec![Some("a"), None, Some("bc"), Some("a")]
+                .into_iter()
+                .collect();
+
+        let expected = hash(&strings)?;
+        assert_eq!(expected.value(0), expected.value(3));
+        assert_ne!(expected.value(0), expected.value(2));
+        assert_eq!(expected.value(1), 0);
+        assert_eq!(hash(&large)?, expected);
+        assert_eq!(hash(&binary)?, expected);
+        assert_eq!(hash(&dictionary)?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_sliced() -> Result<()> {
+        let array = Int64Array::from(vec![Some(1), Some(2), None, Some(1)]);
+        let hashes = hash(&array)?;
+        let sliced = hash(array.slice(1, 3).as_ref())?;
+        assert_eq!(
+            sliced,
+            UInt64Array::from(vec![hashes.value(1), 0, hashes.value(3)])
+        );
+        assert_eq!(hashes.value(0), hashes.value(3));
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_list() -> Result<()> {
+        let mut builder = ListBuilder::new(Int32Builder::new(6));
+        for value in &[vec![1, 2], vec![3], vec![1, 2], vec![2, 1]] {
+            builder.values().append_slice(value)?;
+            builder.append(true)?;
+        }
+        builder.append(false)?;
+        let array = builder.finish();
+
+        let hashes = hash(&array)?;
+        assert_eq!(hashes.value(0), hashes.value(2));
+        assert_ne!(hashes.value(0), hashes.value(1));
+        assert_ne!(hashes.value(0), hashes.value(3));
+        assert_eq!(hashes.value(4), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_hash_unsupported() {
+        let array = UnionBuilder::new_dense(1).build().unwrap();
+        assert!(hash(&array).is_err());
+    }
+
+    #[test]
+    fn test_unique() -> Result<()> {
+        let array = StringArray::from(vec![
+            Some("b"),
+            None,
+            Some("a"),
+            Some("b"),
+            None,
+            Some("c"),
+        ]);
+        let result = unique(&array)?;
+        let result = result.as_any().downcast_ref::<StringArray>().unwrap();
+        let expected = StringArray::from(vec![Some("b"), None, Some("a"), Some("c")]);
+        assert_eq!(result, &expected);
+
+        let array = Float64Array::from(vec![1.5, 1.5, 2.0]);
+        let result = unique(&array)?;
+        let result = result.as_any().downcast_ref::<Float64Array>().unwrap();
+        assert_eq!(result, &Float64Array::from(vec![1.5, 2.0]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_value_counts() -> Result<()> {
+        let array: DictionaryArray<Int32Type> =
+            vec![Some("x"), Some("y"), Some("x"), None, Some("x")]
+                .into_iter()
+                .collect();
+        let result = value_counts(&array)?;
+        assert_eq!(result.len(), 3);
+
+        let values = result.column_by_name("values").unwrap();
+        let values = values
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int32Type>>()
+            .unwrap();
+        let keys = values.keys().iter().collect::<Vec<_>>();
+        assert_eq!(keys, vec![Some(0), Some(1), None]);
+
+        let counts = result.column_by_name("counts").unwrap();
+        let counts = counts.as_any().downcast_ref::<UInt64Array>().unwrap();
+        assert_eq!(counts, &UInt64Array::from(vec![3, 1, 1]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_in() -> Result<()> {
+        let value_set = ValueSet::try_new(&Int32Array::from(vec![1, 3, 3, 5]))?;
+        assert_eq!(value_set.len(), 3);
+        assert!(!value_set.contains_null());
+
+        let array = Int32Array::from(vec![Some(1), Some(2), None, Some(5)]);
+        let result = is_in(&array, &value_set)?;
+        let expected =
+            BooleanArray::from(vec![Some(true), Some(false), None, Some(true)]);
+        assert_eq!(result, expected);
+
+        let result = is_in(array.slice(1, 3).as_ref(), &value_set)?;
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![Some(false), None, Some(true)])
+        );
+
+        let value_set = ValueSet::try_new(&Int32Array::from(vec![Some(1), None]))?;
+        assert!(value_set.contains_null());
+        let result = is_in(&array, &value_set)?;
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![Some(true), None, None, None])
+        );
+
+        assert!(is_in(&Int64Array::from(vec![1]), &value_set).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_in_dictionary() -> Result<()> {
+        let values: DictionaryArray<Int8Type> = vec!["a", "b"].into_iter().collect();
+        let value_set = ValueSet::try_new(&values)?;
+        assert_eq!(value_set.data_type(), &DataType::Utf8);
+
+        let array: DictionaryArray<Int16Type> =
+            vec![Some("b"), None, Some("c"), Some("a")]
+                .into_iter()
+                .collect();
+        let result = is_in(&array, &value_set)?;
+        let expected =
+            BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+        assert_eq!(result, expected);
+
+        let array = StringArray::from(vec!["c", "a"]);
+        let result = is_in(&array, &value_set)?;
+        assert_eq!(result, BooleanArray::from(vec![false, true]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_in_binary() -> Result<()> {
+        let value_set = ValueSet::try_new(&LargeBinaryArray::from(vec![
+            "ab".as_bytes(),
+            "".as_bytes(),
+        ]))?;
+        let array = LargeBinaryArray::from(vec!["".as_bytes(), "a".as_bytes()]);
+        let result = is_in(&array, &value_set)?;
+        assert_eq!(result, BooleanArray::from(vec![true, false]));
+        Ok(())
+    }
+}
@@ -24,9 +24,13 @@ pub mod cast;
 pub mod comparison;
 pub mod concat;
 pub mod filter;
+pub mod hash;
 pub mod length;
 pub mod limit;
+pub mod partition;
+pub mod regexp;
 pub mod sort;
 pub mod substring;
 pub mod take;
 pub mod temporal;
+pub mod zip;
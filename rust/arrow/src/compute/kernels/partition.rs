@@ -0,0 +1,219 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines partition kernels, which find the ranges of equal keys in sorted columns.
+
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use crate::compute::kernels::sort::{LexicographicalComparator, SortColumn};
+use crate::error::{ArrowError, Result};
+
+/// Given columns sorted lexicographically with the same [SortOptions](super::sort::SortOptions)
+/// as `columns`, returns the contiguous ranges of rows with equal keys, in order.
+///
+/// Nulls are equal to each other and different from any value. The result is undefined
+/// if the columns are not sorted.
+///
+/// Example:
+///
+/// ```
+/// use std::sync::Arc;
+/// use arrow::array::{ArrayRef, Int32Array};
+/// use arrow::compute::kernels::partition::lexicographical_partition_ranges;
+/// use arrow::compute::kernels::sort::SortColumn;
+///
+/// let columns = vec![SortColumn {
+///     values: Arc::new(Int32Array::from(vec![1, 1, 2, 3, 3, 3])) as ArrayRef,
+///     options: None,
+/// }];
+/// let ranges = lexicographical_partition_ranges(&columns).unwrap();
+/// assert_eq!(ranges.collect::<Vec<_>>(), vec![0..2, 2..3, 3..6]);
+/// ```
+pub fn lexicographical_partition_ranges(
+    columns: &[SortColumn],
+) -> Result<impl Iterator<Item = Range<usize>> + '_> {
+    LexicographicalPartitionIterator::try_new(columns)
+}
+
+struct LexicographicalPartitionIterator<'a> {
+    comparator: LexicographicalComparator<'a>,
+    num_rows: usize,
+    previous_partition_point: usize,
+}
+
+impl<'a> LexicographicalPartitionIterator<'a> {
+    fn try_new(columns: &'a [SortColumn]) -> Result<Self> {
+        if columns.is_empty() {
+            return Err(ArrowError::InvalidArgumentError(
+                "Partition requires at least one column".to_string(),
+            ));
+        }
+        let num_rows = columns[0].values.len();
+        if columns.iter().any(|item| item.values.len() != num_rows) {
+            return Err(ArrowError::ComputeError(
+                "Lexical sort columns have different row counts".to_string(),
+            ));
+        };
+
+        Ok(Self {
+            comparator: LexicographicalComparator::try_new(columns)?,
+            num_rows,
+            previous_partition_point: 0,
+        })
+    }
+}
+
+impl<'a> Iterator for LexicographicalPartitionIterator<'a> {
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.previous_partition_point >= self.num_rows {
+            return None;
+        }
+        let start = self.previous_partition_point;
+        let end = exponential_search_next_partition_point(start, self.num_rows, |idx| {
+            self.comparator.compare(&start, &idx) == Ordering::Equal
+        });
+        self.previous_partition_point = end;
+        Some(start..end)
+    }
+}
+
+/// Returns the first index in `start..end` for which `pred` is false, given that `pred`
+/// is true for a (possibly empty) prefix of the range and false afterwards.
+///
+/// Short runs are found in `O(log(run length))` by doubling the probed distance before
+/// the binary search.
+fn exponential_search_next_partition_point<F>(start: usize, end: usize, pred: F) -> usize
+where
+    F: Fn(usize) -> bool,
+{
+    let mut bound = 1;
+    while start + bound < end && pred(start + bound) {
+        bound *= 2;
+    }
+
+    // the partition point is within (start + bound / 2, min(start + bound, end)]
+    let mut low = start + bound / 2;
+    let mut high = end.min(start + bound);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::array::*;
+    use crate::compute::kernels::sort::SortOptions;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_partition_point() {
+        let input = vec![1, 1, 1, 2, 2, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5];
+        let mut ranges = vec![];
+        let mut start = 0;
+        while start < input.len() {
+            let end = exponential_search_next_partition_point(start, input.len(), |i| {
+                input[i] == input[start]
+            });
+            ranges.push(start..end);
+            start = end;
+        }
+        assert_eq!(ranges, vec![0..3, 3..5, 5..6, 6..15, 15..16]);
+    }
+
+    #[test]
+    fn test_lexicographical_partition_ranges_empty() {
+        assert!(lexicographical_partition_ranges(&[]).is_err());
+
+        let columns = vec![SortColumn {
+            values: Arc::new(Int64Array::from(Vec::<i64>::new())) as ArrayRef,
+            options: None,
+        }];
+        let ranges = lexicographical_partition_ranges(&columns).unwrap();
+        assert_eq!(ranges.count(), 0);
+    }
+
+    #[test]
+    fn test_lexicographical_partition_ranges_different_lengths() {
+        let columns = vec![
+            SortColumn {
+                values: Arc::new(Int64Array::from(vec![1, 2])) as ArrayRef,
+                options: None,
+            },
+            SortColumn {
+                values: Arc::new(Int64Array::from(vec![1])) as ArrayRef,
+                options: None,
+            },
+        ];
+        assert!(lexicographical_partition_ranges(&columns).is_err());
+    }
+
+    #[test]
+    fn test_lexicographical_partition_ranges_with_nulls() {
+        let columns = vec![
+            SortColumn {
+                values: Arc::new(Int64Array::from(vec![
+                    None,
+                    None,
+                    Some(3),
+                    Some(3),
+                    Some(1),
+                    Some(1),
+                ])) as ArrayRef,
+                options: Some(SortOptions {
+                    descending: true,
+                    nulls_first: true,
+                }),
+            },
+            SortColumn {
+                values: Arc::new(StringArray::from(vec![
+                    Some("a"),
+                    Some("a"),
+                    Some("b"),
+                    Some("c"),
+                    None,
+                    None,
+                ])) as ArrayRef,
+                options: Some(SortOptions {
+                    descending: false,
+                    nulls_first: true,
+                }),
+            },
+        ];
+        let ranges = lexicographical_partition_ranges(&columns).unwrap();
+        assert_eq!(ranges.collect::<Vec<_>>(), vec![0..2, 2..3, 3..4, 4..6]);
+    }
+
+    #[test]
+    fn test_lexicographical_partition_ranges_single_run() {
+        let columns = vec![SortColumn {
+            values: Arc::new(BooleanArray::from(vec![true; 1000])) as ArrayRef,
+            options: None,
+        }];
+        let ranges = lexicographical_partition_ranges(&columns).unwrap();
+        assert_eq!(ranges.collect::<Vec<_>>(), vec![0..1000]);
+    }
+}
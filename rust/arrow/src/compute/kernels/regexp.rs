@@ -0,0 +1,633 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Defines kernels to match, extract and replace regular expressions in
+//! \[Large\]StringArrays.
+//!
+//! Every kernel comes in two variants: one taking a single pattern for the whole
+//! array, and one taking an array of patterns, where each distinct pattern is
+//! compiled once per call. Flags are the inline flags of the
+//! [regex](https://docs.rs/regex) crate, e.g. `i` for case-insensitive matching.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use regex::Regex;
+
+use crate::array::*;
+use crate::compute::kernels::filter::filter;
+use crate::error::{ArrowError, Result};
+
+/// Compiles `pattern` with optional inline `flags`. When `allow_global` is set, the
+/// `g` flag is accepted and ignored, as it is handled by the caller.
+fn build_regex(pattern: &str, flags: Option<&str>, allow_global: bool) -> Result<Regex> {
+    let flags = match flags {
+        Some(flags) if allow_global => flags.replace('g', ""),
+        Some(flags) => flags.to_string(),
+        None => String::new(),
+    };
+    let pattern = if flags.is_empty() {
+        pattern.to_string()
+    } else {
+        format!("(?{}){}", flags, pattern)
+    };
+    Regex::new(&pattern).map_err(|e| {
+        ArrowError::ComputeError(format!("Regular expression did not compile: {}", e))
+    })
+}
+
+/// Regular expressions compiled by a kernel, keyed by pattern and flags
+struct RegexCache<'a> {
+    regexes: HashMap<(&'a str, Option<&'a str>), Regex>,
+    allow_global: bool,
+}
+
+impl<'a> RegexCache<'a> {
+    fn new(allow_global: bool) -> Self {
+        Self {
+            regexes: HashMap::new(),
+            allow_global,
+        }
+    }
+
+    fn get(&mut self, pattern: &'a str, flags: Option<&'a str>) -> Result<&Regex> {
+        let key = (pattern, flags);
+        if !self.regexes.contains_key(&key) {
+            let regex = build_regex(pattern, flags, self.allow_global)?;
+            self.regexes.insert(key, regex);
+        }
+        Ok(&self.regexes[&key])
+    }
+}
+
+fn check_lengths(name: &str, len: usize, other_len: usize) -> Result<()> {
+    if len != other_len {
+        return Err(ArrowError::ComputeError(format!(
+            "Cannot perform {} on arrays of different length",
+            name
+        )));
+    }
+    Ok(())
+}
+
+/// Checks that an optional flags array has one entry per row
+fn check_flags_length<OffsetSize: StringOffsetSizeTrait>(
+    name: &str,
+    len: usize,
+    flags: Option<&GenericStringArray<OffsetSize>>,
+) -> Result<()> {
+    match flags {
+        Some(flags) => check_lengths(name, len, flags.len()),
+        None => Ok(()),
+    }
+}
+
+/// Returns the value of an optional flags array at index `i`
+fn flags_at<OffsetSize: StringOffsetSizeTrait>(
+    flags: Option<&GenericStringArray<OffsetSize>>,
+    i: usize,
+) -> Option<&str> {
+    flags.and_then(|flags| {
+        if flags.is_null(i) {
+            None
+        } else {
+            Some(flags.value(i))
+        }
+    })
+}
+
+/// Returns the value of a string array at index `i`, or `None` if it is null
+fn value_at<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    i: usize,
+) -> Option<&str> {
+    if array.is_null(i) {
+        None
+    } else {
+        Some(array.value(i))
+    }
+}
+
+/// Appends the capture groups of the first match of `regex` in `value`, or the
+/// whole match if the expression has no groups, and a null list if it does not match
+fn append_match<OffsetSize: StringOffsetSizeTrait>(
+    builder: &mut GenericListBuilder<OffsetSize, GenericStringBuilder<OffsetSize>>,
+    regex: &Regex,
+    value: &str,
+) -> Result<()> {
+    match regex.captures(value) {
+        Some(captures) => {
+            let groups = if captures.len() > 1 { 1 } else { 0 }..captures.len();
+            for group in groups {
+                match captures.get(group) {
+                    Some(m) => builder.values().append_value(m.as_str())?,
+                    None => builder.values().append(false)?,
+                }
+            }
+            builder.append(true)
+        }
+        None => builder.append(false),
+    }
+}
+
+fn check_group(regex: &Regex, group: usize) -> Result<()> {
+    if group >= regex.captures_len() {
+        return Err(ArrowError::ComputeError(format!(
+            "Regular expression '{}' has no group {}",
+            regex.as_str(),
+            group
+        )));
+    }
+    Ok(())
+}
+
+fn replace<'a>(
+    regex: &Regex,
+    value: &'a str,
+    replacement: &str,
+    flags: Option<&str>,
+) -> Cow<'a, str> {
+    match flags {
+        Some(flags) if flags.contains('g') => regex.replace_all(value, replacement),
+        _ => regex.replace(value, replacement),
+    }
+}
+
+/// Returns whether each value of `array` matches the pattern at the same index of
+/// `regex_array`, with optional per-row `flags`. The result is null where either
+/// the value or the pattern is null.
+/// # Example
+/// ```rust
+/// use arrow::array::{BooleanArray, StringArray};
+/// use arrow::compute::kernels::regexp::regexp_is_match;
+/// # fn main() -> arrow::error::Result<()> {
+/// let array = StringArray::from(vec!["arrow", "parquet", "flight"]);
+/// let patterns = StringArray::from(vec!["^ar", "^ar", "ght$"]);
+/// let result = regexp_is_match(&array, &patterns, None)?;
+/// assert_eq!(result, BooleanArray::from(vec![true, false, true]));
+/// # Ok(())
+/// # }
+/// ```
+pub fn regexp_is_match<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    regex_array: &GenericStringArray<OffsetSize>,
+    flags: Option<&GenericStringArray<OffsetSize>>,
+) -> Result<BooleanArray> {
+    check_lengths("regexp_is_match", array.len(), regex_array.len())?;
+    check_flags_length("regexp_is_match", array.len(), flags)?;
+    let mut cache = RegexCache::new(false);
+    let mut b = BooleanBuilder::new(array.len());
+    for i in 0..array.len() {
+        match (value_at(array, i), value_at(regex_array, i)) {
+            (Some(value), Some(pattern)) => {
+                let regex = cache.get(pattern, flags_at(flags, i))?;
+                b.append_value(regex.is_match(value))?;
+            }
+            _ => b.append_null()?,
+        }
+    }
+    Ok(b.finish())
+}
+
+/// Returns whether each value of `array` matches `regex`, which can be used with
+/// [`filter`](crate::compute::kernels::filter::filter) to select the matching values.
+/// The result is null where the value is null.
+pub fn regexp_is_match_scalar<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    regex: &str,
+    flags: Option<&str>,
+) -> Result<BooleanArray> {
+    let regex = build_regex(regex, flags, false)?;
+    let mut b = BooleanBuilder::new(array.len());
+    for i in 0..array.len() {
+        match value_at(array, i) {
+            Some(value) => b.append_value(regex.is_match(value))?,
+            None => b.append_null()?,
+        }
+    }
+    Ok(b.finish())
+}
+
+/// Filters `array` to the values that match `regex`, dropping nulls.
+/// # Example
+/// ```rust
+/// use arrow::array::StringArray;
+/// use arrow::compute::kernels::regexp::regexp_filter;
+/// # fn main() -> arrow::error::Result<()> {
+/// let array = StringArray::from(vec![Some("arrow"), None, Some("parquet")]);
+/// let result = regexp_filter(&array, "^p", None)?;
+/// assert_eq!(result, StringArray::from(vec!["parquet"]));
+/// # Ok(())
+/// # }
+/// ```
+pub fn regexp_filter<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    regex: &str,
+    flags: Option<&str>,
+) -> Result<GenericStringArray<OffsetSize>> {
+    let predicate = regexp_is_match_scalar(array, regex, flags)?;
+    let filtered = filter(array, &predicate)?;
+    Ok(GenericStringArray::<OffsetSize>::from(filtered.data()))
+}
+
+/// Extracts the first match of the pattern at the same index of `regex_array` from
+/// each value of `array`, as a list of strings. The list holds the capture groups of
+/// the match, or the whole match if the pattern has no groups, and is null where the
+/// pattern does not match.
+/// # Example
+/// ```rust
+/// use arrow::array::{Array, ListArray, StringArray};
+/// use arrow::compute::kernels::regexp::regexp_match;
+/// # fn main() -> arrow::error::Result<()> {
+/// let array = StringArray::from(vec!["key=value", "nothing"]);
+/// let patterns = StringArray::from(vec!["(\\w+)=(\\w+)", "(\\w+)=(\\w+)"]);
+/// let result = regexp_match(&array, &patterns, None)?;
+/// let result = result.as_any().downcast_ref::<ListArray>().unwrap();
+/// let first = result.value(0);
+/// let first = first.as_any().downcast_ref::<StringArray>().unwrap();
+/// assert_eq!(first, &StringArray::from(vec!["key", "value"]));
+/// assert!(result.is_null(1));
+/// # Ok(())
+/// # }
+/// ```
+pub fn regexp_match<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    regex_array: &GenericStringArray<OffsetSize>,
+    flags: Option<&GenericStringArray<OffsetSize>>,
+) -> Result<ArrayRef> {
+    check_lengths("regexp_match", array.len(), regex_array.len())?;
+    check_flags_length("regexp_match", array.len(), flags)?;
+    let mut cache = RegexCache::new(false);
+    let values_builder = GenericStringBuilder::<OffsetSize>::new(0);
+    let mut builder = GenericListBuilder::with_capacity(values_builder, array.len());
+    for i in 0..array.len() {
+        match (value_at(array, i), value_at(regex_array, i)) {
+            (Some(value), Some(pattern)) => {
+                let regex = cache.get(pattern, flags_at(flags, i))?;
+                append_match(&mut builder, regex, value)?;
+            }
+            _ => builder.append(false)?,
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Extracts the first match of `regex` from each value of `array`, as a list of
+/// strings. See [`regexp_match`] for details.
+pub fn regexp_match_scalar<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    regex: &str,
+    flags: Option<&str>,
+) -> Result<ArrayRef> {
+    let regex = build_regex(regex, flags, false)?;
+    let values_builder = GenericStringBuilder::<OffsetSize>::new(0);
+    let mut builder = GenericListBuilder::with_capacity(values_builder, array.len());
+    for i in 0..array.len() {
+        match value_at(array, i) {
+            Some(value) => append_match(&mut builder, &regex, value)?,
+            None => builder.append(false)?,
+        }
+    }
+    Ok(Arc::new(builder.finish()))
+}
+
+/// Extracts capture group `group` of the first match of the pattern at the same
+/// index of `regex_array` from each value of `array`, where group 0 is the whole
+/// match. The result is null where the pattern does not match or the group did not
+/// participate in the match.
+/// # Error
+/// This function errors when a pattern has fewer than `group` capture groups.
+pub fn regexp_extract<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    regex_array: &GenericStringArray<OffsetSize>,
+    group: usize,
+) -> Result<GenericStringArray<OffsetSize>> {
+    check_lengths("regexp_extract", array.len(), regex_array.len())?;
+    let mut cache = RegexCache::new(false);
+    let mut b = GenericStringBuilder::<OffsetSize>::new(0);
+    for i in 0..array.len() {
+        match (value_at(array, i), value_at(regex_array, i)) {
+            (Some(value), Some(pattern)) => {
+                let regex = cache.get(pattern, None)?;
+                check_group(regex, group)?;
+                match regex.captures(value).and_then(|c| c.get(group)) {
+                    Some(m) => b.append_value(m.as_str())?,
+                    None => b.append(false)?,
+                }
+            }
+            _ => b.append(false)?,
+        }
+    }
+    Ok(b.finish())
+}
+
+/// Extracts capture group `group` of the first match of `regex` from each value of
+/// `array`. See [`regexp_extract`] for details.
+/// # Example
+/// ```rust
+/// use arrow::array::StringArray;
+/// use arrow::compute::kernels::regexp::regexp_extract_scalar;
+/// # fn main() -> arrow::error::Result<()> {
+/// let array = StringArray::from(vec![Some("2021-03-04"), Some("none"), None]);
+/// let result = regexp_extract_scalar(&array, "(\\d+)-(\\d+)", 2)?;
+/// assert_eq!(result, StringArray::from(vec![Some("03"), None, None]));
+/// # Ok(())
+/// # }
+/// ```
+pub fn regexp_extract_scalar<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    regex: &str,
+    group: usize,
+) -> Result<GenericStringArray<OffsetSize>> {
+    let regex = build_regex(regex, None, false)?;
+    check_group(&regex, group)?;
+    let mut b = GenericStringBuilder::<OffsetSize>::new(0);
+    for i in 0..array.len() {
+        match value_at(array, i).and_then(|v| regex.captures(v)?.get(group)) {
+            Some(m) => b.append_value(m.as_str())?,
+            None => b.append(false)?,
+        }
+    }
+    Ok(b.finish())
+}
+
+/// Replaces the first match of the pattern at the same index of `regex_array` in
+/// each value of `array` with the replacement at the same index of
+/// `replacement_array`, or every match if the row's flags contain `g`. Replacements
+/// refer to capture groups as `$1` or `${name}`. The result is null where the value,
+/// pattern or replacement is null.
+/// # Example
+/// ```rust
+/// use arrow::array::StringArray;
+/// use arrow::compute::kernels::regexp::regexp_replace;
+/// # fn main() -> arrow::error::Result<()> {
+/// let array = StringArray::from(vec!["aaa", "aaa"]);
+/// let patterns = StringArray::from(vec!["a", "a"]);
+/// let replacements = StringArray::from(vec!["b", "c"]);
+/// let flags = StringArray::from(vec![Some("g"), None]);
+/// let result = regexp_replace(&array, &patterns, &replacements, Some(&flags))?;
+/// assert_eq!(result, StringArray::from(vec!["bbb", "caa"]));
+/// # Ok(())
+/// # }
+/// ```
+pub fn regexp_replace<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    regex_array: &GenericStringArray<OffsetSize>,
+    replacement_array: &GenericStringArray<OffsetSize>,
+    flags: Option<&GenericStringArray<OffsetSize>>,
+) -> Result<GenericStringArray<OffsetSize>> {
+    check_lengths("regexp_replace", array.len(), regex_array.len())?;
+    check_lengths("regexp_replace", array.len(), replacement_array.len())?;
+    check_flags_length("regexp_replace", array.len(), flags)?;
+    let mut cache = RegexCache::new(true);
+    let mut b = GenericStringBuilder::<OffsetSize>::new(0);
+    for i in 0..array.len() {
+        match (
+            value_at(array, i),
+            value_at(regex_array, i),
+            value_at(replacement_array, i),
+        ) {
+            (Some(value), Some(pattern), Some(replacement)) => {
+                let flags = flags_at(flags, i);
+                let regex = cache.get(pattern, flags)?;
+                b.append_value(&replace(regex, value, replacement, flags))?;
+            }
+            _ => b.append(false)?,
+        }
+    }
+    Ok(b.finish())
+}
+
+/// Replaces the first match of `regex` in each value of `array` with
+/// `replacement`, or every match if `flags` contain `g`. See [`regexp_replace`]
+/// for details.
+pub fn regexp_replace_scalar<OffsetSize: StringOffsetSizeTrait>(
+    array: &GenericStringArray<OffsetSize>,
+    regex: &str,
+    replacement: &str,
+    flags: Option<&str>,
+) -> Result<GenericStringArray<OffsetSize>> {
+    let regex = build_regex(regex, flags, true)?;
+    let mut b = GenericStringBuilder::<OffsetSize>::new(0);
+    for i in 0..array.len() {
+        match value_at(array, i) {
+            Some(value) => b.append_value(&replace(&regex, value, replacement, flags))?,
+            None => b.append(false)?,
+        }
+    }
+    Ok(b.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn list_values(array: &ArrayRef) -> Vec<Option<Vec<Option<String>>>> {
+        let list = array.as_any().downcast_ref::<ListArray>().unwrap();
+        (0..list.len())
+            .map(|i| {
+                if list.is_null(i) {
+                    return None;
+                }
+                let values = list.value(i);
+                let values = values.as_any().downcast_ref::<StringArray>().unwrap();
+                Some(values.iter().map(|v| v.map(|v| v.to_string())).collect())
+            })
+            .collect()
+    }
+
+    fn strings(values: Vec<Option<&str>>) -> Option<Vec<Option<String>>> {
+        Some(
+            values
+                .into_iter()
+                .map(|v| v.map(|v| v.to_string()))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_regexp_is_match() {
+        let array =
+            StringArray::from(vec![Some("Arrow"), Some("arrow"), None, Some("x")]);
+        let patterns =
+            StringArray::from(vec![Some("^ar"), Some("^ar"), Some("^ar"), None]);
+
+        let result = regexp_is_match(&array, &patterns, None).unwrap();
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![Some(false), Some(true), None, None])
+        );
+
+        let flags = StringArray::from(vec!["i", "i", "i", "i"]);
+        let result = regexp_is_match(&array, &patterns, Some(&flags)).unwrap();
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![Some(true), Some(true), None, None])
+        );
+
+        let result = regexp_is_match_scalar(&array, "^AR", Some("i")).unwrap();
+        assert_eq!(
+            result,
+            BooleanArray::from(vec![Some(true), Some(true), None, Some(false)])
+        );
+    }
+
+    #[test]
+    fn test_regexp_is_match_large_string() {
+        let array = LargeStringArray::from(vec!["foo", "bar"]);
+        let result = regexp_is_match_scalar(&array, "o+", None).unwrap();
+        assert_eq!(result, BooleanArray::from(vec![true, false]));
+    }
+
+    #[test]
+    fn test_regexp_filter() {
+        let array =
+            StringArray::from(vec![Some("arrow"), None, Some("ARROW"), Some("x")]);
+        let result = regexp_filter(&array, "^arr", Some("i")).unwrap();
+        assert_eq!(result, StringArray::from(vec!["arrow", "ARROW"]));
+    }
+
+    #[test]
+    fn test_regexp_match() {
+        let array = StringArray::from(vec![
+            Some("abc-005-def"),
+            Some("X-7-5"),
+            Some("X545"),
+            None,
+            Some("foobarbequebaz"),
+        ]);
+        let patterns = StringArray::from(vec![
+            r".*-(\d*)-.*",
+            r".*-(\d*)-.*",
+            r".*-(\d*)-.*",
+            r".*-(\d*)-.*",
+            r"(bar)(beque)",
+        ]);
+        let result = regexp_match(&array, &patterns, None).unwrap();
+        assert_eq!(
+            list_values(&result),
+            vec![
+                strings(vec![Some("005")]),
+                strings(vec![Some("7")]),
+                None,
+                None,
+                strings(vec![Some("bar"), Some("beque")]),
+            ]
+        );
+
+        // without groups, the whole match is returned
+        let result = regexp_match_scalar(&array, r"\d+", None).unwrap();
+        assert_eq!(
+            list_values(&result),
+            vec![
+                strings(vec![Some("005")]),
+                strings(vec![Some("7")]),
+                strings(vec![Some("545")]),
+                None,
+                None,
+            ]
+        );
+
+        // groups that do not participate are null
+        let result = regexp_match_scalar(&array, r"(X)|(foo)", None).unwrap();
+        assert_eq!(list_values(&result)[4], strings(vec![None, Some("foo")]));
+    }
+
+    #[test]
+    fn test_regexp_extract() {
+        let array = StringArray::from(vec![Some("100-200"), Some("300"), None]);
+        let patterns = StringArray::from(vec![r"(\d+)-(\d+)", r"(\d+)", r"(\d+)"]);
+
+        let result = regexp_extract(&array, &patterns, 1).unwrap();
+        assert_eq!(
+            result,
+            StringArray::from(vec![Some("100"), Some("300"), None])
+        );
+
+        let result = regexp_extract(&array, &patterns, 2);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Compute error: Regular expression '(\\d+)' has no group 2"
+        );
+
+        let result = regexp_extract_scalar(&array, r"(\d+)-(\d+)", 0).unwrap();
+        assert_eq!(result, StringArray::from(vec![Some("100-200"), None, None]));
+    }
+
+    #[test]
+    fn test_regexp_replace() {
+        let array = StringArray::from(vec![Some("Thomas"), Some("Tom"), None]);
+        let patterns = StringArray::from(vec![".[mN]a.", "o", "o"]);
+        let replacements = StringArray::from(vec!["M", "0", "0"]);
+
+        let result = regexp_replace(&array, &patterns, &replacements, None).unwrap();
+        assert_eq!(
+            result,
+            StringArray::from(vec![Some("ThM"), Some("T0m"), None])
+        );
+
+        let result = regexp_replace_scalar(&array, "[aeiou]", "_", None).unwrap();
+        assert_eq!(
+            result,
+            StringArray::from(vec![Some("Th_mas"), Some("T_m"), None])
+        );
+
+        let result = regexp_replace_scalar(&array, "[AEIOU]", "_", Some("gi")).unwrap();
+        assert_eq!(
+            result,
+            StringArray::from(vec![Some("Th_m_s"), Some("T_m"), None])
+        );
+
+        let result = regexp_replace_scalar(&array, "(T)(o)", "$2$1", None).unwrap();
+        assert_eq!(
+            result,
+            StringArray::from(vec![Some("Thomas"), Some("oTm"), None])
+        );
+    }
+
+    #[test]
+    fn test_regexp_invalid() {
+        let array = StringArray::from(vec!["a"]);
+        let result = regexp_is_match_scalar(&array, "(", None);
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .starts_with("Compute error: Regular expression did not compile"));
+
+        // `g` is only meaningful for regexp_replace
+        assert!(regexp_match_scalar(&array, "a", Some("g")).is_err());
+
+        let patterns = StringArray::from(vec!["a", "b"]);
+        let result = regexp_is_match(&array, &patterns, None);
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Compute error: Cannot perform regexp_is_match on arrays of different length"
+        );
+
+        // flags must have one entry per row
+        let array = StringArray::from(vec!["a", "b"]);
+        let flags = StringArray::from(vec!["i"]);
+        assert!(regexp_is_match(&array, &patterns, Some(&flags)).is_err());
+        assert!(regexp_match(&array, &patterns, Some(&flags)).is_err());
+        let result = regexp_replace(&array, &patterns, &patterns, Some(&flags));
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "Compute error: Cannot perform regexp_replace on arrays of different length"
+        );
+    }
+}
@@ -155,48 +155,6 @@ pub fn sort_to_indices(
             sort_primitive::<DurationNanosecondType, _>(values, v, n, cmp, &options)
         }
         DataType::Utf8 => sort_string(values, v, n, &options),
-        DataType::List(field) => match field.data_type() {
-            DataType::Int8 => sort_list::<i32, Int8Type>(values, v, n, &options),
-            DataType::Int16 => sort_list::<i32, Int16Type>(values, v, n, &options),
-            DataType::Int32 => sort_list::<i32, Int32Type>(values, v, n, &options),
-            DataType::Int64 => sort_list::<i32, Int64Type>(values, v, n, &options),
-            DataType::UInt8 => sort_list::<i32, UInt8Type>(values, v, n, &options),
-            DataType::UInt16 => sort_list::<i32, UInt16Type>(values, v, n, &options),
-            DataType::UInt32 => sort_list::<i32, UInt32Type>(values, v, n, &options),
-            DataType::UInt64 => sort_list::<i32, UInt64Type>(values, v, n, &options),
-            t => Err(ArrowError::ComputeError(format!(
-                "Sort not supported for list type {:?}",
-                t
-            ))),
-        },
-        DataType::LargeList(field) => match field.data_type() {
-            DataType::Int8 => sort_list::<i64, Int8Type>(values, v, n, &options),
-            DataType::Int16 => sort_list::<i64, Int16Type>(values, v, n, &options),
-            DataType::Int32 => sort_list::<i64, Int32Type>(values, v, n, &options),
-            DataType::Int64 => sort_list::<i64, Int64Type>(values, v, n, &options),
-            DataType::UInt8 => sort_list::<i64, UInt8Type>(values, v, n, &options),
-            DataType::UInt16 => sort_list::<i64, UInt16Type>(values, v, n, &options),
-            DataType::UInt32 => sort_list::<i64, UInt32Type>(values, v, n, &options),
-            DataType::UInt64 => sort_list::<i64, UInt64Type>(values, v, n, &options),
-            t => Err(ArrowError::ComputeError(format!(
-                "Sort not supported for list type {:?}",
-                t
-            ))),
-        },
-        DataType::FixedSizeList(field, _) => match field.data_type() {
-            DataType::Int8 => sort_list::<i32, Int8Type>(values, v, n, &options),
-            DataType::Int16 => sort_list::<i32, Int16Type>(values, v, n, &options),
-            DataType::Int32 => sort_list::<i32, Int32Type>(values, v, n, &options),
-            DataType::Int64 => sort_list::<i32, Int64Type>(values, v, n, &options),
-            DataType::UInt8 => sort_list::<i32, UInt8Type>(values, v, n, &options),
-            DataType::UInt16 => sort_list::<i32, UInt16Type>(values, v, n, &options),
-            DataType::UInt32 => sort_list::<i32, UInt32Type>(values, v, n, &options),
-            DataType::UInt64 => sort_list::<i32, UInt64Type>(values, v, n, &options),
-            t => Err(ArrowError::ComputeError(format!(
-                "Sort not supported for list type {:?}",
-                t
-            ))),
-        },
         DataType::Dictionary(key_type, value_type)
             if *value_type.as_ref() == DataType::Utf8 =>
         {
@@ -231,10 +189,12 @@ pub fn sort_to_indices(
                 ))),
             }
         }
-        t => Err(ArrowError::ComputeError(format!(
-            "Sort not supported for data type {:?}",
-            t
-        ))),
+        t => sort_generic(values, v, n, &options).map_err(|e| {
+            ArrowError::ComputeError(format!(
+                "Sort not supported for data type {:?}: {}",
+                t, e
+            ))
+        }),
     }
 }
 
@@ -485,67 +445,28 @@ where
     Ok(UInt32Array::from(valid_indices))
 }
 
-fn sort_list<S, T>(
+/// Sort values of any type with a natural order in [ord](crate::array::ord), such as
+/// lists, structs and dictionaries
+fn sort_generic(
     values: &ArrayRef,
-    value_indices: Vec<u32>,
+    mut value_indices: Vec<u32>,
     mut null_indices: Vec<u32>,
     options: &SortOptions,
-) -> Result<UInt32Array>
-where
-    S: OffsetSizeTrait,
-    T: ArrowPrimitiveType,
-    T::Native: std::cmp::PartialOrd,
-{
-    let mut valids: Vec<(u32, ArrayRef)> = values
-        .as_any()
-        .downcast_ref::<FixedSizeListArray>()
-        .map_or_else(
-            || {
-                let values = as_list_array::<S>(values);
-                value_indices
-                    .iter()
-                    .copied()
-                    .map(|index| (index, values.value(index as usize)))
-                    .collect()
-            },
-            |values| {
-                value_indices
-                    .iter()
-                    .copied()
-                    .map(|index| (index, values.value(index as usize)))
-                    .collect()
-            },
-        );
-
+) -> Result<UInt32Array> {
+    let cmp = build_compare(values.as_ref(), values.as_ref())?;
     if !options.descending {
-        valids.sort_by(|a, b| cmp_array(a.1.as_ref(), b.1.as_ref()))
+        value_indices.sort_by(|a, b| cmp(*a as usize, *b as usize))
     } else {
-        valids.sort_by(|a, b| cmp_array(a.1.as_ref(), b.1.as_ref()).reverse())
+        value_indices.sort_by(|a, b| cmp(*a as usize, *b as usize).reverse())
     }
 
-    let mut valid_indices: Vec<u32> = valids.iter().map(|tuple| tuple.0).collect();
-
     if options.nulls_first {
-        null_indices.append(&mut valid_indices);
+        null_indices.append(&mut value_indices);
         return Ok(UInt32Array::from(null_indices));
     }
 
-    valid_indices.append(&mut null_indices);
-    Ok(UInt32Array::from(valid_indices))
-}
-
-/// Compare two `Array`s based on the ordering defined in [ord](crate::array::ord).
-fn cmp_array(a: &Array, b: &Array) -> Ordering {
-    let cmp_op = build_compare(a, b).unwrap();
-    let length = a.len().max(b.len());
-
-    for i in 0..length {
-        let result = cmp_op(i, i);
-        if result != Ordering::Equal {
-            return result;
-        }
-    }
-    Ordering::Equal
+    value_indices.append(&mut null_indices);
+    Ok(UInt32Array::from(value_indices))
 }
 
 /// One column to be used in lexicographical sort
@@ -610,16 +531,69 @@ pub fn lexsort(columns: &[SortColumn]) -> Result<Vec<ArrayRef>> {
 
 /// Sort elements lexicographically from a list of `ArrayRef` into an unsigned integer
 /// (`UInt32Array`) of indices.
+///
+/// The sort is stable: rows with equal keys keep their relative order.
 pub fn lexsort_to_indices(columns: &[SortColumn]) -> Result<UInt32Array> {
+    lexsort_to_indices_with_options(columns, &LexSortOptions::default())
+}
+
+/// Options that define how lexicographical sort kernels should behave
+#[derive(Clone, Copy, Debug)]
+pub struct LexSortOptions {
+    /// Whether rows with equal keys must keep their relative order. An unstable sort
+    /// may be faster.
+    pub stable: bool,
+    /// If set, only the indices of the first `limit` rows of the sorted output are
+    /// returned, which avoids sorting the remaining rows.
+    pub limit: Option<usize>,
+}
+
+impl Default for LexSortOptions {
+    fn default() -> Self {
+        Self {
+            stable: true,
+            limit: None,
+        }
+    }
+}
+
+/// Sort elements lexicographically from a list of `ArrayRef` into an unsigned integer
+/// (`UInt32Array`) of indices, using `options` to control stability and to return only
+/// the first rows of the sorted output.
+///
+/// Example:
+///
+/// ```
+/// use std::sync::Arc;
+/// use arrow::array::{ArrayRef, Int32Array, UInt32Array};
+/// use arrow::compute::kernels::sort::{
+///     lexsort_to_indices_with_options, LexSortOptions, SortColumn,
+/// };
+///
+/// let columns = vec![SortColumn {
+///     values: Arc::new(Int32Array::from(vec![5, 1, 4, 1, 3])) as ArrayRef,
+///     options: None,
+/// }];
+/// let options = LexSortOptions { stable: true, limit: Some(3) };
+/// let indices = lexsort_to_indices_with_options(&columns, &options).unwrap();
+/// assert_eq!(indices, UInt32Array::from(vec![1, 3, 4]));
+/// ```
+pub fn lexsort_to_indices_with_options(
+    columns: &[SortColumn],
+    options: &LexSortOptions,
+) -> Result<UInt32Array> {
     if columns.is_empty() {
         return Err(ArrowError::InvalidArgumentError(
             "Sort requires at least one column".to_string(),
         ));
     }
-    if columns.len() == 1 {
-        // fallback to non-lexical sort
+    if columns.len() == 1 && options.limit.is_none() {
+        // fallback to non-lexical sort, which is stable except for the nulls of a
+        // descending sort, as they are reversed
         let column = &columns[0];
-        return sort_to_indices(&column.values, column.options);
+        if !options.stable || !column.options.unwrap_or_default().descending {
+            return sort_to_indices(&column.values, column.options);
+        }
     }
 
     let row_count = columns[0].values.len();
@@ -629,26 +603,54 @@ pub fn lexsort_to_indices(columns: &[SortColumn]) -> Result<UInt32Array> {
         ));
     };
 
-    // map to data and DynComparator
-    let flat_columns = columns
-        .iter()
-        .map(
-            |column| -> Result<(&ArrayDataRef, DynComparator, SortOptions)> {
-                // flatten and convert build comparators
-                // use ArrayData for is_valid checks later to avoid dynamic call
-                let values = column.values.as_ref();
-                let data = values.data_ref();
-                Ok((
-                    data,
-                    build_compare(values, values)?,
-                    column.options.unwrap_or_default(),
-                ))
-            },
-        )
-        .collect::<Result<Vec<(&ArrayDataRef, DynComparator, SortOptions)>>>()?;
+    let comparator = LexicographicalComparator::try_new(columns)?;
+    let mut value_indices = (0..row_count).collect::<Vec<usize>>();
+
+    match options.limit {
+        Some(limit) if limit < row_count => {
+            // breaking ties by index makes the order total, hence partial sorting with
+            // unstable algorithms returns the same rows as a stable sort would
+            let cmp = |a: &usize, b: &usize| {
+                let order = comparator.compare(a, b);
+                if options.stable {
+                    order.then_with(|| a.cmp(b))
+                } else {
+                    order
+                }
+            };
+            if limit > 0 {
+                value_indices.select_nth_unstable_by(limit - 1, cmp);
+            }
+            value_indices.truncate(limit);
+            value_indices.sort_unstable_by(cmp);
+        }
+        _ => {
+            if options.stable {
+                value_indices.sort_by(|a, b| comparator.compare(a, b));
+            } else {
+                value_indices.sort_unstable_by(|a, b| comparator.compare(a, b));
+            }
+        }
+    }
+
+    Ok(UInt32Array::from(
+        value_indices
+            .into_iter()
+            .map(|i| i as u32)
+            .collect::<Vec<u32>>(),
+    ))
+}
+
+/// Compares rows of a list of sort columns lexicographically, following the
+/// [SortOptions] of each column
+pub(crate) struct LexicographicalComparator<'a> {
+    compare_items: Vec<(&'a ArrayDataRef, DynComparator<'a>, SortOptions)>,
+}
 
-    let lex_comparator = |a_idx: &usize, b_idx: &usize| -> Ordering {
-        for (data, comparator, sort_option) in flat_columns.iter() {
+impl LexicographicalComparator<'_> {
+    /// Compares the rows at `a_idx` and `b_idx`
+    pub(crate) fn compare(&self, a_idx: &usize, b_idx: &usize) -> Ordering {
+        for (data, comparator, sort_option) in self.compare_items.iter() {
             match (data.is_valid(*a_idx), data.is_valid(*b_idx)) {
                 (true, true) => {
                     match (comparator)(*a_idx, *b_idx) {
@@ -683,17 +685,28 @@ pub fn lexsort_to_indices(columns: &[SortColumn]) -> Result<UInt32Array> {
         }
 
         Ordering::Equal
-    };
-
-    let mut value_indices = (0..row_count).collect::<Vec<usize>>();
-    value_indices.sort_by(lex_comparator);
+    }
 
-    Ok(UInt32Array::from(
-        value_indices
-            .into_iter()
-            .map(|i| i as u32)
-            .collect::<Vec<u32>>(),
-    ))
+    /// Builds a comparator over `columns`, which must all have the same length
+    pub(crate) fn try_new(
+        columns: &[SortColumn],
+    ) -> Result<LexicographicalComparator<'_>> {
+        let compare_items = columns
+            .iter()
+            .map(|column| {
+                // flatten and convert build comparators
+                // use ArrayData for is_valid checks later to avoid dynamic call
+                let values = column.values.as_ref();
+                let data = values.data_ref();
+                Ok((
+                    data,
+                    build_compare(values, values)?,
+                    column.options.unwrap_or_default(),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Ok(LexicographicalComparator { compare_items })
+    }
 }
 
 #[cfg(test)]
@@ -1811,4 +1824,105 @@ mod tests {
         ];
         test_lex_sort_arrays(input, expected);
     }
+
+    fn lexsort_test_columns() -> Vec<SortColumn> {
+        vec![
+            SortColumn {
+                values: Arc::new(Int32Array::from(vec![
+                    Some(3),
+                    None,
+                    Some(1),
+                    Some(3),
+                    Some(1),
+                    Some(2),
+                    Some(1),
+                ])) as ArrayRef,
+                options: Some(SortOptions {
+                    descending: false,
+                    nulls_first: false,
+                }),
+            },
+            SortColumn {
+                values: Arc::new(StringArray::from(vec![
+                    "b", "x", "a", "a", "a", "z", "c",
+                ])) as ArrayRef,
+                options: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_lex_sort_stable() {
+        let columns = lexsort_test_columns();
+        let indices = lexsort_to_indices(&columns).unwrap();
+        // rows 2 and 4 have equal keys and keep their relative order
+        assert_eq!(indices, UInt32Array::from(vec![2, 4, 6, 5, 3, 0, 1]));
+
+        let options = LexSortOptions {
+            stable: false,
+            limit: None,
+        };
+        let indices = lexsort_to_indices_with_options(&columns, &options).unwrap();
+        let indices = indices.values();
+        assert!(indices[..2] == [2, 4] || indices[..2] == [4, 2]);
+        assert_eq!(&indices[2..], &[6, 5, 3, 0, 1]);
+    }
+
+    #[test]
+    fn test_lex_sort_limit() {
+        let columns = lexsort_test_columns();
+        for limit in 0..9 {
+            let options = LexSortOptions {
+                stable: true,
+                limit: Some(limit),
+            };
+            let indices = lexsort_to_indices_with_options(&columns, &options).unwrap();
+            let expected = [2u32, 4, 6, 5, 3, 0, 1];
+            assert_eq!(indices.values(), &expected[..limit.min(7)]);
+        }
+
+        // a single column with a limit uses the partial sort
+        let options = LexSortOptions {
+            stable: true,
+            limit: Some(2),
+        };
+        let indices = lexsort_to_indices_with_options(&columns[..1], &options).unwrap();
+        assert_eq!(indices, UInt32Array::from(vec![2, 4]));
+    }
+
+    #[test]
+    fn test_lex_sort_stable_descending_nulls() {
+        let column = SortColumn {
+            values: Arc::new(Int64Array::from(vec![None, Some(1), None, Some(2), None]))
+                as ArrayRef,
+            options: Some(SortOptions {
+                descending: true,
+                nulls_first: true,
+            }),
+        };
+        let constant = SortColumn {
+            values: Arc::new(Int64Array::from(vec![0; 5])) as ArrayRef,
+            options: None,
+        };
+        let expected = UInt32Array::from(vec![0, 2, 4, 3, 1]);
+        // the nulls keep their relative order with a single column, as with several
+        assert_eq!(
+            lexsort_to_indices(std::slice::from_ref(&column)).unwrap(),
+            expected
+        );
+        assert_eq!(lexsort_to_indices(&[column, constant]).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_sort_unsupported_type_error() {
+        let array = Arc::new(NullArray::new(3)) as ArrayRef;
+        let err = sort_to_indices(&array, None).unwrap_err().to_string();
+        // the error from building the comparator is kept
+        assert!(
+            err.contains("Sort not supported for data type Null"),
+            "{}",
+            err
+        );
+        assert!(err.contains("has no natural order"), "{}", err);
+    }
 }
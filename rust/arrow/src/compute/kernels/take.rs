@@ -231,12 +231,43 @@ where
             DataType::UInt16 => downcast_dict_take!(UInt16Type, values, indices),
             DataType::UInt32 => downcast_dict_take!(UInt32Type, values, indices),
             DataType::UInt64 => downcast_dict_take!(UInt64Type, values, indices),
-            t => unimplemented!("Take not supported for dictionary key type {:?}", t),
+            t => Err(ArrowError::ComputeError(format!(
+                "Take not supported for dictionary key type {:?}",
+                t
+            ))),
         },
-        t => unimplemented!("Take not supported for data type {:?}", t),
+        DataType::Float16 => Err(ArrowError::ComputeError(
+            "Take not supported for data type Float16".to_string(),
+        )),
+        _ => take_generic(values, indices),
     }
 }
 
+/// `take` implementation for any data type supported by [MutableArrayData], such as
+/// unions, copying one slot at a time.
+fn take_generic<IndexType>(
+    values: &Array,
+    indices: &PrimitiveArray<IndexType>,
+) -> Result<ArrayRef>
+where
+    IndexType: ArrowNumericType,
+    IndexType::Native: ToPrimitive,
+{
+    let data = values.data();
+    let mut mutable = MutableArrayData::new(vec![data.as_ref()], true, indices.len());
+    for i in 0..indices.len() {
+        if indices.is_valid(i) {
+            let index = ToPrimitive::to_usize(&indices.value(i)).ok_or_else(|| {
+                ArrowError::ComputeError("Cast to usize failed".to_string())
+            })?;
+            mutable.extend(0, index, index + 1);
+        } else {
+            mutable.extend_nulls(1);
+        }
+    }
+    Ok(make_array(Arc::new(mutable.freeze())))
+}
+
 /// Options that define how `take` should behave
 #[derive(Clone, Debug)]
 pub struct TakeOptions {
@@ -1320,6 +1351,86 @@ mod tests {
         assert_eq!(a, &struct_array);
     }
 
+    fn check_union_values(array: &ArrayRef, expected: &[Option<(i8, f64)>]) {
+        let array = array.as_any().downcast_ref::<UnionArray>().unwrap();
+        assert_eq!(array.len(), expected.len());
+        for (i, expected) in expected.iter().enumerate() {
+            match expected {
+                Some((type_id, value)) => {
+                    assert!(array.is_valid(i));
+                    assert_eq!(array.type_id(i), *type_id);
+                    let value_array = array.value(i);
+                    let actual = match type_id {
+                        0 => value_array
+                            .as_any()
+                            .downcast_ref::<Int32Array>()
+                            .unwrap()
+                            .value(0) as f64,
+                        _ => value_array
+                            .as_any()
+                            .downcast_ref::<Float64Array>()
+                            .unwrap()
+                            .value(0),
+                    };
+                    assert_eq!(actual, *value);
+                }
+                None => assert!(array.is_null(i)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_take_sparse_union() -> Result<()> {
+        let mut builder = UnionBuilder::new_sparse(4);
+        builder.append::<Int32Type>("a", 1)?;
+        builder.append::<Float64Type>("b", 2.5)?;
+        builder.append_null()?;
+        builder.append::<Int32Type>("a", 4)?;
+        let array = Arc::new(builder.build()?) as ArrayRef;
+
+        let index = UInt32Array::from(vec![Some(3), None, Some(1), Some(2), Some(0)]);
+        let taken = take(array.as_ref(), &index, None)?;
+        check_union_values(
+            &taken,
+            &[Some((0, 4.0)), None, Some((1, 2.5)), None, Some((0, 1.0))],
+        );
+
+        // sliced
+        let taken = take(
+            array.slice(1, 3).as_ref(),
+            &UInt32Array::from(vec![2, 0]),
+            None,
+        )?;
+        check_union_values(&taken, &[Some((0, 4.0)), Some((1, 2.5))]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_take_dense_union() -> Result<()> {
+        let mut builder = UnionBuilder::new_dense(4);
+        builder.append::<Int32Type>("a", 1)?;
+        builder.append_null()?;
+        builder.append::<Float64Type>("b", 2.5)?;
+        builder.append::<Int32Type>("a", 4)?;
+        let array = Arc::new(builder.build()?) as ArrayRef;
+
+        let index = UInt32Array::from(vec![3, 2, 1, 0]);
+        let taken = take(array.as_ref(), &index, None)?;
+        check_union_values(
+            &taken,
+            &[Some((0, 4.0)), Some((1, 2.5)), None, Some((0, 1.0))],
+        );
+
+        // sliced past the null slot
+        let taken = take(
+            array.slice(2, 2).as_ref(),
+            &UInt32Array::from(vec![1, 0]),
+            None,
+        )?;
+        check_union_values(&taken, &[Some((0, 4.0)), Some((1, 2.5))]);
+        Ok(())
+    }
+
     #[test]
     fn test_take_struct_with_nulls() {
         let array = create_test_struct();
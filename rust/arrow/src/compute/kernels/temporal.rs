@@ -16,42 +16,279 @@
 // under the License.
 
 //! Defines temporal kernels for time and date related functions.
+//!
+//! Timestamps with a timezone, such as `Timestamp(unit, Some("+08:00"))`, are
+//! converted to local time before their components are extracted. The timezone is
+//! either UTC, a fixed offset of the form `+HH:MM`, `+HHMM` or `+HH`, or a name of
+//! the IANA timezone database such as `Europe/Paris`.
 
-use chrono::Timelike;
+use chrono::{
+    Datelike, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike,
+};
+use chrono_tz::Tz;
 
 use crate::array::*;
 use crate::datatypes::*;
-use crate::error::Result;
+use crate::error::{ArrowError, Result};
 
-/// Extracts the hours of a given temporal array as an array of integers
-pub fn hour<T>(array: &PrimitiveArray<T>) -> Result<Int32Array>
+/// The timezone of a timestamp
+enum Timezone {
+    Offset(FixedOffset),
+    Named(Tz),
+}
+
+impl Timezone {
+    /// Converts a datetime in UTC to the local datetime of this timezone
+    fn to_local(&self, utc: &NaiveDateTime) -> NaiveDateTime {
+        match self {
+            Timezone::Offset(offset) => offset.from_utc_datetime(utc).naive_local(),
+            Timezone::Named(tz) => tz.from_utc_datetime(utc).naive_local(),
+        }
+    }
+}
+
+/// Parses the timezone of a timestamp, which is either a fixed offset from UTC or
+/// the name of a timezone
+fn parse_timezone(tz: &str) -> Result<Timezone> {
+    if let Some(offset) = parse_fixed_offset(tz) {
+        return Ok(Timezone::Offset(offset));
+    }
+    tz.parse::<Tz>().map(Timezone::Named).map_err(|_| {
+        ArrowError::ComputeError(format!(
+            "Unsupported timezone '{}', expected UTC, an offset such as +08:00 or a \
+             timezone name such as Europe/Paris",
+            tz
+        ))
+    })
+}
+
+/// Parses `UTC` or an offset of the form `+HH:MM`, `+HHMM` or `+HH`
+fn parse_fixed_offset(tz: &str) -> Option<FixedOffset> {
+    match tz {
+        "UTC" | "utc" | "Z" | "z" => return Some(FixedOffset::east(0)),
+        _ => {}
+    }
+
+    let sign = match tz.chars().next() {
+        Some('+') => 1,
+        Some('-') => -1,
+        _ => return None,
+    };
+    let digits = tz[1..].replace(':', "");
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let (hours, minutes) = match digits.len() {
+        2 => (&digits[..2], "0"),
+        4 => (&digits[..2], &digits[2..]),
+        _ => return None,
+    };
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    if hours > 23 || minutes > 59 {
+        return None;
+    }
+    Some(FixedOffset::east(sign * (hours * 3600 + minutes * 60)))
+}
+
+/// Returns the timezone of a timestamp type with a timezone
+fn timezone_of(data_type: &DataType) -> Result<Option<Timezone>> {
+    match data_type {
+        DataType::Timestamp(_, Some(tz)) => parse_timezone(tz).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Applies `op` to the local date of every value of a `Date32`, `Date64` or
+/// `Timestamp` array
+fn extract_date_component<T, F>(
+    array: &PrimitiveArray<T>,
+    name: &str,
+    op: F,
+) -> Result<Int32Array>
 where
     T: ArrowTemporalType + ArrowNumericType,
     i64: std::convert::From<T::Native>,
+    F: Fn(NaiveDate) -> i32,
 {
+    if let DataType::Time32(_) | DataType::Time64(_) = array.data_type() {
+        return Err(ArrowError::ComputeError(format!(
+            "{} does not support {:?}",
+            name,
+            array.data_type()
+        )));
+    }
+    let timezone = timezone_of(array.data_type())?;
+
     let mut b = Int32Builder::new(array.len());
     for i in 0..array.len() {
         if array.is_null(i) {
             b.append_null()?;
-        } else {
-            match array.data_type() {
-                &DataType::Time32(_) | &DataType::Time64(_) => {
-                    match array.value_as_time(i) {
-                        Some(time) => b.append_value(time.hour() as i32)?,
-                        None => b.append_null()?,
-                    }
-                }
-                _ => match array.value_as_datetime(i) {
-                    Some(dt) => b.append_value(dt.hour() as i32)?,
-                    None => b.append_null()?,
-                },
-            }
+            continue;
+        }
+        let datetime = array.value_as_datetime(i).map(|dt| match &timezone {
+            Some(timezone) => timezone.to_local(&dt),
+            None => dt,
+        });
+        match datetime {
+            Some(dt) => b.append_value(op(dt.date()))?,
+            None => b.append_null()?,
         }
     }
 
     Ok(b.finish())
 }
 
+/// Applies `op` to the local time of every value of a temporal array. `Date32`
+/// and `Date64` values are at midnight.
+fn extract_time_component<T, F>(array: &PrimitiveArray<T>, op: F) -> Result<Int32Array>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: std::convert::From<T::Native>,
+    F: Fn(NaiveTime) -> i32,
+{
+    let timezone = timezone_of(array.data_type())?;
+
+    let mut b = Int32Builder::new(array.len());
+    for i in 0..array.len() {
+        if array.is_null(i) {
+            b.append_null()?;
+            continue;
+        }
+        let time = match array.data_type() {
+            DataType::Time32(_) | DataType::Time64(_) => array.value_as_time(i),
+            _ => array.value_as_datetime(i).map(|dt| match &timezone {
+                Some(timezone) => timezone.to_local(&dt).time(),
+                None => dt.time(),
+            }),
+        };
+        match time {
+            Some(time) => b.append_value(op(time))?,
+            None => b.append_null()?,
+        }
+    }
+
+    Ok(b.finish())
+}
+
+/// Extracts the years of a given temporal array as an array of integers
+pub fn year<T>(array: &PrimitiveArray<T>) -> Result<Int32Array>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: std::convert::From<T::Native>,
+{
+    extract_date_component(array, "year", |date| date.year())
+}
+
+/// Extracts the quarters (1 to 4) of a given temporal array as an array of integers
+pub fn quarter<T>(array: &PrimitiveArray<T>) -> Result<Int32Array>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: std::convert::From<T::Native>,
+{
+    extract_date_component(array, "quarter", |date| date.month0() as i32 / 3 + 1)
+}
+
+/// Extracts the months (1 to 12) of a given temporal array as an array of integers
+pub fn month<T>(array: &PrimitiveArray<T>) -> Result<Int32Array>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: std::convert::From<T::Native>,
+{
+    extract_date_component(array, "month", |date| date.month() as i32)
+}
+
+/// Extracts the days of the month (1 to 31) of a given temporal array as an array
+/// of integers
+pub fn day<T>(array: &PrimitiveArray<T>) -> Result<Int32Array>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: std::convert::From<T::Native>,
+{
+    extract_date_component(array, "day", |date| date.day() as i32)
+}
+
+/// Extracts the days of the year (1 to 366) of a given temporal array as an array
+/// of integers
+pub fn day_of_year<T>(array: &PrimitiveArray<T>) -> Result<Int32Array>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: std::convert::From<T::Native>,
+{
+    extract_date_component(array, "day_of_year", |date| date.ordinal() as i32)
+}
+
+/// Extracts the days of the week of a given temporal array as an array of
+/// integers, where Monday is 0 and Sunday is 6
+pub fn weekday<T>(array: &PrimitiveArray<T>) -> Result<Int32Array>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: std::convert::From<T::Native>,
+{
+    extract_date_component(array, "weekday", |date| {
+        date.weekday().num_days_from_monday() as i32
+    })
+}
+
+/// Extracts the days of the week of a given temporal array as an array of
+/// integers, where Sunday is 0 and Saturday is 6
+pub fn num_days_from_sunday<T>(array: &PrimitiveArray<T>) -> Result<Int32Array>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: std::convert::From<T::Native>,
+{
+    extract_date_component(array, "num_days_from_sunday", |date| {
+        date.weekday().num_days_from_sunday() as i32
+    })
+}
+
+/// Extracts the ISO 8601 week numbers (1 to 53) of a given temporal array as an
+/// array of integers
+pub fn week<T>(array: &PrimitiveArray<T>) -> Result<Int32Array>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: std::convert::From<T::Native>,
+{
+    extract_date_component(array, "week", |date| date.iso_week().week() as i32)
+}
+
+/// Extracts the hours of a given temporal array as an array of integers
+pub fn hour<T>(array: &PrimitiveArray<T>) -> Result<Int32Array>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: std::convert::From<T::Native>,
+{
+    extract_time_component(array, |time| time.hour() as i32)
+}
+
+/// Extracts the minutes of a given temporal array as an array of integers
+pub fn minute<T>(array: &PrimitiveArray<T>) -> Result<Int32Array>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: std::convert::From<T::Native>,
+{
+    extract_time_component(array, |time| time.minute() as i32)
+}
+
+/// Extracts the seconds of a given temporal array as an array of integers
+pub fn second<T>(array: &PrimitiveArray<T>) -> Result<Int32Array>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: std::convert::From<T::Native>,
+{
+    extract_time_component(array, |time| time.second() as i32)
+}
+
+/// Extracts the nanoseconds within the second of a given temporal array as an
+/// array of integers
+pub fn nanosecond<T>(array: &PrimitiveArray<T>) -> Result<Int32Array>
+where
+    T: ArrowTemporalType + ArrowNumericType,
+    i64: std::convert::From<T::Native>,
+{
+    extract_time_component(array, |time| time.nanosecond() as i32)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -77,4 +314,104 @@ mod tests {
         assert_eq!(10, b.value(0));
         assert_eq!(23, b.value(1));
     }
+
+    #[test]
+    fn test_temporal_array_date32_date_components() {
+        // 2018-01-01 (Monday), null, 2020-12-31 (Thursday)
+        let a: PrimitiveArray<Date32Type> = vec![Some(17532), None, Some(18627)].into();
+
+        let b = year(&a).unwrap();
+        assert_eq!(2018, b.value(0));
+        assert_eq!(false, b.is_valid(1));
+        assert_eq!(2020, b.value(2));
+
+        assert_eq!(vec![Some(1), None, Some(4)], collect(quarter(&a)));
+        assert_eq!(vec![Some(1), None, Some(12)], collect(month(&a)));
+        assert_eq!(vec![Some(1), None, Some(31)], collect(day(&a)));
+        assert_eq!(vec![Some(1), None, Some(366)], collect(day_of_year(&a)));
+        assert_eq!(vec![Some(0), None, Some(3)], collect(weekday(&a)));
+        assert_eq!(
+            vec![Some(1), None, Some(4)],
+            collect(num_days_from_sunday(&a))
+        );
+        assert_eq!(vec![Some(1), None, Some(53)], collect(week(&a)));
+        assert_eq!(vec![Some(0), None, Some(0)], collect(hour(&a)));
+    }
+
+    #[test]
+    fn test_temporal_array_timestamp_components() {
+        // 2019-02-20T04:23:45.123456789, 2021-01-03T23:59:59
+        let a = TimestampNanosecondArray::from_vec(
+            vec![1550636625123456789, 1609718399000000000],
+            None,
+        );
+
+        assert_eq!(vec![Some(2019), Some(2021)], collect(year(&a)));
+        assert_eq!(vec![Some(2), Some(1)], collect(month(&a)));
+        assert_eq!(vec![Some(20), Some(3)], collect(day(&a)));
+        assert_eq!(vec![Some(8), Some(53)], collect(week(&a)));
+        assert_eq!(vec![Some(4), Some(23)], collect(hour(&a)));
+        assert_eq!(vec![Some(23), Some(59)], collect(minute(&a)));
+        assert_eq!(vec![Some(45), Some(59)], collect(second(&a)));
+        assert_eq!(vec![Some(123456789), Some(0)], collect(nanosecond(&a)));
+    }
+
+    #[test]
+    fn test_temporal_array_timestamp_with_timezone() {
+        // 2021-01-03T23:30:00Z is 2021-01-04T07:30:00 at +08:00
+        let a =
+            TimestampSecondArray::from_vec(vec![1609716600], Some("+08:00".to_string()));
+        assert_eq!(vec![Some(2021)], collect(year(&a)));
+        assert_eq!(vec![Some(4)], collect(day(&a)));
+        assert_eq!(vec![Some(0)], collect(weekday(&a)));
+        assert_eq!(vec![Some(1)], collect(week(&a)));
+        assert_eq!(vec![Some(7)], collect(hour(&a)));
+        assert_eq!(vec![Some(30)], collect(minute(&a)));
+
+        // and 2021-01-03T18:00:00 at -0530
+        let a =
+            TimestampSecondArray::from_vec(vec![1609716600], Some("-0530".to_string()));
+        assert_eq!(vec![Some(3)], collect(day(&a)));
+        assert_eq!(vec![Some(18)], collect(hour(&a)));
+        assert_eq!(vec![Some(0)], collect(minute(&a)));
+
+        let a = TimestampSecondArray::from_vec(vec![1609716600], Some("UTC".to_string()));
+        assert_eq!(vec![Some(23)], collect(hour(&a)));
+
+        // 2021-01-04T00:30:00 in winter and 2021-07-01T14:00:00 in summer in Paris
+        let a = TimestampSecondArray::from_vec(
+            vec![1609716600, 1625140800],
+            Some("Europe/Paris".to_string()),
+        );
+        assert_eq!(vec![Some(4), Some(1)], collect(day(&a)));
+        assert_eq!(vec![Some(0), Some(14)], collect(hour(&a)));
+        assert_eq!(vec![Some(30), Some(0)], collect(minute(&a)));
+
+        let a = TimestampSecondArray::from_vec(
+            vec![1609716600],
+            Some("Mars/Olympus_Mons".to_string()),
+        );
+        assert!(hour(&a).is_err());
+    }
+
+    #[test]
+    fn test_temporal_array_time_components() {
+        let a: PrimitiveArray<Time64NanosecondType> =
+            vec![Some(37_815_000_000_123), None].into();
+
+        assert_eq!(vec![Some(10), None], collect(hour(&a)));
+        assert_eq!(vec![Some(30), None], collect(minute(&a)));
+        assert_eq!(vec![Some(15), None], collect(second(&a)));
+        assert_eq!(vec![Some(123), None], collect(nanosecond(&a)));
+
+        let res = year(&a);
+        assert_eq!(
+            "Compute error: year does not support Time64(Nanosecond)",
+            res.unwrap_err().to_string()
+        );
+    }
+
+    fn collect(array: Result<Int32Array>) -> Vec<Option<i32>> {
+        array.unwrap().iter().collect()
+    }
 }
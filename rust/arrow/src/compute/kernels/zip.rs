@@ -0,0 +1,242 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+//! Defines the zip kernel, which selects values from two arrays based on a boolean mask.
+//!
+//! Example:
+//!
+//! ```
+//! use arrow::array::{Array, BooleanArray, Int32Array};
+//! use arrow::compute::zip;
+//!
+//! let mask = BooleanArray::from(vec![Some(true), Some(false), None]);
+//! let truthy = Int32Array::from(vec![1, 2, 3]);
+//! let falsy = Int32Array::from(vec![10, 20, 30]);
+//! let result = zip(&mask, &truthy, &falsy).unwrap();
+//! let result = result.as_any().downcast_ref::<Int32Array>().unwrap();
+//! assert_eq!(result, &Int32Array::from(vec![1, 20, 30]));
+//! ```
+
+use std::sync::Arc;
+
+use crate::array::*;
+use crate::error::{ArrowError, Result};
+
+/// Returns an array with the values of `truthy` in the slots where `mask` is true and
+/// the values of `falsy` everywhere else. Null slots of `mask` select from `falsy`,
+/// like the `ELSE` branch of a SQL `CASE`.
+///
+/// `truthy` and `falsy` must have the same data type and the same length as `mask`.
+/// Any type supported by [MutableArrayData] can be selected.
+pub fn zip(mask: &BooleanArray, truthy: &Array, falsy: &Array) -> Result<ArrayRef> {
+    zip_impl(mask, (truthy, false), (falsy, false))
+}
+
+/// Like [zip], but `truthy` is a scalar given as an array of length 1, which is
+/// repeated in every slot where `mask` is true.
+pub fn zip_truthy_scalar(
+    mask: &BooleanArray,
+    truthy: &Array,
+    falsy: &Array,
+) -> Result<ArrayRef> {
+    zip_impl(mask, (truthy, true), (falsy, false))
+}
+
+/// Like [zip], but `falsy` is a scalar given as an array of length 1, which is
+/// repeated in every slot where `mask` is false or null.
+pub fn zip_falsy_scalar(
+    mask: &BooleanArray,
+    truthy: &Array,
+    falsy: &Array,
+) -> Result<ArrayRef> {
+    zip_impl(mask, (truthy, false), (falsy, true))
+}
+
+/// Like [zip], but both `truthy` and `falsy` are scalars given as arrays of length 1.
+pub fn zip_scalars(
+    mask: &BooleanArray,
+    truthy: &Array,
+    falsy: &Array,
+) -> Result<ArrayRef> {
+    zip_impl(mask, (truthy, true), (falsy, true))
+}
+
+/// Checks that `array` can be selected from for a mask of length `len`.
+fn check_operand(array: &Array, is_scalar: bool, len: usize, name: &str) -> Result<()> {
+    let expected = if is_scalar { 1 } else { len };
+    if array.len() != expected {
+        return Err(ArrowError::ComputeError(format!(
+            "zip requires {} to have length {}, but it has length {}",
+            name,
+            expected,
+            array.len()
+        )));
+    }
+    Ok(())
+}
+
+fn zip_impl(
+    mask: &BooleanArray,
+    (truthy, truthy_is_scalar): (&Array, bool),
+    (falsy, falsy_is_scalar): (&Array, bool),
+) -> Result<ArrayRef> {
+    if truthy.data_type() != falsy.data_type() {
+        return Err(ArrowError::ComputeError(format!(
+            "zip requires arrays of the same type, got {:?} and {:?}",
+            truthy.data_type(),
+            falsy.data_type()
+        )));
+    }
+    let len = mask.len();
+    check_operand(truthy, truthy_is_scalar, len, "truthy")?;
+    check_operand(falsy, falsy_is_scalar, len, "falsy")?;
+
+    let mut mutable = MutableArrayData::new(
+        vec![truthy.data_ref().as_ref(), falsy.data_ref().as_ref()],
+        false,
+        len,
+    );
+
+    // copies the slots `start..end` of the operand at `index`, or repeats it if
+    // it is a scalar
+    let mut extend = |index: usize, is_scalar: bool, start: usize, end: usize| {
+        if is_scalar {
+            (start..end).for_each(|_| mutable.extend(index, 0, 1));
+        } else {
+            mutable.extend(index, start, end);
+        }
+    };
+
+    // selects runs of consecutive slots with the same mask value at once
+    let mut start = 0;
+    while start < len {
+        let selected = mask.is_valid(start) && mask.value(start);
+        let mut end = start + 1;
+        while end < len && (mask.is_valid(end) && mask.value(end)) == selected {
+            end += 1;
+        }
+        if selected {
+            extend(0, truthy_is_scalar, start, end);
+        } else {
+            extend(1, falsy_is_scalar, start, end);
+        }
+        start = end;
+    }
+
+    Ok(make_array(Arc::new(mutable.freeze())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zip_primitive() -> Result<()> {
+        let mask = BooleanArray::from(vec![
+            Some(true),
+            Some(true),
+            Some(false),
+            None,
+            Some(true),
+        ]);
+        let truthy = Int32Array::from(vec![Some(1), None, Some(3), Some(4), Some(5)]);
+        let falsy = Int32Array::from(vec![Some(10), Some(20), None, Some(40), Some(50)]);
+
+        let result = zip(&mask, &truthy, &falsy)?;
+        let result = result.as_any().downcast_ref::<Int32Array>().unwrap();
+        let expected = Int32Array::from(vec![Some(1), None, None, Some(40), Some(5)]);
+        assert_eq!(result, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_zip_sliced() -> Result<()> {
+        let mask = BooleanArray::from(vec![false, true, false, true]);
+        let mask = mask.slice(1, 3);
+        let mask = mask.as_any().downcast_ref::<BooleanArray>().unwrap();
+        let truthy = StringArray::from(vec!["a", "b", "c", "d"]).slice(1, 3);
+        let falsy = StringArray::from(vec!["w", "x", "y", "z"]).slice(1, 3);
+
+        let result = zip(mask, truthy.as_ref(), falsy.as_ref())?;
+        let result = result.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(result, &StringArray::from(vec!["b", "y", "d"]));
+        Ok(())
+    }
+
+    #[test]
+    fn test_zip_scalars() -> Result<()> {
+        let mask = BooleanArray::from(vec![Some(true), None, Some(false), Some(true)]);
+        let truthy = StringArray::from(vec!["yes"]);
+        let falsy = StringArray::from(vec![Some("a"), Some("b"), None, Some("d")]);
+
+        let result = zip_truthy_scalar(&mask, &truthy, &falsy)?;
+        let result = result.as_any().downcast_ref::<StringArray>().unwrap();
+        let expected = StringArray::from(vec![Some("yes"), Some("b"), None, Some("yes")]);
+        assert_eq!(result, &expected);
+
+        let result = zip_falsy_scalar(&mask, &falsy, &truthy)?;
+        let result = result.as_any().downcast_ref::<StringArray>().unwrap();
+        let expected = StringArray::from(vec!["a", "yes", "yes", "d"]);
+        assert_eq!(result, &expected);
+
+        let falsy = StringArray::from(vec![None::<&str>]);
+        let result = zip_scalars(&mask, &truthy, &falsy)?;
+        let result = result.as_any().downcast_ref::<StringArray>().unwrap();
+        let expected = StringArray::from(vec![Some("yes"), None, None, Some("yes")]);
+        assert_eq!(result, &expected);
+        Ok(())
+    }
+
+    fn build_list(values: Vec<Option<Vec<i32>>>) -> ListArray {
+        let mut builder = ListBuilder::new(Int32Builder::new(values.len()));
+        for value in values {
+            match value {
+                Some(value) => {
+                    builder.values().append_slice(&value).unwrap();
+                    builder.append(true).unwrap();
+                }
+                None => builder.append(false).unwrap(),
+            }
+        }
+        builder.finish()
+    }
+
+    #[test]
+    fn test_zip_list() -> Result<()> {
+        let truthy = build_list(vec![Some(vec![1, 2]), None, Some(vec![3])]);
+        let falsy = build_list(vec![Some(vec![7]), Some(vec![8]), Some(vec![])]);
+        let mask = BooleanArray::from(vec![false, true, true]);
+
+        let result = zip(&mask, &truthy, &falsy)?;
+        let result = result.as_any().downcast_ref::<ListArray>().unwrap();
+        let expected = build_list(vec![Some(vec![7]), None, Some(vec![3])]);
+        assert_eq!(result, &expected);
+        Ok(())
+    }
+
+    #[test]
+    fn test_zip_errors() {
+        let mask = BooleanArray::from(vec![true, false]);
+        let ints = Int32Array::from(vec![1, 2]);
+        let strings = StringArray::from(vec!["a", "b"]);
+        assert!(zip(&mask, &ints, &strings).is_err());
+
+        let short = Int32Array::from(vec![1]);
+        assert!(zip(&mask, &ints, &short).is_err());
+        assert!(zip_truthy_scalar(&mask, &ints, &short).is_err());
+        assert!(zip_falsy_scalar(&mask, &ints, &short).is_ok());
+    }
+}
@@ -46,7 +46,7 @@ use regex::{Regex, RegexBuilder};
 use std::collections::HashSet;
 use std::fmt;
 use std::fs::File;
-use std::io::{Read, Seek, SeekFrom};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
 use std::sync::Arc;
 
 use csv as csv_crate;
@@ -70,8 +70,60 @@ lazy_static! {
         Regex::new(r"^\d{4}-\d\d-\d\dT\d\d:\d\d:\d\d$").unwrap();
 }
 
-/// Infer the data type of a record
-fn infer_field_schema(string: &str) -> DataType {
+/// The dialect of a CSV file and the representation of its values, which are shared by
+/// schema inference and parsing
+#[derive(Debug, Clone, Default)]
+struct Format {
+    /// An optional column delimiter. Defaults to `b','`
+    delimiter: Option<u8>,
+    /// An optional quote character. Defaults to `b'"'`
+    quote: Option<u8>,
+    /// An optional escape character for quotes, in addition to doubled quotes
+    escape: Option<u8>,
+    /// An optional character that starts comment lines, which are skipped
+    comment: Option<u8>,
+    /// Values that are read as nulls, in addition to empty values of non-string columns
+    null_values: Vec<String>,
+    /// Formats of datetimes that are not in the default `%Y-%m-%dT%H:%M:%S` format
+    datetime_formats: Vec<String>,
+}
+
+impl Format {
+    /// Create a CSV reader of the format
+    fn build_reader<R: Read>(&self, reader: R, has_header: bool) -> csv_crate::Reader<R> {
+        let mut reader_builder = csv_crate::ReaderBuilder::new();
+        reader_builder
+            .has_headers(has_header)
+            .escape(self.escape)
+            .comment(self.comment);
+        if let Some(c) = self.delimiter {
+            reader_builder.delimiter(c);
+        }
+        if let Some(c) = self.quote {
+            reader_builder.quote(c);
+        }
+        reader_builder.from_reader(reader)
+    }
+
+    /// Whether a value is one of the format's null values
+    fn is_null(&self, string: &str) -> bool {
+        self.null_values
+            .iter()
+            .any(|null_value| null_value == string)
+    }
+}
+
+/// Parses a datetime in the default format, or else in one of the given formats
+fn parse_datetime(string: &str, formats: &[String]) -> Option<chrono::NaiveDateTime> {
+    string.parse::<chrono::NaiveDateTime>().ok().or_else(|| {
+        formats
+            .iter()
+            .find_map(|format| chrono::NaiveDateTime::parse_from_str(string, format).ok())
+    })
+}
+
+/// Infer the data type of a record, with additional datetime formats
+fn infer_field_schema(string: &str, datetime_formats: &[String]) -> DataType {
     // when quoting is enabled in the reader, these quotes aren't escaped, we default to
     // Utf8 for them
     if string.starts_with('"') {
@@ -88,6 +140,10 @@ fn infer_field_schema(string: &str) -> DataType {
         DataType::Date64(DateUnit::Millisecond)
     } else if DATE_RE.is_match(string) {
         DataType::Date32(DateUnit::Day)
+    } else if !datetime_formats.is_empty()
+        && parse_datetime(string, datetime_formats).is_some()
+    {
+        DataType::Date64(DateUnit::Millisecond)
     } else {
         DataType::Utf8
     }
@@ -101,13 +157,11 @@ fn infer_field_schema(string: &str) -> DataType {
 /// Return infered schema and number of records used for inference.
 fn infer_file_schema<R: Read + Seek>(
     reader: &mut R,
-    delimiter: u8,
+    format: &Format,
     max_read_records: Option<usize>,
     has_header: bool,
 ) -> Result<(Schema, usize)> {
-    let mut csv_reader = csv_crate::ReaderBuilder::new()
-        .delimiter(delimiter)
-        .from_reader(reader);
+    let mut csv_reader = format.build_reader(reader, true);
 
     // get or create header names
     // when has_header is false, creates default column names with column_ prefix
@@ -146,10 +200,11 @@ fn infer_file_schema<R: Read + Seek>(
 
         for i in 0..header_length {
             if let Some(string) = record.get(i) {
-                if string.is_empty() {
+                if string.is_empty() || format.is_null(string) {
                     nulls[i] = true;
                 } else {
-                    column_types[i].insert(infer_field_schema(string));
+                    column_types[i]
+                        .insert(infer_field_schema(string, &format.datetime_formats));
                 }
             }
         }
@@ -204,11 +259,15 @@ pub fn infer_schema_from_files(
 ) -> Result<Schema> {
     let mut schemas = vec![];
     let mut records_to_read = max_read_records.unwrap_or(std::usize::MAX);
+    let format = Format {
+        delimiter: Some(delimiter),
+        ..Default::default()
+    };
 
     for fname in files.iter() {
         let (schema, records_read) = infer_file_schema(
             &mut File::open(fname)?,
-            delimiter,
+            &format,
             Some(records_to_read),
             has_header,
         )?;
@@ -228,6 +287,129 @@ pub fn infer_schema_from_files(
 // optional bounds of the reader, of the form (min line, max line).
 type Bounds = Option<(usize, usize)>;
 
+/// The state of the CSV parser after a byte, as tracked by `find_record_start`
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ScanState {
+    RecordStart,
+    FieldStart,
+    Unquoted,
+    Quoted,
+    QuotedEscape,
+    /// A quote in a quoted field, which either ends the field or is doubled
+    QuotedQuote,
+    Comment,
+}
+
+/// Find the start of the first record that starts at or after the byte `offset`,
+/// returning its byte position and the number of records (including headers) before it.
+///
+/// A record starts after the first terminator byte of the previous record, which is
+/// where the CSV reader starts reading it, even if it then skips empty and comment lines.
+/// As newlines in quoted fields do not end records, quotes are tracked from the start
+/// of the reader.
+fn find_record_start<R: Read + Seek>(
+    reader: &mut R,
+    format: &Format,
+    offset: u64,
+) -> Result<(u64, usize)> {
+    find_record_starts(reader, format, &[offset]).map(|starts| starts[0])
+}
+
+/// Find the record start of each of the byte `offsets` as `find_record_start` does,
+/// reading the reader once up to the largest offset.
+fn find_record_starts<R: Read + Seek>(
+    reader: &mut R,
+    format: &Format,
+    offsets: &[u64],
+) -> Result<Vec<(u64, usize)>> {
+    let mut starts = vec![(0, 0); offsets.len()];
+    // the indices of the offsets that are past the start of the reader, in ascending
+    // order of offset
+    let mut pending = (0..offsets.len())
+        .filter(|i| offsets[*i] > 0)
+        .collect::<Vec<_>>();
+    pending.sort_by_key(|i| offsets[*i]);
+    let mut pending = pending.into_iter().peekable();
+    if pending.peek().is_none() {
+        return Ok(starts);
+    }
+    let delimiter = format.delimiter.unwrap_or(b',');
+    let quote = format.quote.unwrap_or(b'"');
+
+    reader.seek(SeekFrom::Start(0))?;
+    let mut reader = BufReader::new(reader);
+    let mut position = 0;
+    let mut records = 0;
+    let mut state = ScanState::RecordStart;
+    loop {
+        let buf = reader.fill_buf()?;
+        if buf.is_empty() {
+            // the remaining offsets are past the last record
+            pending.for_each(|i| starts[i] = (position, records));
+            return Ok(starts);
+        }
+        for &b in buf {
+            position += 1;
+            let is_terminator = b == b'\n' || b == b'\r';
+            state = match state {
+                ScanState::Comment if is_terminator => ScanState::RecordStart,
+                ScanState::Comment => ScanState::Comment,
+                // empty lines are skipped
+                ScanState::RecordStart if is_terminator => ScanState::RecordStart,
+                ScanState::RecordStart if Some(b) == format.comment => ScanState::Comment,
+                ScanState::Quoted if Some(b) == format.escape => ScanState::QuotedEscape,
+                ScanState::Quoted if b == quote => ScanState::QuotedQuote,
+                ScanState::Quoted | ScanState::QuotedEscape => ScanState::Quoted,
+                // quotes only start a field, or are doubled within quoted fields
+                ScanState::RecordStart
+                | ScanState::FieldStart
+                | ScanState::QuotedQuote
+                    if b == quote =>
+                {
+                    ScanState::Quoted
+                }
+                _ if b == delimiter => ScanState::FieldStart,
+                _ if is_terminator => {
+                    records += 1;
+                    while let Some(&i) = pending.peek() {
+                        if position < offsets[i] {
+                            break;
+                        }
+                        starts[i] = (position, records);
+                        pending.next();
+                    }
+                    if pending.peek().is_none() {
+                        return Ok(starts);
+                    }
+                    ScanState::RecordStart
+                }
+                _ => ScanState::Unquoted,
+            };
+        }
+        let len = buf.len();
+        reader.consume(len);
+    }
+}
+
+/// The start of a record of a CSV file, as found by
+/// [`ReaderBuilder::find_record_starts`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecordStart {
+    /// The byte position at which the record is read
+    pub byte: u64,
+    /// The number of records before the record, including the headers
+    pub records: usize,
+}
+
+/// The start of a byte range to read
+#[derive(Debug, Clone, Copy)]
+enum RangeStart {
+    /// A byte offset, from which the reader looks for the next record
+    Offset(u64),
+    /// A known record start
+    Record(RecordStart),
+}
+
 /// CSV file reader
 pub struct Reader<R: Read> {
     /// Explicit schema for the CSV file
@@ -244,6 +426,10 @@ pub struct Reader<R: Read> {
     batch_size: usize,
     /// Vector that can hold the `StringRecord`s of the batches
     batch_records: Vec<StringRecord>,
+    /// The format of the values to parse
+    format: Format,
+    /// Byte offset in the reader from which no more records are read
+    end_offset: u64,
 }
 
 impl<R> fmt::Debug for Reader<R>
@@ -307,14 +493,25 @@ impl<R: Read> Reader<R> {
         bounds: Bounds,
         projection: Option<Vec<usize>>,
     ) -> Self {
-        let mut reader_builder = csv_crate::ReaderBuilder::new();
-        reader_builder.has_headers(has_header);
-
-        if let Some(c) = delimiter {
-            reader_builder.delimiter(c);
-        }
+        let format = Format {
+            delimiter,
+            ..Default::default()
+        };
+        Self::from_format(
+            reader, schema, has_header, format, batch_size, bounds, projection,
+        )
+    }
 
-        let mut csv_reader = reader_builder.from_reader(reader);
+    fn from_format(
+        reader: R,
+        schema: SchemaRef,
+        has_header: bool,
+        format: Format,
+        batch_size: usize,
+        bounds: Bounds,
+        projection: Option<Vec<usize>>,
+    ) -> Self {
+        let mut csv_reader = format.build_reader(reader, has_header);
 
         let (start, end) = match bounds {
             None => (0, usize::MAX),
@@ -348,6 +545,8 @@ impl<R: Read> Reader<R> {
             batch_size,
             end,
             batch_records,
+            format,
+            end_offset: u64::MAX,
         }
     }
 }
@@ -360,6 +559,10 @@ impl<R: Read> Iterator for Reader<R> {
 
         let mut read_records = 0;
         for i in 0..min(self.batch_size, remaining) {
+            // records that start at the end offset are left to the next reader
+            if self.reader.position().byte() >= self.end_offset {
+                break;
+            }
             match self.reader.read_record(&mut self.batch_records[i]) {
                 Ok(true) => {
                     read_records += 1;
@@ -386,6 +589,7 @@ impl<R: Read> Iterator for Reader<R> {
             &self.schema.fields(),
             &self.projection,
             self.line_number,
+            &self.format,
         );
 
         self.line_number += read_records;
@@ -400,6 +604,7 @@ fn parse(
     fields: &[Field],
     projection: &Option<Vec<usize>>,
     line_number: usize,
+    format: &Format,
 ) -> Result<RecordBatch> {
     let projection: Vec<usize> = match projection {
         Some(ref v) => v.clone(),
@@ -412,55 +617,79 @@ fn parse(
             let i = *i;
             let field = &fields[i];
             match field.data_type() {
-                &DataType::Boolean => build_boolean_array(line_number, rows, i),
+                &DataType::Boolean => build_boolean_array(line_number, rows, i, format),
                 &DataType::Int8 => {
-                    build_primitive_array::<Int8Type>(line_number, rows, i)
+                    build_primitive_array::<Int8Type>(line_number, rows, i, format)
                 }
                 &DataType::Int16 => {
-                    build_primitive_array::<Int16Type>(line_number, rows, i)
+                    build_primitive_array::<Int16Type>(line_number, rows, i, format)
                 }
                 &DataType::Int32 => {
-                    build_primitive_array::<Int32Type>(line_number, rows, i)
+                    build_primitive_array::<Int32Type>(line_number, rows, i, format)
                 }
                 &DataType::Int64 => {
-                    build_primitive_array::<Int64Type>(line_number, rows, i)
+                    build_primitive_array::<Int64Type>(line_number, rows, i, format)
                 }
                 &DataType::UInt8 => {
-                    build_primitive_array::<UInt8Type>(line_number, rows, i)
+                    build_primitive_array::<UInt8Type>(line_number, rows, i, format)
                 }
                 &DataType::UInt16 => {
-                    build_primitive_array::<UInt16Type>(line_number, rows, i)
+                    build_primitive_array::<UInt16Type>(line_number, rows, i, format)
                 }
                 &DataType::UInt32 => {
-                    build_primitive_array::<UInt32Type>(line_number, rows, i)
+                    build_primitive_array::<UInt32Type>(line_number, rows, i, format)
                 }
                 &DataType::UInt64 => {
-                    build_primitive_array::<UInt64Type>(line_number, rows, i)
+                    build_primitive_array::<UInt64Type>(line_number, rows, i, format)
                 }
                 &DataType::Float32 => {
-                    build_primitive_array::<Float32Type>(line_number, rows, i)
+                    build_primitive_array::<Float32Type>(line_number, rows, i, format)
                 }
                 &DataType::Float64 => {
-                    build_primitive_array::<Float64Type>(line_number, rows, i)
+                    build_primitive_array::<Float64Type>(line_number, rows, i, format)
                 }
                 &DataType::Date32(_) => {
-                    build_primitive_array::<Date32Type>(line_number, rows, i)
+                    build_primitive_array::<Date32Type>(line_number, rows, i, format)
                 }
                 &DataType::Date64(_) => {
-                    build_primitive_array::<Date64Type>(line_number, rows, i)
+                    build_primitive_array::<Date64Type>(line_number, rows, i, format)
+                }
+                &DataType::Timestamp(TimeUnit::Second, _) => {
+                    build_primitive_array::<TimestampSecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        format,
+                    )
+                }
+                &DataType::Timestamp(TimeUnit::Millisecond, _) => {
+                    build_primitive_array::<TimestampMillisecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        format,
+                    )
                 }
                 &DataType::Timestamp(TimeUnit::Microsecond, _) => {
                     build_primitive_array::<TimestampMicrosecondType>(
                         line_number,
                         rows,
                         i,
+                        format,
                     )
                 }
                 &DataType::Timestamp(TimeUnit::Nanosecond, _) => {
-                    build_primitive_array::<TimestampNanosecondType>(line_number, rows, i)
+                    build_primitive_array::<TimestampNanosecondType>(
+                        line_number,
+                        rows,
+                        i,
+                        format,
+                    )
                 }
                 &DataType::Utf8 => Ok(Arc::new(
-                    rows.iter().map(|row| row.get(i)).collect::<StringArray>(),
+                    rows.iter()
+                        .map(|row| row.get(i).filter(|s| !format.is_null(s)))
+                        .collect::<StringArray>(),
                 ) as ArrayRef),
                 other => Err(ArrowError::ParseError(format!(
                     "Unsupported data type {:?}",
@@ -483,6 +712,14 @@ trait Parser: ArrowPrimitiveType {
     fn parse(string: &str) -> Option<Self::Native> {
         string.parse::<Self::Native>().ok()
     }
+
+    /// Parses a value that may be in one of the given datetime formats
+    fn parse_formatted(
+        string: &str,
+        _datetime_formats: &[String],
+    ) -> Option<Self::Native> {
+        Self::parse(string)
+    }
 }
 
 impl Parser for Float32Type {
@@ -531,9 +768,13 @@ impl Parser for Date32Type {
 
 impl Parser for Date64Type {
     fn parse(string: &str) -> Option<i64> {
+        Self::parse_formatted(string, &[])
+    }
+
+    fn parse_formatted(string: &str, datetime_formats: &[String]) -> Option<i64> {
         match Self::DATA_TYPE {
             DataType::Date64(DateUnit::Millisecond) => {
-                let date_time = string.parse::<chrono::NaiveDateTime>().ok()?;
+                let date_time = parse_datetime(string, datetime_formats)?;
                 Self::Native::from_i64(date_time.timestamp_millis())
             }
             _ => None,
@@ -543,9 +784,13 @@ impl Parser for Date64Type {
 
 impl Parser for TimestampNanosecondType {
     fn parse(string: &str) -> Option<i64> {
+        Self::parse_formatted(string, &[])
+    }
+
+    fn parse_formatted(string: &str, datetime_formats: &[String]) -> Option<i64> {
         match Self::DATA_TYPE {
             DataType::Timestamp(TimeUnit::Nanosecond, None) => {
-                let date_time = string.parse::<chrono::NaiveDateTime>().ok()?;
+                let date_time = parse_datetime(string, datetime_formats)?;
                 Self::Native::from_i64(date_time.timestamp_nanos())
             }
             _ => None,
@@ -555,9 +800,13 @@ impl Parser for TimestampNanosecondType {
 
 impl Parser for TimestampMicrosecondType {
     fn parse(string: &str) -> Option<i64> {
+        Self::parse_formatted(string, &[])
+    }
+
+    fn parse_formatted(string: &str, datetime_formats: &[String]) -> Option<i64> {
         match Self::DATA_TYPE {
             DataType::Timestamp(TimeUnit::Microsecond, None) => {
-                let date_time = string.parse::<chrono::NaiveDateTime>().ok()?;
+                let date_time = parse_datetime(string, datetime_formats)?;
                 Self::Native::from_i64(date_time.timestamp_nanos() / 1000)
             }
             _ => None,
@@ -565,6 +814,38 @@ impl Parser for TimestampMicrosecondType {
     }
 }
 
+impl Parser for TimestampMillisecondType {
+    fn parse(string: &str) -> Option<i64> {
+        Self::parse_formatted(string, &[])
+    }
+
+    fn parse_formatted(string: &str, datetime_formats: &[String]) -> Option<i64> {
+        match Self::DATA_TYPE {
+            DataType::Timestamp(TimeUnit::Millisecond, None) => {
+                let date_time = parse_datetime(string, datetime_formats)?;
+                Self::Native::from_i64(date_time.timestamp_millis())
+            }
+            _ => None,
+        }
+    }
+}
+
+impl Parser for TimestampSecondType {
+    fn parse(string: &str) -> Option<i64> {
+        Self::parse_formatted(string, &[])
+    }
+
+    fn parse_formatted(string: &str, datetime_formats: &[String]) -> Option<i64> {
+        match Self::DATA_TYPE {
+            DataType::Timestamp(TimeUnit::Second, None) => {
+                let date_time = parse_datetime(string, datetime_formats)?;
+                Self::Native::from_i64(date_time.timestamp())
+            }
+            _ => None,
+        }
+    }
+}
+
 fn parse_item<T: Parser>(string: &str) -> Option<T::Native> {
     T::parse(string)
 }
@@ -584,17 +865,18 @@ fn build_primitive_array<T: ArrowPrimitiveType + Parser>(
     line_number: usize,
     rows: &[StringRecord],
     col_idx: usize,
+    format: &Format,
 ) -> Result<ArrayRef> {
     rows.iter()
         .enumerate()
         .map(|(row_index, row)| {
             match row.get(col_idx) {
                 Some(s) => {
-                    if s.is_empty() {
+                    if s.is_empty() || format.is_null(s) {
                         return Ok(None);
                     }
 
-                    let parsed = parse_item::<T>(s);
+                    let parsed = T::parse_formatted(s, &format.datetime_formats);
                     match parsed {
                         Some(e) => Ok(Some(e)),
                         None => Err(ArrowError::ParseError(format!(
@@ -618,13 +900,14 @@ fn build_boolean_array(
     line_number: usize,
     rows: &[StringRecord],
     col_idx: usize,
+    format: &Format,
 ) -> Result<ArrayRef> {
     rows.iter()
         .enumerate()
         .map(|(row_index, row)| {
             match row.get(col_idx) {
                 Some(s) => {
-                    if s.is_empty() {
+                    if s.is_empty() || format.is_null(s) {
                         return Ok(None);
                     }
 
@@ -662,6 +945,22 @@ pub struct ReaderBuilder {
     has_header: bool,
     /// An optional column delimiter. Defaults to `b','`
     delimiter: Option<u8>,
+    /// An optional quote character. Defaults to `b'"'`
+    quote: Option<u8>,
+    /// An optional escape character for quotes. By default, quotes are only escaped
+    /// by doubling them
+    escape: Option<u8>,
+    /// An optional character that starts comment lines. By default, there are no comments
+    comment: Option<u8>,
+    /// Values that are read as nulls
+    ///
+    /// Empty values are always read as nulls, except in string columns.
+    null_values: Vec<String>,
+    /// Additional formats of datetimes, used when inferring and parsing dates and timestamps
+    ///
+    /// The formats are `chrono` format strings (e.g. `%d/%m/%Y %H:%M`), which are tried
+    /// after the default `%Y-%m-%dT%H:%M:%S` format.
+    datetime_formats: Vec<String>,
     /// Optional maximum number of records to read during schema inference
     ///
     /// If a number is not provided, all the records are read.
@@ -674,6 +973,11 @@ pub struct ReaderBuilder {
     bounds: Bounds,
     /// Optional projection for which columns to load (zero-based column indices)
     projection: Option<Vec<usize>>,
+    /// Optional byte range of the file to read, of the form (start, end)
+    ///
+    /// The records that start in the range are read, so that a file can be split into
+    /// ranges that are read concurrently.
+    byte_range: Option<(RangeStart, u64)>,
 }
 
 impl Default for ReaderBuilder {
@@ -682,10 +986,16 @@ impl Default for ReaderBuilder {
             schema: None,
             has_header: false,
             delimiter: None,
+            quote: None,
+            escape: None,
+            comment: None,
+            null_values: vec![],
+            datetime_formats: vec![],
             max_records: None,
             batch_size: 1024,
             bounds: None,
             projection: None,
+            byte_range: None,
         }
     }
 }
@@ -736,6 +1046,37 @@ impl ReaderBuilder {
         self
     }
 
+    /// Set the CSV file's quote character as a byte character
+    pub fn with_quote(mut self, quote: u8) -> Self {
+        self.quote = Some(quote);
+        self
+    }
+
+    /// Set the CSV file's escape character for quotes as a byte character
+    pub fn with_escape(mut self, escape: u8) -> Self {
+        self.escape = Some(escape);
+        self
+    }
+
+    /// Set the CSV file's comment character as a byte character, skipping lines that
+    /// start with it
+    pub fn with_comment(mut self, comment: u8) -> Self {
+        self.comment = Some(comment);
+        self
+    }
+
+    /// Set the values that are read as nulls, such as `NA` or `\N`
+    pub fn with_null_values(mut self, null_values: Vec<String>) -> Self {
+        self.null_values = null_values;
+        self
+    }
+
+    /// Set additional `chrono` formats of datetimes, such as `%d/%m/%Y %H:%M`
+    pub fn with_datetime_formats(mut self, datetime_formats: Vec<String>) -> Self {
+        self.datetime_formats = datetime_formats;
+        self
+    }
+
     /// Set the CSV reader to infer the schema of the file
     pub fn infer_schema(mut self, max_records: Option<usize>) -> Self {
         // remove any schema that is set
@@ -756,16 +1097,98 @@ impl ReaderBuilder {
         self
     }
 
+    /// Set the byte range of the file to read, from `start` (inclusive) to `end`
+    /// (exclusive)
+    ///
+    /// The reader reads the records that start in the range, starting from the first
+    /// record boundary at or after `start`, which may be past a newline in a quoted
+    /// field. Splitting a file into adjacent ranges reads each of its records exactly
+    /// once.
+    ///
+    /// Finding the boundary requires scanning the file from its beginning up to
+    /// `start`, though without parsing it, so that reading a file split into `n`
+    /// ranges this way scans it about `n / 2` times. To scan it once, find the
+    /// boundaries of all the ranges with [`ReaderBuilder::find_record_starts`] and
+    /// read them with [`ReaderBuilder::with_record_range`].
+    pub fn with_byte_range(mut self, start: u64, end: u64) -> Self {
+        self.byte_range = Some((RangeStart::Offset(start), end));
+        self
+    }
+
+    /// Set the range of the file to read, from the record `start` (inclusive) to the
+    /// byte `end` (exclusive)
+    ///
+    /// This reads the same records as `with_byte_range` from the offset that `start`
+    /// was found for, without scanning the file.
+    pub fn with_record_range(mut self, start: RecordStart, end: u64) -> Self {
+        self.byte_range = Some((RangeStart::Record(start), end));
+        self
+    }
+
+    /// Find the first record boundary at or after each of the byte `offsets` of a
+    /// file, in a single scan of the file up to the largest offset
+    ///
+    /// The records that start between consecutive offsets can then be read
+    /// concurrently with [`ReaderBuilder::with_record_range`], using the options of
+    /// this builder.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use arrow::csv::ReaderBuilder;
+    /// use std::io::Cursor;
+    ///
+    /// # fn main() -> arrow::error::Result<()> {
+    /// let data = "a,b\n1,\"x\ny\"\n2,z\n";
+    /// let builder = ReaderBuilder::new().has_header(true).infer_schema(None);
+    /// let starts = builder.find_record_starts(&mut Cursor::new(data), &[0, 6])?;
+    /// // the second range starts after the quoted newline
+    /// assert_eq!(starts[1].byte, 12);
+    ///
+    /// let mut reader = ReaderBuilder::new()
+    ///     .has_header(true)
+    ///     .infer_schema(None)
+    ///     .with_record_range(starts[1], data.len() as u64)
+    ///     .build(Cursor::new(data))?;
+    /// assert_eq!(reader.next().unwrap()?.num_rows(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn find_record_starts<R: Read + Seek>(
+        &self,
+        reader: &mut R,
+        offsets: &[u64],
+    ) -> Result<Vec<RecordStart>> {
+        let format = Format {
+            delimiter: self.delimiter,
+            quote: self.quote,
+            escape: self.escape,
+            comment: self.comment,
+            ..Default::default()
+        };
+        Ok(find_record_starts(reader, &format, offsets)?
+            .into_iter()
+            .map(|(byte, records)| RecordStart { byte, records })
+            .collect())
+    }
+
     /// Create a new `Reader` from the `ReaderBuilder`
     pub fn build<R: Read + Seek>(self, mut reader: R) -> Result<Reader<R>> {
+        let format = Format {
+            delimiter: self.delimiter,
+            quote: self.quote,
+            escape: self.escape,
+            comment: self.comment,
+            null_values: self.null_values,
+            datetime_formats: self.datetime_formats,
+        };
         // check if schema should be inferred
-        let delimiter = self.delimiter.unwrap_or(b',');
         let schema = match self.schema {
             Some(schema) => schema,
             None => {
                 let (inferred_schema, _) = infer_file_schema(
                     &mut reader,
-                    delimiter,
+                    &format,
                     self.max_records,
                     self.has_header,
                 )?;
@@ -773,15 +1196,45 @@ impl ReaderBuilder {
                 Arc::new(inferred_schema)
             }
         };
-        Ok(Reader::from_reader(
-            reader,
-            schema,
-            self.has_header,
-            self.delimiter,
-            self.batch_size,
-            None,
-            self.projection.clone(),
-        ))
+        match self.byte_range {
+            None => Ok(Reader::from_format(
+                reader,
+                schema,
+                self.has_header,
+                format,
+                self.batch_size,
+                None,
+                self.projection,
+            )),
+            Some((start, end)) => {
+                let (offset, records) = match start {
+                    RangeStart::Offset(offset) => {
+                        find_record_start(&mut reader, &format, offset)?
+                    }
+                    RangeStart::Record(start) => (start.byte, start.records),
+                };
+                reader.seek(SeekFrom::Start(offset))?;
+                // only the first range contains the headers
+                let has_header = self.has_header && offset == 0;
+                let mut csv_reader = Reader::from_format(
+                    reader,
+                    schema,
+                    has_header,
+                    format,
+                    self.batch_size,
+                    None,
+                    self.projection,
+                );
+                if has_header {
+                    // read the headers, so that the first record starts after them
+                    csv_reader.reader.byte_headers()?;
+                } else if offset > 0 {
+                    csv_reader.line_number = records;
+                }
+                csv_reader.end_offset = end.saturating_sub(offset);
+                Ok(csv_reader)
+            }
+        }
     }
 }
 
@@ -1082,20 +1535,261 @@ mod tests {
 
     #[test]
     fn test_infer_field_schema() {
-        assert_eq!(infer_field_schema("A"), DataType::Utf8);
-        assert_eq!(infer_field_schema("\"123\""), DataType::Utf8);
-        assert_eq!(infer_field_schema("10"), DataType::Int64);
-        assert_eq!(infer_field_schema("10.2"), DataType::Float64);
-        assert_eq!(infer_field_schema("true"), DataType::Boolean);
-        assert_eq!(infer_field_schema("false"), DataType::Boolean);
+        assert_eq!(infer_field_schema("A", &[]), DataType::Utf8);
+        assert_eq!(infer_field_schema("\"123\"", &[]), DataType::Utf8);
+        assert_eq!(infer_field_schema("10", &[]), DataType::Int64);
+        assert_eq!(infer_field_schema("10.2", &[]), DataType::Float64);
+        assert_eq!(infer_field_schema("true", &[]), DataType::Boolean);
+        assert_eq!(infer_field_schema("false", &[]), DataType::Boolean);
         assert_eq!(
-            infer_field_schema("2020-11-08"),
+            infer_field_schema("2020-11-08", &[]),
             DataType::Date32(DateUnit::Day)
         );
         assert_eq!(
-            infer_field_schema("2020-11-08T14:20:01"),
+            infer_field_schema("2020-11-08T14:20:01", &[]),
+            DataType::Date64(DateUnit::Millisecond)
+        );
+    }
+
+    /// The builder of readers of the data of byte range tests
+    fn byte_range_builder() -> ReaderBuilder {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("text", DataType::Utf8, false),
+        ]));
+        ReaderBuilder::new()
+            .with_schema(schema)
+            .has_header(true)
+            .with_comment(b'#')
+            .with_batch_size(2)
+    }
+
+    /// Reads the records of `data` in the given byte ranges
+    fn read_byte_ranges(data: &str, ranges: &[(u64, u64)]) -> Vec<(i64, String)> {
+        ranges
+            .iter()
+            .flat_map(|(start, end)| {
+                let csv = byte_range_builder()
+                    .with_byte_range(*start, *end)
+                    .build(Cursor::new(data))
+                    .unwrap();
+                read_rows(csv)
+            })
+            .collect()
+    }
+
+    /// Reads the records of `data` in the given byte ranges, scanning it once for the
+    /// starts of the ranges
+    fn read_record_ranges(data: &str, ranges: &[(u64, u64)]) -> Vec<(i64, String)> {
+        let offsets = ranges.iter().map(|(start, _)| *start).collect::<Vec<_>>();
+        let starts = byte_range_builder()
+            .find_record_starts(&mut Cursor::new(data), &offsets)
+            .unwrap();
+        starts
+            .into_iter()
+            .zip(ranges)
+            .flat_map(|(start, (_, end))| {
+                let csv = byte_range_builder()
+                    .with_record_range(start, *end)
+                    .build(Cursor::new(data))
+                    .unwrap();
+                read_rows(csv)
+            })
+            .collect()
+    }
+
+    /// Reads the (id, text) rows of the reader of a byte range test
+    fn read_rows<R: Read>(csv: Reader<R>) -> Vec<(i64, String)> {
+        let mut rows = vec![];
+        for batch in csv {
+            let batch = batch.unwrap();
+            let ids = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int64Array>()
+                .unwrap();
+            let texts = batch
+                .column(1)
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap();
+            for i in 0..batch.num_rows() {
+                rows.push((ids.value(i), texts.value(i).to_string()));
+            }
+        }
+        rows
+    }
+
+    #[test]
+    fn test_csv_byte_ranges() {
+        let data = "id,text\n1,\"hello\nworld\"\n2,\"a,\"\"b\"\"\r\n\"\r\n# a \"comment\n3,plain\n\n4,\"x\ny\"\n5,\"\"";
+        let len = data.len() as u64;
+        let expected = vec![
+            (1, "hello\nworld".to_string()),
+            (2, "a,\"b\"\r\n".to_string()),
+            (3, "plain".to_string()),
+            (4, "x\ny".to_string()),
+            (5, "".to_string()),
+        ];
+        assert_eq!(read_byte_ranges(data, &[(0, len)]), expected);
+
+        // split the file in two at every byte
+        for split in 0..=len {
+            assert_eq!(
+                read_byte_ranges(data, &[(0, split), (split, len)]),
+                expected,
+                "split at {}",
+                split
+            );
+        }
+        // split the file into a number of ranges
+        for n in 1..=len {
+            let ranges = (0..n)
+                .map(|i| (len * i / n, len * (i + 1) / n))
+                .collect::<Vec<_>>();
+            assert_eq!(read_byte_ranges(data, &ranges), expected, "{} ranges", n);
+            assert_eq!(read_record_ranges(data, &ranges), expected, "{} ranges", n);
+        }
+    }
+
+    #[test]
+    fn test_find_record_start() {
+        let data = "a,\"b\nc\"\nd\r\ne\n";
+        let format = Format::default();
+        let find =
+            |offset| find_record_start(&mut Cursor::new(data), &format, offset).unwrap();
+        assert_eq!(find(0), (0, 0));
+        assert_eq!(find(1), (8, 1));
+        // the quoted newline does not end the record
+        assert_eq!(find(5), (8, 1));
+        assert_eq!(find(8), (8, 1));
+        // records start after the first byte of a "\r\n" terminator
+        assert_eq!(find(9), (10, 2));
+        assert_eq!(find(10), (10, 2));
+        assert_eq!(find(11), (13, 3));
+        assert_eq!(find(14), (13, 3));
+
+        // the offsets need not be sorted
+        let starts =
+            find_record_starts(&mut Cursor::new(data), &format, &[14, 0, 5, 9, 5])
+                .unwrap();
+        assert_eq!(starts, vec![(13, 3), (0, 0), (8, 1), (10, 2), (8, 1)]);
+    }
+
+    #[test]
+    fn test_infer_field_schema_with_datetime_formats() {
+        let formats = vec!["%d/%m/%Y %H:%M".to_string()];
+        assert_eq!(
+            infer_field_schema("08/11/2020 14:20", &formats),
+            DataType::Date64(DateUnit::Millisecond)
+        );
+        assert_eq!(
+            infer_field_schema("2020-11-08T14:20:01", &formats),
             DataType::Date64(DateUnit::Millisecond)
         );
+        assert_eq!(infer_field_schema("08/11/2020", &formats), DataType::Utf8);
+    }
+
+    #[test]
+    fn test_csv_with_format_options() {
+        let data = "c_int,c_float,c_str,c_datetime
+; a comment
+1,1.5,'a,b',01/02/2020 10:30
+NA,\\N,'it\\'s',2020-11-15T23:05:00
+; another comment
+3,NA,NA,\\N
+";
+        let builder = ReaderBuilder::new()
+            .has_header(true)
+            .infer_schema(None)
+            .with_quote(b'\'')
+            .with_escape(b'\\')
+            .with_comment(b';')
+            .with_null_values(vec!["NA".to_string(), "\\N".to_string()])
+            .with_datetime_formats(vec!["%d/%m/%Y %H:%M".to_string()]);
+        let mut csv = builder.build(Cursor::new(data)).unwrap();
+
+        let expected_schema = Schema::new(vec![
+            Field::new("c_int", DataType::Int64, true),
+            Field::new("c_float", DataType::Float64, true),
+            Field::new("c_str", DataType::Utf8, true),
+            Field::new("c_datetime", DataType::Date64(DateUnit::Millisecond), true),
+        ]);
+        assert_eq!(&expected_schema, csv.schema().as_ref());
+
+        let batch = csv.next().unwrap().unwrap();
+        assert_eq!(3, batch.num_rows());
+
+        let c_int = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(1, c_int.value(0));
+        assert!(c_int.is_null(1));
+        assert_eq!(3, c_int.value(2));
+
+        let c_float = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert!((c_float.value(0) - 1.5).abs() < f64::EPSILON);
+        assert!(c_float.is_null(1));
+        assert!(c_float.is_null(2));
+
+        let c_str = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!("a,b", c_str.value(0));
+        assert_eq!("it's", c_str.value(1));
+        assert!(c_str.is_null(2));
+
+        let c_datetime = batch
+            .column(3)
+            .as_any()
+            .downcast_ref::<Date64Array>()
+            .unwrap();
+        assert_eq!(1_580_553_000_000, c_datetime.value(0));
+        assert_eq!(1_605_481_500_000, c_datetime.value(1));
+        assert!(c_datetime.is_null(2));
+    }
+
+    #[test]
+    fn test_csv_timestamps_with_datetime_formats() {
+        let schema = Schema::new(vec![
+            Field::new("s", DataType::Timestamp(TimeUnit::Second, None), true),
+            Field::new("ms", DataType::Timestamp(TimeUnit::Millisecond, None), true),
+            Field::new("ns", DataType::Timestamp(TimeUnit::Nanosecond, None), true),
+        ]);
+        let data = "01/02/2020 10:30|01/02/2020 10:30|2020-02-01T10:30:00\n";
+        let builder = ReaderBuilder::new()
+            .with_schema(Arc::new(schema))
+            .with_delimiter(b'|')
+            .with_datetime_formats(vec!["%d/%m/%Y %H:%M".to_string()]);
+        let mut csv = builder.build(Cursor::new(data)).unwrap();
+        let batch = csv.next().unwrap().unwrap();
+
+        let s = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<TimestampSecondArray>()
+            .unwrap();
+        assert_eq!(1_580_553_000, s.value(0));
+        let ms = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<TimestampMillisecondArray>()
+            .unwrap();
+        assert_eq!(1_580_553_000_000, ms.value(0));
+        let ns = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<TimestampNanosecondArray>()
+            .unwrap();
+        assert_eq!(1_580_553_000_000_000_000, ns.value(0));
     }
 
     #[test]
@@ -86,6 +86,30 @@ where
     lexical_to_string(c.value(i))
 }
 
+/// The quoting style of the fields of a CSV file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteStyle {
+    /// Quote all fields
+    Always,
+    /// Only quote fields that contain a delimiter, quote or line terminator
+    Necessary,
+    /// Quote all fields that are not numbers
+    NonNumeric,
+    /// Never quote fields, even if the CSV file is then invalid
+    Never,
+}
+
+impl From<QuoteStyle> for csv_crate::QuoteStyle {
+    fn from(quote_style: QuoteStyle) -> Self {
+        match quote_style {
+            QuoteStyle::Always => csv_crate::QuoteStyle::Always,
+            QuoteStyle::Necessary => csv_crate::QuoteStyle::Necessary,
+            QuoteStyle::NonNumeric => csv_crate::QuoteStyle::NonNumeric,
+            QuoteStyle::Never => csv_crate::QuoteStyle::Never,
+        }
+    }
+}
+
 /// A CSV writer
 #[derive(Debug)]
 pub struct Writer<W: Write> {
@@ -101,6 +125,8 @@ pub struct Writer<W: Write> {
     timestamp_format: String,
     /// The time format for time arrays
     time_format: String,
+    /// The value written for nulls
+    null_value: String,
     /// Is the beginning-of-writer
     beginning: bool,
 }
@@ -118,6 +144,7 @@ impl<W: Write> Writer<W> {
             date_format: DEFAULT_DATE_FORMAT.to_string(),
             time_format: DEFAULT_TIME_FORMAT.to_string(),
             timestamp_format: DEFAULT_TIMESTAMP_FORMAT.to_string(),
+            null_value: "".to_string(),
             beginning: true,
         }
     }
@@ -133,8 +160,7 @@ impl<W: Write> Writer<W> {
         for (col_index, item) in buffer.iter_mut().enumerate() {
             let col = batch.column(col_index);
             if col.is_null(row_index) {
-                // write an empty value
-                *item = "".to_string();
+                *item = self.null_value.clone();
                 continue;
             }
             let string = match col.data_type() {
@@ -292,6 +318,14 @@ pub struct WriterBuilder {
     timestamp_format: Option<String>,
     /// Optional time format for time arrays
     time_format: Option<String>,
+    /// Optional quote character. Defaults to `b'"'`
+    quote: Option<u8>,
+    /// Optional escape character for quotes. By default, quotes are escaped by doubling them
+    escape: Option<u8>,
+    /// The quoting style of fields. Defaults to `QuoteStyle::Necessary`
+    quote_style: QuoteStyle,
+    /// Optional value written for nulls. Defaults to an empty value
+    null_value: Option<String>,
 }
 
 impl Default for WriterBuilder {
@@ -302,6 +336,10 @@ impl Default for WriterBuilder {
             date_format: Some(DEFAULT_DATE_FORMAT.to_string()),
             time_format: Some(DEFAULT_TIME_FORMAT.to_string()),
             timestamp_format: Some(DEFAULT_TIMESTAMP_FORMAT.to_string()),
+            quote: None,
+            escape: None,
+            quote_style: QuoteStyle::Necessary,
+            null_value: None,
         }
     }
 }
@@ -363,11 +401,44 @@ impl WriterBuilder {
         self
     }
 
+    /// Set the CSV file's quote character as a byte character
+    pub fn with_quote(mut self, quote: u8) -> Self {
+        self.quote = Some(quote);
+        self
+    }
+
+    /// Set the CSV file's escape character for quotes, instead of doubling them
+    pub fn with_escape(mut self, escape: u8) -> Self {
+        self.escape = Some(escape);
+        self
+    }
+
+    /// Set the quoting style of fields
+    pub fn with_quote_style(mut self, quote_style: QuoteStyle) -> Self {
+        self.quote_style = quote_style;
+        self
+    }
+
+    /// Set the value that is written for nulls, such as `NA` or `\N`
+    pub fn with_null_value(mut self, null_value: String) -> Self {
+        self.null_value = Some(null_value);
+        self
+    }
+
     /// Create a new `Writer`
     pub fn build<W: Write>(self, writer: W) -> Writer<W> {
         let delimiter = self.delimiter.unwrap_or(b',');
         let mut builder = csv_crate::WriterBuilder::new();
-        let writer = builder.delimiter(delimiter).from_writer(writer);
+        builder
+            .delimiter(delimiter)
+            .quote_style(self.quote_style.into());
+        if let Some(quote) = self.quote {
+            builder.quote(quote);
+        }
+        if let Some(escape) = self.escape {
+            builder.escape(escape).double_quote(false);
+        }
+        let writer = builder.from_writer(writer);
         Writer {
             writer,
             delimiter,
@@ -381,6 +452,7 @@ impl WriterBuilder {
             timestamp_format: self
                 .timestamp_format
                 .unwrap_or_else(|| DEFAULT_TIMESTAMP_FORMAT.to_string()),
+            null_value: self.null_value.unwrap_or_default(),
             beginning: true,
         }
     }
@@ -584,4 +656,44 @@ sed do eiusmod tempor,-556132.25,1,,2019-04-18T02:45:55.555000000,23:46:03\n";
         let right = writer.writer.into_inner().map(|s| s.to_string());
         assert_eq!(Some(left.to_string()), right.ok());
     }
+
+    #[test]
+    fn test_write_csv_quote_and_null_options() {
+        let schema = Schema::new(vec![
+            Field::new("c1", DataType::Utf8, true),
+            Field::new("c2", DataType::Int32, true),
+        ]);
+        let c1 = StringArray::from(vec![Some("a,b"), Some("it's"), None]);
+        let c2 = Int32Array::from(vec![Some(1), None, Some(3)]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![Arc::new(c1), Arc::new(c2)])
+                .unwrap();
+
+        let builder = WriterBuilder::new()
+            .with_quote(b'\'')
+            .with_escape(b'\\')
+            .with_quote_style(QuoteStyle::NonNumeric)
+            .with_null_value("NA".to_string());
+        let mut writer = builder.build(StringWriter::new());
+        writer.write(&batch).unwrap();
+
+        let left = "'c1','c2'
+'a,b',1
+'it\\'s','NA'
+'NA',3\n";
+        let right = writer.writer.into_inner().map(|s| s.to_string());
+        assert_eq!(Some(left.to_string()), right.ok());
+
+        // the null value is written as is when fields are not quoted
+        let builder = WriterBuilder::new()
+            .has_headers(false)
+            .with_quote_style(QuoteStyle::Never)
+            .with_null_value("\\N".to_string());
+        let mut writer = builder.build(StringWriter::new());
+        writer.write(&batch).unwrap();
+
+        let left = "a,b,1\nit's,\\N\n\\N,3\n";
+        let right = writer.writer.into_inner().map(|s| s.to_string());
+        assert_eq!(Some(left.to_string()), right.ok());
+    }
 }
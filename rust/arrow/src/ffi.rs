@@ -24,6 +24,9 @@
 //! The second interface maps native Rust types to the Rust-specific implementation of Arrow such as `format` to `Datatype`,
 //! `Buffer`, etc. This is handled by `ArrowArray`.
 //!
+//! Streams of record batches are moved through the [C Stream Interface](https://arrow.apache.org/docs/format/CStreamInterface.html)
+//! via [FFI_ArrowArrayStream] and [ArrowArrayStreamReader].
+//!
 //! ```rust
 //! # use std::sync::Arc;
 //! # use arrow::array::{Int32Array, Array, ArrayData, make_array_from_raw};
@@ -72,24 +75,45 @@ within Rust and lifetime management.
 
 Each ABI-compatible `struct` knowns how to `drop` itself, calling `release`.
 
+Children and dictionaries of an exported struct are owned by the `private_data` of their
+parent, and are released when the parent is released. When importing, children and
+dictionaries are borrowed from the (ref-counted) root, which keeps every buffer alive.
+
 To import an array, unsafely create an `ArrowArray` from two pointers using [ArrowArray::try_from_raw].
 To export an array, create an `ArrowArray` using [ArrowArray::try_new].
+
+Streams of record batches are exchanged through [FFI_ArrowArrayStream], which wraps a
+[RecordBatchReader] when exporting and is read by [ArrowArrayStreamReader] when importing.
 */
 
 use std::{
+    collections::{BTreeMap, HashMap},
+    convert::TryFrom,
     ffi::CStr,
     ffi::CString,
     iter,
     mem::size_of,
+    os::raw::{c_char, c_int, c_void},
     ptr::{self, NonNull},
     sync::Arc,
 };
 
+use crate::array::{make_array, ArrayData, ArrayRef};
 use crate::buffer::Buffer;
-use crate::datatypes::{DataType, DateUnit, TimeUnit};
+use crate::datatypes::{
+    DataType, DateUnit, Field, IntervalUnit, Schema, SchemaRef, TimeUnit,
+};
 use crate::error::{ArrowError, Result};
+use crate::record_batch::{RecordBatch, RecordBatchReader};
 use crate::util::bit_util;
 
+/// Flag of [FFI_ArrowSchema] declaring that the dictionary indices are ordered.
+pub const ARROW_FLAG_DICTIONARY_ORDERED: i64 = 1;
+/// Flag of [FFI_ArrowSchema] declaring that the field is nullable.
+pub const ARROW_FLAG_NULLABLE: i64 = 2;
+/// Flag of [FFI_ArrowSchema] declaring that the keys of a map are sorted.
+pub const ARROW_FLAG_MAP_KEYS_SORTED: i64 = 4;
+
 /// ABI-compatible struct for `ArrowSchema` from C Data Interface
 /// See <https://arrow.apache.org/docs/format/CDataInterface.html#structure-definitions>
 /// This was created by bindgen
@@ -107,35 +131,84 @@ pub struct FFI_ArrowSchema {
     private_data: *mut ::std::os::raw::c_void,
 }
 
+// everything owned by an exported [FFI_ArrowSchema].
+struct SchemaPrivateData {
+    format: CString,
+    name: CString,
+    metadata: Option<Vec<u8>>,
+    children: Box<[*mut FFI_ArrowSchema]>,
+    dictionary: *mut FFI_ArrowSchema,
+}
+
 // callback used to drop [FFI_ArrowSchema] when it is exported.
 unsafe extern "C" fn release_schema(schema: *mut FFI_ArrowSchema) {
+    if schema.is_null() {
+        return;
+    }
     let schema = &mut *schema;
 
-    // take ownership back to release it.
-    CString::from_raw(schema.format as *mut std::os::raw::c_char);
+    // take ownership back to release it, together with its children and dictionary.
+    let private_data = Box::from_raw(schema.private_data as *mut SchemaPrivateData);
+    for child in private_data.children.iter() {
+        drop(Box::from_raw(*child));
+    }
+    if !private_data.dictionary.is_null() {
+        drop(Box::from_raw(private_data.dictionary));
+    }
 
     schema.release = None;
 }
 
 impl FFI_ArrowSchema {
-    /// create a new [FFI_ArrowSchema] from a format.
-    fn new(format: &str) -> FFI_ArrowSchema {
+    /// create a new [FFI_ArrowSchema] owning all its fields, children and dictionary.
+    fn try_new(
+        format: &str,
+        name: &str,
+        metadata: Option<Vec<u8>>,
+        flags: i64,
+        children: Vec<FFI_ArrowSchema>,
+        dictionary: Option<FFI_ArrowSchema>,
+    ) -> Result<FFI_ArrowSchema> {
+        let format = CString::new(format)
+            .map_err(|e| ArrowError::CDataInterface(e.to_string()))?;
+        let name =
+            CString::new(name).map_err(|e| ArrowError::CDataInterface(e.to_string()))?;
+        let children = children
+            .into_iter()
+            .map(|child| Box::into_raw(Box::new(child)))
+            .collect::<Box<[_]>>();
+        let dictionary = dictionary
+            .map(|dictionary| Box::into_raw(Box::new(dictionary)))
+            .unwrap_or_else(ptr::null_mut);
+
         // <https://arrow.apache.org/docs/format/CDataInterface.html#c.ArrowSchema>
-        FFI_ArrowSchema {
-            format: CString::new(format).unwrap().into_raw(),
-            name: std::ptr::null_mut(),
-            metadata: std::ptr::null_mut(),
-            flags: 0,
-            n_children: 0,
-            children: ptr::null_mut(),
-            dictionary: std::ptr::null_mut(),
+        let mut private_data = Box::new(SchemaPrivateData {
+            format,
+            name,
+            metadata,
+            children,
+            dictionary,
+        });
+
+        Ok(FFI_ArrowSchema {
+            format: private_data.format.as_ptr(),
+            name: private_data.name.as_ptr(),
+            metadata: private_data
+                .metadata
+                .as_ref()
+                .map(|m| m.as_ptr() as *const c_char)
+                .unwrap_or_else(ptr::null),
+            flags,
+            n_children: private_data.children.len() as i64,
+            children: private_data.children.as_mut_ptr(),
+            dictionary: private_data.dictionary,
             release: Some(release_schema),
-            private_data: std::ptr::null_mut(),
-        }
+            private_data: Box::into_raw(private_data) as *mut c_void,
+        })
     }
 
     /// create an empty [FFI_ArrowSchema]
-    fn empty() -> Self {
+    pub fn empty() -> Self {
         Self {
             format: std::ptr::null_mut(),
             name: std::ptr::null_mut(),
@@ -151,10 +224,82 @@ impl FFI_ArrowSchema {
 
     /// returns the format of this schema.
     pub fn format(&self) -> &str {
+        assert!(!self.format.is_null());
         unsafe { CStr::from_ptr(self.format) }
             .to_str()
             .expect("The external API has a non-utf8 as format")
     }
+
+    /// returns the name of this schema, or an empty string when it has none.
+    pub fn name(&self) -> &str {
+        if self.name.is_null() {
+            return "";
+        }
+        unsafe { CStr::from_ptr(self.name) }
+            .to_str()
+            .expect("The external API has a non-utf8 as name")
+    }
+
+    /// returns the flags of this schema.
+    pub fn flags(&self) -> i64 {
+        self.flags
+    }
+
+    /// whether the field described by this schema is nullable.
+    pub fn nullable(&self) -> bool {
+        self.flags & ARROW_FLAG_NULLABLE != 0
+    }
+
+    /// returns the child at position `index`.
+    /// # Panic
+    /// Panics if `index` is larger or equal to the number of children.
+    pub fn child(&self, index: usize) -> &Self {
+        assert!(index < self.n_children as usize);
+        unsafe { &**self.children.add(index) }
+    }
+
+    /// returns all children of this schema.
+    pub fn children(&self) -> impl Iterator<Item = &Self> {
+        (0..self.n_children as usize).map(move |i| self.child(i))
+    }
+
+    /// returns the schema of the dictionary values, if this schema is dictionary-encoded.
+    pub fn dictionary(&self) -> Option<&Self> {
+        unsafe { self.dictionary.as_ref() }
+    }
+
+    /// returns the key-value metadata of this schema.
+    pub fn metadata(&self) -> Result<Option<BTreeMap<String, String>>> {
+        if self.metadata.is_null() {
+            return Ok(None);
+        }
+        // the metadata is encoded as an `int32` with the number of pairs, followed by each key
+        // and value, prefixed by their `int32` length.
+        unsafe {
+            let n = ptr::read_unaligned(self.metadata as *const i32);
+            let mut pos = (self.metadata as *const u8).add(size_of::<i32>());
+            let mut read_string = || -> Result<String> {
+                let len = ptr::read_unaligned(pos as *const i32);
+                if len < 0 {
+                    return Err(ArrowError::CDataInterface(
+                        "The external metadata has a negative length".to_string(),
+                    ));
+                }
+                pos = pos.add(size_of::<i32>());
+                let bytes = std::slice::from_raw_parts(pos, len as usize);
+                pos = pos.add(len as usize);
+                String::from_utf8(bytes.to_vec())
+                    .map_err(|e| ArrowError::CDataInterface(e.to_string()))
+            };
+            let mut metadata = BTreeMap::new();
+            for _ in 0..n {
+                let key = read_string()?;
+                let value = read_string()?;
+                metadata.insert(key, value);
+            }
+            Ok(Some(metadata))
+        }
+    }
 }
 
 impl Drop for FFI_ArrowSchema {
@@ -166,9 +311,222 @@ impl Drop for FFI_ArrowSchema {
     }
 }
 
+// encodes key-value metadata as expected by the C data interface.
+fn encode_metadata<'a, I: Iterator<Item = (&'a String, &'a String)>>(
+    metadata: I,
+    len: usize,
+) -> Vec<u8> {
+    let mut bytes = (len as i32).to_ne_bytes().to_vec();
+    for (key, value) in metadata {
+        bytes.extend_from_slice(&(key.len() as i32).to_ne_bytes());
+        bytes.extend_from_slice(key.as_bytes());
+        bytes.extend_from_slice(&(value.len() as i32).to_ne_bytes());
+        bytes.extend_from_slice(value.as_bytes());
+    }
+    bytes
+}
+
+// exports a field with the given properties, recursing into its children and dictionary.
+fn export_field(
+    name: &str,
+    data_type: &DataType,
+    nullable: bool,
+    dict_is_ordered: bool,
+    metadata: Option<Vec<u8>>,
+) -> Result<FFI_ArrowSchema> {
+    let mut flags = if nullable { ARROW_FLAG_NULLABLE } else { 0 };
+
+    let (format, dictionary) = match data_type {
+        DataType::Dictionary(key_type, value_type) => {
+            if dict_is_ordered {
+                flags |= ARROW_FLAG_DICTIONARY_ORDERED;
+            }
+            let dictionary = FFI_ArrowSchema::try_from(value_type.as_ref())?;
+            (from_datatype(key_type)?, Some(dictionary))
+        }
+        data_type => (from_datatype(data_type)?, None),
+    };
+
+    let children = match data_type {
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _) => {
+            vec![FFI_ArrowSchema::try_from(field.as_ref())?]
+        }
+        DataType::Struct(fields) => fields
+            .iter()
+            .map(FFI_ArrowSchema::try_from)
+            .collect::<Result<Vec<_>>>()?,
+        _ => vec![],
+    };
+
+    FFI_ArrowSchema::try_new(&format, name, metadata, flags, children, dictionary)
+}
+
+impl TryFrom<&DataType> for FFI_ArrowSchema {
+    type Error = ArrowError;
+
+    /// exports a [DataType] as an unnamed, nullable field.
+    fn try_from(data_type: &DataType) -> Result<Self> {
+        export_field("", data_type, true, false, None)
+    }
+}
+
+impl TryFrom<&Field> for FFI_ArrowSchema {
+    type Error = ArrowError;
+
+    fn try_from(field: &Field) -> Result<Self> {
+        let metadata = field
+            .metadata()
+            .as_ref()
+            .map(|metadata| encode_metadata(metadata.iter(), metadata.len()));
+        export_field(
+            field.name(),
+            field.data_type(),
+            field.is_nullable(),
+            field.dict_is_ordered().unwrap_or(false),
+            metadata,
+        )
+    }
+}
+
+impl TryFrom<&Schema> for FFI_ArrowSchema {
+    type Error = ArrowError;
+
+    /// exports a [Schema] as a struct whose children are the schema's fields.
+    fn try_from(schema: &Schema) -> Result<Self> {
+        let metadata = if schema.metadata().is_empty() {
+            None
+        } else {
+            let metadata = schema.metadata();
+            Some(encode_metadata(metadata.iter(), metadata.len()))
+        };
+        let children = schema
+            .fields()
+            .iter()
+            .map(FFI_ArrowSchema::try_from)
+            .collect::<Result<Vec<_>>>()?;
+        FFI_ArrowSchema::try_new("+s", "", metadata, 0, children, None)
+    }
+}
+
+impl TryFrom<&FFI_ArrowSchema> for DataType {
+    type Error = ArrowError;
+
+    fn try_from(schema: &FFI_ArrowSchema) -> Result<Self> {
+        to_field_datatype(schema, &mut 0)
+    }
+}
+
+impl TryFrom<&FFI_ArrowSchema> for Field {
+    type Error = ArrowError;
+
+    fn try_from(schema: &FFI_ArrowSchema) -> Result<Self> {
+        to_field(schema, &mut 0)
+    }
+}
+
+impl TryFrom<&FFI_ArrowSchema> for Schema {
+    type Error = ArrowError;
+
+    /// imports a [Schema] from a struct, whose children are the schema's fields.
+    fn try_from(schema: &FFI_ArrowSchema) -> Result<Self> {
+        if schema.format() != "+s" {
+            return Err(ArrowError::CDataInterface(format!(
+                "A schema must be exported as a struct (\"+s\"), but the format is \"{}\"",
+                schema.format()
+            )));
+        }
+        // dictionary ids are unique within the schema
+        let mut next_dict_id = 0;
+        let fields = schema
+            .children()
+            .map(|child| to_field(child, &mut next_dict_id))
+            .collect::<Result<Vec<_>>>()?;
+        let metadata = schema
+            .metadata()?
+            .map(|metadata| metadata.into_iter().collect::<HashMap<_, _>>())
+            .unwrap_or_default();
+        Ok(Schema::new_with_metadata(fields, metadata))
+    }
+}
+
+// parses the time unit of temporal format strings, e.g. the `s` of `tss:`.
+fn to_time_unit(unit: &str, format: &str) -> Result<TimeUnit> {
+    Ok(match unit {
+        "s" => TimeUnit::Second,
+        "m" => TimeUnit::Millisecond,
+        "u" => TimeUnit::Microsecond,
+        "n" => TimeUnit::Nanosecond,
+        _ => {
+            return Err(ArrowError::CDataInterface(format!(
+                "The time unit of the datatype \"{}\" is invalid",
+                format
+            )))
+        }
+    })
+}
+
+fn from_time_unit(unit: &TimeUnit) -> &'static str {
+    match unit {
+        TimeUnit::Second => "s",
+        TimeUnit::Millisecond => "m",
+        TimeUnit::Microsecond => "u",
+        TimeUnit::Nanosecond => "n",
+    }
+}
+
+// parses the integer parameter of format strings such as `w:42`.
+fn parse_size(size: &str, format: &str) -> Result<i32> {
+    size.parse::<i32>().map_err(|_| {
+        ArrowError::CDataInterface(format!(
+            "The size of the datatype \"{}\" is not a valid integer",
+            format
+        ))
+    })
+}
+
+// imports a field, assigning the next id of `next_dict_id` to each dictionary field
+// of it and of its children.
+fn to_field(schema: &FFI_ArrowSchema, next_dict_id: &mut i64) -> Result<Field> {
+    let data_type = to_field_datatype(schema, next_dict_id)?;
+    let mut field = if schema.dictionary().is_some() {
+        let dict_id = *next_dict_id;
+        *next_dict_id += 1;
+        Field::new_dict(
+            schema.name(),
+            data_type,
+            schema.nullable(),
+            dict_id,
+            schema.flags() & ARROW_FLAG_DICTIONARY_ORDERED != 0,
+        )
+    } else {
+        Field::new(schema.name(), data_type, schema.nullable())
+    };
+    field.set_metadata(schema.metadata()?);
+    Ok(field)
+}
+
+// imports the datatype of a field, which is a dictionary when the schema has one.
+fn to_field_datatype(
+    schema: &FFI_ArrowSchema,
+    next_dict_id: &mut i64,
+) -> Result<DataType> {
+    let data_type = to_datatype(schema, next_dict_id)?;
+    Ok(match schema.dictionary() {
+        Some(dictionary) => DataType::Dictionary(
+            Box::new(data_type),
+            Box::new(to_field_datatype(dictionary, next_dict_id)?),
+        ),
+        None => data_type,
+    })
+}
+
 /// maps a DataType `format` to a [DataType](arrow::datatypes::DataType).
+/// Nested types are built from the schema's children; the dictionary, if any, is not handled here.
 /// See https://arrow.apache.org/docs/format/CDataInterface.html#data-type-description-format-strings
-fn to_datatype(format: &str) -> Result<DataType> {
+fn to_datatype(schema: &FFI_ArrowSchema, next_dict_id: &mut i64) -> Result<DataType> {
+    let format = schema.format();
     Ok(match format {
         "n" => DataType::Null,
         "b" => DataType::Boolean,
@@ -193,49 +551,148 @@ fn to_datatype(format: &str) -> Result<DataType> {
         "ttm" => DataType::Time32(TimeUnit::Millisecond),
         "ttu" => DataType::Time64(TimeUnit::Microsecond),
         "ttn" => DataType::Time64(TimeUnit::Nanosecond),
+        "tiM" => DataType::Interval(IntervalUnit::YearMonth),
+        "tiD" => DataType::Interval(IntervalUnit::DayTime),
+        "tDs" | "tDm" | "tDu" | "tDn" => {
+            DataType::Duration(to_time_unit(&format[2..], format)?)
+        }
+        "+l" | "+L" => {
+            if schema.n_children != 1 {
+                return Err(ArrowError::CDataInterface(format!(
+                    "The datatype \"{}\" expects 1 child, but has {}",
+                    format, schema.n_children
+                )));
+            }
+            let field = Box::new(to_field(schema.child(0), next_dict_id)?);
+            if format == "+l" {
+                DataType::List(field)
+            } else {
+                DataType::LargeList(field)
+            }
+        }
+        "+s" => DataType::Struct(
+            schema
+                .children()
+                .map(|child| to_field(child, next_dict_id))
+                .collect::<Result<Vec<_>>>()?,
+        ),
+        _ if format.starts_with("ts") && format.len() >= 4 && &format[3..4] == ":" => {
+            let unit = to_time_unit(&format[2..3], format)?;
+            let timezone = &format[4..];
+            let timezone = if timezone.is_empty() {
+                None
+            } else {
+                Some(timezone.to_string())
+            };
+            DataType::Timestamp(unit, timezone)
+        }
+        _ if format.starts_with("w:") => {
+            DataType::FixedSizeBinary(parse_size(&format[2..], format)?)
+        }
+        _ if format.starts_with("+w:") => {
+            if schema.n_children != 1 {
+                return Err(ArrowError::CDataInterface(format!(
+                    "The datatype \"{}\" expects 1 child, but has {}",
+                    format, schema.n_children
+                )));
+            }
+            DataType::FixedSizeList(
+                Box::new(to_field(schema.child(0), next_dict_id)?),
+                parse_size(&format[3..], format)?,
+            )
+        }
+        _ if format.starts_with("d:") => {
+            let parts = format[2..].split(',').collect::<Vec<_>>();
+            match parts.as_slice() {
+                [precision, scale] | [precision, scale, "128"] => DataType::Decimal(
+                    parse_size(precision, format)? as usize,
+                    parse_size(scale, format)? as usize,
+                ),
+                _ => {
+                    return Err(ArrowError::CDataInterface(format!(
+                        "The decimal datatype \"{}\" is still not supported in Rust implementation",
+                        format
+                    )))
+                }
+            }
+        }
         _ => {
-            return Err(ArrowError::CDataInterface(
-                "The datatype \"{}\" is still not supported in Rust implementation"
-                    .to_string(),
-            ))
+            return Err(ArrowError::CDataInterface(format!(
+                "The datatype \"{}\" is still not supported in Rust implementation",
+                format
+            )))
         }
     })
 }
 
-/// the inverse of [to_datatype]
+/// the inverse of [to_datatype]. Dictionaries must be handled by the caller, as their
+/// format is the format of their keys.
 fn from_datatype(datatype: &DataType) -> Result<String> {
     Ok(match datatype {
-        DataType::Null => "n",
-        DataType::Boolean => "b",
-        DataType::Int8 => "c",
-        DataType::UInt8 => "C",
-        DataType::Int16 => "s",
-        DataType::UInt16 => "S",
-        DataType::Int32 => "i",
-        DataType::UInt32 => "I",
-        DataType::Int64 => "l",
-        DataType::UInt64 => "L",
-        DataType::Float16 => "e",
-        DataType::Float32 => "f",
-        DataType::Float64 => "g",
-        DataType::Binary => "z",
-        DataType::LargeBinary => "Z",
-        DataType::Utf8 => "u",
-        DataType::LargeUtf8 => "U",
-        DataType::Date32(DateUnit::Day) => "tdD",
-        DataType::Date64(DateUnit::Millisecond) => "tdm",
-        DataType::Time32(TimeUnit::Second) => "tts",
-        DataType::Time32(TimeUnit::Millisecond) => "ttm",
-        DataType::Time64(TimeUnit::Microsecond) => "ttu",
-        DataType::Time64(TimeUnit::Nanosecond) => "ttn",
+        DataType::Null => "n".to_string(),
+        DataType::Boolean => "b".to_string(),
+        DataType::Int8 => "c".to_string(),
+        DataType::UInt8 => "C".to_string(),
+        DataType::Int16 => "s".to_string(),
+        DataType::UInt16 => "S".to_string(),
+        DataType::Int32 => "i".to_string(),
+        DataType::UInt32 => "I".to_string(),
+        DataType::Int64 => "l".to_string(),
+        DataType::UInt64 => "L".to_string(),
+        DataType::Float16 => "e".to_string(),
+        DataType::Float32 => "f".to_string(),
+        DataType::Float64 => "g".to_string(),
+        DataType::Binary => "z".to_string(),
+        DataType::LargeBinary => "Z".to_string(),
+        DataType::Utf8 => "u".to_string(),
+        DataType::LargeUtf8 => "U".to_string(),
+        DataType::Date32(DateUnit::Day) => "tdD".to_string(),
+        DataType::Date64(DateUnit::Millisecond) => "tdm".to_string(),
+        DataType::Time32(TimeUnit::Second) => "tts".to_string(),
+        DataType::Time32(TimeUnit::Millisecond) => "ttm".to_string(),
+        DataType::Time64(TimeUnit::Microsecond) => "ttu".to_string(),
+        DataType::Time64(TimeUnit::Nanosecond) => "ttn".to_string(),
+        DataType::Timestamp(unit, timezone) => format!(
+            "ts{}:{}",
+            from_time_unit(unit),
+            timezone.as_deref().unwrap_or("")
+        ),
+        DataType::Duration(unit) => format!("tD{}", from_time_unit(unit)),
+        DataType::Interval(IntervalUnit::YearMonth) => "tiM".to_string(),
+        DataType::Interval(IntervalUnit::DayTime) => "tiD".to_string(),
+        DataType::FixedSizeBinary(size) => format!("w:{}", size),
+        DataType::Decimal(precision, scale) => format!("d:{},{}", precision, scale),
+        DataType::List(_) => "+l".to_string(),
+        DataType::LargeList(_) => "+L".to_string(),
+        DataType::FixedSizeList(_, size) => format!("+w:{}", size),
+        DataType::Struct(_) => "+s".to_string(),
         z => {
             return Err(ArrowError::CDataInterface(format!(
                 "The datatype \"{:?}\" is still not supported in Rust implementation",
                 z
             )))
         }
-    }
-    .to_string())
+    })
+}
+
+// returns the number of buffers that an array of `data_type` has in the C data interface,
+// including the null buffer.
+fn n_buffers(data_type: &DataType) -> Result<usize> {
+    Ok(match data_type {
+        DataType::Null => 0,
+        DataType::Struct(_) | DataType::FixedSizeList(_, _) => 1,
+        DataType::Utf8
+        | DataType::LargeUtf8
+        | DataType::Binary
+        | DataType::LargeBinary => 3,
+        DataType::Union(_) => {
+            return Err(ArrowError::CDataInterface(format!(
+                "The datatype \"{:?}\" is still not supported in Rust implementation",
+                data_type
+            )))
+        }
+        _ => 2,
+    })
 }
 
 // returns the number of bits that buffer `i` (in the C data interface) is expected to have.
@@ -251,52 +708,39 @@ fn bit_width(data_type: &DataType, i: usize) -> Result<usize> {
         (DataType::UInt32, 1) => size_of::<u32>() * 8,
         (DataType::UInt64, 1) => size_of::<u64>() * 8,
         (DataType::Int8, 1) => size_of::<i8>() * 8,
-        (DataType::Int16, 1) => size_of::<i16>() * 8,
-        (DataType::Int32, 1) | (DataType::Date32(_), 1) | (DataType::Time32(_), 1) => size_of::<i32>() * 8,
-        (DataType::Int64, 1) | (DataType::Date64(_), 1) | (DataType::Time64(_), 1) => size_of::<i64>() * 8,
+        (DataType::Int16, 1) | (DataType::Float16, 1) => size_of::<i16>() * 8,
+        (DataType::Int32, 1)
+        | (DataType::Date32(_), 1)
+        | (DataType::Time32(_), 1)
+        | (DataType::Interval(IntervalUnit::YearMonth), 1) => size_of::<i32>() * 8,
+        (DataType::Int64, 1)
+        | (DataType::Date64(_), 1)
+        | (DataType::Time64(_), 1)
+        | (DataType::Timestamp(_, _), 1)
+        | (DataType::Duration(_), 1)
+        | (DataType::Interval(IntervalUnit::DayTime), 1) => size_of::<i64>() * 8,
         (DataType::Float32, 1) => size_of::<f32>() * 8,
         (DataType::Float64, 1) => size_of::<f64>() * 8,
-        // primitive types have a single buffer
-        (DataType::Boolean, _) |
-        (DataType::UInt8, _) |
-        (DataType::UInt16, _) |
-        (DataType::UInt32, _) |
-        (DataType::UInt64, _) |
-        (DataType::Int8, _) |
-        (DataType::Int16, _) |
-        (DataType::Int32, _) | (DataType::Date32(_), _) | (DataType::Time32(_), _) |
-        (DataType::Int64, _) | (DataType::Date64(_), _) | (DataType::Time64(_), _) |
-        (DataType::Float32, _) |
-        (DataType::Float64, _) => {
-            return Err(ArrowError::CDataInterface(format!(
-                "The datatype \"{:?}\" expects 2 buffers, but requested {}. Please verify that the C data interface is correctly implemented.",
-                data_type, i
-            )))
-        }
-        // Variable-sized binaries: have two buffers.
-        // "small": first buffer is i32, second is in bytes
-        (DataType::Utf8, 1) | (DataType::Binary, 1) => size_of::<i32>() * 8,
-        (DataType::Utf8, 2) | (DataType::Binary, 2) => size_of::<u8>() * 8,
-        (DataType::Utf8, _) | (DataType::Binary, _) => {
-            return Err(ArrowError::CDataInterface(format!(
-                "The datatype \"{:?}\" expects 3 buffers, but requested {}. Please verify that the C data interface is correctly implemented.",
-                data_type, i
-            )))
-        }
-        // Variable-sized binaries: have two buffers.
-        // LargeUtf8: first buffer is i64, second is in bytes
-        (DataType::LargeUtf8, 1) | (DataType::LargeBinary, 1) => size_of::<i64>() * 8,
-        (DataType::LargeUtf8, 2) | (DataType::LargeBinary, 2) => size_of::<u8>() * 8,
-        (DataType::LargeUtf8, _) | (DataType::LargeBinary, _) => {
-            return Err(ArrowError::CDataInterface(format!(
-                "The datatype \"{:?}\" expects 3 buffers, but requested {}. Please verify that the C data interface is correctly implemented.",
-                data_type, i
-            )))
+        (DataType::Decimal(_, _), 1) => size_of::<i128>() * 8,
+        (DataType::FixedSizeBinary(size), 1) => *size as usize * 8,
+        // the keys of a dictionary are primitive
+        (DataType::Dictionary(key_type, _), 1) => bit_width(key_type, 1)?,
+        // Variable-sized binaries and lists: the offsets are either i32 or i64
+        (DataType::Utf8, 1) | (DataType::Binary, 1) | (DataType::List(_), 1) => {
+            size_of::<i32>() * 8
         }
+        (DataType::LargeUtf8, 1)
+        | (DataType::LargeBinary, 1)
+        | (DataType::LargeList(_), 1) => size_of::<i64>() * 8,
+        // the values of variable-sized binaries are in bytes
+        (DataType::Utf8, 2)
+        | (DataType::Binary, 2)
+        | (DataType::LargeUtf8, 2)
+        | (DataType::LargeBinary, 2) => size_of::<u8>() * 8,
         _ => {
             return Err(ArrowError::CDataInterface(format!(
-                "The datatype \"{:?}\" is still not supported in Rust implementation",
-                data_type
+                "The datatype \"{:?}\" expects {} buffers, but requested {}. Please verify that the C data interface is correctly implemented.",
+                data_type, n_buffers(data_type)?, i
             )))
         }
     })
@@ -331,8 +775,14 @@ unsafe extern "C" fn release_array(array: *mut FFI_ArrowArray) {
         return;
     }
     let array = &mut *array;
-    // take ownership of `private_data`, therefore dropping it
-    Box::from_raw(array.private_data as *mut PrivateData);
+    // take ownership of `private_data`, therefore dropping it, as well as children and dictionary
+    let private_data = Box::from_raw(array.private_data as *mut PrivateData);
+    for child in private_data.children.iter() {
+        drop(Box::from_raw(*child));
+    }
+    if !private_data.dictionary.is_null() {
+        drop(Box::from_raw(private_data.dictionary));
+    }
 
     array.release = None;
 }
@@ -340,20 +790,45 @@ unsafe extern "C" fn release_array(array: *mut FFI_ArrowArray) {
 struct PrivateData {
     buffers: Vec<Option<Buffer>>,
     buffers_ptr: Box<[*const std::os::raw::c_void]>,
+    children: Box<[*mut FFI_ArrowArray]>,
+    dictionary: *mut FFI_ArrowArray,
 }
 
 impl FFI_ArrowArray {
-    /// creates a new `FFI_ArrowArray` from existing data.
-    /// # Safety
-    /// This method releases `buffers`. Consumers of this struct *must* call `release` before
-    /// releasing this struct, or contents in `buffers` leak.
-    unsafe fn new(
-        length: i64,
-        null_count: i64,
-        offset: i64,
-        n_buffers: i64,
-        buffers: Vec<Option<Buffer>>,
-    ) -> Self {
+    /// creates a new `FFI_ArrowArray` from existing data, recursively exporting its children
+    /// and dictionary.
+    /// Consumers of this struct *must* call `release` before releasing this struct, or the
+    /// buffers of `data` leak.
+    pub fn try_new(data: &ArrayData) -> Result<Self> {
+        // * insert the null buffer at the start
+        // * make all others `Option<Buffer>`.
+        let buffers = if n_buffers(data.data_type())? == 0 {
+            vec![]
+        } else {
+            iter::once(data.null_buffer().cloned())
+                .chain(data.buffers().iter().map(|b| Some(b.clone())))
+                .collect::<Vec<_>>()
+        };
+
+        // the values of a dictionary are exported as its dictionary, not as a child
+        let (children, dictionary) = match data.data_type() {
+            DataType::Dictionary(_, _) => {
+                let values = FFI_ArrowArray::try_new(&data.child_data()[0])?;
+                (vec![], Box::into_raw(Box::new(values)))
+            }
+            _ => (
+                data.child_data()
+                    .iter()
+                    .map(|child| FFI_ArrowArray::try_new(child))
+                    .collect::<Result<Vec<_>>>()?,
+                ptr::null_mut(),
+            ),
+        };
+        let mut children = children
+            .into_iter()
+            .map(|child| Box::into_raw(Box::new(child)))
+            .collect::<Box<[_]>>();
+
         let buffers_ptr = buffers
             .iter()
             .map(|maybe_buffer| match maybe_buffer {
@@ -363,30 +838,35 @@ impl FFI_ArrowArray {
             })
             .collect::<Box<[_]>>();
         let pointer = buffers_ptr.as_ptr() as *mut *const std::ffi::c_void;
+        let n_buffers = buffers.len() as i64;
+        let n_children = children.len() as i64;
+        let children_ptr = children.as_mut_ptr();
 
         // create the private data owning everything.
         // any other data must be added here, e.g. via a struct, to track lifetime.
         let private_data = Box::new(PrivateData {
             buffers,
             buffers_ptr,
+            children,
+            dictionary,
         });
 
-        Self {
-            length,
-            null_count,
-            offset,
+        Ok(Self {
+            length: data.len() as i64,
+            null_count: data.null_count() as i64,
+            offset: data.offset() as i64,
             n_buffers,
-            n_children: 0,
+            n_children,
             buffers: pointer,
-            children: std::ptr::null_mut(),
-            dictionary: std::ptr::null_mut(),
+            children: children_ptr,
+            dictionary,
             release: Some(release_array),
             private_data: Box::into_raw(private_data) as *mut ::std::os::raw::c_void,
-        }
+        })
     }
 
-    // create an empty `FFI_ArrowArray`, which can be used to import data into
-    fn empty() -> Self {
+    /// create an empty `FFI_ArrowArray`, which can be used to import data into
+    pub fn empty() -> Self {
         Self {
             length: 0,
             null_count: 0,
@@ -400,17 +880,34 @@ impl FFI_ArrowArray {
             private_data: std::ptr::null_mut(),
         }
     }
+
+    // the child at position `index`.
+    fn child(&self, index: usize) -> Result<&Self> {
+        if index >= self.n_children as usize || self.children.is_null() {
+            return Err(ArrowError::CDataInterface(format!(
+                "The external array was expected to have at least {} children, but has {}",
+                index + 1,
+                self.n_children
+            )));
+        }
+        unsafe { (*self.children.add(index)).as_ref() }.ok_or_else(|| {
+            ArrowError::CDataInterface(format!(
+                "The external child at position {} is null.",
+                index
+            ))
+        })
+    }
 }
 
 /// returns a new buffer corresponding to the index `i` of the FFI array. It may not exist (null pointer).
-/// `bits` is the number of bits that the native type of this buffer has.
-/// The size of the buffer will be `ceil(self.length * bits, 8)`.
+/// `owner` is the root array that keeps the memory of `array` alive.
 /// # Panic
 /// This function panics if `i` is larger or equal to `n_buffers`.
 /// # Safety
-/// This function assumes that `ceil(self.length * bits, 8)` is the size of the buffer
+/// This function assumes that `len` is the size of the buffer
 unsafe fn create_buffer(
-    array: Arc<FFI_ArrowArray>,
+    owner: &Arc<FFI_ArrowArray>,
+    array: &FFI_ArrowArray,
     index: usize,
     len: usize,
 ) -> Option<Buffer> {
@@ -422,7 +919,143 @@ unsafe fn create_buffer(
     assert!(index < array.n_buffers as usize);
     let ptr = *buffers.add(index);
 
-    NonNull::new(ptr as *mut u8).map(|ptr| Buffer::from_unowned(ptr, len, array))
+    NonNull::new(ptr as *mut u8).map(|ptr| Buffer::from_unowned(ptr, len, owner.clone()))
+}
+
+/// Returns the length, in bytes, of the buffer `i` (indexed according to the C data interface)
+// Rust implementation uses fixed-sized buffers, which require knowledge of their `len`.
+// for variable-sized buffers, such as the second buffer of a stringArray, we need
+// to fetch offset buffer's len to build the second buffer.
+// Note that buffers span the array's offset, i.e. their len covers `offset + length` slots.
+fn buffer_len(array: &FFI_ArrowArray, data_type: &DataType, i: usize) -> Result<usize> {
+    let length = (array.offset + array.length) as usize;
+
+    Ok(match (data_type, i) {
+        (DataType::Utf8, 1)
+        | (DataType::LargeUtf8, 1)
+        | (DataType::Binary, 1)
+        | (DataType::LargeBinary, 1)
+        | (DataType::List(_), 1)
+        | (DataType::LargeList(_), 1) => {
+            // the len of the offset buffer (buffer 1) equals length + 1
+            let bits = bit_width(data_type, i)?;
+            debug_assert_eq!(bits % 8, 0);
+            (length + 1) * (bits / 8)
+        }
+        (DataType::Utf8, 2) | (DataType::Binary, 2) => {
+            // the len of the data buffer (buffer 2) equals the last value of the offset buffer (buffer 1)
+            // first buffer is the null buffer => add(1)
+            // we assume that pointer is aligned for `i32`, as Utf8 uses `i32` offsets.
+            #[allow(clippy::cast_ptr_alignment)]
+            let offset_buffer =
+                unsafe { *(array.buffers as *mut *const u8).add(1) as *const i32 };
+            // get last offset
+            (unsafe { *offset_buffer.add(length) }) as usize
+        }
+        (DataType::LargeUtf8, 2) | (DataType::LargeBinary, 2) => {
+            // the len of the data buffer (buffer 2) equals the last value of the offset buffer (buffer 1)
+            // first buffer is the null buffer => add(1)
+            // we assume that pointer is aligned for `i64`, as Large uses `i64` offsets.
+            #[allow(clippy::cast_ptr_alignment)]
+            let offset_buffer =
+                unsafe { *(array.buffers as *mut *const u8).add(1) as *const i64 };
+            // get last offset
+            (unsafe { *offset_buffer.add(length) }) as usize
+        }
+        // buffer len of primitive types
+        _ => {
+            let bits = bit_width(data_type, i)?;
+            bit_util::ceil(length * bits, 8)
+        }
+    })
+}
+
+// imports the buffers of `array`, as organized by Rust (i.e. null buffer is skipped)
+fn import_buffers(
+    owner: &Arc<FFI_ArrowArray>,
+    array: &FFI_ArrowArray,
+    data_type: &DataType,
+) -> Result<Vec<Buffer>> {
+    (1..array.n_buffers as usize)
+        .map(|index| {
+            let len = buffer_len(array, data_type, index)?;
+
+            unsafe { create_buffer(owner, array, index, len) }.ok_or_else(|| {
+                ArrowError::CDataInterface(format!(
+                    "The external buffer at position {} is null.",
+                    index - 1
+                ))
+            })
+        })
+        .collect()
+}
+
+// imports `array` of type `data_type` into an [ArrayData], recursing into its children
+// and dictionary. `owner` is the root array that keeps the memory of `array` alive.
+fn import_data(
+    owner: &Arc<FFI_ArrowArray>,
+    array: &FFI_ArrowArray,
+    data_type: &DataType,
+) -> Result<ArrayData> {
+    if array.release.is_none() {
+        return Err(ArrowError::CDataInterface(
+            "The external array has already been released".to_string(),
+        ));
+    }
+    let expected = n_buffers(data_type)?;
+    if array.n_buffers as usize != expected {
+        return Err(ArrowError::CDataInterface(format!(
+            "The datatype \"{:?}\" expects {} buffers, but the external array has {}. Please verify that the C data interface is correctly implemented.",
+            data_type, expected, array.n_buffers
+        )));
+    }
+
+    let null_bit_buffer = if expected == 0 {
+        None
+    } else {
+        let len = bit_util::ceil((array.offset + array.length) as usize, 8);
+        unsafe { create_buffer(owner, array, 0, len) }
+    };
+    let buffers = import_buffers(owner, array, data_type)?;
+
+    let child_data = match data_type {
+        DataType::List(field)
+        | DataType::LargeList(field)
+        | DataType::FixedSizeList(field, _) => {
+            vec![import_data(owner, array.child(0)?, field.data_type())?]
+        }
+        DataType::Struct(fields) => fields
+            .iter()
+            .enumerate()
+            .map(|(i, field)| import_data(owner, array.child(i)?, field.data_type()))
+            .collect::<Result<Vec<_>>>()?,
+        DataType::Dictionary(_, value_type) => {
+            let dictionary = unsafe { array.dictionary.as_ref() }.ok_or_else(|| {
+                ArrowError::CDataInterface(
+                    "The external dictionary-encoded array has no dictionary".to_string(),
+                )
+            })?;
+            vec![import_data(owner, dictionary, value_type)?]
+        }
+        _ => vec![],
+    };
+
+    // a negative null count means that it is unknown
+    let null_count = if array.null_count < 0 {
+        None
+    } else {
+        Some(array.null_count as usize)
+    };
+
+    Ok(ArrayData::new(
+        data_type.clone(),
+        array.length as usize,
+        null_count,
+        null_bit_buffer,
+        array.offset as usize,
+        buffers,
+        child_data.into_iter().map(Arc::new).collect(),
+    ))
 }
 
 impl Drop for FFI_ArrowArray {
@@ -444,7 +1077,7 @@ impl Drop for FFI_ArrowArray {
 /// * [ArrowArray::empty] to allocate memory to be filled by an external call
 /// * [ArrowArray::try_from_raw] to consume two non-null allocated pointers
 /// ## Export to the C Data Interface
-/// * [ArrowArray::try_new] to create a new [ArrowArray] from Rust-specific information
+/// * [ArrowArray::try_new] to create a new [ArrowArray] from an [ArrayData]
 /// * [ArrowArray::into_raw] to expose two pointers for [FFI_ArrowArray] and [FFI_ArrowSchema].
 ///
 /// # Safety
@@ -461,33 +1094,11 @@ pub struct ArrowArray {
 }
 
 impl ArrowArray {
-    /// creates a new `ArrowArray`. This is used to export to the C Data Interface.
-    /// # Safety
-    /// See safety of [ArrowArray]
-    pub unsafe fn try_new(
-        data_type: &DataType,
-        len: usize,
-        null_count: usize,
-        null_buffer: Option<Buffer>,
-        offset: usize,
-        buffers: Vec<Buffer>,
-        _child_data: Vec<ArrowArray>,
-    ) -> Result<Self> {
-        let format = from_datatype(data_type)?;
-        // * insert the null buffer at the start
-        // * make all others `Option<Buffer>`.
-        let new_buffers = iter::once(null_buffer)
-            .chain(buffers.iter().map(|b| Some(b.clone())))
-            .collect::<Vec<_>>();
-
-        let schema = Arc::new(FFI_ArrowSchema::new(&format));
-        let array = Arc::new(FFI_ArrowArray::new(
-            len as i64,
-            null_count as i64,
-            offset as i64,
-            new_buffers.len() as i64,
-            new_buffers,
-        ));
+    /// creates a new `ArrowArray` from an [ArrayData], including its children and dictionary.
+    /// This is used to export to the C Data Interface.
+    pub fn try_new(data: ArrayData) -> Result<Self> {
+        let schema = Arc::new(FFI_ArrowSchema::try_from(data.data_type())?);
+        let array = Arc::new(FFI_ArrowArray::try_new(&data)?);
 
         Ok(ArrowArray { schema, array })
     }
@@ -527,84 +1138,27 @@ impl ArrowArray {
         (Arc::into_raw(this.array), Arc::into_raw(this.schema))
     }
 
+    /// imports this array, including its children and dictionary, into an [ArrayData].
+    pub fn to_data(&self) -> Result<ArrayData> {
+        import_data(&self.array, &self.array, &self.data_type()?)
+    }
+
     /// returns the null bit buffer.
     /// Rust implementation uses a buffer that is not part of the array of buffers.
     /// The C Data interface's null buffer is part of the array of buffers.
     pub fn null_bit_buffer(&self) -> Option<Buffer> {
-        // similar to `self.buffer_len(0)`, but without `Result`.
-        let buffer_len = bit_util::ceil(self.array.length as usize, 8);
-
-        unsafe { create_buffer(self.array.clone(), 0, buffer_len) }
-    }
-
-    /// Returns the length, in bytes, of the buffer `i` (indexed according to the C data interface)
-    // Rust implementation uses fixed-sized buffers, which require knowledge of their `len`.
-    // for variable-sized buffers, such as the second buffer of a stringArray, we need
-    // to fetch offset buffer's len to build the second buffer.
-    fn buffer_len(&self, i: usize) -> Result<usize> {
-        let data_type = &self.data_type()?;
-
-        Ok(match (data_type, i) {
-            (DataType::Utf8, 1)
-            | (DataType::LargeUtf8, 1)
-            | (DataType::Binary, 1)
-            | (DataType::LargeBinary, 1) => {
-                // the len of the offset buffer (buffer 1) equals length + 1
-                let bits = bit_width(data_type, i)?;
-                debug_assert_eq!(bits % 8, 0);
-                (self.array.length as usize + 1) * (bits / 8)
-            }
-            (DataType::Utf8, 2) | (DataType::Binary, 2) => {
-                // the len of the data buffer (buffer 2) equals the last value of the offset buffer (buffer 1)
-                let len = self.buffer_len(1)?;
-                // first buffer is the null buffer => add(1)
-                // we assume that pointer is aligned for `i32`, as Utf8 uses `i32` offsets.
-                #[allow(clippy::cast_ptr_alignment)]
-                let offset_buffer = unsafe {
-                    *(self.array.buffers as *mut *const u8).add(1) as *const i32
-                };
-                // get last offset
-                (unsafe { *offset_buffer.add(len / size_of::<i32>() - 1) }) as usize
-            }
-            (DataType::LargeUtf8, 2) | (DataType::LargeBinary, 2) => {
-                // the len of the data buffer (buffer 2) equals the last value of the offset buffer (buffer 1)
-                let len = self.buffer_len(1)?;
-                // first buffer is the null buffer => add(1)
-                // we assume that pointer is aligned for `i64`, as Large uses `i64` offsets.
-                #[allow(clippy::cast_ptr_alignment)]
-                let offset_buffer = unsafe {
-                    *(self.array.buffers as *mut *const u8).add(1) as *const i64
-                };
-                // get last offset
-                (unsafe { *offset_buffer.add(len / size_of::<i64>() - 1) }) as usize
-            }
-            // buffer len of primitive types
-            _ => {
-                let bits = bit_width(data_type, i)?;
-                bit_util::ceil(self.array.length as usize * bits, 8)
-            }
-        })
+        if self.array.n_buffers == 0 {
+            return None;
+        }
+        let buffer_len =
+            bit_util::ceil((self.array.offset + self.array.length) as usize, 8);
+
+        unsafe { create_buffer(&self.array, &self.array, 0, buffer_len) }
     }
 
     /// returns all buffers, as organized by Rust (i.e. null buffer is skipped)
     pub fn buffers(&self) -> Result<Vec<Buffer>> {
-        (0..self.array.n_buffers - 1)
-            .map(|index| {
-                // + 1: skip null buffer
-                let index = (index + 1) as usize;
-
-                let len = self.buffer_len(index)?;
-
-                unsafe { create_buffer(self.array.clone(), index, len) }.ok_or_else(
-                    || {
-                        ArrowError::CDataInterface(format!(
-                            "The external buffer at position {} is null.",
-                            index - 1
-                        ))
-                    },
-                )
-            })
-            .collect()
+        import_buffers(&self.array, &self.array, &self.data_type()?)
     }
 
     /// the length of the array
@@ -629,7 +1183,297 @@ impl ArrowArray {
 
     /// the data_type as declared in the schema
     pub fn data_type(&self) -> Result<DataType> {
-        to_datatype(self.schema.format())
+        DataType::try_from(self.schema.as_ref())
+    }
+}
+
+// error codes returned by the callbacks of [FFI_ArrowArrayStream], as defined by `errno`.
+const EINVAL: c_int = 22;
+const EIO: c_int = 5;
+
+/// ABI-compatible struct for `ArrowArrayStream` from C Stream Interface
+/// See <https://arrow.apache.org/docs/format/CStreamInterface.html#structure-definition>
+#[repr(C)]
+#[derive(Debug)]
+pub struct FFI_ArrowArrayStream {
+    get_schema: Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_ArrowArrayStream,
+            out: *mut FFI_ArrowSchema,
+        ) -> c_int,
+    >,
+    get_next: Option<
+        unsafe extern "C" fn(
+            arg1: *mut FFI_ArrowArrayStream,
+            out: *mut FFI_ArrowArray,
+        ) -> c_int,
+    >,
+    get_last_error:
+        Option<unsafe extern "C" fn(arg1: *mut FFI_ArrowArrayStream) -> *const c_char>,
+    release: Option<unsafe extern "C" fn(arg1: *mut FFI_ArrowArrayStream)>,
+    private_data: *mut c_void,
+}
+
+// everything owned by an exported [FFI_ArrowArrayStream].
+struct StreamPrivateData {
+    reader: Box<dyn RecordBatchReader + Send>,
+    last_error: Option<CString>,
+}
+
+impl StreamPrivateData {
+    fn set_error(&mut self, error: ArrowError) {
+        // an error message with an interior nul byte is truncated
+        let message = error.to_string();
+        let message = message.split('\0').next().unwrap_or_default();
+        self.last_error = CString::new(message).ok();
+    }
+}
+
+// callback used to export the schema of the stream.
+unsafe extern "C" fn get_schema(
+    stream: *mut FFI_ArrowArrayStream,
+    out: *mut FFI_ArrowSchema,
+) -> c_int {
+    let private_data = &mut *((*stream).private_data as *mut StreamPrivateData);
+    match FFI_ArrowSchema::try_from(private_data.reader.schema().as_ref()) {
+        Ok(schema) => {
+            // `out` is not initialized, so it must not be dropped
+            ptr::write(out, schema);
+            0
+        }
+        Err(error) => {
+            private_data.set_error(error);
+            EINVAL
+        }
+    }
+}
+
+// callback used to export the next batch of the stream, as a struct array.
+unsafe extern "C" fn get_next(
+    stream: *mut FFI_ArrowArrayStream,
+    out: *mut FFI_ArrowArray,
+) -> c_int {
+    let private_data = &mut *((*stream).private_data as *mut StreamPrivateData);
+    let array = match private_data.reader.next() {
+        // the end of the stream is signaled by a released array
+        None => Ok(FFI_ArrowArray::empty()),
+        Some(batch) => batch.and_then(|batch| {
+            let fields = batch.schema().fields().clone();
+            let data = ArrayData::new(
+                DataType::Struct(fields),
+                batch.num_rows(),
+                Some(0),
+                None,
+                0,
+                vec![],
+                batch.columns().iter().map(|column| column.data()).collect(),
+            );
+            FFI_ArrowArray::try_new(&data)
+        }),
+    };
+    match array {
+        Ok(array) => {
+            // `out` is not initialized, so it must not be dropped
+            ptr::write(out, array);
+            0
+        }
+        Err(error) => {
+            private_data.set_error(error);
+            EIO
+        }
+    }
+}
+
+// callback used to describe the last error of the stream.
+unsafe extern "C" fn get_last_error(stream: *mut FFI_ArrowArrayStream) -> *const c_char {
+    let private_data = &*((*stream).private_data as *const StreamPrivateData);
+    private_data
+        .last_error
+        .as_ref()
+        .map(|error| error.as_ptr())
+        .unwrap_or_else(ptr::null)
+}
+
+// callback used to drop [FFI_ArrowArrayStream] when it is exported.
+unsafe extern "C" fn release_stream(stream: *mut FFI_ArrowArrayStream) {
+    if stream.is_null() {
+        return;
+    }
+    let stream = &mut *stream;
+    drop(Box::from_raw(stream.private_data as *mut StreamPrivateData));
+
+    stream.release = None;
+}
+
+impl FFI_ArrowArrayStream {
+    /// creates a new [FFI_ArrowArrayStream] exporting the batches of `reader`.
+    /// Each batch is exported as a struct array whose children are the batch's columns.
+    pub fn new(reader: Box<dyn RecordBatchReader + Send>) -> Self {
+        let private_data = Box::new(StreamPrivateData {
+            reader,
+            last_error: None,
+        });
+
+        Self {
+            get_schema: Some(get_schema),
+            get_next: Some(get_next),
+            get_last_error: Some(get_last_error),
+            release: Some(release_stream),
+            private_data: Box::into_raw(private_data) as *mut c_void,
+        }
+    }
+
+    /// creates an empty [FFI_ArrowArrayStream], which can be used to import a stream into
+    pub fn empty() -> Self {
+        Self {
+            get_schema: None,
+            get_next: None,
+            get_last_error: None,
+            release: None,
+            private_data: ptr::null_mut(),
+        }
+    }
+}
+
+impl Drop for FFI_ArrowArrayStream {
+    fn drop(&mut self) {
+        match self.release {
+            None => (),
+            Some(release) => unsafe { release(self) },
+        };
+    }
+}
+
+/// exports `reader` to the C Stream Interface, writing it to `out`.
+/// # Safety
+/// `out` must be a valid pointer to (possibly uninitialized) memory of an [FFI_ArrowArrayStream].
+/// Its previous content is overwritten without being released.
+pub unsafe fn export_reader_into_raw(
+    reader: Box<dyn RecordBatchReader + Send>,
+    out: *mut FFI_ArrowArrayStream,
+) {
+    ptr::write(out, FFI_ArrowArrayStream::new(reader))
+}
+
+/// A [RecordBatchReader] that imports record batches from the C Stream Interface.
+#[derive(Debug)]
+pub struct ArrowArrayStreamReader {
+    stream: FFI_ArrowArrayStream,
+    schema: SchemaRef,
+    // the exported schema, shared by every imported batch
+    ffi_schema: Arc<FFI_ArrowSchema>,
+}
+
+impl ArrowArrayStreamReader {
+    /// creates a new [ArrowArrayStreamReader] that takes ownership of `stream`.
+    /// # Error
+    /// Errors if the stream was already released or if its schema can't be imported.
+    pub fn try_new(mut stream: FFI_ArrowArrayStream) -> Result<Self> {
+        if stream.release.is_none() {
+            return Err(ArrowError::CDataInterface(
+                "The external stream has already been released".to_string(),
+            ));
+        }
+        let get_schema = stream.get_schema.ok_or_else(|| {
+            ArrowError::CDataInterface(
+                "The external stream has no `get_schema` callback".to_string(),
+            )
+        })?;
+
+        let mut ffi_schema = FFI_ArrowSchema::empty();
+        let code = unsafe { get_schema(&mut stream, &mut ffi_schema) };
+        if code != 0 {
+            return Err(stream_error(&mut stream, code));
+        }
+        let schema = Arc::new(Schema::try_from(&ffi_schema)?);
+
+        Ok(Self {
+            stream,
+            schema,
+            ffi_schema: Arc::new(ffi_schema),
+        })
+    }
+
+    /// creates a new [ArrowArrayStreamReader] from a pointer to an [FFI_ArrowArrayStream],
+    /// moving the stream out of it and marking it as released.
+    /// # Safety
+    /// `raw` must be a valid, non-null pointer to an initialized [FFI_ArrowArrayStream].
+    pub unsafe fn from_raw(raw: *mut FFI_ArrowArrayStream) -> Result<Self> {
+        if raw.is_null() {
+            return Err(ArrowError::MemoryError(
+                "The pointer passed to `from_raw` is null".to_string(),
+            ));
+        }
+        Self::try_new(ptr::replace(raw, FFI_ArrowArrayStream::empty()))
+    }
+
+    fn next_batch(&mut self) -> Result<Option<RecordBatch>> {
+        let get_next = self.stream.get_next.ok_or_else(|| {
+            ArrowError::CDataInterface(
+                "The external stream has no `get_next` callback".to_string(),
+            )
+        })?;
+
+        let mut array = FFI_ArrowArray::empty();
+        let code = unsafe { get_next(&mut self.stream, &mut array) };
+        if code != 0 {
+            return Err(stream_error(&mut self.stream, code));
+        }
+        // a released array marks the end of the stream
+        if array.release.is_none() {
+            return Ok(None);
+        }
+
+        let array = ArrowArray {
+            array: Arc::new(array),
+            schema: self.ffi_schema.clone(),
+        };
+        let data = array.to_data()?;
+        let columns = data
+            .child_data()
+            .iter()
+            .map(|child| {
+                let column = make_array(child.clone());
+                // the offset and length of the struct apply to its children
+                if data.offset() != 0 || column.len() != data.len() {
+                    column.slice(data.offset(), data.len())
+                } else {
+                    column
+                }
+            })
+            .collect::<Vec<ArrayRef>>();
+        RecordBatch::try_new(self.schema.clone(), columns).map(Some)
+    }
+}
+
+// returns the error of a stream whose callback returned the non-zero `code`.
+fn stream_error(stream: &mut FFI_ArrowArrayStream, code: c_int) -> ArrowError {
+    let message = stream
+        .get_last_error
+        .map(|get_last_error| unsafe { get_last_error(stream) })
+        .filter(|message| !message.is_null())
+        .map(|message| {
+            unsafe { CStr::from_ptr(message) }
+                .to_string_lossy()
+                .into_owned()
+        });
+    ArrowError::CDataInterface(match message {
+        Some(message) => message,
+        None => format!("The external stream failed with error code {}", code),
+    })
+}
+
+impl Iterator for ArrowArrayStreamReader {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_batch().transpose()
+    }
+}
+
+impl RecordBatchReader for ArrowArrayStreamReader {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
     }
 }
 
@@ -637,11 +1481,15 @@ impl ArrowArray {
 mod tests {
     use super::*;
     use crate::array::{
-        make_array, Array, ArrayData, BinaryOffsetSizeTrait, BooleanArray,
-        GenericBinaryArray, GenericStringArray, Int32Array, StringOffsetSizeTrait,
-        Time32MillisecondArray,
+        make_array, Array, ArrayData, ArrayRef, BinaryOffsetSizeTrait, BooleanArray,
+        DecimalBuilder, DictionaryArray, DurationSecondArray, FixedSizeBinaryArray,
+        FixedSizeListBuilder, GenericBinaryArray, GenericStringArray, Int32Array,
+        Int32Builder, IntervalDayTimeArray, LargeListBuilder, ListBuilder,
+        StringOffsetSizeTrait, StructArray, Time32MillisecondArray,
+        TimestampMillisecondArray,
     };
     use crate::compute::kernels;
+    use crate::datatypes::Int8Type;
     use std::convert::TryFrom;
     use std::sync::Arc;
 
@@ -819,4 +1667,370 @@ mod tests {
         // (drop/release)
         Ok(())
     }
+
+    // exports and imports `array` through the C data interface
+    fn round_trip(array: &ArrayRef) -> Result<ArrayRef> {
+        let array = ArrowArray::try_from(array.data().as_ref().clone())?;
+        let (array, schema) = ArrowArray::into_raw(array);
+
+        // (simulate consumer) import it
+        let array = unsafe { ArrowArray::try_from_raw(array, schema) }?;
+        Ok(make_array(Arc::new(ArrayData::try_from(array)?)))
+    }
+
+    #[test]
+    fn test_temporal_and_decimal() -> Result<()> {
+        let mut decimal = DecimalBuilder::new(3, 10, 2);
+        decimal.append_value(12345)?;
+        decimal.append_null()?;
+        decimal.append_value(-2)?;
+
+        let arrays: Vec<ArrayRef> = vec![
+            Arc::new(TimestampMillisecondArray::from_opt_vec(
+                vec![Some(1), None, Some(3)],
+                Some("Europe/Lisbon".to_string()),
+            )),
+            Arc::new(TimestampMillisecondArray::from_opt_vec(
+                vec![Some(1), None],
+                None,
+            )),
+            Arc::new(DurationSecondArray::from(vec![Some(-1), None, Some(3)])),
+            Arc::new(IntervalDayTimeArray::from(vec![Some(1), None, Some(3)])),
+            Arc::new(decimal.finish()),
+            Arc::new(FixedSizeBinaryArray::from(vec![
+                Some(vec![1, 2]),
+                None,
+                Some(vec![3, 4]),
+            ])),
+        ];
+        for array in arrays {
+            let result = round_trip(&array)?;
+            assert_eq!(result.data_type(), array.data_type());
+            assert_eq!(result.data(), array.data());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_list() -> Result<()> {
+        let mut builder = ListBuilder::new(Int32Builder::new(5));
+        builder.values().append_slice(&[1, 2])?;
+        builder.append(true)?;
+        builder.append(false)?;
+        builder.values().append_slice(&[3])?;
+        builder.append(true)?;
+        let array = Arc::new(builder.finish()) as ArrayRef;
+
+        let result = round_trip(&array)?;
+        assert_eq!(result.data_type(), array.data_type());
+        assert_eq!(result.data(), array.data());
+
+        let mut builder = LargeListBuilder::new(Int32Builder::new(5));
+        builder.values().append_slice(&[1, 2])?;
+        builder.append(true)?;
+        builder.append(false)?;
+        let array = Arc::new(builder.finish()) as ArrayRef;
+
+        let result = round_trip(&array)?;
+        assert_eq!(result.data(), array.data());
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixed_size_list() -> Result<()> {
+        let mut builder = FixedSizeListBuilder::new(Int32Builder::new(6), 2);
+        builder.values().append_slice(&[1, 2])?;
+        builder.append(true)?;
+        builder.values().append_slice(&[0, 0])?;
+        builder.append(false)?;
+        builder.values().append_slice(&[3, 4])?;
+        builder.append(true)?;
+        let array = Arc::new(builder.finish()) as ArrayRef;
+
+        let result = round_trip(&array)?;
+        assert_eq!(result.data_type(), array.data_type());
+        assert_eq!(result.data(), array.data());
+        Ok(())
+    }
+
+    #[test]
+    fn test_struct() -> Result<()> {
+        let strings: ArrayRef = Arc::new(GenericStringArray::<i32>::from(vec![
+            Some("a"),
+            None,
+            Some("c"),
+        ]));
+        let ints: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), Some(2), None]));
+        let array = Arc::new(StructArray::try_from(vec![("s", strings), ("i", ints)])?)
+            as ArrayRef;
+
+        let result = round_trip(&array)?;
+        assert_eq!(result.data_type(), array.data_type());
+        assert_eq!(result.data(), array.data());
+        Ok(())
+    }
+
+    #[test]
+    fn test_dictionary() -> Result<()> {
+        let array: DictionaryArray<Int8Type> =
+            vec![Some("a"), None, Some("b"), Some("a")]
+                .into_iter()
+                .collect();
+        let array = Arc::new(array) as ArrayRef;
+
+        let result = round_trip(&array)?;
+        assert_eq!(result.data_type(), array.data_type());
+        assert_eq!(result.data(), array.data());
+
+        let result = result
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int8Type>>()
+            .unwrap();
+        let values = result.values();
+        let values = values
+            .as_any()
+            .downcast_ref::<GenericStringArray<i32>>()
+            .unwrap();
+        assert_eq!(values.value(0), "a");
+        assert_eq!(values.value(1), "b");
+        Ok(())
+    }
+
+    #[test]
+    fn test_sliced() -> Result<()> {
+        let array = Int32Array::from(vec![Some(1), None, Some(3), Some(4), None]);
+        let array = array.slice(1, 3);
+
+        let result = round_trip(&array)?;
+        assert_eq!(result.offset(), 1);
+        let result = result.as_any().downcast_ref::<Int32Array>().unwrap();
+        let expected = Int32Array::from(vec![None, Some(3), Some(4)]);
+        assert_eq!(
+            result.iter().collect::<Vec<_>>(),
+            expected.iter().collect::<Vec<_>>()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_unsupported_format() {
+        let mut schema = export_field("", &DataType::Utf8, true, false, None).unwrap();
+        // overwrite the format with one that Rust does not support.
+        // The exported format is still owned (and released) by `private_data`.
+        let format = CString::new("+m").unwrap();
+        schema.format = format.as_ptr();
+        assert!(DataType::try_from(&schema).is_err());
+    }
+
+    #[test]
+    fn test_schema_round_trip() -> Result<()> {
+        let mut field = Field::new_dict(
+            "dict",
+            DataType::Dictionary(Box::new(DataType::Int16), Box::new(DataType::Utf8)),
+            true,
+            0,
+            true,
+        );
+        let mut metadata = BTreeMap::new();
+        metadata.insert("key".to_string(), "value".to_string());
+        field.set_metadata(Some(metadata));
+
+        let fields = vec![
+            Field::new(
+                "ts",
+                DataType::Timestamp(TimeUnit::Nanosecond, Some("+01:00".to_string())),
+                false,
+            ),
+            Field::new("duration", DataType::Duration(TimeUnit::Microsecond), true),
+            Field::new(
+                "interval",
+                DataType::Interval(IntervalUnit::YearMonth),
+                true,
+            ),
+            Field::new("decimal", DataType::Decimal(38, 10), true),
+            Field::new("binary", DataType::FixedSizeBinary(3), true),
+            Field::new(
+                "list",
+                DataType::FixedSizeList(
+                    Box::new(Field::new(
+                        "item",
+                        DataType::LargeList(Box::new(Field::new(
+                            "item",
+                            DataType::Date64(DateUnit::Millisecond),
+                            true,
+                        ))),
+                        false,
+                    )),
+                    2,
+                ),
+                true,
+            ),
+            Field::new(
+                "struct",
+                DataType::Struct(vec![
+                    Field::new("a", DataType::Float16, true),
+                    Field::new("b", DataType::Time64(TimeUnit::Nanosecond), false),
+                ]),
+                true,
+            ),
+            field,
+        ];
+        let mut schema_metadata = HashMap::new();
+        schema_metadata.insert("origin".to_string(), "test".to_string());
+        let schema = Schema::new_with_metadata(fields, schema_metadata);
+
+        let ffi_schema = FFI_ArrowSchema::try_from(&schema)?;
+        assert_eq!(ffi_schema.format(), "+s");
+        assert_eq!(ffi_schema.child(0).format(), "tsn:+01:00");
+        assert_eq!(ffi_schema.child(1).format(), "tDu");
+        assert_eq!(ffi_schema.child(2).format(), "tiM");
+        assert_eq!(ffi_schema.child(3).format(), "d:38,10");
+        assert_eq!(ffi_schema.child(4).format(), "w:3");
+        assert_eq!(ffi_schema.child(5).format(), "+w:2");
+        assert_eq!(ffi_schema.child(5).child(0).format(), "+L");
+        assert_eq!(ffi_schema.child(7).format(), "s");
+        assert_eq!(ffi_schema.child(7).dictionary().unwrap().format(), "u");
+        assert_eq!(
+            ffi_schema.child(7).flags(),
+            ARROW_FLAG_NULLABLE | ARROW_FLAG_DICTIONARY_ORDERED
+        );
+
+        assert_eq!(Schema::try_from(&ffi_schema)?, schema);
+        Ok(())
+    }
+
+    #[test]
+    fn test_schema_dictionary_ids() -> Result<()> {
+        let dictionary =
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8));
+        let schema = Schema::new(vec![
+            Field::new_dict("a", dictionary.clone(), true, 0, false),
+            Field::new(
+                "b",
+                DataType::Struct(vec![Field::new_dict(
+                    "c",
+                    dictionary.clone(),
+                    true,
+                    1,
+                    false,
+                )]),
+                true,
+            ),
+            Field::new_dict("d", dictionary, true, 2, false),
+        ]);
+
+        // each imported dictionary field has its own id
+        let ffi_schema = FFI_ArrowSchema::try_from(&schema)?;
+        let imported = Schema::try_from(&ffi_schema)?;
+        assert_eq!(imported, schema);
+        assert_eq!(imported.field(0).dict_id(), Some(0));
+        assert_eq!(imported.field(2).dict_id(), Some(2));
+        Ok(())
+    }
+
+    // a reader over in-memory batches
+    struct TestReader {
+        schema: SchemaRef,
+        batches: std::vec::IntoIter<Result<RecordBatch>>,
+    }
+
+    impl Iterator for TestReader {
+        type Item = Result<RecordBatch>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            self.batches.next()
+        }
+    }
+
+    impl RecordBatchReader for TestReader {
+        fn schema(&self) -> SchemaRef {
+            self.schema.clone()
+        }
+    }
+
+    #[test]
+    fn test_stream_round_trip() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, true),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(Int32Array::from(vec![Some(1), None, Some(3)])),
+                Arc::new(GenericStringArray::<i32>::from(vec![
+                    Some("a"),
+                    Some("b"),
+                    None,
+                ])),
+            ],
+        )?;
+        let reader = TestReader {
+            schema: schema.clone(),
+            batches: vec![Ok(batch.clone()), Ok(batch.slice(1, 2))].into_iter(),
+        };
+
+        // export it into memory owned by the consumer
+        let stream = Box::into_raw(Box::new(FFI_ArrowArrayStream::empty()));
+        unsafe { export_reader_into_raw(Box::new(reader), stream) };
+
+        // (simulate consumer) import it
+        let reader = unsafe { ArrowArrayStreamReader::from_raw(stream) }?;
+        unsafe { drop(Box::from_raw(stream)) };
+        assert_eq!(reader.schema(), schema);
+
+        let batches = reader.collect::<Result<Vec<_>>>()?;
+        assert_eq!(batches.len(), 2);
+        assert_eq!(batches[0].num_rows(), 3);
+        assert_eq!(batches[1].num_rows(), 2);
+        for (batch, expected) in
+            batches.iter().zip(vec![batch.clone(), batch.slice(1, 2)])
+        {
+            assert_eq!(batch.schema(), schema);
+            let a = batch
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap();
+            let expected_a = expected
+                .column(0)
+                .as_any()
+                .downcast_ref::<Int32Array>()
+                .unwrap();
+            assert_eq!(
+                a.iter().collect::<Vec<_>>(),
+                expected_a.iter().collect::<Vec<_>>()
+            );
+            let b = batch
+                .column(1)
+                .as_any()
+                .downcast_ref::<GenericStringArray<i32>>()
+                .unwrap();
+            let expected_b = expected
+                .column(1)
+                .as_any()
+                .downcast_ref::<GenericStringArray<i32>>()
+                .unwrap();
+            assert_eq!(
+                b.iter().collect::<Vec<_>>(),
+                expected_b.iter().collect::<Vec<_>>()
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_error() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, true)]));
+        let reader = TestReader {
+            schema,
+            batches: vec![Err(ArrowError::ComputeError("boom".to_string()))].into_iter(),
+        };
+
+        let mut reader =
+            ArrowArrayStreamReader::try_new(FFI_ArrowArrayStream::new(Box::new(reader)))?;
+        let error = reader.next().unwrap().unwrap_err();
+        assert!(error.to_string().contains("boom"), "{}", error);
+        Ok(())
+    }
 }
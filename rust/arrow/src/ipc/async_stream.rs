@@ -0,0 +1,508 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Async Arrow IPC Stream Reader and Writer
+//!
+//! The `AsyncStreamReader` and `AsyncStreamWriter` read and write the same streaming
+//! format as the `StreamReader` and `StreamWriter`, over tokio's `AsyncRead` and
+//! `AsyncWrite`. The reader is a `futures::Stream` of record batches, and the writer
+//! is a `futures::Sink` of them.
+
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use futures::future::poll_fn;
+use futures::stream::BoxStream;
+use futures::{ready, Sink, Stream, StreamExt};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, BufReader};
+
+use crate::array::ArrayRef;
+use crate::datatypes::{Schema, SchemaRef};
+use crate::error::{ArrowError, Result};
+use crate::ipc;
+use crate::ipc::reader::{read_dictionary, read_record_batch};
+use crate::ipc::writer::{
+    write_continuation, write_message, DictionaryTracker, IpcDataGenerator,
+    IpcWriteOptions,
+};
+use crate::record_batch::RecordBatch;
+
+use ipc::CONTINUATION_MARKER;
+
+/// Arrow Stream reader over an `AsyncRead`, which yields record batches as a `Stream`
+pub struct AsyncStreamReader {
+    /// The schema that is read from the stream's first message
+    schema: SchemaRef,
+
+    /// The record batches that are read from the remainder of the stream
+    batches: BoxStream<'static, Result<RecordBatch>>,
+}
+
+impl AsyncStreamReader {
+    /// Try to create a new stream reader
+    ///
+    /// The first message in the stream is the schema, the reader will fail if it does not
+    /// encounter a schema.
+    pub async fn try_new<R>(reader: R) -> Result<Self>
+    where
+        R: AsyncRead + Unpin + Send + 'static,
+    {
+        let mut reader = BufReader::new(reader);
+        let meta_buffer = match read_metadata(&mut reader).await? {
+            Some(meta_buffer) => meta_buffer,
+            None => {
+                return Err(ArrowError::IoError(
+                    "Unable to read IPC message as schema".to_string(),
+                ))
+            }
+        };
+
+        let message = ipc::root_as_message(&meta_buffer).map_err(|err| {
+            ArrowError::IoError(format!("Unable to get root as message: {:?}", err))
+        })?;
+        // message header is a Schema, so read it
+        let ipc_schema: ipc::Schema = message.header_as_schema().ok_or_else(|| {
+            ArrowError::IoError("Unable to read IPC message as schema".to_string())
+        })?;
+        let schema = Arc::new(ipc::convert::fb_to_schema(ipc_schema));
+
+        let state = StreamState {
+            reader,
+            schema: schema.clone(),
+            dictionaries_by_field: vec![None; schema.fields().len()],
+            finished: false,
+        };
+        let batches = futures::stream::unfold(state, |mut state| async move {
+            state
+                .maybe_next()
+                .await
+                .transpose()
+                .map(|batch| (batch, state))
+        })
+        .boxed();
+
+        Ok(Self { schema, batches })
+    }
+
+    /// Return the schema of the stream
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+impl Stream for AsyncStreamReader {
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        self.batches.poll_next_unpin(cx)
+    }
+}
+
+/// The state of an `AsyncStreamReader` in between record batches
+struct StreamState<R> {
+    reader: BufReader<R>,
+    schema: SchemaRef,
+    /// Optional dictionaries for each schema field, which may be replaced or
+    /// appended to in the streaming format.
+    dictionaries_by_field: Vec<Option<ArrayRef>>,
+    /// Whether the end of the stream was read
+    finished: bool,
+}
+
+impl<R: AsyncRead + Unpin> StreamState<R> {
+    async fn maybe_next(&mut self) -> Result<Option<RecordBatch>> {
+        // dictionary batches are read until a record batch is encountered
+        while !self.finished {
+            let meta_buffer = match read_metadata(&mut self.reader).await? {
+                Some(meta_buffer) => meta_buffer,
+                None => {
+                    self.finished = true;
+                    break;
+                }
+            };
+            let message = ipc::root_as_message(&meta_buffer).map_err(|err| {
+                ArrowError::IoError(format!("Unable to get root as message: {:?}", err))
+            })?;
+
+            // read the block that makes up the message body into a buffer
+            let mut buf = vec![0; message.bodyLength() as usize];
+            self.reader.read_exact(&mut buf).await?;
+
+            match message.header_type() {
+                ipc::MessageHeader::Schema => {
+                    return Err(ArrowError::IoError(
+                        "Not expecting a schema when messages are read".to_string(),
+                    ))
+                }
+                ipc::MessageHeader::RecordBatch => {
+                    let batch = message.header_as_record_batch().ok_or_else(|| {
+                        ArrowError::IoError(
+                            "Unable to read IPC message as record batch".to_string(),
+                        )
+                    })?;
+                    return read_record_batch(
+                        &buf,
+                        batch,
+                        self.schema.clone(),
+                        &self.dictionaries_by_field,
+                    )
+                    .map(Some);
+                }
+                ipc::MessageHeader::DictionaryBatch => {
+                    let batch = message.header_as_dictionary_batch().ok_or_else(|| {
+                        ArrowError::IoError(
+                            "Unable to read IPC message as dictionary batch".to_string(),
+                        )
+                    })?;
+                    read_dictionary(
+                        &buf,
+                        batch,
+                        &self.schema,
+                        &mut self.dictionaries_by_field,
+                    )?;
+                }
+                ipc::MessageHeader::NONE => return Ok(None),
+                t => {
+                    return Err(ArrowError::IoError(format!(
+                        "Reading types other than record batches not yet supported, unable to read {:?}",
+                        t
+                    )))
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// Read the metadata of the next message, returning `None` at the end of the stream
+async fn read_metadata<R: AsyncRead + Unpin>(
+    reader: &mut BufReader<R>,
+) -> Result<Option<Vec<u8>>> {
+    // determine metadata length
+    let mut meta_size: [u8; 4] = [0; 4];
+    match reader.read_exact(&mut meta_size).await {
+        Ok(_) => (),
+        // a stream may end without the "0xFFFFFFFF 0x00000000" marker
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(ArrowError::from(e)),
+    }
+    // If a continuation marker is encountered, skip over it and read
+    // the size from the next four bytes.
+    if meta_size == CONTINUATION_MARKER {
+        reader.read_exact(&mut meta_size).await?;
+    }
+    let meta_len = i32::from_le_bytes(meta_size);
+    if meta_len == 0 {
+        return Ok(None);
+    }
+
+    let mut meta_buffer = vec![0; meta_len as usize];
+    reader.read_exact(&mut meta_buffer).await?;
+    Ok(Some(meta_buffer))
+}
+
+/// Arrow Stream writer over an `AsyncWrite`, which accepts record batches as a `Sink`
+///
+/// The stream must be ended with `finish` or `Sink::poll_close`, as it is not
+/// finished when the writer is dropped.
+pub struct AsyncStreamWriter<W> {
+    /// The object to write to
+    writer: W,
+    /// IPC write options
+    write_options: IpcWriteOptions,
+    /// Whether the end of the stream has been encoded
+    finished: bool,
+    /// Keeps track of dictionaries that have been written
+    dictionary_tracker: DictionaryTracker,
+    data_gen: IpcDataGenerator,
+    /// Encoded messages that are yet to be written
+    buffer: Vec<u8>,
+    /// The number of bytes of `buffer` that have been written
+    written: usize,
+}
+
+impl<W: AsyncWrite + Unpin> AsyncStreamWriter<W> {
+    /// Try create a new writer, with the schema written as part of the header
+    pub fn try_new(writer: W, schema: &Schema) -> Result<Self> {
+        let write_options = IpcWriteOptions::default();
+        Self::try_new_with_options(writer, schema, write_options)
+    }
+
+    /// Try create a new writer with IpcWriteOptions
+    ///
+    /// The schema is buffered, and written to `writer` when the writer is first
+    /// flushed, such as by writing a record batch or finishing the stream.
+    pub fn try_new_with_options(
+        writer: W,
+        schema: &Schema,
+        write_options: IpcWriteOptions,
+    ) -> Result<Self> {
+        let data_gen = IpcDataGenerator::default();
+        // the schema is written before the first record batch
+        let mut buffer = vec![];
+        let encoded_message = data_gen.schema_to_bytes(schema, &write_options);
+        write_message(&mut buffer, encoded_message, &write_options)?;
+        Ok(Self {
+            writer,
+            write_options,
+            finished: false,
+            dictionary_tracker: DictionaryTracker::new(false),
+            data_gen,
+            buffer,
+            written: 0,
+        })
+    }
+
+    /// Write a record batch to the stream
+    pub async fn write(&mut self, batch: &RecordBatch) -> Result<()> {
+        self.encode(batch)?;
+        poll_fn(|cx| Pin::new(&mut *self).poll_flush(cx)).await
+    }
+
+    /// Write continuation bytes, and mark the stream as done
+    ///
+    /// Unlike `Sink::poll_close`, this does not shut the underlying writer down.
+    pub async fn finish(&mut self) -> Result<()> {
+        self.encode_end()?;
+        poll_fn(|cx| Pin::new(&mut *self).poll_flush(cx)).await
+    }
+
+    /// Return the underlying writer
+    pub fn into_inner(self) -> W {
+        self.writer
+    }
+
+    fn encode(&mut self, batch: &RecordBatch) -> Result<()> {
+        if self.finished {
+            return Err(ArrowError::IoError(
+                "Cannot write record batch to stream writer as it is closed".to_string(),
+            ));
+        }
+
+        let (encoded_dictionaries, encoded_message) = self.data_gen.encoded_batch(
+            batch,
+            &mut self.dictionary_tracker,
+            &self.write_options,
+        )?;
+
+        for encoded_dictionary in encoded_dictionaries {
+            write_message(&mut self.buffer, encoded_dictionary, &self.write_options)?;
+        }
+        write_message(&mut self.buffer, encoded_message, &self.write_options)?;
+        Ok(())
+    }
+
+    fn encode_end(&mut self) -> Result<()> {
+        if !self.finished {
+            write_continuation(&mut self.buffer, &self.write_options, 0)?;
+            self.finished = true;
+        }
+        Ok(())
+    }
+
+    /// Write the encoded messages to the writer
+    fn poll_write_buffer(&mut self, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        while self.written < self.buffer.len() {
+            let buf = &self.buffer[self.written..];
+            let n = ready!(Pin::new(&mut self.writer).poll_write(cx, buf))?;
+            if n == 0 {
+                return Poll::Ready(Err(ArrowError::IoError(
+                    "Unable to write IPC message to the stream".to_string(),
+                )));
+            }
+            self.written += n;
+        }
+        self.buffer.clear();
+        self.written = 0;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl<W: AsyncWrite + Unpin> Sink<RecordBatch> for AsyncStreamWriter<W> {
+    type Error = ArrowError;
+
+    fn poll_ready(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        // a record batch is only encoded once the previous one was written
+        self.get_mut().poll_write_buffer(cx)
+    }
+
+    fn start_send(self: Pin<&mut Self>, batch: RecordBatch) -> Result<()> {
+        self.get_mut().encode(&batch)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let this = self.get_mut();
+        ready!(this.poll_write_buffer(cx))?;
+        Pin::new(&mut this.writer)
+            .poll_flush(cx)
+            .map_err(ArrowError::from)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let this = self.get_mut();
+        this.encode_end()?;
+        ready!(Pin::new(&mut *this).poll_flush(cx))?;
+        Pin::new(&mut this.writer)
+            .poll_shutdown(cx)
+            .map_err(ArrowError::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::{SinkExt, TryStreamExt};
+
+    use crate::array::*;
+    use crate::datatypes::{DataType, Field, Int8Type};
+    use crate::ipc::reader::StreamReader;
+    use crate::ipc::writer::StreamWriter;
+
+    fn test_schema() -> Schema {
+        Schema::new(vec![
+            Field::new_dict(
+                "dict",
+                DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+                true,
+                0,
+                false,
+            ),
+            Field::new("int", DataType::Int32, true),
+            Field::new("str", DataType::Utf8, false),
+        ])
+    }
+
+    fn test_batches(schema: &Schema, num_batches: usize) -> Vec<RecordBatch> {
+        let schema = Arc::new(schema.clone());
+        (0..num_batches)
+            .map(|i| {
+                // the dictionary is replaced in every other batch
+                let dict: DictionaryArray<Int8Type> = if i % 2 == 0 {
+                    vec![Some("a"), Some("b"), Some("a")]
+                } else {
+                    vec![Some("c"), None, Some("a")]
+                }
+                .into_iter()
+                .collect();
+                let int = Int32Array::from(vec![Some(i as i32), None, Some(3)]);
+                let str = StringArray::from(vec![format!("{}", i).as_str(), "b", "c"]);
+                RecordBatch::try_new(
+                    schema.clone(),
+                    vec![Arc::new(dict), Arc::new(int), Arc::new(str)],
+                )
+                .unwrap()
+            })
+            .collect()
+    }
+
+    fn assert_batches_eq(left: &[RecordBatch], right: &[RecordBatch]) {
+        assert_eq!(left.len(), right.len());
+        for (left, right) in left.iter().zip(right.iter()) {
+            assert_eq!(left.schema(), right.schema());
+            for i in 0..left.num_columns() {
+                assert_eq!(left.column(i).data(), right.column(i).data());
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_round_trip() {
+        let schema = test_schema();
+        let batches = test_batches(&schema, 10);
+
+        // a small pipe, so that the reader and writer wait on each other
+        let (client, server) = tokio::io::duplex(64);
+
+        let write = async {
+            let mut writer = AsyncStreamWriter::try_new(client, &schema).unwrap();
+            let mut input = futures::stream::iter(batches.clone().into_iter().map(Ok));
+            writer.send_all(&mut input).await.unwrap();
+            writer.close().await.unwrap();
+        };
+        let read = async {
+            let reader = AsyncStreamReader::try_new(server).await.unwrap();
+            assert_eq!(reader.schema().as_ref(), &schema);
+            reader.try_collect::<Vec<_>>().await.unwrap()
+        };
+        let ((), read) = futures::join!(write, read);
+
+        assert_batches_eq(&read, &batches);
+    }
+
+    #[tokio::test]
+    async fn test_read_sync_stream() {
+        let schema = test_schema();
+        let batches = test_batches(&schema, 3);
+
+        let mut buf = vec![];
+        {
+            let mut writer = StreamWriter::try_new(&mut buf, &schema).unwrap();
+            for batch in &batches {
+                writer.write(batch).unwrap();
+            }
+            writer.finish().unwrap();
+        }
+
+        let reader = AsyncStreamReader::try_new(std::io::Cursor::new(buf))
+            .await
+            .unwrap();
+        let read = reader.try_collect::<Vec<_>>().await.unwrap();
+        assert_batches_eq(&read, &batches);
+    }
+
+    #[tokio::test]
+    async fn test_write_sync_stream() {
+        let schema = test_schema();
+        let batches = test_batches(&schema, 3);
+
+        let mut writer = AsyncStreamWriter::try_new(vec![], &schema).unwrap();
+        for batch in &batches {
+            writer.write(batch).await.unwrap();
+        }
+        writer.finish().await.unwrap();
+        assert!(writer.write(&batches[0]).await.is_err());
+        let buf = writer.into_inner();
+
+        let reader = StreamReader::try_new(std::io::Cursor::new(buf)).unwrap();
+        let read = reader.collect::<Result<Vec<_>>>().unwrap();
+        assert_batches_eq(&read, &batches);
+    }
+
+    #[tokio::test]
+    async fn test_empty_stream() {
+        let schema = test_schema();
+
+        let mut writer = AsyncStreamWriter::try_new(vec![], &schema).unwrap();
+        writer.finish().await.unwrap();
+        let buf = writer.into_inner();
+
+        let mut reader = AsyncStreamReader::try_new(std::io::Cursor::new(buf))
+            .await
+            .unwrap();
+        assert_eq!(reader.schema().as_ref(), &schema);
+        assert!(reader.next().await.is_none());
+
+        // a stream must start with a schema
+        let result = AsyncStreamReader::try_new(std::io::Cursor::new(vec![])).await;
+        assert!(result.is_err());
+    }
+}
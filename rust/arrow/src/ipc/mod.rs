@@ -18,6 +18,8 @@
 // TODO: (vcq): Protobuf codegen is not generating Debug impls.
 #![allow(missing_debug_implementations)]
 
+#[cfg(feature = "async")]
+pub mod async_stream;
 pub mod convert;
 pub mod reader;
 pub mod writer;
@@ -18,15 +18,26 @@
 //! Arrow IPC File and Stream Readers
 //!
 //! The `FileReader` and `StreamReader` have similar interfaces,
-//! however the `FileReader` expects a reader that supports `Seek`ing
+//! however the `FileReader` expects a reader that supports `Seek`ing.
+//! The `MmapFileReader`, enabled by the `mmap` feature, memory-maps a file instead, and
+//! reads its arrays without copying them.
 
 use std::collections::HashMap;
+#[cfg(feature = "mmap")]
+use std::fs::File;
 use std::io::{BufReader, Read, Seek, SeekFrom};
+#[cfg(feature = "mmap")]
+use std::ptr::NonNull;
 use std::sync::Arc;
 
+#[cfg(feature = "mmap")]
+use memmap2::Mmap;
+
 use crate::array::*;
+#[cfg(feature = "mmap")]
+use crate::buffer::Allocation;
 use crate::buffer::Buffer;
-use crate::compute::cast;
+use crate::compute::{cast, concat};
 use crate::datatypes::{DataType, Field, IntervalUnit, Schema, SchemaRef};
 use crate::error::{ArrowError, Result};
 use crate::ipc;
@@ -35,11 +46,52 @@ use crate::record_batch::{RecordBatch, RecordBatchReader};
 use ipc::CONTINUATION_MARKER;
 use DataType::*;
 
+/// The body of an IPC message, from which the buffers of its arrays are read.
+///
+/// When `owner` is set, `data` points into memory that `owner` keeps alive (e.g. a
+/// memory-mapped file), and buffers are created without copying whenever they are aligned.
+#[derive(Clone, Copy)]
+struct MessageBody<'a> {
+    data: &'a [u8],
+    #[cfg(feature = "mmap")]
+    owner: Option<&'a Arc<dyn Allocation>>,
+}
+
+impl<'a> MessageBody<'a> {
+    /// A body whose buffers are always copied
+    fn copied(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            #[cfg(feature = "mmap")]
+            owner: None,
+        }
+    }
+}
+
+/// The alignment, in bytes, that a buffer must have to be used without copying it.
+/// This is the largest alignment required by the native types of arrays.
+#[cfg(feature = "mmap")]
+const ZERO_COPY_ALIGNMENT: usize = 8;
+
 /// Read a buffer based on offset and length
-fn read_buffer(buf: &ipc::Buffer, a_data: &[u8]) -> Buffer {
+fn read_buffer(buf: &ipc::Buffer, a_data: MessageBody) -> Buffer {
     let start_offset = buf.offset() as usize;
     let end_offset = start_offset + buf.length() as usize;
-    let buf_data = &a_data[start_offset..end_offset];
+    let buf_data = &a_data.data[start_offset..end_offset];
+    #[cfg(feature = "mmap")]
+    if let Some(owner) = a_data.owner {
+        if buf_data.as_ptr() as usize % ZERO_COPY_ALIGNMENT == 0 {
+            // the pointer comes from a slice, and is thus not null
+            return unsafe {
+                Buffer::from_custom_allocation(
+                    NonNull::new_unchecked(buf_data.as_ptr() as *mut u8),
+                    buf_data.len(),
+                    owner.clone(),
+                )
+            };
+        }
+    }
+    // unaligned buffers are copied into new, aligned, memory
     Buffer::from(&buf_data)
 }
 
@@ -55,7 +107,7 @@ fn read_buffer(buf: &ipc::Buffer, a_data: &[u8]) -> Buffer {
 fn create_array(
     nodes: &[ipc::FieldNode],
     data_type: &DataType,
-    data: &[u8],
+    data: MessageBody,
     buffers: &[ipc::Buffer],
     dictionaries: &[Option<ArrayRef>],
     mut node_index: usize,
@@ -412,6 +464,15 @@ pub fn read_record_batch(
     batch: ipc::RecordBatch,
     schema: SchemaRef,
     dictionaries: &[Option<ArrayRef>],
+) -> Result<RecordBatch> {
+    read_record_batch_body(MessageBody::copied(buf), batch, schema, dictionaries)
+}
+
+fn read_record_batch_body(
+    buf: MessageBody,
+    batch: ipc::RecordBatch,
+    schema: SchemaRef,
+    dictionaries: &[Option<ArrayRef>],
 ) -> Result<RecordBatch> {
     let buffers = batch.buffers().ok_or_else(|| {
         ArrowError::IoError("Unable to get buffers from IPC RecordBatch".to_string())
@@ -429,7 +490,7 @@ pub fn read_record_batch(
         let triple = create_array(
             field_nodes,
             field.data_type(),
-            &buf,
+            buf,
             buffers,
             dictionaries,
             node_index,
@@ -451,12 +512,20 @@ pub fn read_dictionary(
     schema: &Schema,
     dictionaries_by_field: &mut [Option<ArrayRef>],
 ) -> Result<()> {
-    if batch.isDelta() {
-        return Err(ArrowError::IoError(
-            "delta dictionary batches not supported".to_string(),
-        ));
-    }
+    read_dictionary_body(
+        MessageBody::copied(buf),
+        batch,
+        schema,
+        dictionaries_by_field,
+    )
+}
 
+fn read_dictionary_body(
+    buf: MessageBody,
+    batch: ipc::DictionaryBatch,
+    schema: &Schema,
+    dictionaries_by_field: &mut [Option<ArrayRef>],
+) -> Result<()> {
     let id = batch.id();
     let fields_using_this_dictionary = schema.fields_with_dict_id(id);
     let first_field = fields_using_this_dictionary.first().ok_or_else(|| {
@@ -474,8 +543,8 @@ pub fn read_dictionary(
                 metadata: HashMap::new(),
             };
             // Read a single column
-            let record_batch = read_record_batch(
-                &buf,
+            let record_batch = read_record_batch_body(
+                buf,
                 batch.data().unwrap(),
                 Arc::new(schema),
                 &dictionaries_by_field,
@@ -488,6 +557,24 @@ pub fn read_dictionary(
         ArrowError::InvalidArgumentError("dictionary id not found in schema".to_string())
     })?;
 
+    // a delta appends its values to the existing dictionary
+    let dictionary_values = if batch.isDelta() {
+        let existing = schema
+            .fields()
+            .iter()
+            .position(|field| field.dict_id() == Some(id))
+            .and_then(|i| dictionaries_by_field[i].as_ref())
+            .ok_or_else(|| {
+                ArrowError::IoError(format!(
+                    "Received a delta for dictionary {}, which was not previously read",
+                    id
+                ))
+            })?;
+        concat(&[existing.as_ref(), dictionary_values.as_ref()])?
+    } else {
+        dictionary_values
+    };
+
     // for all fields with this dictionary id, update the dictionaries vector
     // in the reader. Note that a dictionary batch may be shared between many fields.
     // We don't currently record the isOrdered field. This could be general
@@ -741,6 +828,282 @@ impl<R: Read + Seek> RecordBatchReader for FileReader<R> {
     }
 }
 
+/// Returns the message and the body of `block`, as stored in the file `data`
+#[cfg(feature = "mmap")]
+fn read_block<'a>(
+    data: &'a [u8],
+    block: &ipc::Block,
+) -> Result<(ipc::Message<'a>, &'a [u8])> {
+    let out_of_bounds = || {
+        ArrowError::IoError(format!(
+            "The block at offset {} is out of bounds of the file",
+            block.offset()
+        ))
+    };
+    if block.offset() < 0 || block.metaDataLength() < 0 || block.bodyLength() < 0 {
+        return Err(out_of_bounds());
+    }
+    let start = block.offset() as usize;
+    let meta_end = start + block.metaDataLength() as usize;
+    let body_end = meta_end + block.bodyLength() as usize;
+    if body_end > data.len() {
+        return Err(out_of_bounds());
+    }
+
+    let mut meta = &data[start..meta_end];
+    if meta.len() >= 4 && meta[..4] == CONTINUATION_MARKER {
+        // continuation marker encountered, read message next
+        meta = &meta[4..];
+    }
+    if meta.len() < 4 {
+        return Err(out_of_bounds());
+    }
+    let meta_len = i32::from_le_bytes([meta[0], meta[1], meta[2], meta[3]]);
+    let meta = &meta[4..];
+    if meta_len < 0 || meta_len as usize > meta.len() {
+        return Err(out_of_bounds());
+    }
+
+    let message = ipc::root_as_message(&meta[..meta_len as usize]).map_err(|err| {
+        ArrowError::IoError(format!("Unable to get root as message: {:?}", err))
+    })?;
+    Ok((message, &data[meta_end..body_end]))
+}
+
+/// Arrow File reader that memory-maps the file
+///
+/// Arrays read from the file have buffers that point directly into the mapping, so that
+/// reading a file does not allocate memory for its contents. Buffers that are not aligned
+/// in the file are copied. Record batches can be read in any order via
+/// [`MmapFileReader::read_batch`].
+#[cfg(feature = "mmap")]
+pub struct MmapFileReader {
+    /// The memory-mapped file
+    mmap: Arc<Mmap>,
+
+    /// The memory-mapped file, shared by the buffers of the arrays read from it
+    owner: Arc<dyn Allocation>,
+
+    /// The schema that is read from the file footer
+    schema: SchemaRef,
+
+    /// The blocks in the file
+    ///
+    /// A block indicates the regions in the file to read to get data
+    blocks: Vec<ipc::Block>,
+
+    /// A counter to keep track of the current block that should be read
+    current_block: usize,
+
+    /// Optional dictionaries for each schema field.
+    dictionaries_by_field: Vec<Option<ArrayRef>>,
+
+    /// Metadata version
+    metadata_version: ipc::MetadataVersion,
+}
+
+#[cfg(feature = "mmap")]
+impl MmapFileReader {
+    /// Try to create a new reader by memory-mapping `file`
+    ///
+    /// Returns errors if the file does not meet the Arrow Format header and footer
+    /// requirements
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified or truncated while it is mapped, i.e. while this reader
+    /// or any array read from it is alive.
+    pub unsafe fn try_new(file: &File) -> Result<Self> {
+        let mmap = Arc::new(Mmap::map(file)?);
+        Self::try_new_from_mmap(mmap)
+    }
+
+    fn try_new_from_mmap(mmap: Arc<Mmap>) -> Result<Self> {
+        let owner = mmap.clone() as Arc<dyn Allocation>;
+        let data: &[u8] = &mmap;
+        let len = data.len();
+
+        // check if header and footer contain correct magic bytes
+        let magic_len = super::ARROW_MAGIC.len();
+        if len < 2 * magic_len + 4 || data[..magic_len] != super::ARROW_MAGIC {
+            return Err(ArrowError::IoError(
+                "Arrow file does not contain correct header".to_string(),
+            ));
+        }
+        if data[len - magic_len..] != super::ARROW_MAGIC {
+            return Err(ArrowError::IoError(
+                "Arrow file does not contain correct footer".to_string(),
+            ));
+        }
+
+        // read footer length
+        let footer_end = len - magic_len - 4;
+        let footer_len = i32::from_le_bytes([
+            data[footer_end],
+            data[footer_end + 1],
+            data[footer_end + 2],
+            data[footer_end + 3],
+        ]);
+        if footer_len < 0 || footer_len as usize > footer_end {
+            return Err(ArrowError::IoError(
+                "Arrow file does not contain a valid footer length".to_string(),
+            ));
+        }
+
+        let footer =
+            ipc::root_as_footer(&data[footer_end - footer_len as usize..footer_end])
+                .map_err(|err| {
+                    ArrowError::IoError(format!(
+                        "Unable to get root as footer: {:?}",
+                        err
+                    ))
+                })?;
+
+        let blocks = footer.recordBatches().ok_or_else(|| {
+            ArrowError::IoError(
+                "Unable to get record batches from IPC Footer".to_string(),
+            )
+        })?;
+
+        let ipc_schema = footer.schema().ok_or_else(|| {
+            ArrowError::IoError("Unable to get schema from IPC Footer".to_string())
+        })?;
+        let schema = ipc::convert::fb_to_schema(ipc_schema);
+
+        // Create an array of optional dictionary value arrays, one per field.
+        let mut dictionaries_by_field = vec![None; schema.fields().len()];
+        for block in footer
+            .dictionaries()
+            .iter()
+            .flat_map(|blocks| blocks.iter())
+        {
+            let (message, body) = read_block(data, block)?;
+            match message.header_type() {
+                ipc::MessageHeader::DictionaryBatch => {
+                    let batch = message.header_as_dictionary_batch().unwrap();
+                    let body = MessageBody {
+                        data: body,
+                        owner: Some(&owner),
+                    };
+                    read_dictionary_body(
+                        body,
+                        batch,
+                        &schema,
+                        &mut dictionaries_by_field,
+                    )?;
+                }
+                t => {
+                    return Err(ArrowError::IoError(format!(
+                        "Expecting DictionaryBatch in dictionary blocks, found {:?}.",
+                        t
+                    )));
+                }
+            };
+        }
+
+        Ok(Self {
+            schema: Arc::new(schema),
+            blocks: blocks.to_vec(),
+            current_block: 0,
+            dictionaries_by_field,
+            metadata_version: footer.version(),
+            owner,
+            mmap,
+        })
+    }
+
+    /// Return the number of batches in the file
+    pub fn num_batches(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Return the schema of the file
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+
+    /// Sets the index of the next record batch returned by the iterator
+    pub fn set_index(&mut self, index: usize) -> Result<()> {
+        if index >= self.blocks.len() {
+            Err(ArrowError::IoError(format!(
+                "Cannot set batch to index {} from {} total batches",
+                index,
+                self.blocks.len()
+            )))
+        } else {
+            self.current_block = index;
+            Ok(())
+        }
+    }
+
+    /// Read the record batch at `index`, without affecting the iterator
+    pub fn read_batch(&self, index: usize) -> Result<RecordBatch> {
+        let block = self.blocks.get(index).ok_or_else(|| {
+            ArrowError::IoError(format!(
+                "Cannot read batch at index {} from {} total batches",
+                index,
+                self.blocks.len()
+            ))
+        })?;
+        let (message, body) = read_block(&self.mmap, block)?;
+
+        // some old test data's footer metadata is not set, so we account for that
+        if self.metadata_version != ipc::MetadataVersion::V1
+            && message.version() != self.metadata_version
+        {
+            return Err(ArrowError::IoError(
+                "Could not read IPC message as metadata versions mismatch".to_string(),
+            ));
+        }
+
+        match message.header_type() {
+            ipc::MessageHeader::RecordBatch => {
+                let batch = message.header_as_record_batch().ok_or_else(|| {
+                    ArrowError::IoError(
+                        "Unable to read IPC message as record batch".to_string(),
+                    )
+                })?;
+                let body = MessageBody {
+                    data: body,
+                    owner: Some(&self.owner),
+                };
+                read_record_batch_body(
+                    body,
+                    batch,
+                    self.schema(),
+                    &self.dictionaries_by_field,
+                )
+            }
+            t => Err(ArrowError::IoError(format!(
+                "Expecting RecordBatch in record batch blocks, found {:?}.",
+                t
+            ))),
+        }
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl Iterator for MmapFileReader {
+    type Item = Result<RecordBatch>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current_block < self.blocks.len() {
+            let batch = self.read_batch(self.current_block);
+            self.current_block += 1;
+            Some(batch)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl RecordBatchReader for MmapFileReader {
+    fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
 /// Arrow Stream reader
 pub struct StreamReader<R: Read> {
     /// Buffered stream reader
@@ -921,8 +1284,12 @@ mod tests {
 
     use flate2::read::GzDecoder;
 
+    #[cfg(feature = "mmap")]
+    use crate::datatypes::Int8Type;
     use crate::util::integration_util::*;
     use std::fs::File;
+    #[cfg(feature = "mmap")]
+    use std::io::Write;
 
     #[test]
     fn read_generated_files() {
@@ -1071,6 +1438,134 @@ mod tests {
         })
     }
 
+    #[cfg(feature = "mmap")]
+    fn mmap_test_batches() -> (SchemaRef, Vec<RecordBatch>) {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("int", DataType::Int64, true),
+            Field::new("utf8", DataType::Utf8, true),
+            Field::new_dict(
+                "dict",
+                DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+                true,
+                0,
+                false,
+            ),
+            Field::new(
+                "list",
+                DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+                true,
+            ),
+        ]));
+        let batches = (0..2)
+            .map(|i| {
+                let mut list = ListBuilder::new(Int32Builder::new(4));
+                list.values().append_slice(&[i, i + 1]).unwrap();
+                list.append(true).unwrap();
+                list.append(false).unwrap();
+                list.values().append_slice(&[i + 2]).unwrap();
+                list.append(true).unwrap();
+                let dict: DictionaryArray<Int8Type> =
+                    vec![Some("a"), None, Some("b")].into_iter().collect();
+                RecordBatch::try_new(
+                    schema.clone(),
+                    vec![
+                        Arc::new(Int64Array::from(vec![Some(i as i64), None, Some(3)])),
+                        Arc::new(StringArray::from(vec![Some("x"), Some("yy"), None])),
+                        Arc::new(dict),
+                        Arc::new(list.finish()),
+                    ],
+                )
+                .unwrap()
+            })
+            .collect();
+        (schema, batches)
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn test_mmap_file_reader() {
+        let (schema, batches) = mmap_test_batches();
+        let mut file = tempfile::tempfile().unwrap();
+        {
+            let mut writer =
+                ipc::writer::FileWriter::try_new(&mut file, &schema).unwrap();
+            for batch in &batches {
+                writer.write(batch).unwrap();
+            }
+            writer.finish().unwrap();
+        }
+
+        let mut reader = unsafe { MmapFileReader::try_new(&file) }.unwrap();
+        assert_eq!(reader.num_batches(), 2);
+        assert_eq!(reader.schema(), schema);
+
+        // random access
+        let last = reader.read_batch(1).unwrap();
+        let first = reader.read_batch(0).unwrap();
+        assert!(reader.read_batch(2).is_err());
+        for (read, expected) in [first, last].iter().zip(batches.iter()) {
+            assert_eq!(read.num_columns(), expected.num_columns());
+            for (a, b) in read.columns().iter().zip(expected.columns()) {
+                assert_eq!(a.data(), b.data());
+            }
+        }
+
+        // buffers point into the mapping
+        let range = reader.mmap.as_ptr() as usize
+            ..reader.mmap.as_ptr() as usize + reader.mmap.len();
+        let ints = reader.read_batch(0).unwrap().column(0).clone();
+        assert!(range.contains(&(ints.data().buffers()[0].as_ptr() as usize)));
+        let dict = reader.read_batch(0).unwrap().column(2).clone();
+        let values = dict.data().child_data()[0].clone();
+        assert!(range.contains(&(values.buffers()[1].as_ptr() as usize)));
+
+        // the iterator reads the remaining batches, and arrays outlive the reader
+        reader.set_index(1).unwrap();
+        let read = reader.collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(read.len(), 1);
+        assert_eq!(read[0].column(0).data(), batches[1].column(0).data());
+        assert_eq!(
+            ints.as_any().downcast_ref::<Int64Array>().unwrap().value(0),
+            0
+        );
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn test_mmap_file_reader_invalid_file() {
+        let mut file = tempfile::tempfile().unwrap();
+        file.write_all(b"ARROW1 not an arrow file").unwrap();
+        let error = unsafe { MmapFileReader::try_new(&file) }.err().unwrap();
+        assert_eq!(
+            error.to_string(),
+            "Io error: Arrow file does not contain correct footer"
+        );
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn test_read_buffer_unaligned() {
+        let owner = Arc::new(vec![1u64, 2, 3]);
+        let data = unsafe {
+            std::slice::from_raw_parts(owner.as_ptr() as *const u8, owner.len() * 8)
+        };
+        let owner = owner as Arc<dyn Allocation>;
+        let body = MessageBody {
+            data,
+            owner: Some(&owner),
+        };
+
+        // aligned buffers are not copied
+        let buffer = read_buffer(&ipc::Buffer::new(8, 8), body);
+        assert_eq!(buffer.as_ptr(), data[8..].as_ptr());
+        assert_eq!(buffer.as_slice(), &data[8..16]);
+
+        // unaligned buffers are copied
+        let buffer = read_buffer(&ipc::Buffer::new(1, 8), body);
+        assert_ne!(buffer.as_ptr(), data[1..].as_ptr());
+        assert_eq!(buffer.as_slice(), &data[1..9]);
+    }
+
     /// Read gzipped JSON file
     fn read_gzip_json(path: &str) -> ArrowJson {
         let testdata = crate::util::test_util::arrow_test_data();
@@ -25,8 +25,9 @@ use std::io::{BufWriter, Write};
 
 use flatbuffers::FlatBufferBuilder;
 
-use crate::array::{ArrayDataRef, ArrayRef};
+use crate::array::{make_array, ArrayDataRef, ArrayRef};
 use crate::buffer::{Buffer, MutableBuffer};
+use crate::compute::concat;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::ipc;
@@ -36,7 +37,7 @@ use crate::util::bit_util;
 use ipc::CONTINUATION_MARKER;
 
 /// IPC write options used to control the behaviour of the writer
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct IpcWriteOptions {
     /// Write padding after memory buffers to this multiple of bytes.
     /// Generally 8 or 64, defaults to 8
@@ -45,6 +46,10 @@ pub struct IpcWriteOptions {
     write_legacy_ipc_format: bool,
     /// The metadata version to write. The Rust IPC writer supports V4+
     metadata_version: ipc::MetadataVersion,
+    /// Whether to write dictionaries that only grew since they were last written as deltas
+    /// (`isDelta` dictionary batches) with the new values only, instead of re-writing them.
+    /// Defaults to false
+    dictionary_deltas: bool,
 }
 
 impl IpcWriteOptions {
@@ -69,6 +74,7 @@ impl IpcWriteOptions {
                 alignment,
                 write_legacy_ipc_format,
                 metadata_version,
+                dictionary_deltas: false,
             }),
             ipc::MetadataVersion::V5 => {
                 if write_legacy_ipc_format {
@@ -81,12 +87,20 @@ impl IpcWriteOptions {
                         alignment,
                         write_legacy_ipc_format,
                         metadata_version,
+                        dictionary_deltas: false,
                     })
                 }
             }
             z => panic!("Unsupported ipc::MetadataVersion {:?}", z),
         }
     }
+
+    /// Sets whether dictionaries that only had values appended since they were last written
+    /// are written as deltas, containing only the new values.
+    pub fn with_dictionary_deltas(mut self, dictionary_deltas: bool) -> Self {
+        self.dictionary_deltas = dictionary_deltas;
+        self
+    }
 }
 
 impl Default for IpcWriteOptions {
@@ -95,6 +109,7 @@ impl Default for IpcWriteOptions {
             alignment: 8,
             write_legacy_ipc_format: true,
             metadata_version: ipc::MetadataVersion::V4,
+            dictionary_deltas: false,
         }
     }
 }
@@ -150,14 +165,28 @@ impl IpcDataGenerator {
                 let dict_data = column.data();
                 let dict_values = &dict_data.child_data()[0];
 
-                let emit = dictionary_tracker.insert(dict_id, column)?;
-
-                if emit {
-                    encoded_dictionaries.push(self.dictionary_batch_to_bytes(
-                        dict_id,
-                        dict_values,
-                        write_options,
-                    ));
+                match dictionary_tracker.update(
+                    dict_id,
+                    column,
+                    write_options.dictionary_deltas,
+                )? {
+                    DictionaryUpdate::Unchanged => (),
+                    DictionaryUpdate::New | DictionaryUpdate::Replaced => {
+                        encoded_dictionaries.push(self.dictionary_batch_to_bytes(
+                            dict_id,
+                            dict_values,
+                            false,
+                            write_options,
+                        ))
+                    }
+                    DictionaryUpdate::Delta(delta) => {
+                        encoded_dictionaries.push(self.dictionary_batch_to_bytes(
+                            dict_id,
+                            &delta,
+                            true,
+                            write_options,
+                        ))
+                    }
                 }
             }
         }
@@ -222,11 +251,12 @@ impl IpcDataGenerator {
     }
 
     /// Write dictionary values into two sets of bytes, one for the header (ipc::Message) and the
-    /// other for the data
+    /// other for the data. `is_delta` marks values that are appended to the existing dictionary
     fn dictionary_batch_to_bytes(
         &self,
         dict_id: i64,
         array_data: &ArrayDataRef,
+        is_delta: bool,
         write_options: &IpcWriteOptions,
     ) -> EncodedData {
         let mut fbb = FlatBufferBuilder::new();
@@ -261,6 +291,7 @@ impl IpcDataGenerator {
             let mut batch_builder = ipc::DictionaryBatchBuilder::new(&mut fbb);
             batch_builder.add_id(dict_id);
             batch_builder.add_data(root);
+            batch_builder.add_isDelta(is_delta);
             batch_builder.finish().as_union_value()
         };
 
@@ -283,9 +314,24 @@ impl IpcDataGenerator {
     }
 }
 
+/// How a dictionary changed since it was last written, as tracked by [`DictionaryTracker`]
+#[derive(Debug)]
+pub enum DictionaryUpdate {
+    /// The dictionary has the same values as when it was last written
+    Unchanged,
+    /// The dictionary has never been written
+    New,
+    /// The dictionary has different values than when it was last written
+    Replaced,
+    /// The dictionary was appended to since it was last written; contains the new values
+    Delta(ArrayDataRef),
+}
+
 /// Keeps track of dictionaries that have been written, to avoid emitting the same dictionary
 /// multiple times. Can optionally error if an update to an existing dictionary is attempted, which
-/// isn't allowed in the `FileWriter`.
+/// isn't allowed in the `FileWriter`. Dictionaries that only had values appended to them can
+/// be emitted as deltas, which are allowed in both the file and the stream formats.
+#[derive(Debug)]
 pub struct DictionaryTracker {
     written: HashMap<i64, ArrayRef>,
     error_on_replacement: bool,
@@ -309,29 +355,69 @@ impl DictionaryTracker {
     ///   has never been seen before, return `Ok(true)` to indicate that the dictionary was just
     ///   inserted.
     pub fn insert(&mut self, dict_id: i64, column: &ArrayRef) -> Result<bool> {
+        self.update(dict_id, column, false)
+            .map(|update| !matches!(update, DictionaryUpdate::Unchanged))
+    }
+
+    /// Keep track of the dictionary with the given ID and values, returning how it changed
+    /// since it was last written. Behaves like [`DictionaryTracker::insert`], except that
+    /// when `deltas` is true and the previous values are a prefix of the new values, the
+    /// appended values are returned as a [`DictionaryUpdate::Delta`] instead of replacing
+    /// the dictionary.
+    pub fn update(
+        &mut self,
+        dict_id: i64,
+        column: &ArrayRef,
+        deltas: bool,
+    ) -> Result<DictionaryUpdate> {
         let dict_data = column.data();
         let dict_values = &dict_data.child_data()[0];
 
         // If a dictionary with this id was already emitted, check if it was the same.
-        if let Some(last) = self.written.get(&dict_id) {
-            if last.data().child_data()[0] == *dict_values {
-                // Same dictionary values => no need to emit it again
-                return Ok(false);
-            } else if self.error_on_replacement {
-                return Err(ArrowError::InvalidArgumentError(
-                    "Dictionary replacement detected when writing IPC file format. \
-                     Arrow IPC files only support a single dictionary for a given field \
-                     across all batches."
-                        .to_string(),
-                ));
+        let update = match self.written.get(&dict_id) {
+            None => DictionaryUpdate::New,
+            Some(last) => {
+                let last_data = last.data();
+                let last_values = &last_data.child_data()[0];
+                if last_values == dict_values {
+                    // Same dictionary values => no need to emit it again
+                    return Ok(DictionaryUpdate::Unchanged);
+                }
+                match delta(last_values, dict_values) {
+                    Some(delta) if deltas => DictionaryUpdate::Delta(delta),
+                    _ if self.error_on_replacement => {
+                        return Err(ArrowError::InvalidArgumentError(
+                            "Dictionary replacement detected when writing IPC file format. \
+                             Arrow IPC files only support a single dictionary for a given field \
+                             across all batches."
+                                .to_string(),
+                        ));
+                    }
+                    _ => DictionaryUpdate::Replaced,
+                }
             }
-        }
+        };
 
         self.written.insert(dict_id, column.clone());
-        Ok(true)
+        Ok(update)
     }
 }
 
+/// Returns the values appended to `last` to obtain `values`, or `None` if `last` is not a
+/// prefix of `values`
+fn delta(last: &ArrayDataRef, values: &ArrayDataRef) -> Option<ArrayDataRef> {
+    if last.data_type() != values.data_type() || last.len() >= values.len() {
+        return None;
+    }
+    let values = make_array(values.clone());
+    if values.slice(0, last.len()).data() != *last {
+        return None;
+    }
+    // the IPC writer does not support offsets, so the new values are copied
+    let appended = values.slice(last.len(), values.len() - last.len());
+    concat(&[appended.as_ref()]).ok().map(|array| array.data())
+}
+
 pub struct FileWriter<W: Write> {
     /// The object to write to
     writer: BufWriter<W>,
@@ -618,7 +704,7 @@ fn write_body_buffers<W: Write>(mut writer: W, data: &[u8]) -> Result<usize> {
 
 /// Write a record batch to the writer, writing the message size before the message
 /// if the record batch is being written to a stream
-fn write_continuation<W: Write>(
+pub(crate) fn write_continuation<W: Write>(
     mut writer: W,
     write_options: &IpcWriteOptions,
     total_len: i32,
@@ -736,6 +822,7 @@ mod tests {
 
     use crate::array::*;
     use crate::datatypes::Field;
+    use crate::error::Result;
     use crate::ipc::reader::*;
     use crate::util::integration_util::*;
     use std::fs::File;
@@ -837,6 +924,184 @@ mod tests {
         }
     }
 
+    fn dictionary_batches(
+        schema: &SchemaRef,
+        values: &[Vec<Option<&str>>],
+    ) -> Vec<RecordBatch> {
+        values
+            .iter()
+            .map(|values| {
+                let array: DictionaryArray<Int8Type> = values.iter().cloned().collect();
+                RecordBatch::try_new(schema.clone(), vec![Arc::new(array)]).unwrap()
+            })
+            .collect()
+    }
+
+    // decodes a dictionary column into its values
+    fn dictionary_values(batch: &RecordBatch) -> Vec<Option<String>> {
+        let array = crate::compute::cast(batch.column(0), &DataType::Utf8).unwrap();
+        let array = array.as_any().downcast_ref::<StringArray>().unwrap();
+        (0..array.len())
+            .map(|i| {
+                if array.is_null(i) {
+                    None
+                } else {
+                    Some(array.value(i).to_string())
+                }
+            })
+            .collect()
+    }
+
+    fn dictionary_schema() -> SchemaRef {
+        Arc::new(Schema::new(vec![Field::new_dict(
+            "dict",
+            DataType::Dictionary(Box::new(DataType::Int8), Box::new(DataType::Utf8)),
+            true,
+            0,
+            false,
+        )]))
+    }
+
+    #[test]
+    fn test_dictionary_deltas() {
+        let schema = dictionary_schema();
+        let batches = dictionary_batches(
+            &schema,
+            &[
+                vec![Some("a"), Some("b")],
+                vec![Some("a"), Some("b"), None, Some("c")],
+                vec![Some("a"), Some("b"), Some("c"), Some("d"), Some("d")],
+                // the dictionary is unchanged
+                vec![Some("a"), Some("b"), Some("c"), Some("d")],
+            ],
+        );
+
+        let options = IpcWriteOptions::default().with_dictionary_deltas(true);
+        let data_gen = IpcDataGenerator::default();
+        let mut tracker = DictionaryTracker::new(false);
+        let dictionaries = batches
+            .iter()
+            .map(|batch| {
+                let (dictionaries, _) = data_gen
+                    .encoded_batch(batch, &mut tracker, &options)
+                    .unwrap();
+                dictionaries
+                    .iter()
+                    .map(|encoded| {
+                        let message = ipc::root_as_message(&encoded.ipc_message).unwrap();
+                        let dictionary = message.header_as_dictionary_batch().unwrap();
+                        (dictionary.isDelta(), dictionary.data().unwrap().length())
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        // the first dictionary is written in full, and then only new values are written
+        assert_eq!(
+            dictionaries,
+            vec![vec![(false, 2)], vec![(true, 1)], vec![(true, 1)], vec![]]
+        );
+
+        // read it back from a stream
+        let mut buf = vec![];
+        {
+            let mut writer =
+                StreamWriter::try_new_with_options(&mut buf, &schema, options.clone())
+                    .unwrap();
+            for batch in &batches {
+                writer.write(batch).unwrap();
+            }
+            writer.finish().unwrap();
+        }
+        let reader = StreamReader::try_new(std::io::Cursor::new(buf)).unwrap();
+        let read = reader.collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(read.len(), batches.len());
+        for (read, expected) in read.iter().zip(batches.iter()) {
+            assert_eq!(dictionary_values(read), dictionary_values(expected));
+        }
+
+        // and from a file, whose dictionaries are all read before its batches
+        let mut buf = vec![];
+        {
+            let mut writer =
+                FileWriter::try_new_with_options(&mut buf, &schema, options).unwrap();
+            for batch in &batches {
+                writer.write(batch).unwrap();
+            }
+            writer.finish().unwrap();
+        }
+        let reader = FileReader::try_new(std::io::Cursor::new(buf)).unwrap();
+        let read = reader.collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(read.len(), batches.len());
+        for (read, expected) in read.iter().zip(batches.iter()) {
+            assert_eq!(dictionary_values(read), dictionary_values(expected));
+        }
+    }
+
+    #[test]
+    fn test_dictionary_replacement() {
+        let schema = dictionary_schema();
+        let batches = dictionary_batches(
+            &schema,
+            &[
+                vec![Some("a"), Some("b")],
+                // not a delta, as the first values changed
+                vec![Some("x"), None, Some("a")],
+                vec![Some("x"), Some("a"), Some("y")],
+            ],
+        );
+
+        let options = IpcWriteOptions::default().with_dictionary_deltas(true);
+        let mut buf = vec![];
+        {
+            let mut writer =
+                StreamWriter::try_new_with_options(&mut buf, &schema, options.clone())
+                    .unwrap();
+            for batch in &batches {
+                writer.write(batch).unwrap();
+            }
+            writer.finish().unwrap();
+        }
+        let reader = StreamReader::try_new(std::io::Cursor::new(buf)).unwrap();
+        let read = reader.collect::<Result<Vec<_>>>().unwrap();
+        assert_eq!(read.len(), batches.len());
+        for (read, expected) in read.iter().zip(batches.iter()) {
+            assert_eq!(dictionary_values(read), dictionary_values(expected));
+        }
+
+        // files do not support replacement
+        let mut buf = vec![];
+        let mut writer =
+            FileWriter::try_new_with_options(&mut buf, &schema, options).unwrap();
+        writer.write(&batches[0]).unwrap();
+        assert!(writer.write(&batches[1]).is_err());
+    }
+
+    #[test]
+    fn test_dictionary_delta_without_dictionary() {
+        let schema = dictionary_schema();
+        let batches = dictionary_batches(&schema, &[vec![Some("a")]]);
+
+        // a delta is written before any dictionary was
+        let data_gen = IpcDataGenerator::default();
+        let options = IpcWriteOptions::default();
+        let values = batches[0].column(0).data().child_data()[0].clone();
+        let encoded = data_gen.dictionary_batch_to_bytes(0, &values, true, &options);
+
+        let message = ipc::root_as_message(&encoded.ipc_message).unwrap();
+        let mut dictionaries = vec![None];
+        let error = read_dictionary(
+            &encoded.arrow_data,
+            message.header_as_dictionary_batch().unwrap(),
+            &schema,
+            &mut dictionaries,
+        )
+        .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Io error: Received a delta for dictionary 0, which was not previously read"
+        );
+    }
+
     #[test]
     fn read_and_rewrite_generated_files() {
         let testdata = crate::util::test_util::arrow_test_data();
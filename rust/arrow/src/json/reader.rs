@@ -51,6 +51,7 @@ use indexmap::set::IndexSet as HashSet;
 use serde_json::Value;
 
 use crate::buffer::MutableBuffer;
+use crate::compute::cast;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 use crate::record_batch::RecordBatch;
@@ -59,119 +60,109 @@ use crate::{array::*, buffer::Buffer};
 
 /// Coerce data type during inference
 ///
+/// * `Null` is coerced to any other type
 /// * `Int64` and `Float64` should be `Float64`
 /// * Lists and scalars are coerced to a list of a compatible scalar
+/// * Structs are coerced to a struct with the fields of all structs
 /// * All other types are coerced to `Utf8`
 fn coerce_data_type(dt: Vec<&DataType>) -> Result<DataType> {
+    let mut dt: Vec<&DataType> =
+        dt.into_iter().filter(|t| **t != DataType::Null).collect();
+    dt.sort();
+    dt.dedup();
     match dt.len() {
+        0 => Ok(DataType::Null),
         1 => Ok(dt[0].clone()),
-        2 => {
-            // there can be a case where a list and scalar both exist
-            if dt.contains(&&DataType::List(Box::new(Field::new(
-                "item",
-                DataType::Float64,
-                true,
-            )))) || dt.contains(&&DataType::List(Box::new(Field::new(
-                "item",
-                DataType::Int64,
-                true,
-            )))) || dt.contains(&&DataType::List(Box::new(Field::new(
-                "item",
-                DataType::Boolean,
-                true,
-            )))) || dt.contains(&&DataType::List(Box::new(Field::new(
-                "item",
-                DataType::Utf8,
-                true,
-            )))) {
-                // we have a list and scalars, so we should get the values and coerce them
-                let mut dt = dt;
-                // sorting guarantees that the list will be the second value
-                dt.sort();
-                match (dt[0], dt[1]) {
-                    (t1, DataType::List(e)) if e.data_type() == &DataType::Float64 => {
-                        if t1 == &DataType::Float64 {
-                            Ok(DataType::List(Box::new(Field::new(
-                                "item",
-                                DataType::Float64,
-                                true,
-                            ))))
-                        } else {
-                            Ok(DataType::List(Box::new(Field::new(
-                                "item",
-                                coerce_data_type(vec![t1, &DataType::Float64])?,
-                                true,
-                            ))))
-                        }
-                    }
-                    (t1, DataType::List(e)) if e.data_type() == &DataType::Int64 => {
-                        if t1 == &DataType::Int64 {
-                            Ok(DataType::List(Box::new(Field::new(
-                                "item",
-                                DataType::Int64,
-                                true,
-                            ))))
-                        } else {
-                            Ok(DataType::List(Box::new(Field::new(
-                                "item",
-                                coerce_data_type(vec![t1, &DataType::Int64])?,
-                                true,
-                            ))))
-                        }
-                    }
-                    (t1, DataType::List(e)) if e.data_type() == &DataType::Boolean => {
-                        if t1 == &DataType::Boolean {
-                            Ok(DataType::List(Box::new(Field::new(
-                                "item",
-                                DataType::Boolean,
-                                true,
-                            ))))
-                        } else {
-                            Ok(DataType::List(Box::new(Field::new(
-                                "item",
-                                coerce_data_type(vec![t1, &DataType::Boolean])?,
-                                true,
-                            ))))
-                        }
-                    }
-                    (t1, DataType::List(e)) if e.data_type() == &DataType::Utf8 => {
-                        if t1 == &DataType::Utf8 {
-                            Ok(DataType::List(Box::new(Field::new(
-                                "item",
-                                DataType::Utf8,
-                                true,
-                            ))))
-                        } else {
-                            Ok(DataType::List(Box::new(Field::new(
-                                "item",
-                                coerce_data_type(vec![t1, &DataType::Utf8])?,
-                                true,
-                            ))))
-                        }
-                    }
-                    (t1, t2) => Err(ArrowError::JsonError(format!(
-                        "Cannot coerce data types for {:?} and {:?}",
-                        t1, t2
-                    ))),
-                }
-            } else if dt.contains(&&DataType::Float64) && dt.contains(&&DataType::Int64) {
+        _ => {
+            if dt.iter().any(|t| matches!(t, DataType::List(_))) {
+                // we have lists and scalars, so we should get the values and coerce them
+                let item_types = dt
+                    .iter()
+                    .map(|t| match t {
+                        DataType::List(field) => field.data_type(),
+                        t => t,
+                    })
+                    .collect();
+                Ok(DataType::List(Box::new(Field::new(
+                    "item",
+                    coerce_data_type(item_types)?,
+                    true,
+                ))))
+            } else if dt.iter().all(|t| matches!(t, DataType::Struct(_))) {
+                let mut fields: HashMap<String, Vec<&DataType>> = HashMap::new();
+                dt.iter()
+                    .flat_map(|t| match t {
+                        DataType::Struct(fields) => fields.iter(),
+                        _ => unreachable!(),
+                    })
+                    .for_each(|field| {
+                        fields
+                            .entry(field.name().to_string())
+                            .or_insert_with(Vec::new)
+                            .push(field.data_type())
+                    });
+                let fields = fields
+                    .into_iter()
+                    .map(|(name, types)| {
+                        coerce_data_type(types).map(|t| Field::new(&name, t, true))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(DataType::Struct(fields))
+            } else if dt.len() == 2
+                && dt.contains(&&DataType::Float64)
+                && dt.contains(&&DataType::Int64)
+            {
                 Ok(DataType::Float64)
             } else {
                 Ok(DataType::Utf8)
             }
         }
-        _ => {
-            // TODO(nevi_me) It's possible to have [float, int, list(float)], which should
-            // return list(float). Will hash this out later
-            Ok(DataType::List(Box::new(Field::new(
-                "item",
-                DataType::Utf8,
-                true,
-            ))))
-        }
     }
 }
 
+/// Infer the data type of a JSON value, with `None` for nulls and lists of nulls
+///
+/// Nested objects are inferred as structs, whose fields are `Null` if they are null.
+fn infer_value_type(value: &Value) -> Result<Option<DataType>> {
+    Ok(match value {
+        Value::Null => None,
+        Value::Bool(_) => Some(DataType::Boolean),
+        Value::Number(n) => {
+            if n.is_f64() {
+                Some(DataType::Float64)
+            } else {
+                // default to i64
+                Some(DataType::Int64)
+            }
+        }
+        Value::String(_) => Some(DataType::Utf8),
+        Value::Array(values) => {
+            // collect the data types in the array, discarding nulls
+            let types = values
+                .iter()
+                .map(infer_value_type)
+                .collect::<Result<Vec<_>>>()?;
+            let types: Vec<&DataType> = types.iter().flatten().collect();
+            if types.is_empty() {
+                None
+            } else {
+                let dt = coerce_data_type(types)?;
+                Some(DataType::List(Box::new(Field::new("item", dt, true))))
+            }
+        }
+        Value::Object(map) => {
+            let fields = map
+                .iter()
+                .map(|(k, v)| {
+                    let dt = infer_value_type(v)?.unwrap_or(DataType::Null);
+                    Ok(Field::new(k, dt, true))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Some(DataType::Struct(fields))
+        }
+    })
+}
+
 /// Generate schema from JSON field names and inferred data types
 fn generate_schema(spec: HashMap<String, HashSet<DataType>>) -> Result<SchemaRef> {
     let fields: Result<Vec<Field>> = spec
@@ -340,125 +331,15 @@ where
     for record in value_iter {
         match record? {
             Value::Object(map) => {
-                let res = map.iter().try_for_each(|(k, v)| {
-                    match v {
-                        Value::Array(a) => {
-                            // collect the data types in array
-                            let types: Result<Vec<Option<&DataType>>> = a
-                                .iter()
-                                .map(|a| match a {
-                                    Value::Null => Ok(None),
-                                    Value::Number(n) => {
-                                        if n.is_i64() {
-                                            Ok(Some(&DataType::Int64))
-                                        } else {
-                                            Ok(Some(&DataType::Float64))
-                                        }
-                                    }
-                                    Value::Bool(_) => Ok(Some(&DataType::Boolean)),
-                                    Value::String(_) => Ok(Some(&DataType::Utf8)),
-                                    Value::Array(_) | Value::Object(_) => {
-                                        Err(ArrowError::JsonError(
-                                            "Nested lists and structs not supported"
-                                                .to_string(),
-                                        ))
-                                    }
-                                })
-                                .collect();
-                            match types {
-                                Ok(types) => {
-                                    // unwrap the Option and discard None values (from
-                                    // JSON nulls)
-                                    let mut types: Vec<&DataType> =
-                                        types.into_iter().filter_map(|t| t).collect();
-                                    types.dedup();
-                                    // if a record contains only nulls, it is not
-                                    // added to values
-                                    if !types.is_empty() {
-                                        let dt = coerce_data_type(types)?;
-
-                                        if values.contains_key(k) {
-                                            let x = values.get_mut(k).unwrap();
-                                            x.insert(DataType::List(Box::new(
-                                                Field::new("item", dt, true),
-                                            )));
-                                        } else {
-                                            // create hashset and add value type
-                                            let mut hs = HashSet::new();
-                                            hs.insert(DataType::List(Box::new(
-                                                Field::new("item", dt, true),
-                                            )));
-                                            values.insert(k.to_string(), hs);
-                                        }
-                                    }
-                                    Ok(())
-                                }
-                                Err(e) => Err(e),
-                            }
-                        }
-                        Value::Bool(_) => {
-                            if values.contains_key(k) {
-                                let x = values.get_mut(k).unwrap();
-                                x.insert(DataType::Boolean);
-                            } else {
-                                // create hashset and add value type
-                                let mut hs = HashSet::new();
-                                hs.insert(DataType::Boolean);
-                                values.insert(k.to_string(), hs);
-                            }
-                            Ok(())
-                        }
-                        Value::Null => {
-                            // do nothing, we treat json as nullable by default when
-                            // inferring
-                            Ok(())
-                        }
-                        Value::Number(n) => {
-                            if n.is_f64() {
-                                if values.contains_key(k) {
-                                    let x = values.get_mut(k).unwrap();
-                                    x.insert(DataType::Float64);
-                                } else {
-                                    // create hashset and add value type
-                                    let mut hs = HashSet::new();
-                                    hs.insert(DataType::Float64);
-                                    values.insert(k.to_string(), hs);
-                                }
-                            } else {
-                                // default to i64
-                                if values.contains_key(k) {
-                                    let x = values.get_mut(k).unwrap();
-                                    x.insert(DataType::Int64);
-                                } else {
-                                    // create hashset and add value type
-                                    let mut hs = HashSet::new();
-                                    hs.insert(DataType::Int64);
-                                    values.insert(k.to_string(), hs);
-                                }
-                            }
-                            Ok(())
-                        }
-                        Value::String(_) => {
-                            if values.contains_key(k) {
-                                let x = values.get_mut(k).unwrap();
-                                x.insert(DataType::Utf8);
-                            } else {
-                                // create hashset and add value type
-                                let mut hs = HashSet::new();
-                                hs.insert(DataType::Utf8);
-                                values.insert(k.to_string(), hs);
-                            }
-                            Ok(())
-                        }
-                        Value::Object(_) => Err(ArrowError::JsonError(
-                            "Inferring schema from nested JSON structs currently not supported"
-                                .to_string(),
-                        )),
+                for (k, v) in map.iter() {
+                    // a record whose value is null (or only contains nulls) is not added
+                    // to values, as json is treated as nullable by default when inferring
+                    if let Some(dt) = infer_value_type(v)? {
+                        values
+                            .entry(k.to_string())
+                            .or_insert_with(HashSet::new)
+                            .insert(dt);
                     }
-                });
-                match res {
-                    Ok(()) => {}
-                    Err(e) => return Err(e),
                 }
             }
             value => {
@@ -813,9 +694,16 @@ impl Decoder {
                 )),
             }
         } else {
-            Err(ArrowError::JsonError(
-                "dictionary types other than UTF-8 not yet supported".to_string(),
-            ))
+            // read the values, and then encode them into a dictionary
+            let values_field = Field::new(col_name, value_type.clone(), true);
+            let values = self.build_struct_array(rows, &[values_field], &[])?;
+            cast(
+                &values[0],
+                &DataType::Dictionary(
+                    Box::new(key_type.clone()),
+                    Box::new(value_type.clone()),
+                ),
+            )
         }
     }
 
@@ -846,11 +734,7 @@ impl Decoder {
     {
         Ok(Arc::new(
             rows.iter()
-                .map(|row| {
-                    row.get(&col_name)
-                        .and_then(|value| value.as_f64())
-                        .and_then(num::cast::cast)
-                })
+                .map(|row| row.get(&col_name).and_then(primitive_value::<T>))
                 .collect::<PrimitiveArray<T>>(),
         ))
     }
@@ -930,14 +814,47 @@ impl Decoder {
             }
             DataType::Float32 => self.read_primitive_list_values::<Float32Type>(rows),
             DataType::Float64 => self.read_primitive_list_values::<Float64Type>(rows),
-            DataType::Timestamp(_, _)
-            | DataType::Date32(_)
-            | DataType::Date64(_)
-            | DataType::Time32(_)
-            | DataType::Time64(_) => {
-                return Err(ArrowError::JsonError(
-                    "Temporal types are not yet supported, see ARROW-4803".to_string(),
-                ))
+            DataType::Timestamp(unit, _) => {
+                let data =
+                    match unit {
+                        TimeUnit::Second => {
+                            self.read_primitive_list_values::<TimestampSecondType>(rows)
+                        }
+                        TimeUnit::Millisecond => self
+                            .read_primitive_list_values::<TimestampMillisecondType>(rows),
+                        TimeUnit::Microsecond => self
+                            .read_primitive_list_values::<TimestampMicrosecondType>(rows),
+                        TimeUnit::Nanosecond => self
+                            .read_primitive_list_values::<TimestampNanosecondType>(rows),
+                    };
+                // set the timezone of the values
+                with_data_type(&data, list_field.data_type())
+            }
+            DataType::Date32(_) => self.read_primitive_list_values::<Date32Type>(rows),
+            DataType::Date64(_) => self.read_primitive_list_values::<Date64Type>(rows),
+            DataType::Time32(TimeUnit::Second) => {
+                self.read_primitive_list_values::<Time32SecondType>(rows)
+            }
+            DataType::Time32(TimeUnit::Millisecond) => {
+                self.read_primitive_list_values::<Time32MillisecondType>(rows)
+            }
+            DataType::Time64(TimeUnit::Microsecond) => {
+                self.read_primitive_list_values::<Time64MicrosecondType>(rows)
+            }
+            DataType::Time64(TimeUnit::Nanosecond) => {
+                self.read_primitive_list_values::<Time64NanosecondType>(rows)
+            }
+            DataType::Dictionary(_, value_type) => {
+                // read the values, and then encode them into a dictionary
+                let values_field = Field::new(
+                    list_field.name(),
+                    value_type.as_ref().clone(),
+                    list_field.is_nullable(),
+                );
+                let values =
+                    self.build_nested_list_array::<OffsetSize>(rows, &values_field)?;
+                let values = make_array(values.data().child_data()[0].clone());
+                cast(&values, list_field.data_type())?.data()
             }
             DataType::Utf8 => {
                 StringArray::from_iter(flatten_json_string_values(rows).into_iter())
@@ -958,29 +875,15 @@ impl Decoder {
                 child.data()
             }
             DataType::Struct(fields) => {
-                // extract list values, with non-lists converted to Value::Null
-                let len = rows.len();
-                let num_bytes = bit_util::ceil(len, 8);
+                // extract list values, with values that are not objects being null
+                let rows = flatten_json_values(rows);
+                let num_bytes = bit_util::ceil(rows.len(), 8);
                 let mut null_buffer = MutableBuffer::from_len_zeroed(num_bytes);
-                let mut struct_index = 0;
-                let rows: Vec<Value> = rows
-                    .iter()
-                    .flat_map(|row| {
-                        if let Value::Array(values) = row {
-                            values.iter().for_each(|_| {
-                                bit_util::set_bit(
-                                    null_buffer.as_slice_mut(),
-                                    struct_index,
-                                );
-                                struct_index += 1;
-                            });
-                            values.clone()
-                        } else {
-                            struct_index += 1;
-                            vec![Value::Null]
-                        }
-                    })
-                    .collect();
+                rows.iter().enumerate().for_each(|(i, row)| {
+                    if row.is_object() {
+                        bit_util::set_bit(null_buffer.as_slice_mut(), i);
+                    }
+                });
                 let arrays =
                     self.build_struct_array(rows.as_slice(), fields.as_slice(), &[])?;
                 let data_type = DataType::Struct(fields.clone());
@@ -999,13 +902,18 @@ impl Decoder {
             }
         };
         // build list
-        let list_data = ArrayData::builder(DataType::List(Box::new(list_field.clone())))
+        let list_data_type = if OffsetSize::prefix() == "Large" {
+            DataType::LargeList(Box::new(list_field.clone()))
+        } else {
+            DataType::List(Box::new(list_field.clone()))
+        };
+        let list_data = ArrayData::builder(list_data_type)
             .len(list_len)
             .add_buffer(Buffer::from_slice_ref(&offsets))
             .add_child_data(array_data)
             .null_bit_buffer(list_nulls.into())
             .build();
-        Ok(Arc::new(GenericListArray::<OffsetSize>::from(list_data)))
+        Ok(make_array(list_data))
     }
 
     /// Builds the child values of a `StructArray`, falling short of constructing the StructArray.
@@ -1061,7 +969,6 @@ impl Decoder {
                     DataType::UInt8 => {
                         self.build_primitive_array::<UInt8Type>(rows, field.name())
                     }
-                    // TODO: this is incomplete
                     DataType::Timestamp(unit, _) => match unit {
                         TimeUnit::Second => self
                             .build_primitive_array::<TimestampSecondType>(
@@ -1083,7 +990,11 @@ impl Decoder {
                                 rows,
                                 field.name(),
                             ),
-                    },
+                    }
+                    // set the timezone of the values
+                    .map(|array| {
+                        make_array(with_data_type(&array.data(), field.data_type()))
+                    }),
                     DataType::Date64(_) => {
                         self.build_primitive_array::<Date64Type>(rows, field.name())
                     }
@@ -1130,9 +1041,24 @@ impl Decoder {
                             })
                             .collect::<StringArray>(),
                     ) as ArrayRef),
+                    DataType::LargeList(ref list_field) => {
+                        // extract rows by name
+                        let extracted_rows = rows
+                            .iter()
+                            .map(|row| {
+                                row.get(field.name()).cloned().unwrap_or(Value::Null)
+                            })
+                            .collect::<Vec<Value>>();
+                        self.build_nested_list_array::<i64>(
+                            extracted_rows.as_slice(),
+                            list_field,
+                        )
+                    }
                     DataType::List(ref list_field) => {
                         match list_field.data_type() {
-                            DataType::Dictionary(ref key_ty, _) => {
+                            DataType::Dictionary(ref key_ty, ref value_ty)
+                                if **value_ty == DataType::Utf8 =>
+                            {
                                 self.build_wrapped_list_array(rows, field.name(), key_ty)
                             }
                             _ => {
@@ -1243,18 +1169,13 @@ impl Decoder {
                 if let Value::Array(values) = row {
                     values
                         .iter()
-                        .map(|value| {
-                            let v: Option<T::Native> =
-                                value.as_f64().and_then(num::cast::cast);
-                            v
-                        })
+                        .map(primitive_value::<T>)
                         .collect::<Vec<Option<T::Native>>>()
-                } else if let Value::Number(value) = row {
-                    // handle the scalar number case
-                    let v: Option<T::Native> = value.as_f64().and_then(num::cast::cast);
-                    v.map(|v| vec![Some(v)]).unwrap_or_default()
-                } else {
+                } else if let Value::Null = row {
                     vec![]
+                } else {
+                    // handle the scalar case
+                    vec![primitive_value::<T>(row)]
                 }
             })
             .collect::<Vec<Option<T::Native>>>();
@@ -1263,6 +1184,102 @@ impl Decoder {
     }
 }
 
+/// Reads a primitive value from a JSON number, or from a string for temporal types
+fn primitive_value<T>(value: &Value) -> Option<T::Native>
+where
+    T: ArrowPrimitiveType,
+    T::Native: num::NumCast,
+{
+    match value {
+        Value::Number(n) => match n.as_i64() {
+            Some(n) => num::cast::cast(n),
+            None => n.as_f64().and_then(num::cast::cast),
+        },
+        Value::String(string) => {
+            parse_temporal(string, &T::DATA_TYPE).and_then(num::cast::cast)
+        }
+        _ => None,
+    }
+}
+
+/// Parses a date, time or timestamp string into a value of a temporal type, in the
+/// type's unit.
+///
+/// Timestamps are either RFC 3339 strings, or `%Y-%m-%dT%H:%M:%S%.f` strings in UTC,
+/// and dates are `%Y-%m-%d` strings.
+fn parse_temporal(string: &str, data_type: &DataType) -> Option<i64> {
+    use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+
+    let datetime = || {
+        chrono::DateTime::parse_from_rfc3339(string)
+            .map(|datetime| datetime.naive_utc())
+            .or_else(|_| string.parse::<NaiveDateTime>())
+            .or_else(|_| NaiveDateTime::parse_from_str(string, "%Y-%m-%d %H:%M:%S%.f"))
+            .or_else(|_| {
+                string
+                    .parse::<NaiveDate>()
+                    .map(|date| date.and_hms(0, 0, 0))
+            })
+            .ok()
+    };
+    let time = || {
+        let time = string.parse::<NaiveTime>().ok()?;
+        Some((
+            time.num_seconds_from_midnight() as i64,
+            time.nanosecond() as i64,
+        ))
+    };
+    match data_type {
+        DataType::Date32(_) => {
+            let days = datetime()?.date() - NaiveDate::from_ymd(1970, 1, 1);
+            Some(days.num_days())
+        }
+        DataType::Date64(_) => Some(datetime()?.timestamp_millis()),
+        DataType::Timestamp(TimeUnit::Second, _) => Some(datetime()?.timestamp()),
+        DataType::Timestamp(TimeUnit::Millisecond, _) => {
+            Some(datetime()?.timestamp_millis())
+        }
+        DataType::Timestamp(TimeUnit::Microsecond, _) => {
+            let datetime = datetime()?;
+            Some(
+                datetime.timestamp() * 1_000_000
+                    + datetime.timestamp_subsec_micros() as i64,
+            )
+        }
+        DataType::Timestamp(TimeUnit::Nanosecond, _) => {
+            Some(datetime()?.timestamp_nanos())
+        }
+        DataType::Time32(TimeUnit::Second) => time().map(|(secs, _)| secs),
+        DataType::Time32(TimeUnit::Millisecond) => {
+            time().map(|(secs, nanos)| secs * 1_000 + nanos / 1_000_000)
+        }
+        DataType::Time64(TimeUnit::Microsecond) => {
+            time().map(|(secs, nanos)| secs * 1_000_000 + nanos / 1_000)
+        }
+        DataType::Time64(TimeUnit::Nanosecond) => {
+            time().map(|(secs, nanos)| secs * 1_000_000_000 + nanos)
+        }
+        _ => None,
+    }
+}
+
+/// Replaces the data type of an array's data with an equivalent one, such as to set the
+/// timezone of timestamps
+fn with_data_type(data: &ArrayDataRef, data_type: &DataType) -> ArrayDataRef {
+    if data.data_type() == data_type {
+        return data.clone();
+    }
+    Arc::new(ArrayData::new(
+        data_type.clone(),
+        data.len(),
+        Some(data.null_count()),
+        data.null_buffer().cloned(),
+        data.offset(),
+        data.buffers().to_vec(),
+        data.child_data().to_vec(),
+    ))
+}
+
 /// Reads a JSON value as a string, regardless of its type.
 /// This is useful if the expected datatype is a string, in which case we preserve
 /// all the values regardless of they type.
@@ -1289,7 +1306,8 @@ fn flatten_json_values(values: &[Value]) -> Vec<Value> {
             if let Value::Array(values) = row {
                 values.clone()
             } else if let Value::Null = row {
-                vec![Value::Null]
+                // null lists have no values
+                vec![]
             } else {
                 // we interpret a scalar as a single-value list to minimise data loss
                 vec![row.clone()]
@@ -1825,6 +1843,29 @@ mod tests {
             ])
             .unwrap()
         );
+        assert_eq!(
+            List(Box::new(Field::new("item", Float64, true))),
+            coerce_data_type(vec![
+                &List(Box::new(Field::new("item", Int64, true))),
+                &List(Box::new(Field::new("item", Float64, true)))
+            ])
+            .unwrap()
+        );
+        // structs are merged field by field
+        assert_eq!(
+            Struct(vec![
+                Field::new("a", Float64, true),
+                Field::new("b", Utf8, true)
+            ]),
+            coerce_data_type(vec![
+                &Struct(vec![Field::new("a", Int64, true)]),
+                &Struct(vec![
+                    Field::new("a", Float64, true),
+                    Field::new("b", Utf8, true)
+                ])
+            ])
+            .unwrap()
+        );
     }
 
     #[test]
@@ -2004,10 +2045,9 @@ mod tests {
             Some("c_text"),
             Some("d_text"),
             None,
-            None,
         ]);
         let c = ArrayDataBuilder::new(c_field.data_type().clone())
-            .len(7)
+            .len(6)
             .add_child_data(d.data())
             .null_bit_buffer(Buffer::from(vec![0b00111011]))
             .build();
@@ -2018,10 +2058,9 @@ mod tests {
             Some(true),
             None,
             Some(true),
-            None,
         ]);
         let a = ArrayDataBuilder::new(a_struct_field.data_type().clone())
-            .len(7)
+            .len(6)
             .add_child_data(b.data())
             .add_child_data(c.clone())
             .null_bit_buffer(Buffer::from(vec![0b00111111]))
@@ -2059,10 +2098,10 @@ mod tests {
             .downcast_ref::<StructArray>()
             .unwrap();
 
-        assert_eq!(7, struct_array.len());
-        assert_eq!(1, struct_array.null_count());
-        assert_eq!(7, expected_struct_array.len());
-        assert_eq!(1, expected_struct_array.null_count());
+        assert_eq!(6, struct_array.len());
+        assert_eq!(0, struct_array.null_count());
+        assert_eq!(6, expected_struct_array.len());
+        assert_eq!(0, expected_struct_array.null_count());
         // test struct's nulls
         assert_eq!(
             struct_array.data().null_buffer(),
@@ -2677,4 +2716,326 @@ mod tests {
         assert_eq!(1, aa.value(3));
         assert_eq!(5, aa.value(7));
     }
+
+    #[test]
+    fn test_json_infer_nested_schema() {
+        let json_content = r#"
+        {"a": {"b": 1, "c": {"d": "text"}}, "e": [{"f": 1}, {"g": true}], "h": [[1, 2], [3]]}
+        {"a": {"b": 2.5, "c": null}, "e": [{"f": 2.5}], "h": [null, [[4]]]}
+        {"a": null, "e": null, "h": [[], [5]]}
+        "#;
+        let mut reader = BufReader::new(Cursor::new(json_content));
+        let schema = infer_json_schema(&mut reader, None).unwrap();
+
+        assert_eq!(
+            Schema::new(vec![
+                Field::new(
+                    "a",
+                    DataType::Struct(vec![
+                        Field::new("b", DataType::Float64, true),
+                        Field::new(
+                            "c",
+                            DataType::Struct(vec![Field::new("d", DataType::Utf8, true)]),
+                            true
+                        ),
+                    ]),
+                    true
+                ),
+                Field::new(
+                    "e",
+                    DataType::List(Box::new(Field::new(
+                        "item",
+                        DataType::Struct(vec![
+                            Field::new("f", DataType::Float64, true),
+                            Field::new("g", DataType::Boolean, true),
+                        ]),
+                        true
+                    ))),
+                    true
+                ),
+                Field::new(
+                    "h",
+                    DataType::List(Box::new(Field::new(
+                        "item",
+                        DataType::List(Box::new(Field::new(
+                            "item",
+                            DataType::List(Box::new(Field::new(
+                                "item",
+                                DataType::Int64,
+                                true
+                            ))),
+                            true
+                        ))),
+                        true
+                    ))),
+                    true
+                ),
+            ]),
+            *schema
+        );
+    }
+
+    #[test]
+    fn test_json_read_inferred_nested() {
+        let json_content = r#"
+        {"a": {"b": 1, "c": {"d": "text"}}, "e": [{"f": 1}, {"g": true}]}
+        {"a": {"b": 2, "c": null}, "e": null}
+        {"a": null, "e": [{"f": 3, "g": false}]}
+        "#;
+        let mut reader = BufReader::new(Cursor::new(json_content));
+        let schema = infer_json_schema(&mut reader, None).unwrap();
+        let mut reader = Reader::new(Cursor::new(json_content), schema, 64, None);
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(3, batch.num_rows());
+
+        let a = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<StructArray>()
+            .unwrap();
+        assert_eq!(1, a.null_count());
+        let b = a.column(0).as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(1, b.value(0));
+        assert_eq!(2, b.value(1));
+        let c = a.column(1).as_any().downcast_ref::<StructArray>().unwrap();
+        assert!(c.is_valid(0));
+        assert!(!c.is_valid(1));
+        let d = c.column(0).as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!("text", d.value(0));
+
+        let e = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .unwrap();
+        assert!(!e.is_valid(1));
+        assert_eq!(
+            e.data().buffers()[0],
+            Buffer::from_slice_ref(&[0i32, 2, 2, 3])
+        );
+        let e_values = e.values();
+        let e_values = e_values.as_any().downcast_ref::<StructArray>().unwrap();
+        assert_eq!(3, e_values.len());
+        assert_eq!(0, e_values.null_count());
+        let f = e_values
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(
+            vec![Some(1), None, Some(3)],
+            f.iter().collect::<Vec<Option<i64>>>()
+        );
+        let g = e_values
+            .column(1)
+            .as_any()
+            .downcast_ref::<BooleanArray>()
+            .unwrap();
+        assert_eq!(
+            vec![None, Some(true), Some(false)],
+            g.iter().collect::<Vec<Option<bool>>>()
+        );
+    }
+
+    #[test]
+    fn test_nested_list_with_nulls() {
+        let inner = Field::new("item", DataType::Int32, true);
+        let field = Field::new(
+            "a",
+            DataType::List(Box::new(Field::new(
+                "item",
+                DataType::List(Box::new(inner)),
+                true,
+            ))),
+            true,
+        );
+        let schema = Arc::new(Schema::new(vec![field]));
+        let json_content = r#"
+        {"a": null}
+        {"a": [null, [1, 2]]}
+        {"a": [[3]]}
+        "#;
+        let mut reader = Reader::new(Cursor::new(json_content), schema, 64, None);
+        let batch = reader.next().unwrap().unwrap();
+
+        let a = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .unwrap();
+        assert!(!a.is_valid(0));
+        assert_eq!(
+            a.data().buffers()[0],
+            Buffer::from_slice_ref(&[0i32, 0, 2, 3])
+        );
+        let lists = a.values();
+        let lists = lists.as_any().downcast_ref::<ListArray>().unwrap();
+        assert!(!lists.is_valid(0));
+        assert_eq!(
+            lists.data().buffers()[0],
+            Buffer::from_slice_ref(&[0i32, 0, 2, 3])
+        );
+        let values = lists.values();
+        let values = values.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(&[1, 2, 3], values.values());
+    }
+
+    #[test]
+    fn test_large_list() {
+        let field = Field::new(
+            "a",
+            DataType::LargeList(Box::new(Field::new("item", DataType::Utf8, true))),
+            true,
+        );
+        let schema = Arc::new(Schema::new(vec![field]));
+        let json_content = r#"
+        {"a": ["x", "y"]}
+        {"a": null}
+        {"a": ["z"]}
+        "#;
+        let mut reader = Reader::new(Cursor::new(json_content), schema, 64, None);
+        let batch = reader.next().unwrap().unwrap();
+
+        let a = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<LargeListArray>()
+            .unwrap();
+        assert_eq!(
+            a.data().buffers()[0],
+            Buffer::from_slice_ref(&[0i64, 2, 2, 3])
+        );
+        assert!(!a.is_valid(1));
+        let values = a.values();
+        let values = values.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!("z", values.value(2));
+    }
+
+    #[test]
+    fn test_temporal_from_json_strings() {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new(
+                "ts",
+                DataType::Timestamp(TimeUnit::Millisecond, Some("UTC".to_string())),
+                true,
+            ),
+            Field::new("date", DataType::Date32(DateUnit::Day), true),
+            Field::new("time", DataType::Time64(TimeUnit::Microsecond), true),
+            Field::new(
+                "ts_list",
+                DataType::List(Box::new(Field::new(
+                    "item",
+                    DataType::Timestamp(TimeUnit::Second, None),
+                    true,
+                ))),
+                true,
+            ),
+        ]));
+        let json_content = r#"
+        {"ts": "1970-01-01T00:00:01.5Z", "date": "1970-01-11", "time": "00:00:01.000002", "ts_list": ["1970-01-01T00:01:00", 5]}
+        {"ts": "1970-01-01 00:00:02+01:00", "date": 3, "time": "invalid", "ts_list": null}
+        {"ts": 3000, "date": null, "time": 4, "ts_list": ["1970-01-02"]}
+        "#;
+        let mut reader = Reader::new(Cursor::new(json_content), schema.clone(), 64, None);
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(schema, batch.schema());
+
+        let ts = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<TimestampMillisecondArray>()
+            .unwrap();
+        assert_eq!(
+            vec![Some(1500), Some(-3_598_000), Some(3000)],
+            ts.iter().collect::<Vec<Option<i64>>>()
+        );
+        let date = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<Date32Array>()
+            .unwrap();
+        assert_eq!(
+            vec![Some(10), Some(3), None],
+            date.iter().collect::<Vec<Option<i32>>>()
+        );
+        let time = batch
+            .column(2)
+            .as_any()
+            .downcast_ref::<Time64MicrosecondArray>()
+            .unwrap();
+        assert_eq!(
+            vec![Some(1_000_002), None, Some(4)],
+            time.iter().collect::<Vec<Option<i64>>>()
+        );
+        let ts_list = batch
+            .column(3)
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .unwrap();
+        assert_eq!(
+            ts_list.data().buffers()[0],
+            Buffer::from_slice_ref(&[0i32, 2, 2, 3])
+        );
+        let values = ts_list.values();
+        let values = values
+            .as_any()
+            .downcast_ref::<TimestampSecondArray>()
+            .unwrap();
+        assert_eq!(&[60, 5, 86400], values.values());
+    }
+
+    #[test]
+    fn test_dictionary_of_non_utf8_values() {
+        let dictionary_type =
+            Dictionary(Box::new(DataType::Int8), Box::new(DataType::Int64));
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", dictionary_type.clone(), true),
+            Field::new(
+                "b",
+                List(Box::new(Field::new("item", dictionary_type, true))),
+                true,
+            ),
+        ]));
+        let json_content = r#"
+        {"a": 1, "b": [1, 2, 1]}
+        {"a": null, "b": null}
+        {"a": 1, "b": [3]}
+        "#;
+        let mut reader = Reader::new(Cursor::new(json_content), schema.clone(), 64, None);
+        let batch = reader.next().unwrap().unwrap();
+        assert_eq!(schema, batch.schema());
+
+        let a = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int8Type>>()
+            .unwrap();
+        assert_eq!(1, a.null_count());
+        assert_eq!(
+            vec![Some(0), None, Some(0)],
+            a.keys().iter().collect::<Vec<Option<i8>>>()
+        );
+        let a_values = a.values();
+        let a_values = a_values.as_any().downcast_ref::<Int64Array>().unwrap();
+        assert_eq!(&[1], a_values.values());
+
+        let b = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<ListArray>()
+            .unwrap();
+        assert_eq!(
+            b.data().buffers()[0],
+            Buffer::from_slice_ref(&[0i32, 3, 3, 4])
+        );
+        let b_values = b.values();
+        let b_values = b_values
+            .as_any()
+            .downcast_ref::<DictionaryArray<Int8Type>>()
+            .unwrap();
+        assert_eq!(
+            vec![Some(0), Some(1), Some(0), Some(2)],
+            b_values.keys().iter().collect::<Vec<Option<i8>>>()
+        );
+    }
 }
@@ -21,6 +21,7 @@
 use std::sync::Arc;
 
 use crate::array::*;
+use crate::compute::concat;
 use crate::datatypes::*;
 use crate::error::{ArrowError, Result};
 
@@ -233,6 +234,198 @@ impl RecordBatch {
     pub fn columns(&self) -> &[ArrayRef] {
         &self.columns[..]
     }
+
+    /// Returns a zero-copy slice of this record batch, starting at `offset` and with
+    /// `length` rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + length` is greater than the number of rows.
+    pub fn slice(&self, offset: usize, length: usize) -> RecordBatch {
+        assert!(
+            offset + length <= self.num_rows(),
+            "the slice of a record batch cannot exceed its number of rows"
+        );
+
+        let columns = self
+            .columns
+            .iter()
+            .map(|column| column.slice(offset, length))
+            .collect();
+
+        RecordBatch {
+            schema: self.schema.clone(),
+            columns,
+        }
+    }
+
+    /// Returns a record batch with only the columns at `indices`, in that order.
+    /// The columns are shared with this record batch and the schema metadata is kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any of the indices is out of bounds.
+    pub fn project(&self, indices: &[usize]) -> Result<RecordBatch> {
+        let (fields, columns) = indices
+            .iter()
+            .map(|i| {
+                if *i >= self.num_columns() {
+                    return Err(ArrowError::InvalidArgumentError(format!(
+                        "project index {} out of bounds, the record batch has {} columns",
+                        i,
+                        self.num_columns()
+                    )));
+                }
+                Ok((self.schema.field(*i).clone(), self.columns[*i].clone()))
+            })
+            .collect::<Result<Vec<_>>>()?
+            .into_iter()
+            .unzip();
+
+        let schema = Schema::new_with_metadata(fields, self.schema.metadata().clone());
+        RecordBatch::try_new(Arc::new(schema), columns)
+    }
+
+    /// Concatenates `batches` into a single record batch with `schema`.
+    /// When `batches` is empty, the result has no rows.
+    ///
+    /// Only the data types of the columns are compared with `schema`, so that batches
+    /// whose fields differ in name, nullability or metadata can be concatenated.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the column data types of any batch differ from `schema`.
+    pub fn concat(schema: &SchemaRef, batches: &[RecordBatch]) -> Result<RecordBatch> {
+        if let Some((i, _)) = batches.iter().enumerate().find(|(_, batch)| {
+            batch.num_columns() != schema.fields().len()
+                || batch
+                    .columns()
+                    .iter()
+                    .zip(schema.fields())
+                    .any(|(column, field)| column.data_type() != field.data_type())
+        }) {
+            return Err(ArrowError::InvalidArgumentError(format!(
+                "batches[{}] column types are different with argument schema",
+                i
+            )));
+        }
+
+        let columns = (0..schema.fields().len())
+            .map(|i| {
+                if batches.is_empty() {
+                    return Ok(new_null_array(schema.field(i).data_type(), 0));
+                }
+                let arrays = batches
+                    .iter()
+                    .map(|batch| batch.column(i).as_ref())
+                    .collect::<Vec<_>>();
+                concat(&arrays)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        RecordBatch::try_new(schema.clone(), columns)
+    }
+
+    /// Adapts this record batch to `schema`, a superset of its own schema. Columns are
+    /// matched by name and reordered to follow `schema`; fields without a column in this
+    /// batch are filled with nulls.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a column of this batch is not in `schema`, if a matched column
+    /// has a different data type or if a missing field is not nullable.
+    pub fn try_align_to_schema(&self, schema: SchemaRef) -> Result<RecordBatch> {
+        if let Some(field) = self
+            .schema
+            .fields()
+            .iter()
+            .find(|field| schema.field_with_name(field.name()).is_err())
+        {
+            return Err(ArrowError::SchemaError(format!(
+                "column \"{}\" is not part of the target schema",
+                field.name()
+            )));
+        }
+
+        let columns = schema
+            .fields()
+            .iter()
+            .map(|field| match self.schema.index_of(field.name()) {
+                Ok(i) => {
+                    let column = &self.columns[i];
+                    if column.data_type() != field.data_type() {
+                        return Err(ArrowError::SchemaError(format!(
+                            "column \"{}\" has type {:?} but the target schema expects {:?}",
+                            field.name(),
+                            column.data_type(),
+                            field.data_type()
+                        )));
+                    }
+                    Ok(column.clone())
+                }
+                Err(_) if field.is_nullable() => {
+                    Ok(new_null_array(field.data_type(), self.num_rows()))
+                }
+                Err(_) => Err(ArrowError::SchemaError(format!(
+                    "column \"{}\" is missing and its field is not nullable",
+                    field.name()
+                ))),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        RecordBatch::try_new(schema, columns)
+    }
+
+    /// Creates a record batch from an iterator of named columns. A field is nullable
+    /// when its column has nulls.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::sync::Arc;
+    /// use arrow::array::{ArrayRef, Int32Array, StringArray};
+    /// use arrow::record_batch::RecordBatch;
+    ///
+    /// # fn main() -> arrow::error::Result<()> {
+    /// let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+    /// let b: ArrayRef = Arc::new(StringArray::from(vec![Some("a"), None]));
+    ///
+    /// let batch = RecordBatch::try_from_iter(vec![("a", a), ("b", b)])?;
+    ///
+    /// assert!(!batch.schema().field(0).is_nullable());
+    /// assert!(batch.schema().field(1).is_nullable());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_from_iter<I, F>(value: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (F, ArrayRef)>,
+        F: AsRef<str>,
+    {
+        Self::try_from_iter_with_nullable(value.into_iter().map(|(name, column)| {
+            let nullable = column.null_count() > 0;
+            (name, column, nullable)
+        }))
+    }
+
+    /// Creates a record batch from an iterator of named columns and whether their
+    /// fields are nullable.
+    pub fn try_from_iter_with_nullable<I, F>(value: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = (F, ArrayRef, bool)>,
+        F: AsRef<str>,
+    {
+        let (fields, columns) = value
+            .into_iter()
+            .map(|(name, column, nullable)| {
+                let field =
+                    Field::new(name.as_ref(), column.data_type().clone(), nullable);
+                (field, column)
+            })
+            .unzip();
+
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+    }
 }
 
 /// Options that control the behaviour used when creating a [`RecordBatch`].
@@ -302,6 +495,8 @@ pub trait RecordBatchReader: Iterator<Item = Result<RecordBatch>> {
 mod tests {
     use super::*;
 
+    use std::collections::{BTreeMap, HashMap};
+
     use crate::buffer::Buffer;
 
     #[test]
@@ -420,4 +615,167 @@ mod tests {
         assert_eq!(batch.column(0).as_ref(), boolean.as_ref());
         assert_eq!(batch.column(1).as_ref(), int.as_ref());
     }
+
+    fn create_test_batch() -> RecordBatch {
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2, 3, 4, 5]));
+        let b: ArrayRef = Arc::new(StringArray::from(vec![
+            Some("a"),
+            None,
+            Some("c"),
+            Some("d"),
+            Some("e"),
+        ]));
+        RecordBatch::try_from_iter(vec![("a", a), ("b", b)]).unwrap()
+    }
+
+    #[test]
+    fn create_record_batch_try_from_iter() {
+        let batch = create_test_batch();
+        assert_eq!(5, batch.num_rows());
+        assert_eq!(
+            batch.schema().fields(),
+            &vec![
+                Field::new("a", DataType::Int32, false),
+                Field::new("b", DataType::Utf8, true),
+            ]
+        );
+
+        let a: ArrayRef = Arc::new(Int32Array::from(vec![1, 2]));
+        let b: ArrayRef = Arc::new(Int32Array::from(vec![1]));
+        assert!(RecordBatch::try_from_iter(vec![("a", a), ("b", b)]).is_err());
+    }
+
+    #[test]
+    fn record_batch_slice() {
+        let batch = create_test_batch();
+        let sliced = batch.slice(1, 3);
+
+        assert_eq!(3, sliced.num_rows());
+        assert_eq!(batch.schema(), sliced.schema());
+        let b = sliced
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert!(b.is_null(0));
+        assert_eq!("c", b.value(1));
+        assert_eq!("d", b.value(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot exceed its number of rows")]
+    fn record_batch_slice_out_of_bounds() {
+        create_test_batch().slice(3, 3);
+    }
+
+    #[test]
+    fn record_batch_project() {
+        let batch = create_test_batch();
+        let projected = batch.project(&[1, 0, 1]).unwrap();
+
+        assert_eq!(3, projected.num_columns());
+        assert_eq!("b", projected.schema().field(0).name());
+        assert_eq!("a", projected.schema().field(1).name());
+        assert_eq!(batch.column(1).as_ref(), projected.column(2).as_ref());
+
+        assert!(batch.project(&[2]).is_err());
+    }
+
+    #[test]
+    fn record_batch_concat() {
+        let batch = create_test_batch();
+        let schema = batch.schema();
+        let concatenated =
+            RecordBatch::concat(&schema, &[batch.slice(3, 2), batch.slice(0, 2)])
+                .unwrap();
+
+        assert_eq!(4, concatenated.num_rows());
+        let a = concatenated
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int32Array>()
+            .unwrap();
+        assert_eq!(a, &Int32Array::from(vec![4, 5, 1, 2]));
+        assert_eq!(1, concatenated.column(1).null_count());
+
+        let empty = RecordBatch::concat(&schema, &[]).unwrap();
+        assert_eq!(0, empty.num_rows());
+        assert_eq!(schema, empty.schema());
+    }
+
+    #[test]
+    fn record_batch_concat_schema_mismatch() {
+        let batch = create_test_batch();
+        let other = batch.project(&[1, 0]).unwrap();
+        let result = RecordBatch::concat(&batch.schema(), &[batch, other]);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn record_batch_concat_different_fields() {
+        let batch = create_test_batch();
+        let mut a = Field::new("a", DataType::Int32, true);
+        let mut field_metadata = BTreeMap::new();
+        field_metadata.insert("key".to_string(), "value".to_string());
+        a.set_metadata(Some(field_metadata));
+        let mut metadata = HashMap::new();
+        metadata.insert("key".to_string(), "value".to_string());
+        let schema = Arc::new(Schema::new_with_metadata(
+            vec![a, Field::new("b", DataType::Utf8, true)],
+            metadata,
+        ));
+        let other =
+            RecordBatch::try_new(schema.clone(), batch.columns().to_vec()).unwrap();
+
+        let concatenated = RecordBatch::concat(&schema, &[batch, other]).unwrap();
+        assert_eq!(10, concatenated.num_rows());
+        assert_eq!(schema, concatenated.schema());
+    }
+
+    #[test]
+    fn record_batch_align_to_schema() {
+        let batch = create_test_batch();
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("c", DataType::Float64, true),
+            Field::new("b", DataType::Utf8, true),
+            Field::new(
+                "d",
+                DataType::List(Box::new(Field::new("item", DataType::Int32, true))),
+                true,
+            ),
+            Field::new("a", DataType::Int32, false),
+        ]));
+
+        let aligned = batch.try_align_to_schema(schema.clone()).unwrap();
+        assert_eq!(schema, aligned.schema());
+        assert_eq!(5, aligned.num_rows());
+        assert_eq!(5, aligned.column(0).null_count());
+        assert_eq!(batch.column(1).as_ref(), aligned.column(1).as_ref());
+        assert_eq!(5, aligned.column(2).null_count());
+        assert_eq!(batch.column(0).as_ref(), aligned.column(3).as_ref());
+    }
+
+    #[test]
+    fn record_batch_align_to_schema_errors() {
+        let batch = create_test_batch();
+
+        // missing non-nullable field
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Int32, false),
+        ]));
+        assert!(batch.try_align_to_schema(schema).is_err());
+
+        // not a superset
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int32, false)]));
+        assert!(batch.try_align_to_schema(schema).is_err());
+
+        // different data type
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int64, false),
+            Field::new("b", DataType::Utf8, true),
+        ]));
+        assert!(batch.try_align_to_schema(schema).is_err());
+    }
 }
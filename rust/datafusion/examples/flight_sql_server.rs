@@ -0,0 +1,363 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use futures::stream;
+use tonic::transport::Server;
+use tonic::Status;
+
+use arrow::array::{ArrayRef, BinaryArray, StringArray};
+use arrow::compute::like_utf8_scalar;
+use arrow::datatypes::{Schema, SchemaRef};
+use arrow::ipc::writer::IpcWriteOptions;
+use arrow::record_batch::RecordBatch;
+
+use datafusion::physical_plan::{collect, ExecutionPlan};
+use datafusion::prelude::*;
+
+use arrow_flight::server::SendableRecordBatchStream;
+use arrow_flight::sql::server::{
+    metadata_flight_info, FlightSqlServer, FlightSqlService,
+};
+use arrow_flight::sql::{
+    ActionClosePreparedStatementRequest, ActionCreatePreparedStatementRequest,
+    ActionCreatePreparedStatementResult, CommandGetCatalogs, CommandGetDbSchemas,
+    CommandGetTableTypes, CommandGetTables, CommandPreparedStatementQuery,
+    CommandStatementQuery, CommandStatementUpdate, TicketStatementQuery,
+};
+use arrow_flight::utils::ipc_message_from_arrow_schema;
+use arrow_flight::{FlightDescriptor, FlightInfo};
+
+/// A Flight SQL service answering queries with a DataFusion `ExecutionContext`.
+///
+/// Tables are exposed as belonging to a single catalog and schema. Planned
+/// queries and prepared statements are kept in memory, keyed by handle.
+pub struct DataFusionFlightSqlService {
+    ctx: Mutex<ExecutionContext>,
+    statements: Mutex<HashMap<Vec<u8>, String>>,
+    next_handle: AtomicU64,
+}
+
+const CATALOG_NAME: &str = "datafusion";
+const SCHEMA_NAME: &str = "public";
+const TABLE_TYPE: &str = "TABLE";
+
+impl DataFusionFlightSqlService {
+    pub fn new(ctx: ExecutionContext) -> Self {
+        Self {
+            ctx: Mutex::new(ctx),
+            statements: Mutex::new(HashMap::new()),
+            next_handle: AtomicU64::new(0),
+        }
+    }
+
+    /// Plan `query`, returning its physical plan
+    fn plan(&self, query: &str) -> Result<Arc<dyn ExecutionPlan>, Status> {
+        let ctx = self.ctx.lock().unwrap();
+        ctx.create_logical_plan(query)
+            .and_then(|plan| ctx.optimize(&plan))
+            .and_then(|plan| ctx.create_physical_plan(&plan))
+            .map_err(to_tonic_err)
+    }
+
+    /// Execute `query`, returning its schema and results
+    async fn execute(
+        &self,
+        query: &str,
+    ) -> Result<(SchemaRef, SendableRecordBatchStream), Status> {
+        let plan = self.plan(query)?;
+        let batches = collect(plan.clone()).await.map_err(to_tonic_err)?;
+        Ok((
+            plan.schema(),
+            Box::pin(stream::iter(batches.into_iter().map(Ok))),
+        ))
+    }
+
+    /// Store `query` under a new handle
+    fn register_statement(&self, query: String) -> Vec<u8> {
+        let handle = self
+            .next_handle
+            .fetch_add(1, Ordering::SeqCst)
+            .to_string()
+            .into_bytes();
+        self.statements
+            .lock()
+            .unwrap()
+            .insert(handle.clone(), query);
+        handle
+    }
+
+    fn statement(&self, handle: &[u8]) -> Result<String, Status> {
+        self.statements
+            .lock()
+            .unwrap()
+            .get(handle)
+            .cloned()
+            .ok_or_else(|| Status::not_found("Unknown statement handle"))
+    }
+
+    /// The names of the registered tables matching the LIKE `pattern`, sorted
+    fn table_names(&self, pattern: &str) -> Result<Vec<String>, Status> {
+        let mut names: Vec<String> =
+            self.ctx.lock().unwrap().tables().into_iter().collect();
+        names.sort();
+        filter_like(names, pattern)
+    }
+}
+
+/// Keep the `values` matching the LIKE `pattern`; an empty pattern matches all
+fn filter_like(values: Vec<String>, pattern: &str) -> Result<Vec<String>, Status> {
+    if pattern.is_empty() {
+        return Ok(values);
+    }
+    let array = StringArray::from(values.iter().map(|s| s.as_str()).collect::<Vec<_>>());
+    let matches = like_utf8_scalar(&array, pattern).map_err(to_tonic_arrow_err)?;
+    Ok(values
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| matches.value(*i))
+        .map(|(_, value)| value)
+        .collect())
+}
+
+#[tonic::async_trait]
+impl FlightSqlService for DataFusionFlightSqlService {
+    async fn get_flight_info_statement(
+        &self,
+        query: CommandStatementQuery,
+        descriptor: FlightDescriptor,
+    ) -> Result<FlightInfo, Status> {
+        let plan = self.plan(&query.query)?;
+        let handle = self.register_statement(query.query);
+        let ticket = TicketStatementQuery {
+            statement_handle: handle,
+        };
+        metadata_flight_info(&plan.schema(), &ticket, descriptor)
+    }
+
+    async fn get_flight_info_prepared_statement(
+        &self,
+        query: CommandPreparedStatementQuery,
+        descriptor: FlightDescriptor,
+    ) -> Result<FlightInfo, Status> {
+        let plan = self.plan(&self.statement(&query.prepared_statement_handle)?)?;
+        metadata_flight_info(&plan.schema(), &query, descriptor)
+    }
+
+    async fn do_get_statement(
+        &self,
+        ticket: TicketStatementQuery,
+    ) -> Result<(SchemaRef, SendableRecordBatchStream), Status> {
+        // a planned query is executed once
+        let query = self
+            .statements
+            .lock()
+            .unwrap()
+            .remove(&ticket.statement_handle)
+            .ok_or_else(|| Status::not_found("Unknown statement handle"))?;
+        self.execute(&query).await
+    }
+
+    async fn do_get_prepared_statement(
+        &self,
+        query: CommandPreparedStatementQuery,
+    ) -> Result<(SchemaRef, SendableRecordBatchStream), Status> {
+        let query = self.statement(&query.prepared_statement_handle)?;
+        self.execute(&query).await
+    }
+
+    async fn do_get_catalogs(
+        &self,
+        _query: CommandGetCatalogs,
+    ) -> Result<(SchemaRef, SendableRecordBatchStream), Status> {
+        let schema = Arc::new(CommandGetCatalogs::schema());
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(StringArray::from(vec![CATALOG_NAME]))],
+        )
+        .map_err(to_tonic_arrow_err)?;
+        Ok((schema, Box::pin(stream::iter(vec![Ok(batch)]))))
+    }
+
+    async fn do_get_schemas(
+        &self,
+        query: CommandGetDbSchemas,
+    ) -> Result<(SchemaRef, SendableRecordBatchStream), Status> {
+        let schema = Arc::new(CommandGetDbSchemas::schema());
+        let schemas = if query.catalog.is_empty() || query.catalog == CATALOG_NAME {
+            filter_like(
+                vec![SCHEMA_NAME.to_string()],
+                &query.db_schema_filter_pattern,
+            )?
+        } else {
+            vec![]
+        };
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![
+                Arc::new(StringArray::from(vec![CATALOG_NAME; schemas.len()])),
+                Arc::new(StringArray::from(
+                    schemas.iter().map(|s| s.as_str()).collect::<Vec<_>>(),
+                )),
+            ],
+        )
+        .map_err(to_tonic_arrow_err)?;
+        Ok((schema, Box::pin(stream::iter(vec![Ok(batch)]))))
+    }
+
+    async fn do_get_tables(
+        &self,
+        query: CommandGetTables,
+    ) -> Result<(SchemaRef, SendableRecordBatchStream), Status> {
+        let schema = Arc::new(query.schema());
+        let include = (query.catalog.is_empty() || query.catalog == CATALOG_NAME)
+            && (query.table_types.is_empty()
+                || query.table_types.iter().any(|t| t == TABLE_TYPE));
+        let schemas = filter_like(
+            vec![SCHEMA_NAME.to_string()],
+            &query.db_schema_filter_pattern,
+        )?;
+        let names = if include && !schemas.is_empty() {
+            self.table_names(&query.table_name_filter_pattern)?
+        } else {
+            vec![]
+        };
+
+        let mut columns: Vec<ArrayRef> = vec![
+            Arc::new(StringArray::from(vec![CATALOG_NAME; names.len()])),
+            Arc::new(StringArray::from(vec![SCHEMA_NAME; names.len()])),
+            Arc::new(StringArray::from(
+                names.iter().map(|s| s.as_str()).collect::<Vec<_>>(),
+            )),
+            Arc::new(StringArray::from(vec![TABLE_TYPE; names.len()])),
+        ];
+        if query.include_schema {
+            let options = IpcWriteOptions::default();
+            let ctx = self.ctx.lock().unwrap();
+            let schemas = names
+                .iter()
+                .map(|name| {
+                    let table = ctx.table(name).map_err(to_tonic_err)?;
+                    let schema: Schema = table.schema().clone().into();
+                    ipc_message_from_arrow_schema(&schema, &options)
+                        .map_err(to_tonic_arrow_err)
+                })
+                .collect::<Result<Vec<_>, Status>>()?;
+            columns.push(Arc::new(BinaryArray::from(
+                schemas.iter().map(|s| s.as_slice()).collect::<Vec<_>>(),
+            )));
+        }
+
+        let batch =
+            RecordBatch::try_new(schema.clone(), columns).map_err(to_tonic_arrow_err)?;
+        Ok((schema, Box::pin(stream::iter(vec![Ok(batch)]))))
+    }
+
+    async fn do_get_table_types(
+        &self,
+        _query: CommandGetTableTypes,
+    ) -> Result<(SchemaRef, SendableRecordBatchStream), Status> {
+        let schema = Arc::new(CommandGetTableTypes::schema());
+        let batch = RecordBatch::try_new(
+            schema.clone(),
+            vec![Arc::new(StringArray::from(vec![TABLE_TYPE]))],
+        )
+        .map_err(to_tonic_arrow_err)?;
+        Ok((schema, Box::pin(stream::iter(vec![Ok(batch)]))))
+    }
+
+    async fn do_put_statement_update(
+        &self,
+        command: CommandStatementUpdate,
+    ) -> Result<i64, Status> {
+        // DataFusion only supports DDL such as CREATE EXTERNAL TABLE, which is
+        // applied when the statement is planned
+        self.ctx
+            .lock()
+            .unwrap()
+            .sql(&command.query)
+            .map_err(to_tonic_err)?;
+        Ok(-1)
+    }
+
+    async fn create_prepared_statement(
+        &self,
+        request: ActionCreatePreparedStatementRequest,
+    ) -> Result<ActionCreatePreparedStatementResult, Status> {
+        let plan = self.plan(&request.query)?;
+        let dataset_schema =
+            ipc_message_from_arrow_schema(&plan.schema(), &IpcWriteOptions::default())
+                .map_err(to_tonic_arrow_err)?;
+        Ok(ActionCreatePreparedStatementResult {
+            prepared_statement_handle: self.register_statement(request.query),
+            dataset_schema,
+            parameter_schema: vec![],
+        })
+    }
+
+    async fn close_prepared_statement(
+        &self,
+        request: ActionClosePreparedStatementRequest,
+    ) -> Result<(), Status> {
+        self.statements
+            .lock()
+            .unwrap()
+            .remove(&request.prepared_statement_handle);
+        Ok(())
+    }
+}
+
+fn to_tonic_err(e: datafusion::error::DataFusionError) -> Status {
+    Status::internal(format!("{:?}", e))
+}
+
+fn to_tonic_arrow_err(e: arrow::error::ArrowError) -> Status {
+    Status::internal(format!("{:?}", e))
+}
+
+/// This example shows how to expose DataFusion over the network as a Flight SQL service,
+/// answering queries and catalog requests against a registered CSV file.
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let addr = "0.0.0.0:50051".parse()?;
+
+    // create local execution context
+    let mut ctx = ExecutionContext::new();
+
+    let testdata = arrow::util::test_util::arrow_test_data();
+
+    // register csv file with the execution context
+    ctx.register_csv(
+        "aggregate_test_100",
+        &format!("{}/csv/aggregate_test_100.csv", testdata),
+        CsvReadOptions::new(),
+    )?;
+
+    let service = FlightSqlServer::new(DataFusionFlightSqlService::new(ctx));
+
+    println!("Listening on {:?}", addr);
+
+    Server::builder()
+        .add_service(service.into_service())
+        .serve(addr)
+        .await?;
+
+    Ok(())
+}
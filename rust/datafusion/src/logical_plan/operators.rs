@@ -52,6 +52,14 @@ pub enum Operator {
     Like,
     /// Does not match a wildcard pattern
     NotLike,
+    /// Case sensitive regex match, like `~`
+    RegexMatch,
+    /// Case insensitive regex match, like `~*`
+    RegexIMatch,
+    /// Case sensitive regex not match, like `!~`
+    RegexNotMatch,
+    /// Case insensitive regex not match, like `!~*`
+    RegexNotIMatch,
 }
 
 impl fmt::Display for Operator {
@@ -72,6 +80,10 @@ impl fmt::Display for Operator {
             Operator::Or => "OR",
             Operator::Like => "LIKE",
             Operator::NotLike => "NOT LIKE",
+            Operator::RegexMatch => "~",
+            Operator::RegexIMatch => "~*",
+            Operator::RegexNotMatch => "!~",
+            Operator::RegexNotIMatch => "!~*",
         };
         write!(f, "{}", display)
     }
@@ -21,8 +21,13 @@ use std::sync::Arc;
 
 use crate::error::{DataFusionError, Result};
 use arrow::{
-    array::{Array, ArrayData, ArrayRef, StringArray, TimestampNanosecondArray},
+    array::{
+        Array, ArrayData, ArrayRef, Date32Array, Date64Array, Int32Array, StringArray,
+        TimestampMicrosecondArray, TimestampMillisecondArray, TimestampNanosecondArray,
+        TimestampSecondArray,
+    },
     buffer::Buffer,
+    compute::kernels::temporal,
     datatypes::{DataType, TimeUnit, ToByteSlice},
 };
 use chrono::Duration;
@@ -206,6 +211,101 @@ pub fn to_timestamp(args: &[ArrayRef]) -> Result<TimestampNanosecondArray> {
     Ok(TimestampNanosecondArray::from(Arc::new(data)))
 }
 
+macro_rules! extract_date_part {
+    ($ARRAY: expr, $FN:expr) => {
+        match $ARRAY.data_type() {
+            DataType::Date32(_) => {
+                let array = $ARRAY.as_any().downcast_ref::<Date32Array>().unwrap();
+                Ok($FN(array)?)
+            }
+            DataType::Date64(_) => {
+                let array = $ARRAY.as_any().downcast_ref::<Date64Array>().unwrap();
+                Ok($FN(array)?)
+            }
+            DataType::Timestamp(time_unit, _) => match time_unit {
+                TimeUnit::Second => {
+                    let array = $ARRAY
+                        .as_any()
+                        .downcast_ref::<TimestampSecondArray>()
+                        .unwrap();
+                    Ok($FN(array)?)
+                }
+                TimeUnit::Millisecond => {
+                    let array = $ARRAY
+                        .as_any()
+                        .downcast_ref::<TimestampMillisecondArray>()
+                        .unwrap();
+                    Ok($FN(array)?)
+                }
+                TimeUnit::Microsecond => {
+                    let array = $ARRAY
+                        .as_any()
+                        .downcast_ref::<TimestampMicrosecondArray>()
+                        .unwrap();
+                    Ok($FN(array)?)
+                }
+                TimeUnit::Nanosecond => {
+                    let array = $ARRAY
+                        .as_any()
+                        .downcast_ref::<TimestampNanosecondArray>()
+                        .unwrap();
+                    Ok($FN(array)?)
+                }
+            },
+            datatype => Err(DataFusionError::Internal(format!(
+                "date_part does not support datatype {:?}",
+                datatype
+            ))),
+        }
+    };
+}
+
+/// date_part SQL function, which also implements `EXTRACT(part FROM expr)`.
+///
+/// Extracts `part` from a date or timestamp as an integer. `dow` counts days from
+/// Sunday (0) and `week` is the ISO 8601 week number. The part is read from the
+/// first row, as it is expected to be a literal.
+pub fn date_part(args: &[ArrayRef]) -> Result<ArrayRef> {
+    let part_array = args[0]
+        .as_any()
+        .downcast_ref::<StringArray>()
+        .ok_or_else(|| {
+            DataFusionError::Execution(
+                "Could not cast date_part part input to StringArray".to_string(),
+            )
+        })?;
+    let array = &args[1];
+
+    if part_array.is_empty() {
+        return Ok(Arc::new(Int32Array::from(Vec::<i32>::new())));
+    }
+    if part_array.is_null(0) {
+        return Err(DataFusionError::Execution(
+            "date_part part can not be null".to_string(),
+        ));
+    }
+
+    let result: Result<Int32Array> = match part_array.value(0).to_lowercase().as_str() {
+        "year" => extract_date_part!(array, temporal::year),
+        "quarter" => extract_date_part!(array, temporal::quarter),
+        "month" => extract_date_part!(array, temporal::month),
+        "week" => extract_date_part!(array, temporal::week),
+        "day" => extract_date_part!(array, temporal::day),
+        "doy" => extract_date_part!(array, temporal::day_of_year),
+        "dow" => extract_date_part!(array, temporal::num_days_from_sunday),
+        "hour" => extract_date_part!(array, temporal::hour),
+        "minute" => extract_date_part!(array, temporal::minute),
+        "second" => extract_date_part!(array, temporal::second),
+        "nanosecond" => extract_date_part!(array, temporal::nanosecond),
+        unsupported => Err(DataFusionError::Execution(format!(
+            "Unsupported date_part part: {}",
+            unsupported
+        ))),
+    };
+
+    Ok(Arc::new(result?))
+}
+
 /// date_trunc SQL function
 pub fn date_trunc(args: &[ArrayRef]) -> Result<TimestampNanosecondArray> {
     let granularity_array =
@@ -563,4 +663,46 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn date_part_test() -> Result<()> {
+        let mut ts_builder = StringBuilder::new(2);
+        ts_builder.append_value("2020-09-08T13:42:29.190855Z")?;
+        ts_builder.append_null()?;
+        let ts_array =
+            Arc::new(to_timestamp(&[Arc::new(ts_builder.finish())])?) as ArrayRef;
+
+        let part_array =
+            |part: &str| -> ArrayRef { Arc::new(StringArray::from(vec![part, part])) };
+        let date_part_values = |part: &str| -> Result<Vec<Option<i32>>> {
+            let result = date_part(&[part_array(part), ts_array.clone()])?;
+            let result = result.as_any().downcast_ref::<Int32Array>().unwrap();
+            Ok(result.iter().collect())
+        };
+
+        assert_eq!(date_part_values("year")?, vec![Some(2020), None]);
+        assert_eq!(date_part_values("QUARTER")?, vec![Some(3), None]);
+        assert_eq!(date_part_values("month")?, vec![Some(9), None]);
+        assert_eq!(date_part_values("week")?, vec![Some(37), None]);
+        assert_eq!(date_part_values("day")?, vec![Some(8), None]);
+        assert_eq!(date_part_values("doy")?, vec![Some(252), None]);
+        assert_eq!(date_part_values("dow")?, vec![Some(2), None]);
+        assert_eq!(date_part_values("hour")?, vec![Some(13), None]);
+        assert_eq!(date_part_values("minute")?, vec![Some(42), None]);
+        assert_eq!(date_part_values("second")?, vec![Some(29), None]);
+        assert_eq!(date_part_values("nanosecond")?, vec![Some(190855000), None]);
+
+        let res = date_part(&[part_array("century"), ts_array.clone()]);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Execution error: Unsupported date_part part: century"
+        );
+
+        let date_array = Arc::new(Date32Array::from(vec![18513, 18514])) as ArrayRef;
+        let result = date_part(&[part_array("day"), date_array])?;
+        let result = result.as_any().downcast_ref::<Int32Array>().unwrap();
+        assert_eq!(result.iter().collect::<Vec<_>>(), vec![Some(8), Some(9)]);
+
+        Ok(())
+    }
 }
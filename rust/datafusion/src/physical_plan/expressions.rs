@@ -28,13 +28,16 @@ use crate::logical_plan::Operator;
 use crate::physical_plan::{Accumulator, AggregateExpr, PhysicalExpr};
 use crate::scalar::ScalarValue;
 use arrow::array::{
-    self, Array, BooleanBuilder, GenericStringArray, LargeStringArray,
-    StringOffsetSizeTrait,
+    self, make_array, Array, BooleanBuilder, GenericStringArray, LargeStringArray,
+    MutableArrayData, StringOffsetSizeTrait,
 };
 use arrow::compute;
 use arrow::compute::kernels;
-use arrow::compute::kernels::arithmetic::{add, divide, multiply, negate, subtract};
-use arrow::compute::kernels::boolean::{and, nullif, or};
+use arrow::compute::kernels::arithmetic::{
+    add, add_scalar, divide, divide_scalar, modulus, modulus_scalar, multiply,
+    multiply_scalar, negate, subtract, subtract_scalar,
+};
+use arrow::compute::kernels::boolean::{and_kleene, nullif, or_kleene};
 use arrow::compute::kernels::comparison::{eq, gt, gt_eq, lt, lt_eq, neq};
 use arrow::compute::kernels::comparison::{
     eq_scalar, gt_eq_scalar, gt_scalar, lt_eq_scalar, lt_scalar, neq_scalar,
@@ -47,6 +50,7 @@ use arrow::compute::kernels::comparison::{
     eq_utf8_scalar, gt_eq_utf8_scalar, gt_utf8_scalar, lt_eq_utf8_scalar, lt_utf8_scalar,
     neq_utf8_scalar,
 };
+use arrow::compute::kernels::regexp::{regexp_is_match, regexp_is_match_scalar};
 use arrow::compute::kernels::sort::{SortColumn, SortOptions};
 use arrow::datatypes::{DataType, DateUnit, Schema, TimeUnit};
 use arrow::record_batch::RecordBatch;
@@ -1023,7 +1027,7 @@ macro_rules! compute_op {
 
 macro_rules! binary_string_array_op_scalar {
     ($LEFT:expr, $RIGHT:expr, $OP:ident) => {{
-        let result = match $LEFT.data_type() {
+        let result: Result<ArrayRef> = match $LEFT.data_type() {
             DataType::Utf8 => compute_utf8_op_scalar!($LEFT, $RIGHT, $OP, StringArray),
             other => Err(DataFusionError::Internal(format!(
                 "Unsupported data type {:?}",
@@ -1070,11 +1074,36 @@ macro_rules! binary_primitive_array_op {
     }};
 }
 
+/// Invoke a compute kernel on an array and a scalar
+/// The binary_primitive_array_op_scalar macro only evaluates for primitive types
+/// like integers and floats.
+macro_rules! binary_primitive_array_op_scalar {
+    ($LEFT:expr, $RIGHT:expr, $OP:ident) => {{
+        let result: Result<ArrayRef> = match $LEFT.data_type() {
+            DataType::Int8 => compute_op_scalar!($LEFT, $RIGHT, $OP, Int8Array),
+            DataType::Int16 => compute_op_scalar!($LEFT, $RIGHT, $OP, Int16Array),
+            DataType::Int32 => compute_op_scalar!($LEFT, $RIGHT, $OP, Int32Array),
+            DataType::Int64 => compute_op_scalar!($LEFT, $RIGHT, $OP, Int64Array),
+            DataType::UInt8 => compute_op_scalar!($LEFT, $RIGHT, $OP, UInt8Array),
+            DataType::UInt16 => compute_op_scalar!($LEFT, $RIGHT, $OP, UInt16Array),
+            DataType::UInt32 => compute_op_scalar!($LEFT, $RIGHT, $OP, UInt32Array),
+            DataType::UInt64 => compute_op_scalar!($LEFT, $RIGHT, $OP, UInt64Array),
+            DataType::Float32 => compute_op_scalar!($LEFT, $RIGHT, $OP, Float32Array),
+            DataType::Float64 => compute_op_scalar!($LEFT, $RIGHT, $OP, Float64Array),
+            other => Err(DataFusionError::Internal(format!(
+                "Unsupported data type {:?}",
+                other
+            ))),
+        };
+        Some(result)
+    }};
+}
+
 /// The binary_array_op_scalar macro includes types that extend beyond the primitive,
 /// such as Utf8 strings.
 macro_rules! binary_array_op_scalar {
     ($LEFT:expr, $RIGHT:expr, $OP:ident) => {{
-        let result = match $LEFT.data_type() {
+        let result: Result<ArrayRef> = match $LEFT.data_type() {
             DataType::Int8 => compute_op_scalar!($LEFT, $RIGHT, $OP, Int8Array),
             DataType::Int16 => compute_op_scalar!($LEFT, $RIGHT, $OP, Int16Array),
             DataType::Int32 => compute_op_scalar!($LEFT, $RIGHT, $OP, Int32Array),
@@ -1148,6 +1177,81 @@ macro_rules! boolean_op {
         Ok(Arc::new($OP(&ll, &rr)?))
     }};
 }
+/// Returns the flags and whether the result is negated for a regex operator
+fn regex_flags(op: &Operator) -> (Option<&'static str>, bool) {
+    match op {
+        Operator::RegexIMatch => (Some("i"), false),
+        Operator::RegexNotMatch => (None, true),
+        Operator::RegexNotIMatch => (Some("i"), true),
+        _ => (None, false),
+    }
+}
+
+fn negate_regex_result(result: BooleanArray, negated: bool) -> Result<ArrayRef> {
+    if negated {
+        Ok(Arc::new(kernels::boolean::not(&result)?))
+    } else {
+        Ok(Arc::new(result))
+    }
+}
+
+/// Evaluates a regex operator with a literal pattern, compiling it once. Returns
+/// `None` for a null pattern so that the array implementation is used instead.
+fn regex_match_scalar(
+    array: &ArrayRef,
+    scalar: &ScalarValue,
+    op: &Operator,
+) -> Option<Result<ArrayRef>> {
+    let (flags, negated) = regex_flags(op);
+    let result = match (array.data_type(), scalar) {
+        (DataType::Utf8, ScalarValue::Utf8(Some(pattern))) => {
+            let array = array.as_any().downcast_ref::<StringArray>().unwrap();
+            regexp_is_match_scalar(array, pattern, flags)
+        }
+        (DataType::LargeUtf8, ScalarValue::LargeUtf8(Some(pattern))) => {
+            let array = array.as_any().downcast_ref::<LargeStringArray>().unwrap();
+            regexp_is_match_scalar(array, pattern, flags)
+        }
+        _ => return None,
+    };
+    Some(
+        result
+            .map_err(DataFusionError::from)
+            .and_then(|result| negate_regex_result(result, negated)),
+    )
+}
+
+/// Evaluates a regex operator on an array of values and an array of patterns
+fn regex_match(left: &ArrayRef, right: &ArrayRef, op: &Operator) -> Result<ArrayRef> {
+    let (flags, negated) = regex_flags(op);
+    let flags = flags.map(|flags| vec![flags; left.len()]);
+    let result = match left.data_type() {
+        DataType::Utf8 => {
+            let flags = flags.map(StringArray::from);
+            regexp_is_match(
+                left.as_any().downcast_ref::<StringArray>().unwrap(),
+                right.as_any().downcast_ref::<StringArray>().unwrap(),
+                flags.as_ref(),
+            )?
+        }
+        DataType::LargeUtf8 => {
+            let flags = flags.map(LargeStringArray::from);
+            regexp_is_match(
+                left.as_any().downcast_ref::<LargeStringArray>().unwrap(),
+                right.as_any().downcast_ref::<LargeStringArray>().unwrap(),
+                flags.as_ref(),
+            )?
+        }
+        other => {
+            return Err(DataFusionError::Internal(format!(
+                "Unsupported data type {:?} for regex operator {}",
+                other, op
+            )))
+        }
+    };
+    negate_regex_result(result, negated)
+}
+
 /// Binary expression
 #[derive(Debug)]
 pub struct BinaryExpr {
@@ -1342,20 +1446,22 @@ fn common_binary_type(
         Operator::Eq | Operator::NotEq => eq_coercion(lhs_type, rhs_type),
         // "like" operators operate on strings and always return a boolean
         Operator::Like | Operator::NotLike => string_coercion(lhs_type, rhs_type),
+        // regex operators operate on strings and always return a boolean
+        Operator::RegexMatch
+        | Operator::RegexIMatch
+        | Operator::RegexNotMatch
+        | Operator::RegexNotIMatch => string_coercion(lhs_type, rhs_type),
         // order-comparison operators have their own rules
         Operator::Lt | Operator::Gt | Operator::GtEq | Operator::LtEq => {
             order_coercion(lhs_type, rhs_type)
         }
         // for math expressions, the final value of the coercion is also the return type
         // because coercion favours higher information types
-        Operator::Plus | Operator::Minus | Operator::Divide | Operator::Multiply => {
-            numerical_coercion(lhs_type, rhs_type)
-        }
-        Operator::Modulus => {
-            return Err(DataFusionError::NotImplemented(
-                "Modulus operator is still not supported".to_string(),
-            ))
-        }
+        Operator::Plus
+        | Operator::Minus
+        | Operator::Divide
+        | Operator::Multiply
+        | Operator::Modulus => numerical_coercion(lhs_type, rhs_type),
     };
 
     // re-write the error message of failed coercions to include the operator's information
@@ -1391,17 +1497,20 @@ pub fn binary_operator_data_type(
         | Operator::Or
         | Operator::Like
         | Operator::NotLike
+        | Operator::RegexMatch
+        | Operator::RegexIMatch
+        | Operator::RegexNotMatch
+        | Operator::RegexNotIMatch
         | Operator::Lt
         | Operator::Gt
         | Operator::GtEq
         | Operator::LtEq => Ok(DataType::Boolean),
         // math operations return the same value as the common coerced type
-        Operator::Plus | Operator::Minus | Operator::Divide | Operator::Multiply => {
-            Ok(common_type)
-        }
-        Operator::Modulus => Err(DataFusionError::NotImplemented(
-            "Modulus operator is still not supported".to_string(),
-        )),
+        Operator::Plus
+        | Operator::Minus
+        | Operator::Divide
+        | Operator::Multiply
+        | Operator::Modulus => Ok(common_type),
     }
 }
 
@@ -1477,6 +1586,29 @@ impl PhysicalExpr for BinaryExpr {
                     Operator::NotLike => {
                         binary_string_array_op_scalar!(array, scalar.clone(), nlike)
                     }
+                    Operator::RegexMatch
+                    | Operator::RegexIMatch
+                    | Operator::RegexNotMatch
+                    | Operator::RegexNotIMatch => {
+                        regex_match_scalar(array, scalar, &self.op)
+                    }
+                    // arithmetic with a null literal is handled by the array implementation
+                    _ if scalar.is_null() => None,
+                    Operator::Plus => {
+                        binary_primitive_array_op_scalar!(array, scalar.clone(), add)
+                    }
+                    Operator::Minus => {
+                        binary_primitive_array_op_scalar!(array, scalar.clone(), subtract)
+                    }
+                    Operator::Multiply => {
+                        binary_primitive_array_op_scalar!(array, scalar.clone(), multiply)
+                    }
+                    Operator::Divide => {
+                        binary_primitive_array_op_scalar!(array, scalar.clone(), divide)
+                    }
+                    Operator::Modulus => {
+                        binary_primitive_array_op_scalar!(array, scalar.clone(), modulus)
+                    }
                     // if scalar operation is not supported - fallback to array implementation
                     _ => None,
                 }
@@ -1496,6 +1628,14 @@ impl PhysicalExpr for BinaryExpr {
                     Operator::NotEq => {
                         binary_array_op_scalar!(array, scalar.clone(), neq)
                     }
+                    // addition and multiplication are commutative
+                    _ if scalar.is_null() => None,
+                    Operator::Plus => {
+                        binary_primitive_array_op_scalar!(array, scalar.clone(), add)
+                    }
+                    Operator::Multiply => {
+                        binary_primitive_array_op_scalar!(array, scalar.clone(), multiply)
+                    }
                     // if scalar operation is not supported - fallback to array implementation
                     _ => None,
                 }
@@ -1516,6 +1656,10 @@ impl PhysicalExpr for BinaryExpr {
         let result: Result<ArrayRef> = match &self.op {
             Operator::Like => binary_string_array_op!(left, right, like),
             Operator::NotLike => binary_string_array_op!(left, right, nlike),
+            Operator::RegexMatch
+            | Operator::RegexIMatch
+            | Operator::RegexNotMatch
+            | Operator::RegexNotIMatch => regex_match(&left, &right, &self.op),
             Operator::Lt => binary_array_op!(left, right, lt),
             Operator::LtEq => binary_array_op!(left, right, lt_eq),
             Operator::Gt => binary_array_op!(left, right, gt),
@@ -1528,7 +1672,7 @@ impl PhysicalExpr for BinaryExpr {
             Operator::Divide => binary_primitive_array_op!(left, right, divide),
             Operator::And => {
                 if left_data_type == DataType::Boolean {
-                    boolean_op!(left, right, and)
+                    boolean_op!(left, right, and_kleene)
                 } else {
                     return Err(DataFusionError::Internal(format!(
                         "Cannot evaluate binary expression {:?} with types {:?} and {:?}",
@@ -1540,7 +1684,7 @@ impl PhysicalExpr for BinaryExpr {
             }
             Operator::Or => {
                 if left_data_type == DataType::Boolean {
-                    boolean_op!(left, right, or)
+                    boolean_op!(left, right, or_kleene)
                 } else {
                     return Err(DataFusionError::Internal(format!(
                         "Cannot evaluate binary expression {:?} with types {:?} and {:?}",
@@ -1548,9 +1692,7 @@ impl PhysicalExpr for BinaryExpr {
                     )));
                 }
             }
-            Operator::Modulus => Err(DataFusionError::NotImplemented(
-                "Modulus operator is still not supported".to_string(),
-            )),
+            Operator::Modulus => binary_primitive_array_op!(left, right, modulus),
         };
         result.map(|a| ColumnarValue::Array(a))
     }
@@ -2018,165 +2160,42 @@ pub fn case(
     Ok(Arc::new(CaseExpr::try_new(expr, when_thens, else_expr)?))
 }
 
-macro_rules! if_then_else {
-    ($BUILDER_TYPE:ty, $ARRAY_TYPE:ty, $BOOLS:expr, $TRUE:expr, $FALSE:expr) => {{
-        let true_values = $TRUE
-            .as_ref()
-            .as_any()
-            .downcast_ref::<$ARRAY_TYPE>()
-            .expect("true_values downcast failed");
-
-        let false_values = $FALSE
-            .as_ref()
-            .as_any()
-            .downcast_ref::<$ARRAY_TYPE>()
-            .expect("false_values downcast failed");
-
-        let mut builder = <$BUILDER_TYPE>::new($BOOLS.len());
-        for i in 0..$BOOLS.len() {
-            if $BOOLS.is_null(i) {
-                if false_values.is_null(i) {
-                    builder.append_null()?;
-                } else {
-                    builder.append_value(false_values.value(i))?;
-                }
-            } else if $BOOLS.value(i) {
-                if true_values.is_null(i) {
-                    builder.append_null()?;
-                } else {
-                    builder.append_value(true_values.value(i))?;
-                }
-            } else {
-                if false_values.is_null(i) {
-                    builder.append_null()?;
-                } else {
-                    builder.append_value(false_values.value(i))?;
-                }
-            }
-        }
-        Ok(Arc::new(builder.finish()))
-    }};
-}
-
+/// Selects the values of `true_values` where `bools` is true and the values of
+/// `false_values` elsewhere, without expanding scalars into arrays.
 fn if_then_else(
     bools: &BooleanArray,
-    true_values: ArrayRef,
-    false_values: ArrayRef,
-    data_type: &DataType,
+    true_values: ColumnarValue,
+    false_values: ColumnarValue,
 ) -> Result<ArrayRef> {
-    match data_type {
-        DataType::UInt8 => if_then_else!(
-            array::UInt8Builder,
-            array::UInt8Array,
-            bools,
-            true_values,
-            false_values
-        ),
-        DataType::UInt16 => if_then_else!(
-            array::UInt16Builder,
-            array::UInt16Array,
-            bools,
-            true_values,
-            false_values
-        ),
-        DataType::UInt32 => if_then_else!(
-            array::UInt32Builder,
-            array::UInt32Array,
-            bools,
-            true_values,
-            false_values
-        ),
-        DataType::UInt64 => if_then_else!(
-            array::UInt64Builder,
-            array::UInt64Array,
-            bools,
-            true_values,
-            false_values
-        ),
-        DataType::Int8 => if_then_else!(
-            array::Int8Builder,
-            array::Int8Array,
-            bools,
-            true_values,
-            false_values
-        ),
-        DataType::Int16 => if_then_else!(
-            array::Int16Builder,
-            array::Int16Array,
-            bools,
-            true_values,
-            false_values
-        ),
-        DataType::Int32 => if_then_else!(
-            array::Int32Builder,
-            array::Int32Array,
-            bools,
-            true_values,
-            false_values
-        ),
-        DataType::Int64 => if_then_else!(
-            array::Int64Builder,
-            array::Int64Array,
-            bools,
-            true_values,
-            false_values
-        ),
-        DataType::Float32 => if_then_else!(
-            array::Float32Builder,
-            array::Float32Array,
-            bools,
-            true_values,
-            false_values
-        ),
-        DataType::Float64 => if_then_else!(
-            array::Float64Builder,
-            array::Float64Array,
-            bools,
-            true_values,
-            false_values
-        ),
-        DataType::Utf8 => if_then_else!(
-            array::StringBuilder,
-            array::StringArray,
+    let result = match (true_values, false_values) {
+        (ColumnarValue::Array(t), ColumnarValue::Array(f)) => {
+            compute::zip(bools, t.as_ref(), f.as_ref())
+        }
+        (ColumnarValue::Scalar(t), ColumnarValue::Array(f)) => {
+            compute::zip_truthy_scalar(bools, t.to_array_of_size(1).as_ref(), f.as_ref())
+        }
+        (ColumnarValue::Array(t), ColumnarValue::Scalar(f)) => {
+            compute::zip_falsy_scalar(bools, t.as_ref(), f.to_array_of_size(1).as_ref())
+        }
+        (ColumnarValue::Scalar(t), ColumnarValue::Scalar(f)) => compute::zip_scalars(
             bools,
-            true_values,
-            false_values
+            t.to_array_of_size(1).as_ref(),
+            f.to_array_of_size(1).as_ref(),
         ),
-        other => Err(DataFusionError::Execution(format!(
-            "CASE does not support '{:?}'",
-            other
-        ))),
-    }
-}
-
-macro_rules! make_null_array {
-    ($TY:ty, $N:expr) => {{
-        let mut builder = <$TY>::new($N);
-        for _ in 0..$N {
-            builder.append_null()?;
-        }
-        Ok(Arc::new(builder.finish()))
-    }};
+    };
+    result.map_err(|e| DataFusionError::Execution(format!("CASE failed: {}", e)))
 }
 
-fn build_null_array(data_type: &DataType, num_rows: usize) -> Result<ArrayRef> {
-    match data_type {
-        DataType::UInt8 => make_null_array!(array::UInt8Builder, num_rows),
-        DataType::UInt16 => make_null_array!(array::UInt16Builder, num_rows),
-        DataType::UInt32 => make_null_array!(array::UInt32Builder, num_rows),
-        DataType::UInt64 => make_null_array!(array::UInt64Builder, num_rows),
-        DataType::Int8 => make_null_array!(array::Int8Builder, num_rows),
-        DataType::Int16 => make_null_array!(array::Int16Builder, num_rows),
-        DataType::Int32 => make_null_array!(array::Int32Builder, num_rows),
-        DataType::Int64 => make_null_array!(array::Int64Builder, num_rows),
-        DataType::Float32 => make_null_array!(array::Float32Builder, num_rows),
-        DataType::Float64 => make_null_array!(array::Float64Builder, num_rows),
-        DataType::Utf8 => make_null_array!(array::StringBuilder, num_rows),
-        other => Err(DataFusionError::Execution(format!(
-            "CASE does not support '{:?}'",
-            other
-        ))),
-    }
+/// Builds an array of `num_rows` nulls with the same data type as `value`.
+fn build_null_array(value: &ColumnarValue, num_rows: usize) -> ArrayRef {
+    let value = match value {
+        ColumnarValue::Array(array) => array.clone(),
+        ColumnarValue::Scalar(scalar) => scalar.to_array_of_size(1),
+    };
+    let mut mutable =
+        MutableArrayData::new(vec![value.data_ref().as_ref()], true, num_rows);
+    mutable.extend_nulls(num_rows);
+    make_array(Arc::new(mutable.freeze()))
 }
 
 macro_rules! array_equals {
@@ -2238,17 +2257,16 @@ impl CaseExpr {
     ///     [ELSE result]
     /// END
     fn case_when_with_expr(&self, batch: &RecordBatch) -> Result<ColumnarValue> {
-        let return_type = self.when_then_expr[0].1.data_type(&batch.schema())?;
         let expr = self.expr.as_ref().unwrap();
         let base_value = expr.evaluate(batch)?;
         let base_type = expr.data_type(&batch.schema())?;
         let base_value = base_value.into_array(batch.num_rows());
 
-        // start with the else condition, or nulls
-        let mut current_value: Option<ArrayRef> = if let Some(e) = &self.else_expr {
-            Some(e.evaluate(batch)?.into_array(batch.num_rows()))
-        } else {
-            Some(build_null_array(&return_type, batch.num_rows())?)
+        // start with the else condition, or nulls once the type of the last
+        // "then" value is known
+        let mut current_value = match &self.else_expr {
+            Some(e) => Some(e.evaluate(batch)?),
+            None => None,
         };
 
         // walk backwards through the when/then expressions
@@ -2259,20 +2277,21 @@ impl CaseExpr {
             let when_value = when_value.into_array(batch.num_rows());
 
             let then_value = self.when_then_expr[i].1.evaluate(batch)?;
-            let then_value = then_value.into_array(batch.num_rows());
 
             // build boolean array representing which rows match the "when" value
             let when_match = array_equals(&base_type, when_value, base_value.clone())?;
 
-            current_value = Some(if_then_else(
+            let else_value = current_value.unwrap_or_else(|| {
+                ColumnarValue::Array(build_null_array(&then_value, batch.num_rows()))
+            });
+            current_value = Some(ColumnarValue::Array(if_then_else(
                 &when_match,
                 then_value,
-                current_value.unwrap(),
-                &return_type,
-            )?);
+                else_value,
+            )?));
         }
 
-        Ok(ColumnarValue::Array(current_value.unwrap()))
+        Ok(current_value.unwrap())
     }
 
     /// This function evaluates the form of CASE where each WHEN expression is a boolean
@@ -2283,13 +2302,11 @@ impl CaseExpr {
     ///      [ELSE result]
     /// END
     fn case_when_no_expr(&self, batch: &RecordBatch) -> Result<ColumnarValue> {
-        let return_type = self.when_then_expr[0].1.data_type(&batch.schema())?;
-
-        // start with the else condition, or nulls
-        let mut current_value: Option<ArrayRef> = if let Some(e) = &self.else_expr {
-            Some(e.evaluate(batch)?.into_array(batch.num_rows()))
-        } else {
-            Some(build_null_array(&return_type, batch.num_rows())?)
+        // start with the else condition, or nulls once the type of the last
+        // "then" value is known
+        let mut current_value = match &self.else_expr {
+            Some(e) => Some(e.evaluate(batch)?),
+            None => None,
         };
 
         // walk backwards through the when/then expressions
@@ -2305,17 +2322,16 @@ impl CaseExpr {
                 .expect("WHEN expression did not return a BooleanArray");
 
             let then_value = self.when_then_expr[i].1.evaluate(batch)?;
-            let then_value = then_value.into_array(batch.num_rows());
 
-            current_value = Some(if_then_else(
-                &when_value,
-                then_value,
-                current_value.unwrap(),
-                &return_type,
-            )?);
+            let else_value = current_value.unwrap_or_else(|| {
+                ColumnarValue::Array(build_null_array(&then_value, batch.num_rows()))
+            });
+            current_value = Some(ColumnarValue::Array(if_then_else(
+                when_value, then_value, else_value,
+            )?));
         }
 
-        Ok(ColumnarValue::Array(current_value.unwrap()))
+        Ok(current_value.unwrap())
     }
 }
 
@@ -2545,12 +2561,18 @@ impl PhysicalSortExpr {
     }
 }
 
+/// Lists with at least this many values are evaluated with a hash set of the values
+/// instead of comparing every row against every value.
+const IN_LIST_SET_THRESHOLD: usize = 8;
+
 /// InList
 #[derive(Debug)]
 pub struct InListExpr {
     expr: Arc<dyn PhysicalExpr>,
     list: Vec<Arc<dyn PhysicalExpr>>,
     negated: bool,
+    /// The hash set of the list values, built once when they are all literals
+    value_set: Option<compute::ValueSet>,
 }
 
 macro_rules! make_contains {
@@ -2616,10 +2638,24 @@ impl InListExpr {
         list: Vec<Arc<dyn PhysicalExpr>>,
         negated: bool,
     ) -> Self {
+        let value_set = if list.len() >= IN_LIST_SET_THRESHOLD {
+            list.iter()
+                .map(|expr| {
+                    expr.as_any()
+                        .downcast_ref::<Literal>()
+                        .map(|literal| ColumnarValue::Scalar(literal.value().clone()))
+                })
+                .collect::<Option<Vec<_>>>()
+                // an error is raised again when evaluating the expression
+                .and_then(|list_values| build_value_set(&list_values).ok().flatten())
+        } else {
+            None
+        };
         Self {
             expr,
             list,
             negated,
+            value_set,
         }
     }
 
@@ -2638,6 +2674,21 @@ impl InListExpr {
         self.negated
     }
 
+    /// Compare against a hash set of the values
+    fn compare_set(
+        &self,
+        array: &ArrayRef,
+        value_set: &compute::ValueSet,
+    ) -> Result<ColumnarValue> {
+        let result = compute::is_in(array.as_ref(), value_set)?;
+        let result = if self.negated {
+            compute::not(&result)?
+        } else {
+            result
+        };
+        Ok(ColumnarValue::Array(Arc::new(result)))
+    }
+
     /// Compare for specific utf8 types
     fn compare_utf8<T: StringOffsetSizeTrait>(
         &self,
@@ -2703,6 +2754,36 @@ impl InListExpr {
     }
 }
 
+/// Builds a hash set of the list values, when they are all scalars
+fn build_value_set(list_values: &[ColumnarValue]) -> Result<Option<compute::ValueSet>> {
+    let mut contains_null = false;
+    let mut values = vec![];
+    for value in list_values {
+        match value {
+            ColumnarValue::Scalar(s) if s.is_null() => contains_null = true,
+            ColumnarValue::Scalar(s) => values.push(s.to_array_of_size(1)),
+            ColumnarValue::Array(_) => return Ok(None),
+        }
+    }
+    if values.is_empty() {
+        return Ok(None);
+    }
+    let values = values.iter().map(|v| v.as_ref()).collect::<Vec<_>>();
+    let values = compute::concat(&values)?;
+    let values = if contains_null {
+        let data = values.data();
+        let mut mutable =
+            MutableArrayData::new(vec![data.as_ref()], true, data.len() + 1);
+        mutable.extend(0, 0, data.len());
+        mutable.extend_nulls(1);
+        make_array(Arc::new(mutable.freeze()))
+    } else {
+        values
+    };
+
+    Ok(Some(compute::ValueSet::try_new(values.as_ref())?))
+}
+
 impl fmt::Display for InListExpr {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         if self.negated {
@@ -2730,16 +2811,26 @@ impl PhysicalExpr for InListExpr {
     fn evaluate(&self, batch: &RecordBatch) -> Result<ColumnarValue> {
         let value = self.expr.evaluate(batch)?;
         let value_data_type = value.data_type();
+        let array = match value {
+            ColumnarValue::Array(array) => array,
+            ColumnarValue::Scalar(scalar) => scalar.to_array(),
+        };
+
+        if let Some(value_set) = &self.value_set {
+            return self.compare_set(&array, value_set);
+        }
+
         let list_values = self
             .list
             .iter()
             .map(|expr| expr.evaluate(batch))
             .collect::<Result<Vec<_>>>()?;
 
-        let array = match value {
-            ColumnarValue::Array(array) => array,
-            ColumnarValue::Scalar(scalar) => scalar.to_array(),
-        };
+        if list_values.len() >= IN_LIST_SET_THRESHOLD {
+            if let Some(value_set) = build_value_set(&list_values)? {
+                return self.compare_set(&array, &value_set);
+            }
+        }
 
         match value_data_type {
             DataType::Float32 => {
@@ -2880,6 +2971,149 @@ mod tests {
         Ok(())
     }
 
+    // SQL truth tables for AND and OR, where `None` is NULL
+    #[test]
+    fn binary_and_or_kleene() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Boolean, true),
+            Field::new("b", DataType::Boolean, true),
+        ]);
+        let a = BooleanArray::from(vec![
+            None,
+            None,
+            None,
+            Some(false),
+            Some(false),
+            Some(false),
+            Some(true),
+            Some(true),
+            Some(true),
+        ]);
+        let b = BooleanArray::from(vec![
+            None,
+            Some(false),
+            Some(true),
+            None,
+            Some(false),
+            Some(true),
+            None,
+            Some(false),
+            Some(true),
+        ]);
+        let batch =
+            RecordBatch::try_new(Arc::new(schema), vec![Arc::new(a), Arc::new(b)])?;
+
+        let expected = BooleanArray::from(vec![
+            None,
+            Some(false),
+            None,
+            Some(false),
+            Some(false),
+            Some(false),
+            None,
+            Some(false),
+            Some(true),
+        ]);
+        let expr = binary_simple(col("a"), Operator::And, col("b"));
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows());
+        let result = result
+            .as_any()
+            .downcast_ref::<BooleanArray>()
+            .expect("failed to downcast to BooleanArray");
+        assert_eq!(result, &expected);
+
+        let expected = BooleanArray::from(vec![
+            None,
+            None,
+            Some(true),
+            None,
+            Some(false),
+            Some(true),
+            Some(true),
+            Some(true),
+            Some(true),
+        ]);
+        let expr = binary_simple(col("a"), Operator::Or, col("b"));
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows());
+        let result = result
+            .as_any()
+            .downcast_ref::<BooleanArray>()
+            .expect("failed to downcast to BooleanArray");
+        assert_eq!(result, &expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn binary_regex_operators() -> Result<()> {
+        let schema = Schema::new(vec![
+            Field::new("a", DataType::Utf8, true),
+            Field::new("b", DataType::Utf8, true),
+        ]);
+        let a = StringArray::from(vec![Some("Hello"), Some("world"), None, Some("x")]);
+        let b = StringArray::from(vec![Some("^h"), Some("^w"), Some("^h"), None]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema.clone()),
+            vec![Arc::new(a), Arc::new(b)],
+        )?;
+
+        let cases = vec![
+            (
+                Operator::RegexMatch,
+                vec![Some(false), Some(true), None, None],
+            ),
+            (
+                Operator::RegexIMatch,
+                vec![Some(true), Some(true), None, None],
+            ),
+            (
+                Operator::RegexNotMatch,
+                vec![Some(true), Some(false), None, None],
+            ),
+            (
+                Operator::RegexNotIMatch,
+                vec![Some(false), Some(false), None, None],
+            ),
+        ];
+        for (op, expected) in cases {
+            let expr = binary(col("a"), op, col("b"), &schema)?;
+            assert_eq!(expr.data_type(&schema)?, DataType::Boolean);
+            let result = expr.evaluate(&batch)?.into_array(batch.num_rows());
+            let result = result.as_any().downcast_ref::<BooleanArray>().unwrap();
+            assert_eq!(result, &BooleanArray::from(expected), "{}", op);
+        }
+
+        // with a literal pattern
+        let cases = vec![
+            (
+                Operator::RegexMatch,
+                vec![Some(false), Some(false), None, Some(false)],
+            ),
+            (
+                Operator::RegexIMatch,
+                vec![Some(true), Some(false), None, Some(false)],
+            ),
+            (
+                Operator::RegexNotMatch,
+                vec![Some(true), Some(true), None, Some(true)],
+            ),
+            (
+                Operator::RegexNotIMatch,
+                vec![Some(false), Some(true), None, Some(true)],
+            ),
+        ];
+        for (op, expected) in cases {
+            let pattern = lit(ScalarValue::Utf8(Some("^h".to_string())));
+            let expr = binary(col("a"), op, pattern, &schema)?;
+            assert_eq!(format!("a {} ^h", op), format!("{}", expr));
+            let result = expr.evaluate(&batch)?.into_array(batch.num_rows());
+            let result = result.as_any().downcast_ref::<BooleanArray>().unwrap();
+            assert_eq!(result, &BooleanArray::from(expected), "{}", op);
+        }
+
+        Ok(())
+    }
+
     #[test]
     fn literal_i32() -> Result<()> {
         // create an arbitrary record bacth
@@ -3910,6 +4144,61 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn modulus_op() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Int32, false),
+        ]));
+        let a = Arc::new(Int32Array::from(vec![8, 32, 128, 513, 2049]));
+        let b = Arc::new(Int32Array::from(vec![2, 5, 7, 16, 32]));
+
+        apply_arithmetic::<Int32Type>(
+            schema,
+            vec![a, b],
+            Operator::Modulus,
+            Int32Array::from(vec![0, 2, 2, 1, 1]),
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn arithmetic_op_scalar() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int32, true)]);
+        let a = Int32Array::from(vec![Some(8), None, Some(33)]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(a)])?;
+
+        let cases = vec![
+            (Operator::Plus, vec![Some(11), None, Some(36)]),
+            (Operator::Minus, vec![Some(5), None, Some(30)]),
+            (Operator::Multiply, vec![Some(24), None, Some(99)]),
+            (Operator::Divide, vec![Some(2), None, Some(11)]),
+            (Operator::Modulus, vec![Some(2), None, Some(0)]),
+        ];
+        for (op, expected) in cases {
+            let expr = binary_simple(col("a"), op, lit(ScalarValue::Int32(Some(3))));
+            let result = expr.evaluate(&batch)?.into_array(batch.num_rows());
+            assert_array_eq::<Int32Type>(Int32Array::from(expected), result);
+        }
+
+        // a literal on the left of commutative operators
+        let expr =
+            binary_simple(lit(ScalarValue::Int32(Some(3))), Operator::Plus, col("a"));
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows());
+        assert_array_eq::<Int32Type>(
+            Int32Array::from(vec![Some(11), None, Some(36)]),
+            result,
+        );
+
+        // a null literal yields nulls
+        let expr = binary_simple(col("a"), Operator::Plus, lit(ScalarValue::Int32(None)));
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows());
+        assert_eq!(result.null_count(), 3);
+
+        Ok(())
+    }
+
     fn apply_arithmetic<T: ArrowNumericType>(
         schema: SchemaRef,
         data: Vec<ArrayRef>,
@@ -4144,6 +4433,45 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn case_without_expr_boolean() -> Result<()> {
+        let batch = case_test_batch()?;
+
+        // CASE WHEN a = 'foo' THEN true WHEN a = 'bar' THEN a = 'baz' END
+        let when1 = binary(
+            col("a"),
+            Operator::Eq,
+            lit(ScalarValue::Utf8(Some("foo".to_string()))),
+            &batch.schema(),
+        )?;
+        let then1 = lit(ScalarValue::Boolean(Some(true)));
+        let when2 = binary(
+            col("a"),
+            Operator::Eq,
+            lit(ScalarValue::Utf8(Some("bar".to_string()))),
+            &batch.schema(),
+        )?;
+        let then2 = binary(
+            col("a"),
+            Operator::Eq,
+            lit(ScalarValue::Utf8(Some("baz".to_string()))),
+            &batch.schema(),
+        )?;
+
+        let expr = case(None, &[(when1, then1), (when2, then2)], None)?;
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows());
+        let result = result
+            .as_any()
+            .downcast_ref::<BooleanArray>()
+            .expect("failed to downcast to BooleanArray");
+
+        let expected = &BooleanArray::from(vec![Some(true), None, None, Some(false)]);
+
+        assert_eq!(expected, result);
+
+        Ok(())
+    }
+
     fn case_test_batch() -> Result<RecordBatch> {
         let schema = Schema::new(vec![Field::new("a", DataType::Utf8, true)]);
         let a = StringArray::from(vec![Some("foo"), Some("baz"), None, Some("bar")]);
@@ -4243,6 +4571,73 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn in_list_large() -> Result<()> {
+        let schema = Schema::new(vec![Field::new("a", DataType::Int64, true)]);
+        let a = Int64Array::from(vec![Some(0), Some(20), None]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(a)])?;
+
+        // expression: "a in (0, 2, 4, ..., 18)"
+        let list = (0..10)
+            .map(|v| lit(ScalarValue::Int64(Some(v * 2))))
+            .collect::<Vec<_>>();
+        in_list!(batch, list, &false, vec![Some(true), Some(false), None]);
+
+        // expression: "a not in (0, 2, 4, ..., 18, NULL)"
+        let mut list = (0..10)
+            .map(|v| lit(ScalarValue::Int64(Some(v * 2))))
+            .collect::<Vec<_>>();
+        list.push(lit(ScalarValue::Utf8(None)));
+        in_list!(batch, list, &true, vec![Some(false), None, None]);
+
+        // types the row-by-row comparison does not support
+        let schema =
+            Schema::new(vec![Field::new("d", DataType::Date32(DateUnit::Day), true)]);
+        let d = Date32Array::from(vec![Some(3), Some(30)]);
+        let batch = RecordBatch::try_new(Arc::new(schema), vec![Arc::new(d)])?;
+        let list = (0..10)
+            .map(|v| lit(ScalarValue::Date32(Some(v))))
+            .collect::<Vec<_>>();
+        let expr = in_list(col("d"), list, &false)?;
+        let result = expr.evaluate(&batch)?.into_array(batch.num_rows());
+        let result = result.as_any().downcast_ref::<BooleanArray>().unwrap();
+        assert_eq!(result, &BooleanArray::from(vec![true, false]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn in_list_set_built_once() -> Result<()> {
+        let schema = Arc::new(Schema::new(vec![Field::new("a", DataType::Int64, true)]));
+        // expression: "a in (0, 2, 4, ..., 18)"
+        let list = (0..10)
+            .map(|v| lit(ScalarValue::Int64(Some(v * 2))))
+            .collect::<Vec<_>>();
+        let expr = InListExpr::new(col("a"), list, false);
+        assert!(expr.value_set.is_some());
+
+        // the set is reused for every batch
+        for (values, expected) in vec![
+            (
+                vec![Some(0), Some(20), None],
+                vec![Some(true), Some(false), None],
+            ),
+            (vec![Some(3), Some(18)], vec![Some(false), Some(true)]),
+        ] {
+            let a = Int64Array::from(values);
+            let batch = RecordBatch::try_new(schema.clone(), vec![Arc::new(a)])?;
+            let result = expr.evaluate(&batch)?.into_array(batch.num_rows());
+            let result = result.as_any().downcast_ref::<BooleanArray>().unwrap();
+            assert_eq!(result, &BooleanArray::from(expected));
+        }
+
+        // the set cannot be built before evaluation from columns
+        let list = (0..10).map(|_| col("a")).collect::<Vec<_>>();
+        assert!(InListExpr::new(col("a"), list, false).value_set.is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn in_list_float64() -> Result<()> {
         let schema = Schema::new(vec![Field::new("a", DataType::Float64, true)]);
@@ -39,6 +39,7 @@ use crate::physical_plan::crypto_expressions;
 use crate::physical_plan::datetime_expressions;
 use crate::physical_plan::expressions::{nullif_func, SUPPORTED_NULLIF_TYPES};
 use crate::physical_plan::math_expressions;
+use crate::physical_plan::regex_expressions;
 use crate::physical_plan::string_expressions;
 use arrow::{
     array::ArrayRef,
@@ -137,6 +138,8 @@ pub enum BuiltinScalarFunction {
     NullIf,
     /// Date truncate
     DateTrunc,
+    /// Date part, also used for SQL EXTRACT
+    DatePart,
     /// MD5
     MD5,
     /// SHA224
@@ -147,6 +150,10 @@ pub enum BuiltinScalarFunction {
     SHA384,
     /// SHA512,
     SHA512,
+    /// regexp_match
+    RegexpMatch,
+    /// regexp_replace
+    RegexpReplace,
 }
 
 impl fmt::Display for BuiltinScalarFunction {
@@ -188,6 +195,7 @@ impl FromStr for BuiltinScalarFunction {
             "upper" => BuiltinScalarFunction::Upper,
             "to_timestamp" => BuiltinScalarFunction::ToTimestamp,
             "date_trunc" => BuiltinScalarFunction::DateTrunc,
+            "date_part" => BuiltinScalarFunction::DatePart,
             "array" => BuiltinScalarFunction::Array,
             "nullif" => BuiltinScalarFunction::NullIf,
             "md5" => BuiltinScalarFunction::MD5,
@@ -195,6 +203,8 @@ impl FromStr for BuiltinScalarFunction {
             "sha256" => BuiltinScalarFunction::SHA256,
             "sha384" => BuiltinScalarFunction::SHA384,
             "sha512" => BuiltinScalarFunction::SHA512,
+            "regexp_match" => BuiltinScalarFunction::RegexpMatch,
+            "regexp_replace" => BuiltinScalarFunction::RegexpReplace,
             _ => {
                 return Err(DataFusionError::Plan(format!(
                     "There is no built-in function named {}",
@@ -295,6 +305,19 @@ pub fn return_type(
         BuiltinScalarFunction::DateTrunc => {
             Ok(DataType::Timestamp(TimeUnit::Nanosecond, None))
         }
+        BuiltinScalarFunction::DatePart => Ok(DataType::Int32),
+        BuiltinScalarFunction::RegexpMatch => {
+            check_arg_count(fun, arg_types, 2..=3)?;
+            Ok(DataType::List(Box::new(Field::new(
+                "item",
+                arg_types[0].clone(),
+                true,
+            ))))
+        }
+        BuiltinScalarFunction::RegexpReplace => {
+            check_arg_count(fun, arg_types, 3..=4)?;
+            Ok(arg_types[0].clone())
+        }
         BuiltinScalarFunction::Array => Ok(DataType::FixedSizeList(
             Box::new(Field::new("item", arg_types[0].clone(), true)),
             arg_types.len() as i32,
@@ -474,6 +497,23 @@ pub fn create_physical_expr(
         BuiltinScalarFunction::DateTrunc => {
             |args| Ok(Arc::new(datetime_expressions::date_trunc(args)?))
         }
+        BuiltinScalarFunction::DatePart => datetime_expressions::date_part,
+        BuiltinScalarFunction::RegexpMatch => |args| match args[0].data_type() {
+            DataType::Utf8 => regex_expressions::regexp_match::<i32>(args),
+            DataType::LargeUtf8 => regex_expressions::regexp_match::<i64>(args),
+            other => Err(DataFusionError::Internal(format!(
+                "Unsupported data type {:?} for function regexp_match",
+                other,
+            ))),
+        },
+        BuiltinScalarFunction::RegexpReplace => |args| match args[0].data_type() {
+            DataType::Utf8 => regex_expressions::regexp_replace::<i32>(args),
+            DataType::LargeUtf8 => regex_expressions::regexp_replace::<i64>(args),
+            other => Err(DataFusionError::Internal(format!(
+                "Unsupported data type {:?} for function regexp_replace",
+                other,
+            ))),
+        },
         BuiltinScalarFunction::Array => |args| Ok(array_expressions::array(args)?),
     });
     // coerce
@@ -492,6 +532,24 @@ pub fn create_physical_expr(
     )))
 }
 
+/// Returns an error if the number of arguments of `fun` is not in `range`
+fn check_arg_count(
+    fun: &BuiltinScalarFunction,
+    arg_types: &[DataType],
+    range: std::ops::RangeInclusive<usize>,
+) -> Result<()> {
+    if !range.contains(&arg_types.len()) {
+        return Err(DataFusionError::Plan(format!(
+            "Function '{}' requires {} to {} arguments, but {} were given",
+            fun,
+            range.start(),
+            range.end(),
+            arg_types.len()
+        )));
+    }
+    Ok(())
+}
+
 /// the signatures supported by the function `fun`.
 fn signature(fun: &BuiltinScalarFunction) -> Signature {
     // note: the physical expression must accept the type returned by this function or the execution panics.
@@ -499,6 +557,10 @@ fn signature(fun: &BuiltinScalarFunction) -> Signature {
     // for now, the list is small, as we do not have many built-in functions.
     match fun {
         BuiltinScalarFunction::Concat => Signature::Variadic(vec![DataType::Utf8]),
+        // the number of arguments is checked by `return_type`
+        BuiltinScalarFunction::RegexpMatch | BuiltinScalarFunction::RegexpReplace => {
+            Signature::Variadic(vec![DataType::Utf8, DataType::LargeUtf8])
+        }
         BuiltinScalarFunction::Upper
         | BuiltinScalarFunction::Lower
         | BuiltinScalarFunction::Length
@@ -517,6 +579,8 @@ fn signature(fun: &BuiltinScalarFunction) -> Signature {
             DataType::Utf8,
             DataType::Timestamp(TimeUnit::Nanosecond, None),
         ]),
+        // the part must be a string and the date or timestamp is checked when evaluated
+        BuiltinScalarFunction::DatePart => Signature::Any(2),
         BuiltinScalarFunction::Array => {
             Signature::Variadic(array_expressions::SUPPORTED_ARRAY_TYPES.to_vec())
         }
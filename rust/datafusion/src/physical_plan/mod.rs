@@ -293,6 +293,7 @@ pub mod merge;
 pub mod parquet;
 pub mod planner;
 pub mod projection;
+pub mod regex_expressions;
 pub mod repartition;
 pub mod sort;
 pub mod string_expressions;
@@ -0,0 +1,172 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Regex expressions
+
+use std::sync::Arc;
+
+use crate::error::{DataFusionError, Result};
+use arrow::array::{ArrayRef, GenericStringArray, StringOffsetSizeTrait};
+use arrow::compute::kernels::regexp;
+
+fn downcast_string_arg<'a, T: StringOffsetSizeTrait>(
+    arg: &'a ArrayRef,
+    name: &str,
+) -> Result<&'a GenericStringArray<T>> {
+    arg.as_any()
+        .downcast_ref::<GenericStringArray<T>>()
+        .ok_or_else(|| {
+            DataFusionError::Internal(format!(
+                "could not cast {} to {}",
+                name,
+                std::any::type_name::<GenericStringArray<T>>()
+            ))
+        })
+}
+
+/// Converts a PostgreSQL replacement string, which refers to capture groups as `\1`
+/// and to the whole match as `\&`, to the syntax of the regex crate.
+fn postgres_replacement(replacement: &str) -> String {
+    let mut result = String::with_capacity(replacement.len());
+    let mut chars = replacement.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.peek() {
+                Some(d) if d.is_ascii_digit() => {
+                    result.push_str(&format!("${{{}}}", d));
+                    chars.next();
+                }
+                Some('&') => {
+                    result.push_str("${0}");
+                    chars.next();
+                }
+                Some('\\') => {
+                    result.push('\\');
+                    chars.next();
+                }
+                _ => result.push('\\'),
+            },
+            '$' => result.push_str("$$"),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+/// regexp_match SQL function, like PostgreSQL's
+/// `regexp_match(string, pattern [, flags])`. Returns the capture groups of the
+/// first match as a list of strings, or null if the pattern does not match.
+pub fn regexp_match<T: StringOffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
+    match args.len() {
+        2 | 3 => {
+            let values = downcast_string_arg::<T>(&args[0], "string")?;
+            let patterns = downcast_string_arg::<T>(&args[1], "pattern")?;
+            let flags = match args.get(2) {
+                Some(flags) => Some(downcast_string_arg::<T>(flags, "flags")?),
+                None => None,
+            };
+            Ok(regexp::regexp_match(values, patterns, flags)?)
+        }
+        other => Err(DataFusionError::Internal(format!(
+            "regexp_match was called with {} arguments. It requires 2 or 3.",
+            other
+        ))),
+    }
+}
+
+/// regexp_replace SQL function, like PostgreSQL's
+/// `regexp_replace(string, pattern, replacement [, flags])`. Replaces the first
+/// match of the pattern, or every match with the `g` flag.
+pub fn regexp_replace<T: StringOffsetSizeTrait>(args: &[ArrayRef]) -> Result<ArrayRef> {
+    match args.len() {
+        3 | 4 => {
+            let values = downcast_string_arg::<T>(&args[0], "string")?;
+            let patterns = downcast_string_arg::<T>(&args[1], "pattern")?;
+            let replacements = downcast_string_arg::<T>(&args[2], "replacement")?;
+            let replacements: GenericStringArray<T> = replacements
+                .iter()
+                .map(|r| r.map(postgres_replacement))
+                .collect();
+            let flags = match args.get(3) {
+                Some(flags) => Some(downcast_string_arg::<T>(flags, "flags")?),
+                None => None,
+            };
+            Ok(Arc::new(regexp::regexp_replace(
+                values,
+                patterns,
+                &replacements,
+                flags,
+            )?))
+        }
+        other => Err(DataFusionError::Internal(format!(
+            "regexp_replace was called with {} arguments. It requires 3 or 4.",
+            other
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::{Array, ListArray, StringArray};
+
+    #[test]
+    fn postgres_replacement_syntax() {
+        assert_eq!(postgres_replacement(r"\1-\2"), "${1}-${2}");
+        assert_eq!(postgres_replacement(r"[\&]"), "[${0}]");
+        assert_eq!(postgres_replacement(r"a\\b"), r"a\b");
+        assert_eq!(postgres_replacement("$1"), "$$1");
+    }
+
+    #[test]
+    fn regexp_replace_test() -> Result<()> {
+        let values = Arc::new(StringArray::from(vec!["foobarbaz", "abc"])) as ArrayRef;
+        let patterns = Arc::new(StringArray::from(vec!["b(..)", "b(..)"])) as ArrayRef;
+        let replacements =
+            Arc::new(StringArray::from(vec![r"X\1Y", r"X\1Y"])) as ArrayRef;
+        let flags = Arc::new(StringArray::from(vec!["g", ""])) as ArrayRef;
+
+        let result = regexp_replace::<i32>(&[
+            values.clone(),
+            patterns.clone(),
+            replacements,
+            flags,
+        ])?;
+        let result = result.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(result, &StringArray::from(vec!["fooXarYXazY", "abc"]));
+
+        assert!(regexp_replace::<i32>(&[values, patterns]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn regexp_match_test() -> Result<()> {
+        let values =
+            Arc::new(StringArray::from(vec![Some("Key=Value"), None])) as ArrayRef;
+        let patterns =
+            Arc::new(StringArray::from(vec!["key=(.*)", "key=(.*)"])) as ArrayRef;
+        let flags = Arc::new(StringArray::from(vec!["i", "i"])) as ArrayRef;
+
+        let result = regexp_match::<i32>(&[values, patterns, flags])?;
+        let result = result.as_any().downcast_ref::<ListArray>().unwrap();
+        let first = result.value(0);
+        let first = first.as_any().downcast_ref::<StringArray>().unwrap();
+        assert_eq!(first, &StringArray::from(vec!["Value"]));
+        assert!(result.is_null(1));
+        Ok(())
+    }
+}
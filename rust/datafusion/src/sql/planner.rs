@@ -310,6 +310,9 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                     "NATURAL JOIN is not supported (https://issues.apache.org/jira/browse/ARROW-10727)".to_string(),
                 ))
             }
+            JoinConstraint::None => Err(DataFusionError::NotImplemented(
+                "NONE constraint is not supported".to_string(),
+            )),
         }
     }
 
@@ -624,7 +627,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
 
     fn sql_expr_to_logical_expr(&self, sql: &SQLExpr) -> Result<Expr> {
         match sql {
-            SQLExpr::Value(Value::Number(n)) => match n.parse::<i64>() {
+            SQLExpr::Value(Value::Number(n, _)) => match n.parse::<i64>() {
                 Ok(n) => Ok(lit(n)),
                 Err(_) => Ok(lit(n.parse::<f64>().unwrap())),
             },
@@ -702,6 +705,25 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                 data_type: convert_data_type(data_type)?,
             }),
 
+            SQLExpr::Extract {
+                ref field,
+                ref expr,
+            } => Ok(Expr::ScalarFunction {
+                fun: functions::BuiltinScalarFunction::DatePart,
+                args: vec![
+                    lit(field.to_string().to_lowercase()),
+                    self.sql_expr_to_logical_expr(expr)?,
+                ],
+            }),
+
+            SQLExpr::Trim {
+                ref expr,
+                trim_where: None,
+            } => Ok(Expr::ScalarFunction {
+                fun: functions::BuiltinScalarFunction::Trim,
+                args: vec![self.sql_expr_to_logical_expr(expr)?],
+            }),
+
             SQLExpr::TypedString {
                 ref data_type,
                 ref value,
@@ -727,7 +749,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                     match expr.as_ref() {
                         // optimization: if it's a number literal, we applly the negative operator
                         // here directly to calculate the new literal.
-                        SQLExpr::Value(Value::Number(n)) => match n.parse::<i64>() {
+                        SQLExpr::Value(Value::Number(n, _)) => match n.parse::<i64>() {
                             Ok(n) => Ok(lit(-n)),
                             Err(_) => Ok(lit(-n
                                 .parse::<f64>()
@@ -792,11 +814,15 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                     BinaryOperator::Minus => Ok(Operator::Minus),
                     BinaryOperator::Multiply => Ok(Operator::Multiply),
                     BinaryOperator::Divide => Ok(Operator::Divide),
-                    BinaryOperator::Modulus => Ok(Operator::Modulus),
+                    BinaryOperator::Modulo => Ok(Operator::Modulus),
                     BinaryOperator::And => Ok(Operator::And),
                     BinaryOperator::Or => Ok(Operator::Or),
                     BinaryOperator::Like => Ok(Operator::Like),
                     BinaryOperator::NotLike => Ok(Operator::NotLike),
+                    BinaryOperator::PGRegexMatch => Ok(Operator::RegexMatch),
+                    BinaryOperator::PGRegexIMatch => Ok(Operator::RegexIMatch),
+                    BinaryOperator::PGRegexNotMatch => Ok(Operator::RegexNotMatch),
+                    BinaryOperator::PGRegexNotIMatch => Ok(Operator::RegexNotIMatch),
                     _ => Err(DataFusionError::NotImplemented(format!(
                         "Unsupported SQL binary operator {:?}",
                         op
@@ -833,6 +859,7 @@ impl<'a, S: ContextProvider> SqlToRel<'a, S> {
                             .map(|a| match a {
                                 FunctionArg::Unnamed(SQLExpr::Value(Value::Number(
                                     _,
+                                    _,
                                 ))) => Ok(lit(1_u8)),
                                 FunctionArg::Unnamed(SQLExpr::Wildcard) => Ok(lit(1_u8)),
                                 _ => self.sql_fn_arg_to_logical_expr(a),
@@ -1269,6 +1296,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn select_extract() {
+        quick_test(
+            "SELECT EXTRACT(YEAR FROM birth_date) FROM person",
+            "Projection: datepart(Utf8(\"year\"), #birth_date)\
+             \n  TableScan: person projection=None",
+        );
+    }
+
     #[test]
     fn test_sum_aggregate() {
         quick_test(
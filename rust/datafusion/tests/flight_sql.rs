@@ -0,0 +1,290 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! End-to-end tests of a DataFusion Flight SQL service over localhost
+
+use std::convert::TryFrom;
+use std::io::Write;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use futures::{stream, TryStreamExt};
+use tonic::transport::Server;
+
+use arrow::array::{Array, BinaryArray, Int32Array, StringArray};
+use arrow::datatypes::{DataType, Field, Schema};
+use arrow::record_batch::RecordBatch;
+
+use datafusion::datasource::MemTable;
+use datafusion::prelude::*;
+
+use arrow_flight::sql::client::FlightSqlClient;
+use arrow_flight::sql::server::FlightSqlServer;
+use arrow_flight::sql::{CommandGetCatalogs, CommandGetDbSchemas, CommandGetTables};
+use arrow_flight::utils::schema_from_ipc_message;
+use arrow_flight::FlightInfo;
+
+// the service under test is the one of the Flight SQL server example
+#[allow(dead_code)]
+#[path = "../examples/flight_sql_server.rs"]
+mod flight_sql_server;
+
+use flight_sql_server::DataFusionFlightSqlService;
+
+fn test_context() -> ExecutionContext {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("id", DataType::Int32, false),
+        Field::new("name", DataType::Utf8, true),
+    ]));
+    let batch = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(Int32Array::from(vec![1, 2, 3, 4])),
+            Arc::new(StringArray::from(vec![
+                Some("one"),
+                Some("two"),
+                None,
+                Some("four"),
+            ])),
+        ],
+    )
+    .unwrap();
+
+    let mut ctx = ExecutionContext::new();
+    for name in &["numbers", "numbers_copy", "other"] {
+        let table = MemTable::try_new(schema.clone(), vec![vec![batch.clone()]]).unwrap();
+        ctx.register_table(name, Box::new(table));
+    }
+    ctx
+}
+
+async fn start_server() -> FlightSqlClient {
+    let listener = tokio::net::TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+        .await
+        .unwrap();
+    let addr = listener.local_addr().unwrap();
+    let service = FlightSqlServer::new(DataFusionFlightSqlService::new(test_context()));
+    tokio::spawn(async move {
+        let incoming = stream::unfold(listener, |mut listener| async move {
+            let stream = listener.accept().await.map(|(stream, _)| stream);
+            Some((stream, listener))
+        });
+        Server::builder()
+            .add_service(service.into_service())
+            .serve_with_incoming(incoming)
+            .await
+            .unwrap();
+    });
+
+    FlightSqlClient::connect(format!("http://{}", addr))
+        .await
+        .unwrap()
+}
+
+/// Fetch and concatenate the results of every endpoint of `info`
+async fn fetch(client: &mut FlightSqlClient, info: FlightInfo) -> Vec<RecordBatch> {
+    let mut batches = vec![];
+    for endpoint in info.endpoint {
+        let stream = client.do_get(endpoint.ticket.unwrap()).await.unwrap();
+        let mut results: Vec<RecordBatch> = stream.try_collect().await.unwrap();
+        batches.append(&mut results);
+    }
+    batches
+}
+
+fn string_column(batches: &[RecordBatch], column: usize) -> Vec<Option<String>> {
+    batches
+        .iter()
+        .flat_map(|batch| {
+            let array = batch
+                .column(column)
+                .as_any()
+                .downcast_ref::<StringArray>()
+                .unwrap();
+            (0..array.len())
+                .map(|i| {
+                    if array.is_null(i) {
+                        None
+                    } else {
+                        Some(array.value(i).to_string())
+                    }
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+fn strings(values: &[&str]) -> Vec<Option<String>> {
+    values.iter().map(|s| Some(s.to_string())).collect()
+}
+
+#[tokio::test]
+async fn statement_query() {
+    let mut client = start_server().await;
+
+    let info = client
+        .execute("SELECT id, name FROM numbers WHERE id > 1".to_string())
+        .await
+        .unwrap();
+    let schema = Schema::try_from(&info).unwrap();
+    assert_eq!(schema.field(0).name(), "id");
+    assert_eq!(schema.field(1).name(), "name");
+
+    let batches = fetch(&mut client, info).await;
+    assert_eq!(batches[0].schema().as_ref(), &schema);
+    let rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+    assert_eq!(rows, 3);
+    let mut names = string_column(&batches, 1);
+    names.sort();
+    assert_eq!(
+        names,
+        vec![None, Some("four".to_string()), Some("two".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn statement_query_errors() {
+    let mut client = start_server().await;
+
+    let err = client
+        .execute("SELECT * FROM missing".to_string())
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("missing"), "{}", err);
+
+    let err = client.execute("SELEC oops".to_string()).await.unwrap_err();
+    assert!(err.to_string().contains("SELEC"), "{}", err);
+}
+
+#[tokio::test]
+async fn prepared_statement() {
+    let mut client = start_server().await;
+
+    let statement = client
+        .prepare("SELECT COUNT(id) FROM numbers".to_string())
+        .await
+        .unwrap();
+    assert_eq!(statement.dataset_schema().unwrap().fields().len(), 1);
+    assert!(statement.parameter_schema().is_none());
+
+    // a prepared statement can be executed repeatedly
+    for _ in 0..2 {
+        let info = statement.execute(&mut client).await.unwrap();
+        let batches = fetch(&mut client, info).await;
+        assert_eq!(batches.len(), 1);
+        assert_eq!(batches[0].num_rows(), 1);
+    }
+
+    let closed = statement.clone();
+    statement.close(&mut client).await.unwrap();
+    assert!(closed.execute(&mut client).await.is_err());
+}
+
+#[tokio::test]
+async fn catalog_metadata() {
+    let mut client = start_server().await;
+
+    let info = client.get_catalogs().await.unwrap();
+    assert_eq!(
+        Schema::try_from(&info).unwrap(),
+        CommandGetCatalogs::schema()
+    );
+    let batches = fetch(&mut client, info).await;
+    assert_eq!(string_column(&batches, 0), strings(&["datafusion"]));
+
+    let info = client
+        .get_db_schemas(CommandGetDbSchemas {
+            catalog: "datafusion".to_string(),
+            db_schema_filter_pattern: "pub%".to_string(),
+        })
+        .await
+        .unwrap();
+    let batches = fetch(&mut client, info).await;
+    assert_eq!(string_column(&batches, 1), strings(&["public"]));
+
+    let info = client.get_table_types().await.unwrap();
+    let batches = fetch(&mut client, info).await;
+    assert_eq!(string_column(&batches, 0), strings(&["TABLE"]));
+}
+
+#[tokio::test]
+async fn get_tables() {
+    let mut client = start_server().await;
+
+    let info = client
+        .get_tables(CommandGetTables::default())
+        .await
+        .unwrap();
+    let batches = fetch(&mut client, info).await;
+    assert_eq!(batches[0].num_columns(), 4);
+    assert_eq!(
+        string_column(&batches, 2),
+        strings(&["numbers", "numbers_copy", "other"])
+    );
+
+    let info = client
+        .get_tables(CommandGetTables {
+            table_name_filter_pattern: "numbers%".to_string(),
+            include_schema: true,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let batches = fetch(&mut client, info).await;
+    assert_eq!(
+        string_column(&batches, 2),
+        strings(&["numbers", "numbers_copy"])
+    );
+    let schemas = batches[0]
+        .column(4)
+        .as_any()
+        .downcast_ref::<BinaryArray>()
+        .unwrap();
+    let schema = schema_from_ipc_message(schemas.value(0)).unwrap();
+    assert_eq!(schema.field(0).name(), "id");
+
+    let info = client
+        .get_tables(CommandGetTables {
+            table_types: vec!["VIEW".to_string()],
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let batches = fetch(&mut client, info).await;
+    assert_eq!(batches[0].num_rows(), 0);
+}
+
+#[tokio::test]
+async fn statement_update() {
+    let mut client = start_server().await;
+
+    let mut file = tempfile::Builder::new().suffix(".csv").tempfile().unwrap();
+    writeln!(file, "a,b\n1,x\n2,y").unwrap();
+    let query = format!(
+        "CREATE EXTERNAL TABLE csv_table (a INT NOT NULL, b VARCHAR NOT NULL) \
+         STORED AS CSV WITH HEADER ROW LOCATION '{}'",
+        file.path().display()
+    );
+    assert_eq!(client.execute_update(query).await.unwrap(), -1);
+
+    let info = client
+        .execute("SELECT b FROM csv_table".to_string())
+        .await
+        .unwrap();
+    let batches = fetch(&mut client, info).await;
+    assert_eq!(string_column(&batches, 0), strings(&["x", "y"]));
+}
@@ -1684,6 +1684,127 @@ async fn to_timstamp() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn extract_date_part() -> Result<()> {
+    let mut ctx = ExecutionContext::new();
+    ctx.register_table("ts_data", make_timestamp_nano_table()?);
+
+    let sql = "SELECT EXTRACT(YEAR FROM ts), EXTRACT(HOUR FROM ts), date_part('dow', ts), date_part('week', ts) FROM ts_data";
+    let actual = execute(&mut ctx, sql).await;
+
+    let expected = vec![
+        vec!["2020", "13", "2", "37"],
+        vec!["2020", "12", "2", "37"],
+        vec!["2020", "11", "2", "37"],
+    ];
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[tokio::test]
+async fn query_and_or_with_nulls() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![
+        Field::new("c1", DataType::Boolean, true),
+        Field::new("c2", DataType::Boolean, true),
+    ]));
+
+    let data = RecordBatch::try_new(
+        schema.clone(),
+        vec![
+            Arc::new(BooleanArray::from(vec![
+                None,
+                None,
+                Some(true),
+                Some(false),
+            ])),
+            Arc::new(BooleanArray::from(vec![
+                Some(true),
+                Some(false),
+                None,
+                None,
+            ])),
+        ],
+    )?;
+
+    let table = MemTable::try_new(schema, vec![vec![data]])?;
+
+    let mut ctx = ExecutionContext::new();
+    ctx.register_table("test", Box::new(table));
+    let sql = "SELECT c1 AND c2, c1 OR c2 FROM test";
+    let actual = execute(&mut ctx, sql).await;
+    let expected = vec![
+        vec!["NULL", "true"],
+        vec!["false", "NULL"],
+        vec!["NULL", "true"],
+        vec!["false", "NULL"],
+    ];
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[tokio::test]
+async fn query_regexp_functions() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![Field::new("c1", DataType::Utf8, true)]));
+
+    let data = RecordBatch::try_new(
+        schema.clone(),
+        vec![Arc::new(StringArray::from(vec![
+            Some("key=value"),
+            Some("Key=Other"),
+            None,
+            Some("plain"),
+        ]))],
+    )?;
+
+    let table = MemTable::try_new(schema, vec![vec![data]])?;
+
+    let mut ctx = ExecutionContext::new();
+    ctx.register_table("test", Box::new(table));
+    let sql = "SELECT regexp_replace(c1, '(\\w+)=(\\w+)', '\\2=\\1'), \
+        regexp_replace(c1, '[aeiou]', '_', 'g'), \
+        regexp_match(c1, 'key=(\\w+)', 'i') \
+        FROM test";
+    let actual = execute(&mut ctx, sql).await;
+    let expected = vec![
+        vec!["value=key", "k_y=v_l__", "[value]"],
+        vec!["Other=Key", "K_y=Oth_r", "[Other]"],
+        vec!["NULL", "NULL", "NULL"],
+        vec!["plain", "pl__n", "NULL"],
+    ];
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
+#[tokio::test]
+async fn query_regex_match_operators() -> Result<()> {
+    let schema = Arc::new(Schema::new(vec![Field::new("c1", DataType::Utf8, true)]));
+
+    let data = RecordBatch::try_new(
+        schema.clone(),
+        vec![Arc::new(StringArray::from(vec![
+            Some("abc"),
+            Some("Abc"),
+            None,
+            Some("xyz"),
+        ]))],
+    )?;
+
+    let table = MemTable::try_new(schema, vec![vec![data]])?;
+
+    let mut ctx = ExecutionContext::new();
+    ctx.register_table("test", Box::new(table));
+    let sql = "SELECT c1 ~ '^a', c1 ~* '^a', c1 !~ '^a', c1 !~* '^a' FROM test";
+    let actual = execute(&mut ctx, sql).await;
+    let expected = vec![
+        vec!["true", "true", "false", "false"],
+        vec!["false", "true", "true", "false"],
+        vec!["NULL", "NULL", "NULL", "NULL"],
+        vec!["false", "false", "true", "true"],
+    ];
+    assert_eq!(expected, actual);
+    Ok(())
+}
+
 #[tokio::test]
 async fn query_is_null() -> Result<()> {
     let schema = Arc::new(Schema::new(vec![Field::new("c1", DataType::Float64, true)]));
@@ -0,0 +1,594 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Contains an asynchronous reader which reads parquet data from an [`AsyncRead`] +
+//! [`AsyncSeek`] source into a [`Stream`] of arrow [`RecordBatch`]es.
+//!
+//! The footer is fetched first, after which only the byte ranges of the column chunks
+//! selected by the projection are read, one row group at a time. Column chunks that are
+//! close to each other in the file are fetched with a single read.
+//!
+//! ```rust, no_run
+//! # async fn run() -> parquet::errors::Result<()> {
+//! use futures::TryStreamExt;
+//! use parquet::arrow::async_reader::ParquetRecordBatchStreamBuilder;
+//!
+//! let file = tokio::fs::File::open("parquet.file").await?;
+//! let stream = ParquetRecordBatchStreamBuilder::new(file)
+//!     .await?
+//!     .with_projection(vec![0, 2])
+//!     .with_batch_size(1024)
+//!     .build()?;
+//!
+//! let batches: Vec<_> = stream.try_collect().await?;
+//! println!("Read {} record batches", batches.len());
+//! # Ok(())
+//! # }
+//! ```
+
+use std::collections::VecDeque;
+use std::io::{Cursor, SeekFrom};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use arrow::datatypes::SchemaRef;
+use arrow::record_batch::RecordBatch;
+use futures::future::{BoxFuture, FutureExt};
+use futures::ready;
+use futures::stream::Stream;
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncSeek, AsyncSeekExt};
+
+use crate::arrow::arrow_reader::{
+    ArrowReader, ParquetFileArrowReader, ParquetRecordBatchReader,
+};
+use crate::arrow::schema::parquet_to_arrow_schema_by_columns;
+use crate::errors::{ParquetError, Result};
+use crate::file::footer::{decode_footer, parse_metadata_buffer};
+use crate::file::metadata::ParquetMetaData;
+use crate::file::reader::{ChunkReader, Length};
+use crate::file::serialized_reader::{SerializedFileReader, SliceableCursor};
+use crate::file::{DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE};
+
+/// The default number of rows in each record batch returned by the stream
+pub const DEFAULT_BATCH_SIZE: usize = 1024;
+
+/// The default maximum gap, in bytes, between two column chunks for them to be fetched
+/// with a single read
+pub const DEFAULT_COALESCE_DISTANCE: u64 = 1024 * 1024;
+
+/// Reads the footer and [`ParquetMetaData`] from the end of an asynchronous source.
+pub async fn read_metadata<T>(input: &mut T) -> Result<ParquetMetaData>
+where
+    T: AsyncRead + AsyncSeek + Unpin,
+{
+    // check file is large enough to hold footer
+    let file_size = input.seek(SeekFrom::End(0)).await?;
+    if file_size < (FOOTER_SIZE as u64) {
+        return Err(general_err!(
+            "Invalid Parquet file. Size is smaller than footer"
+        ));
+    }
+
+    // read up to DEFAULT_FOOTER_READ_SIZE bytes from the end, which usually covers the
+    // whole metadata, and only issue a second read if it does not
+    let default_end_len = std::cmp::min(DEFAULT_FOOTER_READ_SIZE as u64, file_size);
+    input
+        .seek(SeekFrom::Start(file_size - default_end_len))
+        .await?;
+    let mut buf = vec![0; default_end_len as usize];
+    input.read_exact(&mut buf).await?;
+
+    let metadata_len = decode_footer(&buf[buf.len() - FOOTER_SIZE..])?;
+    let footer_metadata_len = (FOOTER_SIZE + metadata_len) as u64;
+    if footer_metadata_len > file_size {
+        return Err(general_err!(
+            "Invalid Parquet file. Metadata start is less than zero ({})",
+            file_size as i64 - footer_metadata_len as i64
+        ));
+    }
+
+    if footer_metadata_len > default_end_len {
+        let mut missing = vec![0; (footer_metadata_len - default_end_len) as usize];
+        input
+            .seek(SeekFrom::Start(file_size - footer_metadata_len))
+            .await?;
+        input.read_exact(&mut missing).await?;
+        missing.extend_from_slice(&buf);
+        buf = missing;
+    }
+
+    let metadata_start = buf.len() - footer_metadata_len as usize;
+    let metadata_end = buf.len() - FOOTER_SIZE;
+    parse_metadata_buffer(Cursor::new(&buf[metadata_start..metadata_end]))
+}
+
+/// A builder used to configure and construct a [`ParquetRecordBatchStream`].
+pub struct ParquetRecordBatchStreamBuilder<T> {
+    input: T,
+    metadata: Arc<ParquetMetaData>,
+    batch_size: usize,
+    row_groups: Option<Vec<usize>>,
+    projection: Option<Vec<usize>>,
+    coalesce_distance: u64,
+}
+
+impl<T: AsyncRead + AsyncSeek + Unpin + Send + 'static>
+    ParquetRecordBatchStreamBuilder<T>
+{
+    /// Creates a new builder by reading the metadata from the end of `input`.
+    pub async fn new(mut input: T) -> Result<Self> {
+        let metadata = read_metadata(&mut input).await?;
+        Ok(Self::new_with_metadata(input, Arc::new(metadata)))
+    }
+
+    /// Creates a new builder from `input` and metadata that has already been read,
+    /// e.g. from a cache.
+    pub fn new_with_metadata(input: T, metadata: Arc<ParquetMetaData>) -> Self {
+        Self {
+            input,
+            metadata,
+            batch_size: DEFAULT_BATCH_SIZE,
+            row_groups: None,
+            projection: None,
+            coalesce_distance: DEFAULT_COALESCE_DISTANCE,
+        }
+    }
+
+    /// Returns the metadata of the parquet file.
+    pub fn metadata(&self) -> &Arc<ParquetMetaData> {
+        &self.metadata
+    }
+
+    /// Returns the arrow schema of the record batches that will be produced, taking
+    /// the projection into account.
+    pub fn schema(&self) -> Result<SchemaRef> {
+        let file_metadata = self.metadata.file_metadata();
+        let schema = parquet_to_arrow_schema_by_columns(
+            file_metadata.schema_descr(),
+            self.column_indices(),
+            file_metadata.key_value_metadata(),
+        )?;
+        Ok(Arc::new(schema))
+    }
+
+    /// Sets the number of rows in each record batch, defaults to
+    /// [`DEFAULT_BATCH_SIZE`].
+    pub fn with_batch_size(self, batch_size: usize) -> Self {
+        Self { batch_size, ..self }
+    }
+
+    /// Only reads the row groups with the given indices, in the given order.
+    pub fn with_row_groups(self, row_groups: Vec<usize>) -> Self {
+        Self {
+            row_groups: Some(row_groups),
+            ..self
+        }
+    }
+
+    /// Only reads the leaf columns with the given indices.
+    pub fn with_projection(self, projection: Vec<usize>) -> Self {
+        Self {
+            projection: Some(projection),
+            ..self
+        }
+    }
+
+    /// Sets the maximum gap, in bytes, between two column chunks for them to be
+    /// fetched with a single read, defaults to [`DEFAULT_COALESCE_DISTANCE`].
+    pub fn with_coalesce_distance(self, coalesce_distance: u64) -> Self {
+        Self {
+            coalesce_distance,
+            ..self
+        }
+    }
+
+    /// Validates the configuration and builds the [`ParquetRecordBatchStream`].
+    pub fn build(self) -> Result<ParquetRecordBatchStream<T>> {
+        let num_columns = self.metadata.file_metadata().schema_descr().num_columns();
+        let columns = self.column_indices();
+        if let Some(col) = columns.iter().find(|col| **col >= num_columns) {
+            return Err(ParquetError::IndexOutOfBound(*col, num_columns));
+        }
+
+        let num_row_groups = self.metadata.num_row_groups();
+        let row_groups = match &self.row_groups {
+            Some(row_groups) => {
+                if let Some(rg) = row_groups.iter().find(|rg| **rg >= num_row_groups) {
+                    return Err(ParquetError::IndexOutOfBound(*rg, num_row_groups));
+                }
+                row_groups.iter().copied().collect()
+            }
+            None => (0..num_row_groups).collect(),
+        };
+
+        let schema = self.schema()?;
+        Ok(ParquetRecordBatchStream {
+            metadata: self.metadata,
+            schema,
+            batch_size: self.batch_size,
+            columns,
+            row_groups,
+            coalesce_distance: self.coalesce_distance,
+            input: Some(self.input),
+            state: StreamState::Init,
+        })
+    }
+
+    fn column_indices(&self) -> Vec<usize> {
+        match &self.projection {
+            Some(projection) => projection.clone(),
+            None => {
+                (0..self.metadata.file_metadata().schema_descr().num_columns()).collect()
+            }
+        }
+    }
+}
+
+enum StreamState<T> {
+    /// Ready to fetch the next row group, if any
+    Init,
+    /// Fetching the column chunks of a row group from the input
+    Reading(BoxFuture<'static, Result<(T, ColumnChunkData)>>),
+    /// Decoding record batches from the fetched column chunks
+    Decoding(ParquetRecordBatchReader),
+    /// An error occurred, the stream is terminated
+    Error,
+}
+
+/// A [`Stream`] of [`RecordBatch`]es read asynchronously from a parquet file.
+///
+/// Column chunks are fetched one row group at a time and decoded synchronously once
+/// all of them are available.
+pub struct ParquetRecordBatchStream<T> {
+    metadata: Arc<ParquetMetaData>,
+    schema: SchemaRef,
+    batch_size: usize,
+    columns: Vec<usize>,
+    row_groups: VecDeque<usize>,
+    coalesce_distance: u64,
+    input: Option<T>,
+    state: StreamState<T>,
+}
+
+impl<T> ParquetRecordBatchStream<T> {
+    /// Returns the arrow schema of the record batches produced by this stream.
+    pub fn schema(&self) -> SchemaRef {
+        self.schema.clone()
+    }
+}
+
+impl<T: AsyncRead + AsyncSeek + Unpin + Send + 'static> ParquetRecordBatchStream<T> {
+    /// Starts fetching the projected column chunks of the given row group.
+    fn fetch_row_group(
+        &mut self,
+        row_group_idx: usize,
+    ) -> BoxFuture<'static, Result<(T, ColumnChunkData)>> {
+        let mut input = self.input.take().expect("input taken by another read");
+        let row_group = self.metadata.row_group(row_group_idx);
+        let chunk_ranges: Vec<(u64, u64)> = self
+            .columns
+            .iter()
+            .map(|col| {
+                let (start, len) = row_group.column(*col).byte_range();
+                (start, start + len)
+            })
+            .collect();
+        let ranges = coalesce_ranges(chunk_ranges, self.coalesce_distance);
+
+        async move {
+            let mut chunks = Vec::with_capacity(ranges.len());
+            for (start, end) in ranges {
+                let mut buf = vec![0; (end - start) as usize];
+                input.seek(SeekFrom::Start(start)).await?;
+                input.read_exact(&mut buf).await?;
+                chunks.push((start, SliceableCursor::new(buf)));
+            }
+            Ok((input, ColumnChunkData { chunks }))
+        }
+        .boxed()
+    }
+
+    /// Creates a synchronous record batch reader over fetched column chunks.
+    fn decode_row_group(
+        &self,
+        row_group_idx: usize,
+        data: ColumnChunkData,
+    ) -> Result<ParquetRecordBatchReader> {
+        let metadata = ParquetMetaData::new(
+            self.metadata.file_metadata().clone(),
+            vec![self.metadata.row_group(row_group_idx).clone()],
+        );
+        let file_reader = SerializedFileReader::new_with_metadata(data, metadata);
+        let mut arrow_reader = ParquetFileArrowReader::new(Arc::new(file_reader));
+        arrow_reader.get_record_reader_by_columns(self.columns.clone(), self.batch_size)
+    }
+}
+
+impl<T: AsyncRead + AsyncSeek + Unpin + Send + 'static> Stream
+    for ParquetRecordBatchStream<T>
+{
+    type Item = Result<RecordBatch>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        loop {
+            match &mut self.state {
+                StreamState::Init => match self.row_groups.front().copied() {
+                    Some(row_group_idx) => {
+                        let fut = self.fetch_row_group(row_group_idx);
+                        self.state = StreamState::Reading(fut);
+                    }
+                    None => return Poll::Ready(None),
+                },
+                StreamState::Reading(fut) => {
+                    let result = ready!(fut.poll_unpin(cx));
+                    let row_group_idx = self.row_groups.pop_front().unwrap();
+                    let reader = result.and_then(|(input, data)| {
+                        self.input = Some(input);
+                        self.decode_row_group(row_group_idx, data)
+                    });
+                    match reader {
+                        Ok(reader) => self.state = StreamState::Decoding(reader),
+                        Err(e) => {
+                            self.state = StreamState::Error;
+                            return Poll::Ready(Some(Err(e)));
+                        }
+                    }
+                }
+                StreamState::Decoding(reader) => match reader.next() {
+                    Some(Ok(batch)) => return Poll::Ready(Some(Ok(batch))),
+                    Some(Err(e)) => {
+                        self.state = StreamState::Error;
+                        return Poll::Ready(Some(Err(e.into())));
+                    }
+                    None => self.state = StreamState::Init,
+                },
+                StreamState::Error => return Poll::Ready(None),
+            }
+        }
+    }
+}
+
+/// Sorts `(start, end)` byte ranges and merges those separated by at most
+/// `max_gap` bytes.
+fn coalesce_ranges(mut ranges: Vec<(u64, u64)>, max_gap: u64) -> Vec<(u64, u64)> {
+    ranges.sort_unstable();
+    let mut coalesced: Vec<(u64, u64)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match coalesced.last_mut() {
+            Some(last) if start <= last.1 + max_gap => last.1 = last.1.max(end),
+            _ => coalesced.push((start, end)),
+        }
+    }
+    coalesced
+}
+
+/// The byte ranges fetched for a row group, exposed as a [`ChunkReader`] so that they
+/// can be decoded by a [`SerializedFileReader`].
+struct ColumnChunkData {
+    /// The fetched ranges and their offsets in the file, sorted by offset
+    chunks: Vec<(u64, SliceableCursor)>,
+}
+
+impl Length for ColumnChunkData {
+    fn len(&self) -> u64 {
+        self.chunks
+            .last()
+            .map(|(offset, chunk)| offset + chunk.len())
+            .unwrap_or(0)
+    }
+}
+
+impl ChunkReader for ColumnChunkData {
+    type T = SliceableCursor;
+
+    fn get_read(&self, start: u64, length: usize) -> Result<Self::T> {
+        self.chunks
+            .iter()
+            .find(|(offset, chunk)| {
+                *offset <= start && start + length as u64 <= offset + chunk.len()
+            })
+            .ok_or_else(|| {
+                general_err!(
+                    "Byte range {}..{} was not fetched",
+                    start,
+                    start + length as u64
+                )
+            })
+            .and_then(|(offset, chunk)| Ok(chunk.slice(start - offset, length)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use arrow::array::{Int32Array, StringArray};
+    use arrow::datatypes::{DataType, Field, Schema};
+    use futures::TryStreamExt;
+
+    use crate::arrow::ArrowWriter;
+    use crate::file::properties::WriterProperties;
+    use crate::file::writer::InMemoryWriteableCursor;
+    use crate::util::test_common::get_temp_filename;
+
+    fn test_batches() -> Vec<RecordBatch> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("a", DataType::Int32, false),
+            Field::new("b", DataType::Utf8, true),
+            Field::new("c", DataType::Int32, true),
+        ]));
+        (0..3)
+            .map(|i| {
+                let a = Int32Array::from(vec![i, i + 1, i + 2, i + 3]);
+                let b = StringArray::from(vec![Some("x"), None, Some("y"), Some("z")]);
+                let c = Int32Array::from(vec![None, Some(i * 10), None, Some(i)]);
+                RecordBatch::try_new(
+                    schema.clone(),
+                    vec![Arc::new(a), Arc::new(b), Arc::new(c)],
+                )
+                .unwrap()
+            })
+            .collect()
+    }
+
+    /// Writes each batch as its own row group
+    fn write_parquet(batches: &[RecordBatch]) -> Vec<u8> {
+        let cursor = InMemoryWriteableCursor::default();
+        {
+            let props = WriterProperties::builder().build();
+            let mut writer =
+                ArrowWriter::try_new(cursor.clone(), batches[0].schema(), Some(props))
+                    .unwrap();
+            for batch in batches {
+                writer.write(batch).unwrap();
+            }
+            writer.close().unwrap();
+        }
+        cursor.into_inner().unwrap()
+    }
+
+    fn assert_batches_eq(expected: &[RecordBatch], actual: &[RecordBatch]) {
+        assert_eq!(expected.len(), actual.len());
+        for (expected, actual) in expected.iter().zip(actual) {
+            assert_eq!(expected.num_columns(), actual.num_columns());
+            for i in 0..expected.num_columns() {
+                assert_eq!(expected.column(i).data(), actual.column(i).data());
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_in_memory() {
+        let batches = test_batches();
+        let data = write_parquet(&batches);
+
+        let builder = ParquetRecordBatchStreamBuilder::new(Cursor::new(data))
+            .await
+            .unwrap();
+        assert_eq!(builder.metadata().num_row_groups(), 3);
+        assert_eq!(builder.schema().unwrap(), batches[0].schema());
+
+        let stream = builder.build().unwrap();
+        let actual: Vec<RecordBatch> = stream.try_collect().await.unwrap();
+        assert_batches_eq(&batches, &actual);
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_file_projection_and_row_groups() {
+        let batches = test_batches();
+        let path = get_temp_filename();
+        std::fs::write(&path, write_parquet(&batches)).unwrap();
+
+        let file = tokio::fs::File::open(&path).await.unwrap();
+        let stream = ParquetRecordBatchStreamBuilder::new(file)
+            .await
+            .unwrap()
+            .with_projection(vec![0, 2])
+            .with_row_groups(vec![2, 0])
+            // never merge chunks, to exercise a read per column chunk
+            .with_coalesce_distance(0)
+            .build()
+            .unwrap();
+        assert_eq!(stream.schema().fields().len(), 2);
+
+        let actual: Vec<RecordBatch> = stream.try_collect().await.unwrap();
+        let expected: Vec<RecordBatch> = vec![&batches[2], &batches[0]]
+            .into_iter()
+            .map(|batch| {
+                let schema = Schema::new(vec![
+                    batch.schema().field(0).clone(),
+                    batch.schema().field(2).clone(),
+                ]);
+                RecordBatch::try_new(
+                    Arc::new(schema),
+                    vec![batch.column(0).clone(), batch.column(2).clone()],
+                )
+                .unwrap()
+            })
+            .collect();
+        assert_batches_eq(&expected, &actual);
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_batch_size() {
+        let batches = test_batches();
+        let data = write_parquet(&batches);
+
+        let stream = ParquetRecordBatchStreamBuilder::new(Cursor::new(data))
+            .await
+            .unwrap()
+            .with_batch_size(3)
+            .build()
+            .unwrap();
+        let actual: Vec<RecordBatch> = stream.try_collect().await.unwrap();
+        let num_rows: Vec<usize> = actual.iter().map(|b| b.num_rows()).collect();
+        assert_eq!(num_rows, vec![3, 1, 3, 1, 3, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_invalid_indices() {
+        let data = write_parquet(&test_batches());
+
+        let builder = ParquetRecordBatchStreamBuilder::new(Cursor::new(data.clone()))
+            .await
+            .unwrap();
+        let err = builder.with_projection(vec![3]).build().err().unwrap();
+        assert_eq!(err, ParquetError::IndexOutOfBound(3, 3));
+
+        let builder = ParquetRecordBatchStreamBuilder::new(Cursor::new(data))
+            .await
+            .unwrap();
+        let err = builder.with_row_groups(vec![5]).build().err().unwrap();
+        assert_eq!(err, ParquetError::IndexOutOfBound(5, 3));
+    }
+
+    #[tokio::test]
+    async fn test_async_reader_corrupt_footer() {
+        let err = ParquetRecordBatchStreamBuilder::new(Cursor::new(vec![0u8; 4]))
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(
+            err,
+            general_err!("Invalid Parquet file. Size is smaller than footer")
+        );
+
+        let err = ParquetRecordBatchStreamBuilder::new(Cursor::new(vec![1u8; 16]))
+            .await
+            .err()
+            .unwrap();
+        assert_eq!(err, general_err!("Invalid Parquet file. Corrupt footer"));
+    }
+
+    #[test]
+    fn test_coalesce_ranges() {
+        let ranges = vec![(100, 200), (0, 50), (60, 95), (500, 600)];
+        assert_eq!(
+            coalesce_ranges(ranges.clone(), 5),
+            vec![(0, 50), (60, 200), (500, 600)]
+        );
+        assert_eq!(coalesce_ranges(ranges.clone(), 300), vec![(0, 600)]);
+        assert_eq!(
+            coalesce_ranges(ranges, 0),
+            vec![(0, 50), (60, 95), (100, 200), (500, 600)]
+        );
+    }
+}
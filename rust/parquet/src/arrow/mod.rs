@@ -52,6 +52,8 @@
 pub(in crate::arrow) mod array_reader;
 pub mod arrow_reader;
 pub mod arrow_writer;
+#[cfg(feature = "async")]
+pub mod async_reader;
 pub(in crate::arrow) mod converter;
 pub mod levels;
 pub(in crate::arrow) mod record_reader;
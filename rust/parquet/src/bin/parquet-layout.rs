@@ -0,0 +1,533 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Binary file to print the physical layout of a Parquet file: its row groups, column
+//! chunks and pages together with their offsets, sizes, encodings and statistics.
+//!
+//! # Install
+//!
+//! `parquet-layout` requires the `cli` feature and can be installed using `cargo`:
+//! ```
+//! cargo install parquet --features cli
+//! ```
+//! After this `parquet-layout` should be globally available:
+//! ```
+//! parquet-layout XYZ.parquet
+//! ```
+//!
+//! The binary can also be built from the source code and run as follows:
+//! ```
+//! cargo run --features cli --bin parquet-layout XYZ.parquet
+//! ```
+//!
+//! # Usage
+//!
+//! ```
+//! parquet-layout <file-path> [format]
+//! ```
+//! where `file-path` is the path to a Parquet file and `format` is the optional output
+//! format, either `text` (default behaviour when not provided) or `json`.
+//!
+//! Pages of encrypted column chunks are not listed.
+
+extern crate parquet;
+
+use std::{
+    cell::Cell,
+    env,
+    fs::File,
+    io::{self, Read},
+    path::Path,
+    process,
+    rc::Rc,
+};
+
+use parquet::{
+    basic::{Encoding, Type},
+    data_type::{ByteArray, FixedLenByteArray, Int96},
+    errors::Result,
+    file::{
+        metadata::{ColumnChunkMetaData, ParquetMetaData, RowGroupMetaData},
+        reader::{ChunkReader, FileReader, SerializedFileReader},
+        serialized_reader::SerializedPageReader,
+        statistics::{self, Statistics},
+    },
+};
+use parquet_format::{PageHeader, PageType};
+use serde_json::{json, Value};
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 2 && args.len() != 3 {
+        println!("Usage: parquet-layout <file-path> [format]");
+        process::exit(1);
+    }
+    let path = Path::new(&args[1]);
+    let json = match args.get(2).map(|s| s.as_str()) {
+        None | Some("text") => false,
+        Some("json") => true,
+        Some(other) => panic!(
+            "Error when reading value for [format] (expected either 'text' or 'json'): {}",
+            other
+        ),
+    };
+    let file = match File::open(path) {
+        Err(e) => panic!("Error when opening file {}: {}", path.display(), e),
+        Ok(f) => f,
+    };
+    let layout = match read_layout(file) {
+        Err(e) => panic!("Error when reading layout of Parquet file: {}", e),
+        Ok(layout) => layout,
+    };
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&layout.to_json()).unwrap()
+        );
+    } else {
+        println!("Layout for file: {}", &args[1]);
+        println!();
+        layout.print(&mut io::stdout()).unwrap();
+    }
+}
+
+/// Layout of a file, built from its [`ParquetMetaData`] and the headers of its pages.
+struct FileLayout {
+    version: i32,
+    num_rows: i64,
+    created_by: Option<String>,
+    key_value_metadata: Vec<(String, Option<String>)>,
+    row_groups: Vec<RowGroupLayout>,
+}
+
+struct RowGroupLayout {
+    num_rows: i64,
+    total_byte_size: i64,
+    columns: Vec<ColumnChunkLayout>,
+}
+
+struct ColumnChunkLayout {
+    path: String,
+    physical_type: Type,
+    compression: String,
+    encodings: Vec<Encoding>,
+    file_offset: i64,
+    data_page_offset: i64,
+    dictionary_page_offset: Option<i64>,
+    index_page_offset: Option<i64>,
+    num_values: i64,
+    compressed_size: i64,
+    uncompressed_size: i64,
+    statistics: Option<Statistics>,
+    encrypted: bool,
+    pages: Vec<PageLayout>,
+}
+
+struct PageLayout {
+    offset: u64,
+    header_size: u64,
+    header: PageHeader,
+    statistics: Option<Statistics>,
+}
+
+fn read_layout(file: File) -> Result<FileLayout> {
+    let reader = SerializedFileReader::new(file.try_clone()?)?;
+    let metadata: &ParquetMetaData = reader.metadata();
+    let file_metadata = metadata.file_metadata();
+
+    let row_groups = metadata
+        .row_groups()
+        .iter()
+        .map(|row_group| read_row_group_layout(&file, row_group))
+        .collect::<Result<_>>()?;
+
+    Ok(FileLayout {
+        version: file_metadata.version(),
+        num_rows: file_metadata.num_rows(),
+        created_by: file_metadata.created_by().clone(),
+        key_value_metadata: file_metadata
+            .key_value_metadata()
+            .iter()
+            .flatten()
+            .map(|kv| (kv.key.clone(), kv.value.clone()))
+            .collect(),
+        row_groups,
+    })
+}
+
+fn read_row_group_layout(
+    file: &File,
+    row_group: &RowGroupMetaData,
+) -> Result<RowGroupLayout> {
+    let columns = row_group
+        .columns()
+        .iter()
+        .map(|column| read_column_chunk_layout(file, column))
+        .collect::<Result<_>>()?;
+    Ok(RowGroupLayout {
+        num_rows: row_group.num_rows(),
+        total_byte_size: row_group.total_byte_size(),
+        columns,
+    })
+}
+
+fn read_column_chunk_layout(
+    file: &File,
+    column: &ColumnChunkMetaData,
+) -> Result<ColumnChunkLayout> {
+    let physical_type = column.column_type();
+    let encrypted = column.crypto_metadata().is_some();
+    let mut pages = vec![];
+
+    if !encrypted {
+        let (start, length) = column.byte_range();
+        let position = Rc::new(Cell::new(0));
+        let source = CountingReader {
+            inner: file.get_read(start, length as usize)?,
+            position: position.clone(),
+        };
+        let mut page_reader = SerializedPageReader::new(
+            source,
+            column.num_values(),
+            column.compression(),
+            physical_type,
+        )?;
+        loop {
+            let page_start = position.get();
+            let header = match page_reader.next_page_header()? {
+                Some(header) => header,
+                None => break,
+            };
+            let page_statistics = page_header_statistics(&header).cloned();
+            pages.push(PageLayout {
+                offset: start + page_start,
+                header_size: position.get()
+                    - page_start
+                    - header.compressed_page_size as u64,
+                statistics: statistics::from_thrift(physical_type, page_statistics),
+                header,
+            });
+        }
+    }
+
+    Ok(ColumnChunkLayout {
+        path: column.column_path().string(),
+        physical_type,
+        compression: column.compression().to_string(),
+        encodings: column.encodings().clone(),
+        file_offset: column.file_offset(),
+        data_page_offset: column.data_page_offset(),
+        dictionary_page_offset: column.dictionary_page_offset(),
+        index_page_offset: column.index_page_offset(),
+        num_values: column.num_values(),
+        compressed_size: column.compressed_size(),
+        uncompressed_size: column.uncompressed_size(),
+        statistics: column.statistics().cloned(),
+        encrypted,
+        pages,
+    })
+}
+
+/// Reader that keeps track of the number of bytes read, to find the offset of pages.
+struct CountingReader<R: Read> {
+    inner: R,
+    position: Rc<Cell<u64>>,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.position.set(self.position.get() + read as u64);
+        Ok(read)
+    }
+}
+
+fn page_header_statistics(header: &PageHeader) -> Option<&parquet_format::Statistics> {
+    if let Some(ref data_page_header) = header.data_page_header {
+        data_page_header.statistics.as_ref()
+    } else if let Some(ref data_page_header) = header.data_page_header_v2 {
+        data_page_header.statistics.as_ref()
+    } else {
+        None
+    }
+}
+
+fn page_type_name(page_type: PageType) -> &'static str {
+    match page_type {
+        PageType::DataPage => "DATA_PAGE",
+        PageType::IndexPage => "INDEX_PAGE",
+        PageType::DictionaryPage => "DICTIONARY_PAGE",
+        PageType::DataPageV2 => "DATA_PAGE_V2",
+    }
+}
+
+/// Returns the statistics as JSON, with the min and max values as JSON numbers or
+/// booleans where possible and as strings otherwise.
+fn statistics_to_json(stats: &Statistics) -> Value {
+    macro_rules! min_max {
+        ($typed:expr, $to_json:expr) => {
+            ($to_json($typed.min()), $to_json($typed.max()))
+        };
+    }
+
+    let (min, max) = if !stats.has_min_max_set() {
+        (Value::Null, Value::Null)
+    } else {
+        match stats {
+            Statistics::Boolean(typed) => min_max!(typed, |v: &bool| json!(v)),
+            Statistics::Int32(typed) => min_max!(typed, |v: &i32| json!(v)),
+            Statistics::Int64(typed) => min_max!(typed, |v: &i64| json!(v)),
+            Statistics::Float(typed) => min_max!(typed, |v: &f32| json!(v)),
+            Statistics::Double(typed) => min_max!(typed, |v: &f64| json!(v)),
+            Statistics::Int96(typed) => min_max!(typed, |v: &Int96| json!(v.to_string())),
+            Statistics::ByteArray(typed) => {
+                min_max!(typed, |v: &ByteArray| json!(v.to_string()))
+            }
+            Statistics::FixedLenByteArray(typed) => {
+                min_max!(typed, |v: &FixedLenByteArray| json!(v.to_string()))
+            }
+        }
+    };
+    json!({
+        "min": min,
+        "max": max,
+        "null_count": stats.null_count(),
+        "distinct_count": stats.distinct_count(),
+        "min_max_deprecated": stats.is_min_max_deprecated(),
+    })
+}
+
+impl FileLayout {
+    fn to_json(&self) -> Value {
+        json!({
+            "version": self.version,
+            "num_rows": self.num_rows,
+            "created_by": self.created_by,
+            "key_value_metadata": self
+                .key_value_metadata
+                .iter()
+                .map(|(key, value)| json!({ "key": key, "value": value }))
+                .collect::<Vec<_>>(),
+            "row_groups": self
+                .row_groups
+                .iter()
+                .map(RowGroupLayout::to_json)
+                .collect::<Vec<_>>(),
+        })
+    }
+
+    fn print(&self, out: &mut dyn io::Write) -> io::Result<()> {
+        writeln!(out, "version: {}", self.version)?;
+        writeln!(out, "num of rows: {}", self.num_rows)?;
+        if let Some(ref created_by) = self.created_by {
+            writeln!(out, "created by: {}", created_by)?;
+        }
+        if !self.key_value_metadata.is_empty() {
+            writeln!(out, "metadata:")?;
+            for (key, value) in &self.key_value_metadata {
+                writeln!(out, "  {}: {}", key, value.as_deref().unwrap_or("NONE"))?;
+            }
+        }
+        writeln!(out, "num of row groups: {}", self.row_groups.len())?;
+        for (i, row_group) in self.row_groups.iter().enumerate() {
+            writeln!(out)?;
+            writeln!(out, "row group {}:", i)?;
+            row_group.print(out)?;
+        }
+        Ok(())
+    }
+}
+
+impl RowGroupLayout {
+    fn to_json(&self) -> Value {
+        json!({
+            "num_rows": self.num_rows,
+            "total_byte_size": self.total_byte_size,
+            "columns": self
+                .columns
+                .iter()
+                .map(ColumnChunkLayout::to_json)
+                .collect::<Vec<_>>(),
+        })
+    }
+
+    fn print(&self, out: &mut dyn io::Write) -> io::Result<()> {
+        writeln!(out, "  num of rows: {}", self.num_rows)?;
+        writeln!(out, "  total byte size: {}", self.total_byte_size)?;
+        writeln!(out, "  num of columns: {}", self.columns.len())?;
+        for column in &self.columns {
+            column.print(out)?;
+        }
+        Ok(())
+    }
+}
+
+impl ColumnChunkLayout {
+    fn to_json(&self) -> Value {
+        json!({
+            "path": self.path,
+            "physical_type": self.physical_type.to_string(),
+            "compression": self.compression,
+            "encodings": self
+                .encodings
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<_>>(),
+            "file_offset": self.file_offset,
+            "data_page_offset": self.data_page_offset,
+            "dictionary_page_offset": self.dictionary_page_offset,
+            "index_page_offset": self.index_page_offset,
+            "num_values": self.num_values,
+            "compressed_size": self.compressed_size,
+            "uncompressed_size": self.uncompressed_size,
+            "statistics": self.statistics.as_ref().map(statistics_to_json),
+            "encrypted": self.encrypted,
+            "pages": self.pages.iter().map(PageLayout::to_json).collect::<Vec<_>>(),
+        })
+    }
+
+    fn print(&self, out: &mut dyn io::Write) -> io::Result<()> {
+        writeln!(out, "  column {}:", self.path)?;
+        writeln!(out, "    physical type: {}", self.physical_type)?;
+        writeln!(out, "    compression: {}", self.compression)?;
+        let encodings: Vec<_> = self.encodings.iter().map(|e| e.to_string()).collect();
+        writeln!(out, "    encodings: {}", encodings.join(" "))?;
+        writeln!(out, "    file offset: {}", self.file_offset)?;
+        writeln!(out, "    data page offset: {}", self.data_page_offset)?;
+        if let Some(offset) = self.dictionary_page_offset {
+            writeln!(out, "    dictionary page offset: {}", offset)?;
+        }
+        if let Some(offset) = self.index_page_offset {
+            writeln!(out, "    index page offset: {}", offset)?;
+        }
+        writeln!(out, "    num of values: {}", self.num_values)?;
+        writeln!(out, "    compressed size: {}", self.compressed_size)?;
+        writeln!(out, "    uncompressed size: {}", self.uncompressed_size)?;
+        match self.statistics {
+            Some(ref stats) => writeln!(out, "    statistics: {}", stats)?,
+            None => writeln!(out, "    statistics: N/A")?,
+        }
+        if self.encrypted {
+            writeln!(out, "    pages: encrypted")?;
+        } else {
+            writeln!(out, "    num of pages: {}", self.pages.len())?;
+            for page in &self.pages {
+                page.print(out)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl PageLayout {
+    fn to_json(&self) -> Value {
+        let header = &self.header;
+        let mut page = json!({
+            "offset": self.offset,
+            "page_type": page_type_name(header.type_),
+            "header_size": self.header_size,
+            "compressed_size": header.compressed_page_size,
+            "uncompressed_size": header.uncompressed_page_size,
+            "crc": header.crc,
+            "statistics": self.statistics.as_ref().map(statistics_to_json),
+        });
+        let fields = page.as_object_mut().unwrap();
+        if let Some(ref h) = header.data_page_header {
+            fields.insert("num_values".to_string(), json!(h.num_values));
+            fields.insert("encoding".to_string(), json!(encoding_name(h.encoding)));
+            fields.insert(
+                "definition_level_encoding".to_string(),
+                json!(encoding_name(h.definition_level_encoding)),
+            );
+            fields.insert(
+                "repetition_level_encoding".to_string(),
+                json!(encoding_name(h.repetition_level_encoding)),
+            );
+        } else if let Some(ref h) = header.data_page_header_v2 {
+            fields.insert("num_values".to_string(), json!(h.num_values));
+            fields.insert("num_nulls".to_string(), json!(h.num_nulls));
+            fields.insert("num_rows".to_string(), json!(h.num_rows));
+            fields.insert("encoding".to_string(), json!(encoding_name(h.encoding)));
+            fields.insert(
+                "definition_levels_byte_length".to_string(),
+                json!(h.definition_levels_byte_length),
+            );
+            fields.insert(
+                "repetition_levels_byte_length".to_string(),
+                json!(h.repetition_levels_byte_length),
+            );
+            fields.insert("is_compressed".to_string(), json!(h.is_compressed));
+        } else if let Some(ref h) = header.dictionary_page_header {
+            fields.insert("num_values".to_string(), json!(h.num_values));
+            fields.insert("encoding".to_string(), json!(encoding_name(h.encoding)));
+            fields.insert("is_sorted".to_string(), json!(h.is_sorted));
+        }
+        page
+    }
+
+    fn print(&self, out: &mut dyn io::Write) -> io::Result<()> {
+        let header = &self.header;
+        write!(
+            out,
+            "    page {} at offset {}: header size {}, compressed size {}, uncompressed size {}",
+            page_type_name(header.type_),
+            self.offset,
+            self.header_size,
+            header.compressed_page_size,
+            header.uncompressed_page_size
+        )?;
+        if let Some(ref h) = header.data_page_header {
+            write!(
+                out,
+                ", values {}, encoding {}, def level encoding {}, rep level encoding {}",
+                h.num_values,
+                encoding_name(h.encoding),
+                encoding_name(h.definition_level_encoding),
+                encoding_name(h.repetition_level_encoding)
+            )?;
+        } else if let Some(ref h) = header.data_page_header_v2 {
+            write!(
+                out,
+                ", values {}, nulls {}, rows {}, encoding {}",
+                h.num_values,
+                h.num_nulls,
+                h.num_rows,
+                encoding_name(h.encoding)
+            )?;
+        } else if let Some(ref h) = header.dictionary_page_header {
+            write!(
+                out,
+                ", values {}, encoding {}",
+                h.num_values,
+                encoding_name(h.encoding)
+            )?;
+        }
+        if let Some(crc) = header.crc {
+            write!(out, ", crc {:#010x}", crc as u32)?;
+        }
+        writeln!(out)?;
+        if let Some(ref stats) = self.statistics {
+            writeln!(out, "      statistics: {}", stats)?;
+        }
+        Ok(())
+    }
+}
+
+fn encoding_name(encoding: parquet_format::Encoding) -> String {
+    Encoding::from(encoding).to_string()
+}
@@ -0,0 +1,204 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! AES-GCM cipher used by the `AES_GCM_V1` encryption algorithm.
+//!
+//! Every encrypted module is stored as
+//! ```text
+//! +--------------+-----------+-------------------+---------+
+//! | length (4)   | nonce (12)| ciphertext        | tag (16)|
+//! +--------------+-----------+-------------------+---------+
+//! ```
+//! where `length` is the little-endian size of everything that follows it.
+
+use aes_gcm::aead::{consts::U12, AeadInPlace, NewAead};
+use aes_gcm::aes::{Aes128, Aes192, Aes256};
+use aes_gcm::{AesGcm, Nonce, Tag};
+use byteorder::{ByteOrder, LittleEndian};
+use rand::RngCore;
+
+use crate::errors::{ParquetError, Result};
+
+pub(crate) const SIZE_LEN: usize = 4;
+pub(crate) const NONCE_LEN: usize = 12;
+pub(crate) const TAG_LEN: usize = 16;
+
+/// Number of bytes an encrypted module is larger than its plaintext.
+pub(crate) const CIPHERTEXT_OVERHEAD: usize = SIZE_LEN + NONCE_LEN + TAG_LEN;
+
+#[derive(Clone)]
+enum AesGcmKey {
+    Aes128(AesGcm<Aes128, U12>),
+    Aes192(AesGcm<Aes192, U12>),
+    Aes256(AesGcm<Aes256, U12>),
+}
+
+/// Applies `$f` to the cipher of any supported key length.
+macro_rules! with_cipher {
+    ($key:expr, $cipher:ident => $f:expr) => {
+        match $key {
+            AesGcmKey::Aes128($cipher) => $f,
+            AesGcmKey::Aes192($cipher) => $f,
+            AesGcmKey::Aes256($cipher) => $f,
+        }
+    };
+}
+
+/// AES-GCM cipher for a single key, which encrypts and decrypts whole modules.
+#[derive(Clone)]
+pub(crate) struct AesGcmV1Cipher {
+    key: AesGcmKey,
+}
+
+impl AesGcmV1Cipher {
+    /// Creates a cipher from a 16, 24 or 32 byte key.
+    pub fn new(key: &[u8]) -> Result<Self> {
+        let key = match key.len() {
+            16 => AesGcmKey::Aes128(AesGcm::new(key.into())),
+            24 => AesGcmKey::Aes192(AesGcm::new(key.into())),
+            32 => AesGcmKey::Aes256(AesGcm::new(key.into())),
+            n => {
+                return Err(general_err!(
+                    "Invalid encryption key length {}, expected 16, 24 or 32 bytes",
+                    n
+                ))
+            }
+        };
+        Ok(Self { key })
+    }
+
+    /// Encrypts `plaintext` with a random nonce, returning the serialized module.
+    pub fn encrypt(&self, plaintext: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        let mut nonce = [0; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        self.encrypt_with_nonce(plaintext, aad, &nonce)
+    }
+
+    /// Encrypts `plaintext` with the given nonce, returning the serialized module.
+    pub fn encrypt_with_nonce(
+        &self,
+        plaintext: &[u8],
+        aad: &[u8],
+        nonce: &[u8],
+    ) -> Result<Vec<u8>> {
+        if nonce.len() != NONCE_LEN {
+            return Err(general_err!(
+                "Invalid nonce length {}, expected {} bytes",
+                nonce.len(),
+                NONCE_LEN
+            ));
+        }
+        let module_len = NONCE_LEN + plaintext.len() + TAG_LEN;
+        let mut module = Vec::with_capacity(SIZE_LEN + module_len);
+        let mut len_bytes = [0; SIZE_LEN];
+        LittleEndian::write_u32(&mut len_bytes, module_len as u32);
+        module.extend_from_slice(&len_bytes);
+        module.extend_from_slice(nonce);
+        module.extend_from_slice(plaintext);
+
+        let nonce: &Nonce<U12> = nonce.into();
+        let buffer = &mut module[SIZE_LEN + NONCE_LEN..];
+        let tag =
+            with_cipher!(&self.key, c => c.encrypt_in_place_detached(nonce, aad, buffer))
+                .map_err(|_| general_err!("Failed to encrypt module"))?;
+        module.extend_from_slice(&tag);
+        Ok(module)
+    }
+
+    /// Decrypts a serialized module, verifying its tag against `aad`.
+    pub fn decrypt(&self, module: &[u8], aad: &[u8]) -> Result<Vec<u8>> {
+        if module.len() < CIPHERTEXT_OVERHEAD {
+            return Err(general_err!(
+                "Encrypted module is too short ({} bytes)",
+                module.len()
+            ));
+        }
+        let module_len = LittleEndian::read_u32(&module[..SIZE_LEN]) as usize;
+        if module_len != module.len() - SIZE_LEN {
+            return Err(general_err!(
+                "Encrypted module length {} does not match the {} bytes available",
+                module_len,
+                module.len() - SIZE_LEN
+            ));
+        }
+        let nonce: &Nonce<U12> = module[SIZE_LEN..SIZE_LEN + NONCE_LEN].into();
+        let tag: &Tag = module[module.len() - TAG_LEN..].into();
+        let mut buffer = module[SIZE_LEN + NONCE_LEN..module.len() - TAG_LEN].to_vec();
+        with_cipher!(&self.key, c => c.decrypt_in_place_detached(nonce, aad, &mut buffer, tag))
+            .map_err(|_| {
+                general_err!("Failed to decrypt module, the key or AAD may be wrong")
+            })?;
+        Ok(buffer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for key_len in &[16, 24, 32] {
+            let cipher = AesGcmV1Cipher::new(&vec![7; *key_len]).unwrap();
+            let module = cipher.encrypt(b"parquet", b"aad").unwrap();
+            assert_eq!(module.len(), 7 + CIPHERTEXT_OVERHEAD);
+            assert_eq!(
+                LittleEndian::read_u32(&module[..SIZE_LEN]) as usize,
+                module.len() - SIZE_LEN
+            );
+            assert_eq!(cipher.decrypt(&module, b"aad").unwrap(), b"parquet");
+        }
+    }
+
+    #[test]
+    fn test_decrypt_with_wrong_aad_or_key() {
+        let cipher = AesGcmV1Cipher::new(b"0123456789012345").unwrap();
+        let module = cipher.encrypt(b"parquet", b"aad").unwrap();
+        assert!(cipher.decrypt(&module, b"other").is_err());
+
+        let other = AesGcmV1Cipher::new(b"1234567890123450").unwrap();
+        assert!(other.decrypt(&module, b"aad").is_err());
+
+        let mut tampered = module.clone();
+        tampered[SIZE_LEN + NONCE_LEN] ^= 1;
+        assert!(cipher.decrypt(&tampered, b"aad").is_err());
+    }
+
+    #[test]
+    fn test_invalid_key_length() {
+        let res = AesGcmV1Cipher::new(b"short");
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet error: Invalid encryption key length 5, expected 16, 24 or 32 bytes"
+        );
+    }
+
+    #[test]
+    fn test_encrypt_with_nonce_is_deterministic() {
+        let cipher = AesGcmV1Cipher::new(b"0123456789012345").unwrap();
+        let nonce = [1; NONCE_LEN];
+        let a = cipher
+            .encrypt_with_nonce(b"footer", b"aad", &nonce)
+            .unwrap();
+        let b = cipher
+            .encrypt_with_nonce(b"footer", b"aad", &nonce)
+            .unwrap();
+        assert_eq!(a, b);
+        assert_eq!(&a[SIZE_LEN..SIZE_LEN + NONCE_LEN], &nonce);
+    }
+}
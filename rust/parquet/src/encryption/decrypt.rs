@@ -0,0 +1,259 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Decryption of the modules of a file that is being read.
+
+use std::{fmt, sync::Arc};
+
+use parquet_format::{
+    ColumnCryptoMetaData, ColumnMetaData, EncryptionAlgorithm, RowGroup,
+};
+use thrift::protocol::TCompactInputProtocol;
+
+use crate::encryption::{
+    ciphers::{AesGcmV1Cipher, NONCE_LEN, SIZE_LEN},
+    create_footer_aad, create_module_aad, FileDecryptionProperties, ModuleType,
+    FOOTER_SIGNATURE_LEN,
+};
+use crate::errors::{ParquetError, Result};
+use crate::schema::types::ColumnPath;
+
+/// Decrypts the footer and column chunks of a single file.
+pub(crate) struct FileDecryptor {
+    properties: FileDecryptionProperties,
+    file_aad: Vec<u8>,
+    footer_key_metadata: Option<Vec<u8>>,
+    // Cipher for the footer key, if the key could be obtained when the file was opened.
+    footer_cipher: Option<AesGcmV1Cipher>,
+}
+
+impl FileDecryptor {
+    /// Creates a decryptor for a file encrypted with `algorithm`. Returns an error if
+    /// the algorithm is not supported, the AAD prefix is missing or if the footer key
+    /// is required but can't be obtained.
+    pub fn new(
+        properties: &FileDecryptionProperties,
+        algorithm: &EncryptionAlgorithm,
+        footer_key_metadata: Option<&[u8]>,
+        footer_key_required: bool,
+    ) -> Result<Self> {
+        let algorithm = match algorithm {
+            EncryptionAlgorithm::AESGCMV1(algorithm) => algorithm,
+            EncryptionAlgorithm::AESGCMCTRV1(_) => {
+                return Err(nyi_err!("AES_GCM_CTR_V1 encryption is not supported"))
+            }
+        };
+
+        let aad_prefix = match (&algorithm.aad_prefix, properties.aad_prefix()) {
+            (Some(stored), Some(supplied)) if stored.as_slice() != supplied => {
+                return Err(general_err!(
+                    "AAD prefix stored in file does not match the one in decryption properties"
+                ))
+            }
+            (Some(stored), _) => stored.clone(),
+            (None, Some(supplied)) => supplied.to_vec(),
+            (None, None) if algorithm.supply_aad_prefix == Some(true) => {
+                return Err(general_err!(
+                    "AAD prefix used for file encryption is not stored in file and not supplied in decryption properties"
+                ))
+            }
+            (None, None) => vec![],
+        };
+        let mut file_aad = aad_prefix;
+        if let Some(aad_file_unique) = &algorithm.aad_file_unique {
+            file_aad.extend_from_slice(aad_file_unique);
+        }
+
+        let footer_cipher = match properties.footer_key(footer_key_metadata) {
+            Ok(key) => Some(AesGcmV1Cipher::new(&key)?),
+            Err(e) if footer_key_required => return Err(e),
+            Err(_) => None,
+        };
+
+        Ok(Self {
+            properties: properties.clone(),
+            file_aad,
+            footer_key_metadata: footer_key_metadata.map(|m| m.to_vec()),
+            footer_cipher,
+        })
+    }
+
+    /// Decrypts an encrypted footer module.
+    pub fn decrypt_footer(&self, module: &[u8]) -> Result<Vec<u8>> {
+        self.footer_cipher()?
+            .decrypt(module, &create_footer_aad(&self.file_aad))
+    }
+
+    /// Verifies the signature that follows a plaintext footer.
+    pub fn verify_footer_signature(&self, footer: &[u8], signature: &[u8]) -> Result<()> {
+        if signature.len() != FOOTER_SIGNATURE_LEN {
+            return Err(general_err!(
+                "Invalid footer signature length {}, expected {} bytes",
+                signature.len(),
+                FOOTER_SIGNATURE_LEN
+            ));
+        }
+        let module = self.footer_cipher()?.encrypt_with_nonce(
+            footer,
+            &create_footer_aad(&self.file_aad),
+            &signature[..NONCE_LEN],
+        )?;
+        if module[SIZE_LEN + NONCE_LEN + footer.len()..] != signature[NONCE_LEN..] {
+            return Err(general_err!("Footer signature verification failed"));
+        }
+        Ok(())
+    }
+
+    /// Replaces the metadata of encrypted columns of a row group with its decrypted
+    /// version. Columns whose key is not available keep the plaintext metadata of
+    /// their redacted copy, if there is one.
+    pub fn decrypt_row_group(
+        &self,
+        row_group: &mut RowGroup,
+        ordinal: usize,
+    ) -> Result<()> {
+        for (i, chunk) in row_group.columns.iter_mut().enumerate() {
+            let (crypto_metadata, encrypted) =
+                match (&chunk.crypto_metadata, &chunk.encrypted_column_metadata) {
+                    (Some(crypto_metadata), Some(encrypted)) => {
+                        (crypto_metadata, encrypted)
+                    }
+                    _ => continue,
+                };
+            let cipher = match self.column_cipher(crypto_metadata) {
+                Ok(cipher) => cipher,
+                Err(_) if chunk.meta_data.is_some() => continue,
+                Err(e) => return Err(e),
+            };
+            let aad = create_module_aad(
+                &self.file_aad,
+                ModuleType::ColumnMetaData,
+                ordinal,
+                i,
+                None,
+            )?;
+            let buffer = cipher.decrypt(encrypted, &aad)?;
+            let mut prot = TCompactInputProtocol::new(buffer.as_slice());
+            chunk.meta_data = Some(ColumnMetaData::read_from_in_protocol(&mut prot)?);
+        }
+        Ok(())
+    }
+
+    /// Returns the decryptor for the pages of an encrypted column chunk.
+    pub fn page_decryptor(
+        self: &Arc<Self>,
+        crypto_metadata: &ColumnCryptoMetaData,
+        row_group_ordinal: usize,
+        column_ordinal: usize,
+        has_dictionary_page: bool,
+    ) -> Result<PageDecryptor> {
+        Ok(PageDecryptor {
+            file_decryptor: self.clone(),
+            cipher: self.column_cipher(crypto_metadata)?,
+            row_group_ordinal,
+            column_ordinal,
+            page_ordinal: 0,
+            dictionary_page_pending: has_dictionary_page,
+        })
+    }
+
+    fn footer_cipher(&self) -> Result<AesGcmV1Cipher> {
+        match &self.footer_cipher {
+            Some(cipher) => Ok(cipher.clone()),
+            None => {
+                let key = self
+                    .properties
+                    .footer_key(self.footer_key_metadata.as_deref())?;
+                AesGcmV1Cipher::new(&key)
+            }
+        }
+    }
+
+    fn column_cipher(
+        &self,
+        crypto_metadata: &ColumnCryptoMetaData,
+    ) -> Result<AesGcmV1Cipher> {
+        match crypto_metadata {
+            ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(_) => self.footer_cipher(),
+            ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(column) => {
+                let path = ColumnPath::new(column.path_in_schema.clone());
+                let key = self
+                    .properties
+                    .column_key(&path, column.key_metadata.as_deref())?;
+                AesGcmV1Cipher::new(&key)
+            }
+        }
+    }
+}
+
+impl fmt::Debug for FileDecryptor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FileDecryptor")
+            .field("properties", &self.properties)
+            .finish()
+    }
+}
+
+/// Decrypts the pages of a single column chunk.
+pub(crate) struct PageDecryptor {
+    file_decryptor: Arc<FileDecryptor>,
+    cipher: AesGcmV1Cipher,
+    row_group_ordinal: usize,
+    column_ordinal: usize,
+    page_ordinal: usize,
+    // The first page of a column chunk with a dictionary is the dictionary page.
+    dictionary_page_pending: bool,
+}
+
+impl PageDecryptor {
+    /// Decrypts the serialized header of the next page.
+    pub fn decrypt_page_header(&self, module: &[u8]) -> Result<Vec<u8>> {
+        let aad = if self.dictionary_page_pending {
+            self.aad(ModuleType::DictionaryPageHeader, None)?
+        } else {
+            self.aad(ModuleType::DataPageHeader, Some(self.page_ordinal))?
+        };
+        self.cipher.decrypt(module, &aad)
+    }
+
+    /// Decrypts the data of the next page. Since the data is the last module of a page
+    /// to be decrypted, this also advances to the next page.
+    pub fn decrypt_page(&mut self, module: &[u8]) -> Result<Vec<u8>> {
+        let aad = if self.dictionary_page_pending {
+            self.dictionary_page_pending = false;
+            self.aad(ModuleType::DictionaryPage, None)?
+        } else {
+            self.page_ordinal += 1;
+            self.aad(ModuleType::DataPage, Some(self.page_ordinal - 1))?
+        };
+        self.cipher.decrypt(module, &aad)
+    }
+
+    fn aad(
+        &self,
+        module_type: ModuleType,
+        page_ordinal: Option<usize>,
+    ) -> Result<Vec<u8>> {
+        create_module_aad(
+            &self.file_decryptor.file_aad,
+            module_type,
+            self.row_group_ordinal,
+            self.column_ordinal,
+            page_ordinal,
+        )
+    }
+}
@@ -0,0 +1,301 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Encryption of the modules of a file that is being written.
+
+use std::{collections::HashMap, sync::Arc};
+
+use parquet_format::{
+    AesGcmV1, ColumnChunk, ColumnCryptoMetaData, EncryptionAlgorithm,
+    EncryptionWithColumnKey, EncryptionWithFooterKey, FileCryptoMetaData, RowGroup,
+};
+use rand::RngCore;
+use thrift::protocol::{TCompactOutputProtocol, TOutputProtocol};
+
+use crate::basic::PageType;
+use crate::encryption::{
+    ciphers::{AesGcmV1Cipher, NONCE_LEN, SIZE_LEN},
+    create_footer_aad, create_module_aad, FileEncryptionProperties, ModuleType,
+};
+use crate::errors::{ParquetError, Result};
+use crate::schema::types::{ColumnPath, SchemaDescriptor};
+
+/// Length of the random file-unique part of the AAD.
+const AAD_FILE_UNIQUE_LEN: usize = 8;
+
+/// Encrypts the footer and column chunks of a single file.
+pub(crate) struct FileEncryptor {
+    properties: FileEncryptionProperties,
+    aad_file_unique: Vec<u8>,
+    file_aad: Vec<u8>,
+    footer_cipher: AesGcmV1Cipher,
+    column_ciphers: HashMap<ColumnPath, AesGcmV1Cipher>,
+}
+
+impl FileEncryptor {
+    /// Creates an encryptor for a file with the given schema. Returns an error if a
+    /// key is invalid or a column key is set for a column that is not in the schema.
+    pub fn new(
+        properties: FileEncryptionProperties,
+        schema_descr: &SchemaDescriptor,
+    ) -> Result<Self> {
+        let footer_cipher = AesGcmV1Cipher::new(properties.footer_key())?;
+        let mut column_ciphers = HashMap::new();
+        for path in properties.column_paths() {
+            if !schema_descr.columns().iter().any(|c| c.path() == path) {
+                return Err(general_err!(
+                    "Encryption key set for column {} which is not in the schema",
+                    path
+                ));
+            }
+            let column_key = properties.column_key(path).unwrap();
+            column_ciphers.insert(path.clone(), AesGcmV1Cipher::new(&column_key.key)?);
+        }
+
+        let mut aad_file_unique = vec![0; AAD_FILE_UNIQUE_LEN];
+        rand::thread_rng().fill_bytes(&mut aad_file_unique);
+        let mut file_aad = properties.aad_prefix().unwrap_or_default().to_vec();
+        file_aad.extend_from_slice(&aad_file_unique);
+
+        Ok(Self {
+            properties,
+            aad_file_unique,
+            file_aad,
+            footer_cipher,
+            column_ciphers,
+        })
+    }
+
+    /// Returns `true` if the footer is encrypted.
+    pub fn encrypted_footer(&self) -> bool {
+        self.properties.encrypted_footer()
+    }
+
+    /// Returns the encryption algorithm written to the file.
+    pub fn encryption_algorithm(&self) -> EncryptionAlgorithm {
+        let aad_prefix = self.properties.aad_prefix();
+        let store_aad_prefix = self.properties.store_aad_prefix();
+        EncryptionAlgorithm::AESGCMV1(AesGcmV1 {
+            aad_prefix: aad_prefix
+                .filter(|_| store_aad_prefix)
+                .map(|prefix| prefix.to_vec()),
+            aad_file_unique: Some(self.aad_file_unique.clone()),
+            supply_aad_prefix: aad_prefix.map(|_| !store_aad_prefix),
+        })
+    }
+
+    /// Returns the crypto metadata written before an encrypted footer.
+    pub fn file_crypto_metadata(&self) -> FileCryptoMetaData {
+        FileCryptoMetaData {
+            encryption_algorithm: self.encryption_algorithm(),
+            key_metadata: self.footer_key_metadata(),
+        }
+    }
+
+    /// Returns the metadata that identifies the footer key.
+    pub fn footer_key_metadata(&self) -> Option<Vec<u8>> {
+        self.properties.footer_key_metadata().map(|m| m.to_vec())
+    }
+
+    /// Encrypts the serialized file metadata.
+    pub fn encrypt_footer(&self, footer: &[u8]) -> Result<Vec<u8>> {
+        self.footer_cipher
+            .encrypt(footer, &create_footer_aad(&self.file_aad))
+    }
+
+    /// Returns the signature of a plaintext footer, i.e. the nonce and tag of the
+    /// encrypted footer.
+    pub fn sign_footer(&self, footer: &[u8]) -> Result<Vec<u8>> {
+        let module = self.encrypt_footer(footer)?;
+        let mut signature = module[SIZE_LEN..SIZE_LEN + NONCE_LEN].to_vec();
+        signature.extend_from_slice(&module[SIZE_LEN + NONCE_LEN + footer.len()..]);
+        Ok(signature)
+    }
+
+    /// Returns the encryptor for the pages of a column chunk, or `None` if the column
+    /// is not encrypted.
+    pub fn page_encryptor(
+        self: &Arc<Self>,
+        column_path: &ColumnPath,
+        row_group_ordinal: usize,
+        column_ordinal: usize,
+    ) -> Option<PageEncryptor> {
+        let cipher = self.column_cipher(column_path)?;
+        Some(PageEncryptor {
+            file_encryptor: self.clone(),
+            cipher: cipher.clone(),
+            row_group_ordinal,
+            column_ordinal,
+            page_ordinal: 0,
+        })
+    }
+
+    /// Adds the crypto metadata to all encrypted columns of a row group, see
+    /// [`FileEncryptor::encrypt_column_chunk`].
+    pub fn encrypt_row_group(&self, mut row_group: RowGroup) -> Result<RowGroup> {
+        let ordinal = row_group.ordinal.unwrap_or(0) as usize;
+        row_group.columns = row_group
+            .columns
+            .into_iter()
+            .enumerate()
+            .map(|(i, chunk)| self.encrypt_column_chunk(chunk, ordinal, i))
+            .collect::<Result<_>>()?;
+        Ok(row_group)
+    }
+
+    /// Adds the crypto metadata to an encrypted column chunk.
+    ///
+    /// The column metadata is encrypted whenever it would otherwise be readable
+    /// without the column key, i.e. for columns with their own key and for all
+    /// encrypted columns when the footer is in plaintext. A plaintext footer keeps a
+    /// copy of the metadata without statistics for readers without keys.
+    pub fn encrypt_column_chunk(
+        &self,
+        mut chunk: ColumnChunk,
+        row_group_ordinal: usize,
+        column_ordinal: usize,
+    ) -> Result<ColumnChunk> {
+        let meta_data = match chunk.meta_data.take() {
+            Some(meta_data) => meta_data,
+            None => return Err(general_err!("Expected to have column metadata")),
+        };
+        let path = ColumnPath::new(meta_data.path_in_schema.clone());
+        let cipher = match self.column_cipher(&path) {
+            Some(cipher) => cipher,
+            None => {
+                chunk.meta_data = Some(meta_data);
+                return Ok(chunk);
+            }
+        };
+
+        let column_key = self.properties.column_key(&path);
+        chunk.crypto_metadata = Some(match column_key {
+            Some(column_key) => {
+                ColumnCryptoMetaData::ENCRYPTIONWITHCOLUMNKEY(EncryptionWithColumnKey {
+                    path_in_schema: meta_data.path_in_schema.clone(),
+                    key_metadata: column_key.key_metadata.clone(),
+                })
+            }
+            None => {
+                ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(EncryptionWithFooterKey {})
+            }
+        });
+
+        if column_key.is_none() && self.encrypted_footer() {
+            chunk.meta_data = Some(meta_data);
+            return Ok(chunk);
+        }
+
+        let mut buffer = Vec::new();
+        {
+            let mut protocol = TCompactOutputProtocol::new(&mut buffer);
+            meta_data.write_to_out_protocol(&mut protocol)?;
+            protocol.flush()?;
+        }
+        let aad = create_module_aad(
+            &self.file_aad,
+            ModuleType::ColumnMetaData,
+            row_group_ordinal,
+            column_ordinal,
+            None,
+        )?;
+        chunk.encrypted_column_metadata = Some(cipher.encrypt(&buffer, &aad)?);
+
+        if !self.encrypted_footer() {
+            let mut redacted = meta_data;
+            redacted.statistics = None;
+            redacted.encoding_stats = None;
+            chunk.meta_data = Some(redacted);
+        }
+        Ok(chunk)
+    }
+
+    fn column_cipher(&self, column_path: &ColumnPath) -> Option<&AesGcmV1Cipher> {
+        if !self.properties.is_column_encrypted(column_path) {
+            None
+        } else {
+            Some(
+                self.column_ciphers
+                    .get(column_path)
+                    .unwrap_or(&self.footer_cipher),
+            )
+        }
+    }
+}
+
+/// Encrypts the pages of a single column chunk.
+pub(crate) struct PageEncryptor {
+    file_encryptor: Arc<FileEncryptor>,
+    cipher: AesGcmV1Cipher,
+    row_group_ordinal: usize,
+    column_ordinal: usize,
+    page_ordinal: usize,
+}
+
+impl PageEncryptor {
+    /// Encrypts the (compressed) data of a page.
+    pub fn encrypt_page(&self, page_type: PageType, data: &[u8]) -> Result<Vec<u8>> {
+        let aad = match page_type {
+            PageType::DICTIONARY_PAGE => self.aad(ModuleType::DictionaryPage, None)?,
+            _ => self.aad(ModuleType::DataPage, Some(self.page_ordinal))?,
+        };
+        self.cipher.encrypt(data, &aad)
+    }
+
+    /// Encrypts the serialized header of a page. Since the header is the last module
+    /// of a page to be encrypted, this also advances to the next data page.
+    pub fn encrypt_page_header(
+        &mut self,
+        page_type: PageType,
+        header: &[u8],
+    ) -> Result<Vec<u8>> {
+        let aad = match page_type {
+            PageType::DICTIONARY_PAGE => {
+                self.aad(ModuleType::DictionaryPageHeader, None)?
+            }
+            _ => self.aad(ModuleType::DataPageHeader, Some(self.page_ordinal))?,
+        };
+        if page_type != PageType::DICTIONARY_PAGE {
+            self.page_ordinal += 1;
+        }
+        self.cipher.encrypt(header, &aad)
+    }
+
+    /// Adds the crypto metadata to the column chunk, see
+    /// [`FileEncryptor::encrypt_column_chunk`].
+    pub fn encrypt_column_chunk(&self, chunk: ColumnChunk) -> Result<ColumnChunk> {
+        self.file_encryptor.encrypt_column_chunk(
+            chunk,
+            self.row_group_ordinal,
+            self.column_ordinal,
+        )
+    }
+
+    fn aad(
+        &self,
+        module_type: ModuleType,
+        page_ordinal: Option<usize>,
+    ) -> Result<Vec<u8>> {
+        create_module_aad(
+            &self.file_encryptor.file_aad,
+            module_type,
+            self.row_group_ordinal,
+            self.column_ordinal,
+            page_ordinal,
+        )
+    }
+}
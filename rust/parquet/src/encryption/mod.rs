@@ -0,0 +1,574 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Parquet modular encryption, as described in the
+//! [specification](https://github.com/apache/parquet-format/blob/master/Encryption.md).
+//!
+//! Files are encrypted with the `AES_GCM_V1` algorithm, either with a single footer key
+//! for all columns ("uniform encryption") or with separate keys for selected columns.
+//! The footer can be encrypted (files start and end with `PARE`), or left in plaintext
+//! and signed, so that readers without keys can still access unencrypted columns.
+//!
+//! Encryption is configured with [`FileEncryptionProperties`] on the
+//! [`WriterProperties`](crate::file::properties::WriterProperties), decryption with
+//! [`FileDecryptionProperties`] on the
+//! [`ReaderProperties`](crate::file::properties::ReaderProperties). Keys can either be
+//! given explicitly, or looked up by their key metadata through a [`KeyRetriever`].
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::{fs, sync::Arc};
+//! use parquet::{
+//!     encryption::{FileDecryptionProperties, FileEncryptionProperties},
+//!     file::{
+//!         properties::{ReaderProperties, WriterProperties},
+//!         reader::{FileReader, SerializedFileReader},
+//!         writer::{FileWriter, SerializedFileWriter},
+//!     },
+//!     schema::{parser::parse_message_type, types::ColumnPath},
+//! };
+//!
+//! let path = std::env::temp_dir().join("encryption_example.parquet");
+//! let footer_key = b"0123456789012345".to_vec();
+//! let column_key = b"1234567890123450".to_vec();
+//!
+//! let schema = Arc::new(parse_message_type("message schema { REQUIRED INT32 ssn; }").unwrap());
+//! let encryption = FileEncryptionProperties::builder(footer_key.clone())
+//!     .set_column_key(ColumnPath::from("ssn"), column_key.clone())
+//!     .build();
+//! let props = Arc::new(
+//!     WriterProperties::builder()
+//!         .set_file_encryption_properties(encryption)
+//!         .build(),
+//! );
+//! let file = fs::File::create(&path).unwrap();
+//! let mut writer = SerializedFileWriter::new(file, schema, props).unwrap();
+//! writer.close().unwrap();
+//!
+//! let decryption = FileDecryptionProperties::builder()
+//!     .set_footer_key(footer_key)
+//!     .set_column_key(ColumnPath::from("ssn"), column_key)
+//!     .build();
+//! let props = ReaderProperties::builder()
+//!     .set_file_decryption_properties(decryption)
+//!     .build();
+//! let file = fs::File::open(&path).unwrap();
+//! let reader = SerializedFileReader::new_with_properties(file, props).unwrap();
+//! assert_eq!(reader.metadata().file_metadata().num_rows(), 0);
+//! ```
+
+pub(crate) mod ciphers;
+pub(crate) mod decrypt;
+pub(crate) mod encrypt;
+mod properties;
+
+pub use self::properties::{
+    FileDecryptionProperties, FileDecryptionPropertiesBuilder, FileEncryptionProperties,
+    FileEncryptionPropertiesBuilder, KeyRetriever,
+};
+
+use crate::errors::{ParquetError, Result};
+
+/// Length of the signature appended to a plaintext footer, i.e. nonce and tag.
+pub(crate) const FOOTER_SIGNATURE_LEN: usize = ciphers::NONCE_LEN + ciphers::TAG_LEN;
+
+/// Type of an encrypted module, which is part of its AAD.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ModuleType {
+    Footer = 0,
+    ColumnMetaData = 1,
+    DataPage = 2,
+    DictionaryPage = 3,
+    DataPageHeader = 4,
+    DictionaryPageHeader = 5,
+}
+
+/// Creates the AAD of the footer module.
+pub(crate) fn create_footer_aad(file_aad: &[u8]) -> Vec<u8> {
+    let mut aad = Vec::with_capacity(file_aad.len() + 1);
+    aad.extend_from_slice(file_aad);
+    aad.push(ModuleType::Footer as u8);
+    aad
+}
+
+/// Creates the AAD of a module within a column chunk. Ordinals are stored as
+/// little-endian 16 bit integers, the page ordinal only for data pages and their
+/// headers.
+pub(crate) fn create_module_aad(
+    file_aad: &[u8],
+    module_type: ModuleType,
+    row_group_ordinal: usize,
+    column_ordinal: usize,
+    page_ordinal: Option<usize>,
+) -> Result<Vec<u8>> {
+    let mut aad = Vec::with_capacity(file_aad.len() + 7);
+    aad.extend_from_slice(file_aad);
+    aad.push(module_type as u8);
+    for (name, ordinal) in [
+        ("row group", Some(row_group_ordinal)),
+        ("column", Some(column_ordinal)),
+        ("page", page_ordinal),
+    ]
+    .iter()
+    {
+        if let Some(ordinal) = *ordinal {
+            if ordinal > i16::MAX as usize {
+                return Err(general_err!(
+                    "Encrypted files can't have a {} ordinal larger than {}",
+                    name,
+                    i16::MAX
+                ));
+            }
+            aad.extend_from_slice(&(ordinal as i16).to_le_bytes());
+        }
+    }
+    Ok(aad)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::{collections::HashMap, sync::Arc};
+
+    use crate::column::writer::ColumnWriter;
+    use crate::data_type::ByteArray;
+    use crate::file::{
+        properties::{ReaderProperties, WriterProperties},
+        reader::{FileReader, SerializedFileReader},
+        serialized_reader::SliceableCursor,
+        writer::{FileWriter, InMemoryWriteableCursor, SerializedFileWriter},
+        PARQUET_MAGIC_ENCRYPTED_FOOTER,
+    };
+    use crate::record::{Field, Row};
+    use crate::schema::{parser::parse_message_type, types::ColumnPath};
+    use crate::util::test_common::get_test_file;
+
+    const FOOTER_KEY: &[u8] = b"0123456789012345";
+    const COLUMN_KEY: &[u8] = b"1234567890123450";
+
+    struct MapKeyRetriever(HashMap<Vec<u8>, Vec<u8>>);
+
+    impl KeyRetriever for MapKeyRetriever {
+        fn retrieve_key(&self, key_metadata: &[u8]) -> Result<Vec<u8>> {
+            self.0
+                .get(key_metadata)
+                .cloned()
+                .ok_or_else(|| general_err!("Unknown key {:?}", key_metadata))
+        }
+    }
+
+    fn key_retriever(keys: &[(&str, &[u8])]) -> Arc<dyn KeyRetriever> {
+        let keys = keys
+            .iter()
+            .map(|(metadata, key)| (metadata.as_bytes().to_vec(), key.to_vec()))
+            .collect();
+        Arc::new(MapKeyRetriever(keys))
+    }
+
+    /// Writes two row groups with several (dictionary and data) pages per column.
+    fn write_file(encryption: FileEncryptionProperties) -> Vec<u8> {
+        let schema = Arc::new(
+            parse_message_type(
+                "message schema {
+                    REQUIRED INT32 id;
+                    OPTIONAL BYTE_ARRAY name (UTF8);
+                    REQUIRED DOUBLE salary;
+                }",
+            )
+            .unwrap(),
+        );
+        let props = Arc::new(
+            WriterProperties::builder()
+                .set_write_batch_size(2)
+                .set_data_pagesize_limit(1)
+                .set_page_checksum_enabled(true)
+                .set_file_encryption_properties(encryption)
+                .build(),
+        );
+        let cursor = InMemoryWriteableCursor::default();
+        {
+            let mut writer =
+                SerializedFileWriter::new(cursor.clone(), schema, props).unwrap();
+            for rg in 0..2 {
+                let ids: Vec<i32> = (rg * 5..rg * 5 + 5).collect();
+                let mut row_group_writer = writer.next_row_group().unwrap();
+                while let Some(mut col_writer) = row_group_writer.next_column().unwrap() {
+                    match col_writer {
+                        ColumnWriter::Int32ColumnWriter(ref mut typed) => {
+                            typed.write_batch(&ids, None, None).unwrap();
+                        }
+                        ColumnWriter::ByteArrayColumnWriter(ref mut typed) => {
+                            let names: Vec<ByteArray> = ids
+                                .iter()
+                                .filter(|id| *id % 2 == 0)
+                                .map(|id| {
+                                    ByteArray::from(format!("name{}", id % 3).as_str())
+                                })
+                                .collect();
+                            let def_levels: Vec<i16> =
+                                ids.iter().map(|id| (id % 2 == 0) as i16).collect();
+                            typed.write_batch(&names, Some(&def_levels), None).unwrap();
+                        }
+                        ColumnWriter::DoubleColumnWriter(ref mut typed) => {
+                            let salaries: Vec<f64> =
+                                ids.iter().map(|id| *id as f64 * 1.5).collect();
+                            typed.write_batch(&salaries, None, None).unwrap();
+                        }
+                        _ => unreachable!(),
+                    }
+                    row_group_writer.close_column(col_writer).unwrap();
+                }
+                writer.close_row_group(row_group_writer).unwrap();
+            }
+            writer.close().unwrap();
+        }
+        cursor.into_inner().unwrap()
+    }
+
+    fn open_file(
+        data: &[u8],
+        decryption: Option<FileDecryptionProperties>,
+    ) -> Result<SerializedFileReader<SliceableCursor>> {
+        let mut props = ReaderProperties::builder().set_verify_page_checksum(true);
+        if let Some(decryption) = decryption {
+            props = props.set_file_decryption_properties(decryption);
+        }
+        SerializedFileReader::new_with_properties(
+            SliceableCursor::new(data.to_vec()),
+            props.build(),
+        )
+    }
+
+    fn read_rows(reader: &SerializedFileReader<SliceableCursor>) -> Vec<Row> {
+        reader.get_row_iter(None).unwrap().collect()
+    }
+
+    fn check_rows(rows: &[Row]) {
+        assert_eq!(rows.len(), 10);
+        for (i, row) in rows.iter().enumerate() {
+            let expected_name = if i % 2 == 0 {
+                Field::Str(format!("name{}", i % 3))
+            } else {
+                Field::Null
+            };
+            let fields: Vec<&Field> = row.get_column_iter().map(|(_, f)| f).collect();
+            assert_eq!(
+                fields,
+                vec![
+                    &Field::Int(i as i32),
+                    &expected_name,
+                    &Field::Double(i as f64 * 1.5)
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn test_create_footer_aad() {
+        assert_eq!(create_footer_aad(b"abc"), b"abc\x00");
+    }
+
+    #[test]
+    fn test_create_module_aad() {
+        let aad = create_module_aad(b"abc", ModuleType::DataPage, 1, 2, Some(3)).unwrap();
+        assert_eq!(aad, b"abc\x02\x01\x00\x02\x00\x03\x00");
+
+        let aad =
+            create_module_aad(b"abc", ModuleType::DictionaryPageHeader, 258, 0, None)
+                .unwrap();
+        assert_eq!(aad, b"abc\x05\x02\x01\x00\x00");
+
+        let res = create_module_aad(b"", ModuleType::DataPage, 0, 0, Some(40000));
+        assert!(res.is_err());
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet error: Encrypted files can't have a page ordinal larger than 32767"
+        );
+    }
+
+    #[test]
+    fn test_uniform_encryption() {
+        let data =
+            write_file(FileEncryptionProperties::builder(FOOTER_KEY.to_vec()).build());
+        assert_eq!(&data[..4], &PARQUET_MAGIC_ENCRYPTED_FOOTER);
+        assert_eq!(&data[data.len() - 4..], &PARQUET_MAGIC_ENCRYPTED_FOOTER);
+
+        let decryption = FileDecryptionProperties::builder()
+            .set_footer_key(FOOTER_KEY.to_vec())
+            .build();
+        let reader = open_file(&data, Some(decryption)).unwrap();
+        assert_eq!(reader.num_row_groups(), 2);
+        let column = reader.metadata().row_group(1).column(2);
+        assert!(column.statistics().is_some());
+        assert_eq!(
+            column.crypto_metadata(),
+            Some(
+                &parquet_format::ColumnCryptoMetaData::ENCRYPTIONWITHFOOTERKEY(
+                    parquet_format::EncryptionWithFooterKey {}
+                )
+            )
+        );
+        check_rows(&read_rows(&reader));
+
+        let res = open_file(&data, None);
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet error: Parquet file has an encrypted footer, but no decryption properties were provided"
+        );
+
+        let decryption = FileDecryptionProperties::builder()
+            .set_footer_key(COLUMN_KEY.to_vec())
+            .build();
+        let res = open_file(&data, Some(decryption));
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet error: Failed to decrypt module, the key or AAD may be wrong"
+        );
+    }
+
+    #[test]
+    fn test_column_keys_with_key_retriever() {
+        let encryption = FileEncryptionProperties::builder(FOOTER_KEY.to_vec())
+            .set_footer_key_metadata(b"kf".to_vec())
+            .set_column_key_with_metadata(
+                ColumnPath::from("salary"),
+                COLUMN_KEY.to_vec(),
+                b"kc1".to_vec(),
+            )
+            .build();
+        let data = write_file(encryption);
+
+        let retriever = key_retriever(&[("kf", FOOTER_KEY), ("kc1", COLUMN_KEY)]);
+        let decryption = FileDecryptionProperties::builder()
+            .set_key_retriever(retriever)
+            .build();
+        let reader = open_file(&data, Some(decryption)).unwrap();
+        let row_group = reader.metadata().row_group(0);
+        assert!(row_group.column(0).crypto_metadata().is_none());
+        assert!(row_group.column(2).crypto_metadata().is_some());
+        assert!(row_group.column(2).statistics().is_some());
+        check_rows(&read_rows(&reader));
+
+        // The column key is required to decrypt the column metadata
+        let decryption = FileDecryptionProperties::builder()
+            .set_key_retriever(key_retriever(&[("kf", FOOTER_KEY)]))
+            .build();
+        let res = open_file(&data, Some(decryption));
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet error: Unknown key [107, 99, 49]"
+        );
+    }
+
+    #[test]
+    fn test_plaintext_footer() {
+        let encryption = FileEncryptionProperties::builder(FOOTER_KEY.to_vec())
+            .set_plaintext_footer(true)
+            .set_column_key(ColumnPath::from("salary"), COLUMN_KEY.to_vec())
+            .build();
+        let data = write_file(encryption);
+        assert_eq!(&data[..4], b"PAR1");
+        assert_eq!(&data[data.len() - 4..], b"PAR1");
+
+        let decryption = FileDecryptionProperties::builder()
+            .set_footer_key(FOOTER_KEY.to_vec())
+            .set_column_key(ColumnPath::from("salary"), COLUMN_KEY.to_vec())
+            .build();
+        let reader = open_file(&data, Some(decryption.clone())).unwrap();
+        assert!(reader
+            .metadata()
+            .row_group(0)
+            .column(2)
+            .statistics()
+            .is_some());
+        check_rows(&read_rows(&reader));
+
+        // Without keys, only the metadata of the encrypted column is available, with
+        // its statistics removed
+        let reader = open_file(&data, None).unwrap();
+        let row_group = reader.metadata().row_group(0);
+        assert!(row_group.column(0).statistics().is_some());
+        assert!(row_group.column(2).statistics().is_none());
+        let row_group_reader = reader.get_row_group(0).unwrap();
+        let mut page_reader = row_group_reader.get_column_page_reader(0).unwrap();
+        assert!(page_reader.get_next_page().unwrap().is_some());
+        let res = row_group_reader.get_column_page_reader(2);
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet error: Column \"salary\" is encrypted, but no decryption properties were provided"
+        );
+
+        // Tampering with the footer is detected
+        let mut tampered = data.clone();
+        let created_by = data.windows(10).rposition(|w| w == b"parquet-rs").unwrap();
+        tampered[created_by] = b'P';
+        let res = open_file(&tampered, Some(decryption));
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet error: Footer signature verification failed"
+        );
+    }
+
+    #[test]
+    fn test_aad_prefix() {
+        let encryption = FileEncryptionProperties::builder(FOOTER_KEY.to_vec())
+            .set_aad_prefix(b"tester".to_vec())
+            .set_store_aad_prefix(false)
+            .build();
+        let data = write_file(encryption);
+
+        let decryption = |prefix: Option<&[u8]>| {
+            let mut builder =
+                FileDecryptionProperties::builder().set_footer_key(FOOTER_KEY.to_vec());
+            if let Some(prefix) = prefix {
+                builder = builder.set_aad_prefix(prefix.to_vec());
+            }
+            builder.build()
+        };
+
+        let reader = open_file(&data, Some(decryption(Some(b"tester")))).unwrap();
+        check_rows(&read_rows(&reader));
+
+        let res = open_file(&data, Some(decryption(None)));
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet error: AAD prefix used for file encryption is not stored in file and not supplied in decryption properties"
+        );
+        let res = open_file(&data, Some(decryption(Some(b"other"))));
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn test_unknown_encrypted_column() {
+        let encryption = FileEncryptionProperties::builder(FOOTER_KEY.to_vec())
+            .set_column_key(ColumnPath::from("ssn"), COLUMN_KEY.to_vec())
+            .build();
+        let schema = Arc::new(
+            parse_message_type("message schema { REQUIRED INT32 id; }").unwrap(),
+        );
+        let props = Arc::new(
+            WriterProperties::builder()
+                .set_file_encryption_properties(encryption)
+                .build(),
+        );
+        let res =
+            SerializedFileWriter::new(InMemoryWriteableCursor::default(), schema, props);
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Parquet error: Encryption key set for column \"ssn\" which is not in the schema"
+        );
+    }
+
+    // Interoperability with the encrypted files of the parquet-testing repository,
+    // which are written by parquet-mr and Arrow C++.
+
+    const TEST_COLUMN_KEY_1: &[u8] = b"1234567890123450";
+    const TEST_COLUMN_KEY_2: &[u8] = b"1234567890123451";
+
+    fn test_file_decryption() -> FileDecryptionPropertiesBuilder {
+        FileDecryptionProperties::builder().set_key_retriever(key_retriever(&[
+            ("kf", FOOTER_KEY),
+            ("kc1", TEST_COLUMN_KEY_1),
+            ("kc2", TEST_COLUMN_KEY_2),
+        ]))
+    }
+
+    fn check_test_file(file_name: &str, decryption: FileDecryptionProperties) {
+        let props = ReaderProperties::builder()
+            .set_file_decryption_properties(decryption)
+            .build();
+        let reader =
+            SerializedFileReader::new_with_properties(get_test_file(file_name), props)
+                .unwrap();
+        let mut num_rows = 0;
+        for (i, row) in reader.get_row_iter(None).unwrap().enumerate() {
+            for (name, field) in row.get_column_iter() {
+                match (name.as_str(), field) {
+                    ("int32_field", Field::Int(v)) => assert_eq!(*v, i as i32),
+                    ("float_field", Field::Float(v)) => {
+                        assert_eq!(*v, i as f32 * 1.1f32)
+                    }
+                    ("double_field", Field::Double(v)) => {
+                        assert!((*v - i as f64 * 1.1111111).abs() < 1e-9)
+                    }
+                    _ => {}
+                }
+            }
+            num_rows += 1;
+        }
+        assert_eq!(num_rows, reader.metadata().file_metadata().num_rows());
+    }
+
+    #[test]
+    fn test_read_uniform_encryption() {
+        check_test_file(
+            "uniform_encryption.parquet.encrypted",
+            test_file_decryption().build(),
+        );
+    }
+
+    #[test]
+    fn test_read_encrypt_columns_and_footer() {
+        check_test_file(
+            "encrypt_columns_and_footer.parquet.encrypted",
+            test_file_decryption().build(),
+        );
+    }
+
+    #[test]
+    fn test_read_encrypt_columns_plaintext_footer() {
+        check_test_file(
+            "encrypt_columns_plaintext_footer.parquet.encrypted",
+            test_file_decryption().build(),
+        );
+    }
+
+    #[test]
+    fn test_read_encrypt_columns_and_footer_aad() {
+        check_test_file(
+            "encrypt_columns_and_footer_aad.parquet.encrypted",
+            test_file_decryption().build(),
+        );
+    }
+
+    #[test]
+    fn test_read_encrypt_columns_and_footer_disable_aad_storage() {
+        check_test_file(
+            "encrypt_columns_and_footer_disable_aad_storage.parquet.encrypted",
+            test_file_decryption()
+                .set_aad_prefix(b"tester".to_vec())
+                .build(),
+        );
+    }
+
+    #[test]
+    fn test_read_encrypt_columns_and_footer_ctr() {
+        let props = ReaderProperties::builder()
+            .set_file_decryption_properties(test_file_decryption().build())
+            .build();
+        let res = SerializedFileReader::new_with_properties(
+            get_test_file("encrypt_columns_and_footer_ctr.parquet.encrypted"),
+            props,
+        );
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "NYI: AES_GCM_CTR_V1 encryption is not supported"
+        );
+    }
+}
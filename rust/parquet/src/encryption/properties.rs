@@ -0,0 +1,440 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+//! Encryption and decryption properties.
+
+use std::{collections::HashMap, fmt, sync::Arc};
+
+use crate::errors::{ParquetError, Result};
+use crate::schema::types::ColumnPath;
+
+const DEFAULT_ENCRYPTED_FOOTER: bool = true;
+const DEFAULT_STORE_AAD_PREFIX: bool = true;
+const DEFAULT_VERIFY_PLAINTEXT_FOOTER: bool = true;
+
+/// Retrieves encryption keys by the key metadata stored in the file.
+///
+/// Key metadata is an arbitrary byte string set by the writer, e.g. the id of a key in
+/// a key management service.
+pub trait KeyRetriever: Send + Sync {
+    /// Returns the key identified by `key_metadata`.
+    fn retrieve_key(&self, key_metadata: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// Key and optional key metadata of an encrypted column.
+#[derive(Clone)]
+pub(crate) struct ColumnKey {
+    pub(crate) key: Vec<u8>,
+    pub(crate) key_metadata: Option<Vec<u8>>,
+}
+
+/// Properties for writing an encrypted file.
+///
+/// Use [`FileEncryptionPropertiesBuilder`] to assemble these properties. If no column
+/// keys are set, all columns are encrypted with the footer key; otherwise only the
+/// columns that have a key are encrypted, and the remaining columns are stored in
+/// plaintext.
+#[derive(Clone)]
+pub struct FileEncryptionProperties {
+    encrypted_footer: bool,
+    footer_key: Vec<u8>,
+    footer_key_metadata: Option<Vec<u8>>,
+    column_keys: HashMap<ColumnPath, ColumnKey>,
+    aad_prefix: Option<Vec<u8>>,
+    store_aad_prefix: bool,
+}
+
+impl FileEncryptionProperties {
+    /// Returns builder for encryption properties with the given footer key.
+    pub fn builder(footer_key: Vec<u8>) -> FileEncryptionPropertiesBuilder {
+        FileEncryptionPropertiesBuilder::new(footer_key)
+    }
+
+    /// Returns `true` if the footer is encrypted, `false` if it is stored in plaintext
+    /// and signed with the footer key.
+    pub fn encrypted_footer(&self) -> bool {
+        self.encrypted_footer
+    }
+
+    /// Returns the footer key metadata, if set.
+    pub fn footer_key_metadata(&self) -> Option<&[u8]> {
+        self.footer_key_metadata.as_deref()
+    }
+
+    /// Returns the AAD prefix, if set.
+    pub fn aad_prefix(&self) -> Option<&[u8]> {
+        self.aad_prefix.as_deref()
+    }
+
+    /// Returns `true` if the AAD prefix is stored in the file.
+    pub fn store_aad_prefix(&self) -> bool {
+        self.store_aad_prefix
+    }
+
+    /// Returns `true` if the column is encrypted.
+    pub fn is_column_encrypted(&self, col: &ColumnPath) -> bool {
+        self.column_keys.is_empty() || self.column_keys.contains_key(col)
+    }
+
+    /// Returns the paths of all columns that have their own key.
+    pub fn column_paths(&self) -> Vec<&ColumnPath> {
+        self.column_keys.keys().collect()
+    }
+
+    pub(crate) fn footer_key(&self) -> &[u8] {
+        &self.footer_key
+    }
+
+    pub(crate) fn column_key(&self, col: &ColumnPath) -> Option<&ColumnKey> {
+        self.column_keys.get(col)
+    }
+}
+
+impl fmt::Debug for FileEncryptionProperties {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Keys are deliberately left out
+        f.debug_struct("FileEncryptionProperties")
+            .field("encrypted_footer", &self.encrypted_footer)
+            .field("footer_key_metadata", &self.footer_key_metadata)
+            .field("encrypted_columns", &self.column_paths())
+            .field("aad_prefix", &self.aad_prefix)
+            .field("store_aad_prefix", &self.store_aad_prefix)
+            .finish()
+    }
+}
+
+/// Builder for [`FileEncryptionProperties`].
+pub struct FileEncryptionPropertiesBuilder {
+    encrypted_footer: bool,
+    footer_key: Vec<u8>,
+    footer_key_metadata: Option<Vec<u8>>,
+    column_keys: HashMap<ColumnPath, ColumnKey>,
+    aad_prefix: Option<Vec<u8>>,
+    store_aad_prefix: bool,
+}
+
+impl FileEncryptionPropertiesBuilder {
+    /// Returns default state of the builder with the given footer key.
+    fn new(footer_key: Vec<u8>) -> Self {
+        Self {
+            encrypted_footer: DEFAULT_ENCRYPTED_FOOTER,
+            footer_key,
+            footer_key_metadata: None,
+            column_keys: HashMap::new(),
+            aad_prefix: None,
+            store_aad_prefix: DEFAULT_STORE_AAD_PREFIX,
+        }
+    }
+
+    /// Finalizes the configuration and returns immutable encryption properties.
+    pub fn build(self) -> FileEncryptionProperties {
+        FileEncryptionProperties {
+            encrypted_footer: self.encrypted_footer,
+            footer_key: self.footer_key,
+            footer_key_metadata: self.footer_key_metadata,
+            column_keys: self.column_keys,
+            aad_prefix: self.aad_prefix,
+            store_aad_prefix: self.store_aad_prefix,
+        }
+    }
+
+    /// Sets flag to store the footer in plaintext, signed with the footer key, instead
+    /// of encrypting it.
+    pub fn set_plaintext_footer(mut self, value: bool) -> Self {
+        self.encrypted_footer = !value;
+        self
+    }
+
+    /// Sets the metadata stored in the file to identify the footer key.
+    pub fn set_footer_key_metadata(mut self, value: Vec<u8>) -> Self {
+        self.footer_key_metadata = Some(value);
+        self
+    }
+
+    /// Sets the key of a column.
+    pub fn set_column_key(mut self, col: ColumnPath, key: Vec<u8>) -> Self {
+        self.column_keys.insert(
+            col,
+            ColumnKey {
+                key,
+                key_metadata: None,
+            },
+        );
+        self
+    }
+
+    /// Sets the key of a column, and the metadata stored in the file to identify it.
+    pub fn set_column_key_with_metadata(
+        mut self,
+        col: ColumnPath,
+        key: Vec<u8>,
+        key_metadata: Vec<u8>,
+    ) -> Self {
+        self.column_keys.insert(
+            col,
+            ColumnKey {
+                key,
+                key_metadata: Some(key_metadata),
+            },
+        );
+        self
+    }
+
+    /// Sets the AAD prefix, which binds the file to an identifier such as its name.
+    pub fn set_aad_prefix(mut self, value: Vec<u8>) -> Self {
+        self.aad_prefix = Some(value);
+        self
+    }
+
+    /// Sets flag to store the AAD prefix in the file. If not stored, readers have to
+    /// supply it.
+    pub fn set_store_aad_prefix(mut self, value: bool) -> Self {
+        self.store_aad_prefix = value;
+        self
+    }
+}
+
+/// Properties for reading an encrypted file.
+///
+/// Use [`FileDecryptionPropertiesBuilder`] to assemble these properties. Keys set
+/// explicitly take precedence over keys returned by the [`KeyRetriever`].
+#[derive(Clone)]
+pub struct FileDecryptionProperties {
+    footer_key: Option<Vec<u8>>,
+    column_keys: HashMap<ColumnPath, Vec<u8>>,
+    key_retriever: Option<Arc<dyn KeyRetriever>>,
+    aad_prefix: Option<Vec<u8>>,
+    verify_plaintext_footer: bool,
+}
+
+impl FileDecryptionProperties {
+    /// Returns builder for decryption properties with default values.
+    pub fn builder() -> FileDecryptionPropertiesBuilder {
+        FileDecryptionPropertiesBuilder::with_defaults()
+    }
+
+    /// Returns the AAD prefix, if set.
+    pub fn aad_prefix(&self) -> Option<&[u8]> {
+        self.aad_prefix.as_deref()
+    }
+
+    /// Returns `true` if the signature of plaintext footers is verified.
+    pub fn verify_plaintext_footer(&self) -> bool {
+        self.verify_plaintext_footer
+    }
+
+    /// Returns the footer key, set explicitly or retrieved by its key metadata.
+    pub(crate) fn footer_key(&self, key_metadata: Option<&[u8]>) -> Result<Vec<u8>> {
+        self.find_key("footer key", self.footer_key.as_ref(), key_metadata)
+    }
+
+    /// Returns the key of a column, set explicitly or retrieved by its key metadata.
+    pub(crate) fn column_key(
+        &self,
+        col: &ColumnPath,
+        key_metadata: Option<&[u8]>,
+    ) -> Result<Vec<u8>> {
+        let name = format!("key for column {}", col);
+        self.find_key(&name, self.column_keys.get(col), key_metadata)
+    }
+
+    fn find_key(
+        &self,
+        name: &str,
+        explicit: Option<&Vec<u8>>,
+        key_metadata: Option<&[u8]>,
+    ) -> Result<Vec<u8>> {
+        if let Some(key) = explicit {
+            return Ok(key.clone());
+        }
+        match (&self.key_retriever, key_metadata) {
+            (Some(retriever), Some(key_metadata)) => retriever.retrieve_key(key_metadata),
+            (Some(_), None) => Err(general_err!(
+                "No {} set and the file has no key metadata to retrieve it",
+                name
+            )),
+            (None, _) => Err(general_err!("No {} set and no key retriever", name)),
+        }
+    }
+}
+
+impl fmt::Debug for FileDecryptionProperties {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Keys are deliberately left out
+        f.debug_struct("FileDecryptionProperties")
+            .field("columns", &self.column_keys.keys().collect::<Vec<_>>())
+            .field("key_retriever", &self.key_retriever.is_some())
+            .field("aad_prefix", &self.aad_prefix)
+            .field("verify_plaintext_footer", &self.verify_plaintext_footer)
+            .finish()
+    }
+}
+
+/// Builder for [`FileDecryptionProperties`].
+pub struct FileDecryptionPropertiesBuilder {
+    footer_key: Option<Vec<u8>>,
+    column_keys: HashMap<ColumnPath, Vec<u8>>,
+    key_retriever: Option<Arc<dyn KeyRetriever>>,
+    aad_prefix: Option<Vec<u8>>,
+    verify_plaintext_footer: bool,
+}
+
+impl FileDecryptionPropertiesBuilder {
+    /// Returns default state of the builder.
+    fn with_defaults() -> Self {
+        Self {
+            footer_key: None,
+            column_keys: HashMap::new(),
+            key_retriever: None,
+            aad_prefix: None,
+            verify_plaintext_footer: DEFAULT_VERIFY_PLAINTEXT_FOOTER,
+        }
+    }
+
+    /// Finalizes the configuration and returns immutable decryption properties.
+    pub fn build(self) -> FileDecryptionProperties {
+        FileDecryptionProperties {
+            footer_key: self.footer_key,
+            column_keys: self.column_keys,
+            key_retriever: self.key_retriever,
+            aad_prefix: self.aad_prefix,
+            verify_plaintext_footer: self.verify_plaintext_footer,
+        }
+    }
+
+    /// Sets the footer key.
+    pub fn set_footer_key(mut self, value: Vec<u8>) -> Self {
+        self.footer_key = Some(value);
+        self
+    }
+
+    /// Sets the key of a column.
+    pub fn set_column_key(mut self, col: ColumnPath, key: Vec<u8>) -> Self {
+        self.column_keys.insert(col, key);
+        self
+    }
+
+    /// Sets the retriever used for keys that are not set explicitly.
+    pub fn set_key_retriever(mut self, value: Arc<dyn KeyRetriever>) -> Self {
+        self.key_retriever = Some(value);
+        self
+    }
+
+    /// Sets the AAD prefix, required for files that were written without storing it.
+    pub fn set_aad_prefix(mut self, value: Vec<u8>) -> Self {
+        self.aad_prefix = Some(value);
+        self
+    }
+
+    /// Sets flag to verify the signature of plaintext footers. Verification requires
+    /// the footer key.
+    pub fn set_verify_plaintext_footer(mut self, value: bool) -> Self {
+        self.verify_plaintext_footer = value;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MapKeyRetriever(HashMap<Vec<u8>, Vec<u8>>);
+
+    impl KeyRetriever for MapKeyRetriever {
+        fn retrieve_key(&self, key_metadata: &[u8]) -> Result<Vec<u8>> {
+            self.0
+                .get(key_metadata)
+                .cloned()
+                .ok_or_else(|| general_err!("unknown key"))
+        }
+    }
+
+    #[test]
+    fn test_encryption_properties() {
+        let props =
+            FileEncryptionProperties::builder(b"0123456789012345".to_vec()).build();
+        assert!(props.encrypted_footer());
+        assert!(props.store_aad_prefix());
+        assert!(props.is_column_encrypted(&ColumnPath::from("a")));
+
+        let props = FileEncryptionProperties::builder(b"0123456789012345".to_vec())
+            .set_plaintext_footer(true)
+            .set_column_key(ColumnPath::from("a"), b"1234567890123450".to_vec())
+            .set_aad_prefix(b"tester".to_vec())
+            .set_store_aad_prefix(false)
+            .build();
+        assert!(!props.encrypted_footer());
+        assert!(!props.store_aad_prefix());
+        assert_eq!(props.aad_prefix(), Some(&b"tester"[..]));
+        assert!(props.is_column_encrypted(&ColumnPath::from("a")));
+        assert!(!props.is_column_encrypted(&ColumnPath::from("b")));
+
+        // Keys are not part of the debug output
+        assert!(!format!("{:?}", props).contains("49, 50, 51"));
+    }
+
+    #[test]
+    fn test_decryption_properties_keys() {
+        let mut keys = HashMap::new();
+        keys.insert(b"kf".to_vec(), b"0123456789012345".to_vec());
+        keys.insert(b"kc1".to_vec(), b"1234567890123450".to_vec());
+        let props = FileDecryptionProperties::builder()
+            .set_column_key(ColumnPath::from("b"), b"1234567890123451".to_vec())
+            .set_key_retriever(Arc::new(MapKeyRetriever(keys)))
+            .build();
+        assert!(props.verify_plaintext_footer());
+
+        assert_eq!(
+            props.footer_key(Some(b"kf")).unwrap(),
+            b"0123456789012345".to_vec()
+        );
+        assert_eq!(
+            props
+                .column_key(&ColumnPath::from("a"), Some(b"kc1"))
+                .unwrap(),
+            b"1234567890123450".to_vec()
+        );
+        assert_eq!(
+            props
+                .column_key(&ColumnPath::from("b"), Some(b"kc1"))
+                .unwrap(),
+            b"1234567890123451".to_vec()
+        );
+        assert_eq!(
+            props.footer_key(None).err().unwrap().to_string(),
+            "Parquet error: No footer key set and the file has no key metadata to retrieve it"
+        );
+        assert_eq!(
+            props
+                .column_key(&ColumnPath::from("c"), Some(b"kc3"))
+                .err()
+                .unwrap()
+                .to_string(),
+            "Parquet error: unknown key"
+        );
+
+        let props = FileDecryptionProperties::builder().build();
+        assert_eq!(
+            props
+                .column_key(&ColumnPath::from("c"), Some(b"kc3"))
+                .err()
+                .unwrap()
+                .to_string(),
+            "Parquet error: No key for column \"c\" set and no key retriever"
+        );
+    }
+}
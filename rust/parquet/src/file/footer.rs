@@ -22,15 +22,19 @@ use std::{
 };
 
 use byteorder::{ByteOrder, LittleEndian};
+#[cfg(feature = "encryption")]
+use parquet_format::FileCryptoMetaData as TFileCryptoMetaData;
 use parquet_format::{ColumnOrder as TColumnOrder, FileMetaData as TFileMetaData};
 use thrift::protocol::TCompactInputProtocol;
 
 use crate::basic::ColumnOrder;
 
+#[cfg(feature = "encryption")]
+use crate::encryption::{decrypt::FileDecryptor, FileDecryptionProperties};
 use crate::errors::{ParquetError, Result};
 use crate::file::{
     metadata::*, reader::ChunkReader, DEFAULT_FOOTER_READ_SIZE, FOOTER_SIZE,
-    PARQUET_MAGIC,
+    PARQUET_MAGIC, PARQUET_MAGIC_ENCRYPTED_FOOTER,
 };
 
 use crate::schema::types::{self, SchemaDescriptor};
@@ -44,6 +48,47 @@ use crate::schema::types::{self, SchemaDescriptor};
 /// The reader first reads DEFAULT_FOOTER_SIZE bytes from the end of the file.
 /// If it is not enough according to the length indicated in the footer, it reads more bytes.
 pub fn parse_metadata<R: ChunkReader>(chunk_reader: &R) -> Result<ParquetMetaData> {
+    let (metadata_read, _, encrypted_footer) = read_metadata(chunk_reader)?;
+    if encrypted_footer {
+        return Err(general_err!(
+            "Parquet file has an encrypted footer, but no decryption properties were provided"
+        ));
+    }
+    parse_metadata_buffer(metadata_read)
+}
+
+/// Same as [`parse_metadata`], but decrypts the metadata of encrypted files with the
+/// given decryption properties.
+///
+/// Files with a plaintext footer can be read without decryption properties, in which
+/// case only their unencrypted columns are readable.
+#[cfg(feature = "encryption")]
+pub fn parse_metadata_with_decryption<R: ChunkReader>(
+    chunk_reader: &R,
+    decryption_properties: Option<&FileDecryptionProperties>,
+) -> Result<ParquetMetaData> {
+    let (metadata_read, metadata_len, encrypted_footer) = read_metadata(chunk_reader)?;
+    if decryption_properties.is_none() && !encrypted_footer {
+        return parse_metadata_buffer(metadata_read);
+    }
+    let mut buf = Vec::with_capacity(metadata_len);
+    metadata_read
+        .take(metadata_len as u64)
+        .read_to_end(&mut buf)?;
+    match decryption_properties {
+        Some(props) => parse_encrypted_metadata_buffer(&buf, encrypted_footer, props),
+        None => Err(general_err!(
+            "Parquet file has an encrypted footer, but no decryption properties were provided"
+        )),
+    }
+}
+
+/// Reads the footer of a Parquet file, returning a reader positioned at the start of
+/// the serialized metadata, the length of the metadata and whether the footer is
+/// encrypted.
+fn read_metadata<R: ChunkReader>(
+    chunk_reader: &R,
+) -> Result<(Box<dyn Read + '_>, usize, bool)> {
     // check file is large enough to hold footer
     let file_size = chunk_reader.len();
     if file_size < (FOOTER_SIZE as u64) {
@@ -59,26 +104,14 @@ pub fn parse_metadata<R: ChunkReader>(chunk_reader: &R) -> Result<ParquetMetaDat
     let mut default_len_end_buf = vec![0; default_end_len];
     default_end_reader.read_exact(&mut default_len_end_buf)?;
 
-    // check this is indeed a parquet file
-    if default_len_end_buf[default_end_len - 4..] != PARQUET_MAGIC {
-        return Err(general_err!("Invalid Parquet file. Corrupt footer"));
-    }
-
-    // get the metadata length from the footer
-    let metadata_len = LittleEndian::read_i32(
-        &default_len_end_buf[default_end_len - 8..default_end_len - 4],
-    ) as i64;
-    if metadata_len < 0 {
-        return Err(general_err!(
-            "Invalid Parquet file. Metadata length is less than zero ({})",
-            metadata_len
-        ));
-    }
-    let footer_metadata_len = FOOTER_SIZE + metadata_len as usize;
+    let (metadata_len, encrypted_footer) = decode_footer_with_magic(
+        &default_len_end_buf[default_end_len - FOOTER_SIZE..default_end_len],
+    )?;
+    let footer_metadata_len = FOOTER_SIZE + metadata_len;
 
     // build up the reader covering the entire metadata
     let mut default_end_cursor = Cursor::new(default_len_end_buf);
-    let metadata_read: Box<dyn Read>;
+    let metadata_read: Box<dyn Read + '_>;
     if footer_metadata_len > file_size as usize {
         return Err(general_err!(
             "Invalid Parquet file. Metadata start is less than zero ({})",
@@ -92,15 +125,133 @@ pub fn parse_metadata<R: ChunkReader>(chunk_reader: &R) -> Result<ParquetMetaDat
         // the end of file read by default is not long enough, read missing bytes
         let complementary_end_read = chunk_reader.get_read(
             file_size - footer_metadata_len as u64,
-            FOOTER_SIZE + metadata_len as usize - default_end_len,
+            footer_metadata_len - default_end_len,
         )?;
         metadata_read = Box::new(complementary_end_read.chain(default_end_cursor));
     }
+    Ok((metadata_read, metadata_len, encrypted_footer))
+}
+
+/// Decodes the Parquet footer, i.e. the last 8 bytes of a file, returning the length
+/// of the serialized metadata that precedes it.
+///
+/// Returns error if the magic bytes are missing or the length is negative.
+pub fn decode_footer(footer: &[u8]) -> Result<usize> {
+    match decode_footer_with_magic(footer)? {
+        (_, true) => Err(general_err!(
+            "Parquet file has an encrypted footer, which is not supported here"
+        )),
+        (metadata_len, false) => Ok(metadata_len),
+    }
+}
 
-    // TODO: row group filtering
+/// Decodes the Parquet footer, also accepting the magic bytes of files with an
+/// encrypted footer. Returns the metadata length and whether the footer is encrypted.
+fn decode_footer_with_magic(footer: &[u8]) -> Result<(usize, bool)> {
+    if footer.len() != FOOTER_SIZE {
+        return Err(general_err!("Invalid Parquet file. Corrupt footer"));
+    }
+    let encrypted_footer = if footer[4..] == PARQUET_MAGIC {
+        false
+    } else if footer[4..] == PARQUET_MAGIC_ENCRYPTED_FOOTER {
+        true
+    } else {
+        return Err(general_err!("Invalid Parquet file. Corrupt footer"));
+    };
+
+    // get the metadata length from the footer
+    let metadata_len = LittleEndian::read_i32(&footer[..4]) as i64;
+    if metadata_len < 0 {
+        return Err(general_err!(
+            "Invalid Parquet file. Metadata length is less than zero ({})",
+            metadata_len
+        ));
+    }
+    Ok((metadata_len as usize, encrypted_footer))
+}
+
+/// Parses [`ParquetMetaData`] from a reader positioned at the start of the Thrift
+/// encoded file metadata.
+pub fn parse_metadata_buffer<T: Read>(metadata_read: T) -> Result<ParquetMetaData> {
+    let t_file_metadata = read_file_metadata(metadata_read)?;
+    from_thrift_file_metadata(t_file_metadata)
+}
+
+/// Parses [`ParquetMetaData`] of an encrypted file from the serialized metadata, which
+/// is either the crypto metadata followed by the encrypted footer, or the plaintext
+/// footer followed by its signature.
+#[cfg(feature = "encryption")]
+fn parse_encrypted_metadata_buffer(
+    buf: &[u8],
+    encrypted_footer: bool,
+    props: &FileDecryptionProperties,
+) -> Result<ParquetMetaData> {
+    let mut cursor = Cursor::new(buf);
+    if encrypted_footer {
+        let t_crypto_metadata = {
+            let mut prot = TCompactInputProtocol::new(&mut cursor);
+            TFileCryptoMetaData::read_from_in_protocol(&mut prot)
+                .map_err(|e| general_err!("Could not parse crypto metadata: {}", e))?
+        };
+        let decryptor = FileDecryptor::new(
+            props,
+            &t_crypto_metadata.encryption_algorithm,
+            t_crypto_metadata.key_metadata.as_deref(),
+            true,
+        )?;
+        let footer = decryptor.decrypt_footer(&buf[cursor.position() as usize..])?;
+        let t_file_metadata = read_file_metadata(footer.as_slice())?;
+        from_encrypted_thrift_file_metadata(t_file_metadata, decryptor)
+    } else {
+        let t_file_metadata = read_file_metadata(&mut cursor)?;
+        let algorithm = match t_file_metadata.encryption_algorithm {
+            Some(ref algorithm) => algorithm,
+            None => {
+                return Err(general_err!(
+                    "Decryption properties were provided for a Parquet file that is not encrypted"
+                ))
+            }
+        };
+        let decryptor = FileDecryptor::new(
+            props,
+            algorithm,
+            t_file_metadata.footer_signing_key_metadata.as_deref(),
+            props.verify_plaintext_footer(),
+        )?;
+        if props.verify_plaintext_footer() {
+            let footer_len = cursor.position() as usize;
+            decryptor.verify_footer_signature(&buf[..footer_len], &buf[footer_len..])?;
+        }
+        from_encrypted_thrift_file_metadata(t_file_metadata, decryptor)
+    }
+}
+
+/// Reads the Thrift encoded file metadata.
+fn read_file_metadata<T: Read>(metadata_read: T) -> Result<TFileMetaData> {
     let mut prot = TCompactInputProtocol::new(metadata_read);
-    let t_file_metadata: TFileMetaData = TFileMetaData::read_from_in_protocol(&mut prot)
-        .map_err(|e| ParquetError::General(format!("Could not parse metadata: {}", e)))?;
+    TFileMetaData::read_from_in_protocol(&mut prot)
+        .map_err(|e| ParquetError::General(format!("Could not parse metadata: {}", e)))
+}
+
+/// Converts the Thrift file metadata of an encrypted file, decrypting the metadata of
+/// its encrypted columns.
+#[cfg(feature = "encryption")]
+fn from_encrypted_thrift_file_metadata(
+    mut t_file_metadata: TFileMetaData,
+    decryptor: FileDecryptor,
+) -> Result<ParquetMetaData> {
+    for (i, rg) in t_file_metadata.row_groups.iter_mut().enumerate() {
+        // Page AADs depend on the row group ordinal, so make sure it is known
+        let ordinal = *rg.ordinal.get_or_insert(i as i16) as usize;
+        decryptor.decrypt_row_group(rg, ordinal)?;
+    }
+    Ok(from_thrift_file_metadata(t_file_metadata)?
+        .with_file_decryptor(Some(Arc::new(decryptor))))
+}
+
+/// Converts the Thrift file metadata.
+fn from_thrift_file_metadata(t_file_metadata: TFileMetaData) -> Result<ParquetMetaData> {
+    // TODO: row group filtering
     let schema = types::from_thrift(&t_file_metadata.schema)?;
     let schema_descr = Arc::new(SchemaDescriptor::new(schema));
     let mut row_groups = Vec::new();
@@ -35,9 +35,11 @@
 
 use std::sync::Arc;
 
-use parquet_format::{ColumnChunk, ColumnMetaData, RowGroup};
+use parquet_format::{ColumnChunk, ColumnCryptoMetaData, ColumnMetaData, RowGroup};
 
 use crate::basic::{ColumnOrder, Compression, Encoding, Type};
+#[cfg(feature = "encryption")]
+use crate::encryption::decrypt::FileDecryptor;
 use crate::errors::{ParquetError, Result};
 use crate::file::statistics::{self, Statistics};
 use crate::schema::types::{
@@ -50,6 +52,8 @@ use crate::schema::types::{
 pub struct ParquetMetaData {
     file_metadata: FileMetaData,
     row_groups: Vec<RowGroupMetaData>,
+    #[cfg(feature = "encryption")]
+    file_decryptor: Option<Arc<FileDecryptor>>,
 }
 
 impl ParquetMetaData {
@@ -59,9 +63,28 @@ impl ParquetMetaData {
         ParquetMetaData {
             file_metadata,
             row_groups,
+            #[cfg(feature = "encryption")]
+            file_decryptor: None,
         }
     }
 
+    /// Sets the decryptor for the column chunks of an encrypted file.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn with_file_decryptor(
+        mut self,
+        file_decryptor: Option<Arc<FileDecryptor>>,
+    ) -> Self {
+        self.file_decryptor = file_decryptor;
+        self
+    }
+
+    /// Returns the decryptor for the column chunks, if the file is encrypted and was
+    /// opened with decryption properties.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn file_decryptor(&self) -> Option<&Arc<FileDecryptor>> {
+        self.file_decryptor.as_ref()
+    }
+
     /// Returns file metadata as reference.
     pub fn file_metadata(&self) -> &FileMetaData {
         &self.file_metadata
@@ -192,6 +215,7 @@ pub struct RowGroupMetaData {
     columns: Vec<ColumnChunkMetaData>,
     num_rows: i64,
     total_byte_size: i64,
+    ordinal: Option<i16>,
     schema_descr: SchemaDescPtr,
 }
 
@@ -226,6 +250,11 @@ impl RowGroupMetaData {
         self.total_byte_size
     }
 
+    /// Position of this row group in the file, if known.
+    pub fn ordinal(&self) -> Option<i16> {
+        self.ordinal
+    }
+
     /// Returns reference to a schema descriptor.
     pub fn schema_descr(&self) -> &SchemaDescriptor {
         self.schema_descr.as_ref()
@@ -244,6 +273,7 @@ impl RowGroupMetaData {
         assert_eq!(schema_descr.num_columns(), rg.columns.len());
         let total_byte_size = rg.total_byte_size;
         let num_rows = rg.num_rows;
+        let ordinal = rg.ordinal;
         let mut columns = vec![];
         for (c, d) in rg.columns.drain(0..).zip(schema_descr.columns()) {
             let cc = ColumnChunkMetaData::from_thrift(d.clone(), c)?;
@@ -253,6 +283,7 @@ impl RowGroupMetaData {
             columns,
             num_rows,
             total_byte_size,
+            ordinal,
             schema_descr,
         })
     }
@@ -264,6 +295,9 @@ impl RowGroupMetaData {
             total_byte_size: self.total_byte_size,
             num_rows: self.num_rows,
             sorting_columns: None,
+            file_offset: None,
+            total_compressed_size: None,
+            ordinal: self.ordinal,
         }
     }
 }
@@ -274,6 +308,7 @@ pub struct RowGroupMetaDataBuilder {
     schema_descr: SchemaDescPtr,
     num_rows: i64,
     total_byte_size: i64,
+    ordinal: Option<i16>,
 }
 
 impl RowGroupMetaDataBuilder {
@@ -284,6 +319,7 @@ impl RowGroupMetaDataBuilder {
             schema_descr,
             num_rows: 0,
             total_byte_size: 0,
+            ordinal: None,
         }
     }
 
@@ -299,6 +335,12 @@ impl RowGroupMetaDataBuilder {
         self
     }
 
+    /// Sets position of this row group in the file.
+    pub fn set_ordinal(mut self, value: i16) -> Self {
+        self.ordinal = Some(value);
+        self
+    }
+
     /// Sets column metadata for this row group.
     pub fn set_column_metadata(mut self, value: Vec<ColumnChunkMetaData>) -> Self {
         self.columns = value;
@@ -319,6 +361,7 @@ impl RowGroupMetaDataBuilder {
             columns: self.columns,
             num_rows: self.num_rows,
             total_byte_size: self.total_byte_size,
+            ordinal: self.ordinal,
             schema_descr: self.schema_descr,
         })
     }
@@ -341,6 +384,7 @@ pub struct ColumnChunkMetaData {
     index_page_offset: Option<i64>,
     dictionary_page_offset: Option<i64>,
     statistics: Option<Statistics>,
+    crypto_metadata: Option<ColumnCryptoMetaData>,
 }
 
 /// Represents common operations for a column chunk.
@@ -454,6 +498,11 @@ impl ColumnChunkMetaData {
         self.statistics.as_ref()
     }
 
+    /// Returns how this column chunk is encrypted, or `None` if it is not encrypted.
+    pub fn crypto_metadata(&self) -> Option<&ColumnCryptoMetaData> {
+        self.crypto_metadata.as_ref()
+    }
+
     /// Method to convert from Thrift.
     pub fn from_thrift(column_descr: ColumnDescPtr, cc: ColumnChunk) -> Result<Self> {
         if cc.meta_data.is_none() {
@@ -477,6 +526,7 @@ impl ColumnChunkMetaData {
         let index_page_offset = col_metadata.index_page_offset;
         let dictionary_page_offset = col_metadata.dictionary_page_offset;
         let statistics = statistics::from_thrift(column_type, col_metadata.statistics);
+        let crypto_metadata = cc.crypto_metadata;
         let result = ColumnChunkMetaData {
             column_type,
             column_path,
@@ -492,6 +542,7 @@ impl ColumnChunkMetaData {
             index_page_offset,
             dictionary_page_offset,
             statistics,
+            crypto_metadata,
         };
         Ok(result)
     }
@@ -512,6 +563,7 @@ impl ColumnChunkMetaData {
             dictionary_page_offset: self.dictionary_page_offset,
             statistics: statistics::to_thrift(self.statistics.as_ref()),
             encoding_stats: None,
+            bloom_filter_offset: None,
         };
 
         ColumnChunk {
@@ -522,6 +574,8 @@ impl ColumnChunkMetaData {
             offset_index_length: None,
             column_index_offset: None,
             column_index_length: None,
+            crypto_metadata: self.crypto_metadata.clone(),
+            encrypted_column_metadata: None,
         }
     }
 }
@@ -644,6 +698,7 @@ impl ColumnChunkMetaDataBuilder {
             index_page_offset: self.index_page_offset,
             dictionary_page_offset: self.dictionary_page_offset,
             statistics: self.statistics,
+            crypto_metadata: None,
         })
     }
 }
@@ -664,9 +719,11 @@ mod tests {
         let row_group_meta = RowGroupMetaData::builder(schema_descr.clone())
             .set_num_rows(1000)
             .set_total_byte_size(2000)
+            .set_ordinal(3)
             .set_column_metadata(columns)
             .build()
             .unwrap();
+        assert_eq!(row_group_meta.ordinal(), Some(3));
 
         let row_group_exp = row_group_meta.to_thrift();
         let row_group_res =
@@ -15,7 +15,7 @@
 // specific language governing permissions and limitations
 // under the License.
 
-//! Writer properties.
+//! Writer and reader properties.
 //!
 //! # Usage
 //!
@@ -46,11 +46,20 @@
 //!     props.encoding(&ColumnPath::from("col2")),
 //!     Some(Encoding::PLAIN)
 //! );
+//!
+//! // Reader properties are assembled the same way.
+//! let props = ReaderProperties::builder()
+//!     .set_verify_page_checksum(true)
+//!     .build();
+//!
+//! assert!(props.verify_page_checksum());
 //! ```
 
 use std::{collections::HashMap, sync::Arc};
 
 use crate::basic::{Compression, Encoding};
+#[cfg(feature = "encryption")]
+use crate::encryption::{FileDecryptionProperties, FileEncryptionProperties};
 use crate::file::metadata::KeyValue;
 use crate::schema::types::ColumnPath;
 
@@ -63,6 +72,8 @@ const DEFAULT_DICTIONARY_PAGE_SIZE_LIMIT: usize = DEFAULT_PAGE_SIZE;
 const DEFAULT_STATISTICS_ENABLED: bool = true;
 const DEFAULT_MAX_STATISTICS_SIZE: usize = 4096;
 const DEFAULT_MAX_ROW_GROUP_SIZE: usize = 128 * 1024 * 1024;
+const DEFAULT_PAGE_CHECKSUM_ENABLED: bool = false;
+const DEFAULT_VERIFY_PAGE_CHECKSUM: bool = false;
 const DEFAULT_CREATED_BY: &str = env!("PARQUET_CREATED_BY");
 
 /// Parquet writer version.
@@ -98,8 +109,11 @@ pub struct WriterProperties {
     write_batch_size: usize,
     max_row_group_size: usize,
     writer_version: WriterVersion,
+    page_checksum_enabled: bool,
     created_by: String,
     pub(crate) key_value_metadata: Option<Vec<KeyValue>>,
+    #[cfg(feature = "encryption")]
+    file_encryption_properties: Option<Arc<FileEncryptionProperties>>,
     default_column_properties: ColumnProperties,
     column_properties: HashMap<ColumnPath, ColumnProperties>,
 }
@@ -139,6 +153,11 @@ impl WriterProperties {
         self.writer_version
     }
 
+    /// Returns `true` if a CRC32 checksum is computed and written for every page.
+    pub fn page_checksum_enabled(&self) -> bool {
+        self.page_checksum_enabled
+    }
+
     /// Returns `created_by` string.
     pub fn created_by(&self) -> &str {
         &self.created_by
@@ -149,6 +168,12 @@ impl WriterProperties {
         &self.key_value_metadata
     }
 
+    /// Returns encryption properties, if the file is encrypted.
+    #[cfg(feature = "encryption")]
+    pub fn file_encryption_properties(&self) -> Option<&FileEncryptionProperties> {
+        self.file_encryption_properties.as_deref()
+    }
+
     /// Returns encoding for a data page, when dictionary encoding is enabled.
     /// This is not configurable.
     #[inline]
@@ -224,8 +249,11 @@ pub struct WriterPropertiesBuilder {
     write_batch_size: usize,
     max_row_group_size: usize,
     writer_version: WriterVersion,
+    page_checksum_enabled: bool,
     created_by: String,
     key_value_metadata: Option<Vec<KeyValue>>,
+    #[cfg(feature = "encryption")]
+    file_encryption_properties: Option<Arc<FileEncryptionProperties>>,
     default_column_properties: ColumnProperties,
     column_properties: HashMap<ColumnPath, ColumnProperties>,
 }
@@ -239,8 +267,11 @@ impl WriterPropertiesBuilder {
             write_batch_size: DEFAULT_WRITE_BATCH_SIZE,
             max_row_group_size: DEFAULT_MAX_ROW_GROUP_SIZE,
             writer_version: DEFAULT_WRITER_VERSION,
+            page_checksum_enabled: DEFAULT_PAGE_CHECKSUM_ENABLED,
             created_by: DEFAULT_CREATED_BY.to_string(),
             key_value_metadata: None,
+            #[cfg(feature = "encryption")]
+            file_encryption_properties: None,
             default_column_properties: ColumnProperties::new(),
             column_properties: HashMap::new(),
         }
@@ -254,8 +285,11 @@ impl WriterPropertiesBuilder {
             write_batch_size: self.write_batch_size,
             max_row_group_size: self.max_row_group_size,
             writer_version: self.writer_version,
+            page_checksum_enabled: self.page_checksum_enabled,
             created_by: self.created_by,
             key_value_metadata: self.key_value_metadata,
+            #[cfg(feature = "encryption")]
+            file_encryption_properties: self.file_encryption_properties,
             default_column_properties: self.default_column_properties,
             column_properties: self.column_properties,
         }
@@ -294,6 +328,13 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets flag to compute a CRC32 checksum of every page and store it in the page
+    /// header, so that readers can detect corrupted pages.
+    pub fn set_page_checksum_enabled(mut self, value: bool) -> Self {
+        self.page_checksum_enabled = value;
+        self
+    }
+
     /// Sets "created by" property.
     pub fn set_created_by(mut self, value: String) -> Self {
         self.created_by = value;
@@ -306,6 +347,16 @@ impl WriterPropertiesBuilder {
         self
     }
 
+    /// Sets encryption properties, which enables modular encryption of the file.
+    #[cfg(feature = "encryption")]
+    pub fn set_file_encryption_properties(
+        mut self,
+        value: FileEncryptionProperties,
+    ) -> Self {
+        self.file_encryption_properties = Some(Arc::new(value));
+        self
+    }
+
     // ----------------------------------------------------------------------
     // Setters for any column (global)
 
@@ -500,6 +551,89 @@ impl ColumnProperties {
     }
 }
 
+/// Reference counted reader properties.
+pub type ReaderPropertiesPtr = Arc<ReaderProperties>;
+
+/// Reader properties.
+///
+/// All properties are immutable, use [`ReaderPropertiesBuilder`] to assemble these
+/// properties.
+#[derive(Debug, Clone)]
+pub struct ReaderProperties {
+    verify_page_checksum: bool,
+    #[cfg(feature = "encryption")]
+    file_decryption_properties: Option<Arc<FileDecryptionProperties>>,
+}
+
+impl ReaderProperties {
+    /// Returns builder for reader properties with default values.
+    pub fn builder() -> ReaderPropertiesBuilder {
+        ReaderPropertiesBuilder::with_defaults()
+    }
+
+    /// Returns `true` if the CRC32 checksum of every page that has one is verified
+    /// when the page is read.
+    pub fn verify_page_checksum(&self) -> bool {
+        self.verify_page_checksum
+    }
+
+    /// Returns decryption properties, if set.
+    #[cfg(feature = "encryption")]
+    pub fn file_decryption_properties(&self) -> Option<&FileDecryptionProperties> {
+        self.file_decryption_properties.as_deref()
+    }
+}
+
+impl Default for ReaderProperties {
+    fn default() -> Self {
+        Self::builder().build()
+    }
+}
+
+/// Reader properties builder.
+pub struct ReaderPropertiesBuilder {
+    verify_page_checksum: bool,
+    #[cfg(feature = "encryption")]
+    file_decryption_properties: Option<Arc<FileDecryptionProperties>>,
+}
+
+impl ReaderPropertiesBuilder {
+    /// Returns default state of the builder.
+    fn with_defaults() -> Self {
+        Self {
+            verify_page_checksum: DEFAULT_VERIFY_PAGE_CHECKSUM,
+            #[cfg(feature = "encryption")]
+            file_decryption_properties: None,
+        }
+    }
+
+    /// Finalizes the configuration and returns immutable reader properties struct.
+    pub fn build(self) -> ReaderProperties {
+        ReaderProperties {
+            verify_page_checksum: self.verify_page_checksum,
+            #[cfg(feature = "encryption")]
+            file_decryption_properties: self.file_decryption_properties,
+        }
+    }
+
+    /// Sets flag to verify the CRC32 checksum of pages. Pages written without a
+    /// checksum are not verified.
+    pub fn set_verify_page_checksum(mut self, value: bool) -> Self {
+        self.verify_page_checksum = value;
+        self
+    }
+
+    /// Sets decryption properties, which are required to read encrypted files.
+    #[cfg(feature = "encryption")]
+    pub fn set_file_decryption_properties(
+        mut self,
+        value: FileDecryptionProperties,
+    ) -> Self {
+        self.file_decryption_properties = Some(Arc::new(value));
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -521,8 +655,11 @@ mod tests {
         assert_eq!(props.write_batch_size(), DEFAULT_WRITE_BATCH_SIZE);
         assert_eq!(props.max_row_group_size(), DEFAULT_MAX_ROW_GROUP_SIZE);
         assert_eq!(props.writer_version(), DEFAULT_WRITER_VERSION);
+        assert_eq!(props.page_checksum_enabled(), DEFAULT_PAGE_CHECKSUM_ENABLED);
         assert_eq!(props.created_by(), DEFAULT_CREATED_BY);
         assert_eq!(props.key_value_metadata(), &None);
+        #[cfg(feature = "encryption")]
+        assert!(props.file_encryption_properties().is_none());
         assert_eq!(props.encoding(&ColumnPath::from("col")), None);
         assert_eq!(
             props.compression(&ColumnPath::from("col")),
@@ -603,6 +740,7 @@ mod tests {
             .set_dictionary_pagesize_limit(20)
             .set_write_batch_size(30)
             .set_max_row_group_size(40)
+            .set_page_checksum_enabled(true)
             .set_created_by("default".to_owned())
             .set_key_value_metadata(Some(vec![KeyValue::new(
                 "key".to_string(),
@@ -627,6 +765,7 @@ mod tests {
         assert_eq!(props.dictionary_pagesize_limit(), 20);
         assert_eq!(props.write_batch_size(), 30);
         assert_eq!(props.max_row_group_size(), 40);
+        assert_eq!(props.page_checksum_enabled(), true);
         assert_eq!(props.created_by(), "default");
         assert_eq!(
             props.key_value_metadata(),
@@ -676,4 +815,50 @@ mod tests {
             DEFAULT_DICTIONARY_ENABLED
         );
     }
+
+    #[test]
+    fn test_reader_properties_default_settings() {
+        let props = ReaderProperties::builder().build();
+        assert_eq!(props.verify_page_checksum(), DEFAULT_VERIFY_PAGE_CHECKSUM);
+        #[cfg(feature = "encryption")]
+        assert!(props.file_decryption_properties().is_none());
+    }
+
+    #[test]
+    fn test_reader_properties_builder() {
+        let props = ReaderProperties::builder()
+            .set_verify_page_checksum(true)
+            .build();
+        assert_eq!(props.verify_page_checksum(), true);
+    }
+
+    #[test]
+    #[cfg(feature = "encryption")]
+    fn test_properties_encryption() {
+        let props = WriterProperties::builder()
+            .set_file_encryption_properties(
+                FileEncryptionProperties::builder(b"0123456789012345".to_vec())
+                    .set_plaintext_footer(true)
+                    .build(),
+            )
+            .build();
+        assert_eq!(
+            props
+                .file_encryption_properties()
+                .map(|p| p.encrypted_footer()),
+            Some(false)
+        );
+
+        let props = ReaderProperties::builder()
+            .set_file_decryption_properties(
+                FileDecryptionProperties::builder()
+                    .set_aad_prefix(b"tester".to_vec())
+                    .build(),
+            )
+            .build();
+        assert_eq!(
+            props.file_decryption_properties().unwrap().aad_prefix(),
+            Some(&b"tester"[..])
+        );
+    }
 }
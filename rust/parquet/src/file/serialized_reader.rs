@@ -20,17 +20,29 @@
 
 use std::{convert::TryFrom, fs::File, io::Read, path::Path, sync::Arc};
 
+#[cfg(feature = "encryption")]
+use byteorder::{ByteOrder, LittleEndian};
+#[cfg(feature = "encryption")]
+use parquet_format::ColumnCryptoMetaData;
 use parquet_format::{PageHeader, PageType};
 use thrift::protocol::TCompactInputProtocol;
 
 use crate::basic::{Compression, Encoding, Type};
 use crate::column::page::{Page, PageReader};
 use crate::compression::{create_codec, Codec};
+#[cfg(feature = "encryption")]
+use crate::encryption::decrypt::{FileDecryptor, PageDecryptor};
 use crate::errors::{ParquetError, Result};
-use crate::file::{footer, metadata::*, reader::*, statistics};
+use crate::file::{
+    footer,
+    metadata::*,
+    properties::{ReaderProperties, ReaderPropertiesPtr},
+    reader::*,
+    statistics,
+};
 use crate::record::reader::RowIter;
 use crate::record::Row;
-use crate::schema::types::Type as SchemaType;
+use crate::schema::types::{ColumnPath, Type as SchemaType};
 use crate::util::{io::TryClone, memory::ByteBufferPtr};
 
 // export `SliceableCursor` and `FileSource` publically so clients can
@@ -125,19 +137,43 @@ impl IntoIterator for SerializedFileReader<File> {
 pub struct SerializedFileReader<R: ChunkReader> {
     chunk_reader: Arc<R>,
     metadata: ParquetMetaData,
+    props: ReaderPropertiesPtr,
 }
 
 impl<R: 'static + ChunkReader> SerializedFileReader<R> {
     /// Creates file reader from a Parquet file.
     /// Returns error if Parquet file does not exist or is corrupt.
     pub fn new(chunk_reader: R) -> Result<Self> {
+        Self::new_with_properties(chunk_reader, ReaderProperties::default())
+    }
+
+    /// Creates file reader from a Parquet file with the given reader properties.
+    /// Returns error if Parquet file does not exist or is corrupt.
+    pub fn new_with_properties(chunk_reader: R, props: ReaderProperties) -> Result<Self> {
+        #[cfg(feature = "encryption")]
+        let metadata = footer::parse_metadata_with_decryption(
+            &chunk_reader,
+            props.file_decryption_properties(),
+        )?;
+        #[cfg(not(feature = "encryption"))]
         let metadata = footer::parse_metadata(&chunk_reader)?;
         Ok(Self {
             chunk_reader: Arc::new(chunk_reader),
             metadata,
+            props: Arc::new(props),
         })
     }
 
+    /// Creates file reader from a chunk reader and metadata that has already been
+    /// parsed, e.g. by an asynchronous reader that fetched the footer separately.
+    pub fn new_with_metadata(chunk_reader: R, metadata: ParquetMetaData) -> Self {
+        Self {
+            chunk_reader: Arc::new(chunk_reader),
+            metadata,
+            props: Arc::new(ReaderProperties::default()),
+        }
+    }
+
     /// Filters row group metadata to only those row groups,
     /// for which the predicate function returns true
     pub fn filter_row_groups(
@@ -150,10 +186,14 @@ impl<R: 'static + ChunkReader> SerializedFileReader<R> {
                 filtered_row_groups.push(row_group_metadata.clone());
             }
         }
-        self.metadata = ParquetMetaData::new(
+        let metadata = ParquetMetaData::new(
             self.metadata.file_metadata().clone(),
             filtered_row_groups,
         );
+        #[cfg(feature = "encryption")]
+        let metadata =
+            metadata.with_file_decryptor(self.metadata.file_decryptor().cloned());
+        self.metadata = metadata;
     }
 }
 
@@ -170,10 +210,12 @@ impl<R: 'static + ChunkReader> FileReader for SerializedFileReader<R> {
         let row_group_metadata = self.metadata.row_group(i);
         // Row groups should be processed sequentially.
         let f = Arc::clone(&self.chunk_reader);
-        Ok(Box::new(SerializedRowGroupReader::new(
-            f,
-            row_group_metadata,
-        )))
+        let row_group_reader =
+            SerializedRowGroupReader::new(f, row_group_metadata, self.props.clone());
+        #[cfg(feature = "encryption")]
+        let row_group_reader =
+            row_group_reader.with_file_decryptor(self.metadata.file_decryptor().cloned());
+        Ok(Box::new(row_group_reader))
     }
 
     fn get_row_iter(&self, projection: Option<SchemaType>) -> Result<RowIter> {
@@ -185,16 +227,62 @@ impl<R: 'static + ChunkReader> FileReader for SerializedFileReader<R> {
 pub struct SerializedRowGroupReader<'a, R: ChunkReader> {
     chunk_reader: Arc<R>,
     metadata: &'a RowGroupMetaData,
+    props: ReaderPropertiesPtr,
+    #[cfg(feature = "encryption")]
+    file_decryptor: Option<Arc<FileDecryptor>>,
 }
 
 impl<'a, R: ChunkReader> SerializedRowGroupReader<'a, R> {
-    /// Creates new row group reader from a file and row group metadata.
-    fn new(chunk_reader: Arc<R>, metadata: &'a RowGroupMetaData) -> Self {
+    /// Creates new row group reader from a file, row group metadata and reader
+    /// properties.
+    fn new(
+        chunk_reader: Arc<R>,
+        metadata: &'a RowGroupMetaData,
+        props: ReaderPropertiesPtr,
+    ) -> Self {
         Self {
             chunk_reader,
             metadata,
+            props,
+            #[cfg(feature = "encryption")]
+            file_decryptor: None,
         }
     }
+
+    /// Sets the decryptor of an encrypted file, which decrypts the pages of this row
+    /// group's encrypted columns.
+    #[cfg(feature = "encryption")]
+    fn with_file_decryptor(mut self, file_decryptor: Option<Arc<FileDecryptor>>) -> Self {
+        self.file_decryptor = file_decryptor;
+        self
+    }
+
+    /// Decrypts the pages of the encrypted column `i` read by `page_reader`.
+    #[cfg(feature = "encryption")]
+    fn with_page_decryptor<T: Read>(
+        &self,
+        page_reader: SerializedPageReader<T>,
+        crypto_metadata: &ColumnCryptoMetaData,
+        i: usize,
+    ) -> Result<SerializedPageReader<T>> {
+        let col = self.metadata.column(i);
+        let file_decryptor = self.file_decryptor.as_ref().ok_or_else(|| {
+            general_err!(
+                "Column {} is encrypted, but no decryption properties were provided",
+                col.column_path()
+            )
+        })?;
+        let row_group_ordinal = self.metadata.ordinal().ok_or_else(|| {
+            general_err!("Row group ordinal is required to decrypt column chunks")
+        })?;
+        let page_decryptor = file_decryptor.page_decryptor(
+            crypto_metadata,
+            row_group_ordinal as usize,
+            i,
+            col.has_dictionary_page(),
+        )?;
+        Ok(page_reader.with_decryptor(page_decryptor))
+    }
 }
 
 impl<'a, R: 'static + ChunkReader> RowGroupReader for SerializedRowGroupReader<'a, R> {
@@ -211,12 +299,27 @@ impl<'a, R: 'static + ChunkReader> RowGroupReader for SerializedRowGroupReader<'
         let col = self.metadata.column(i);
         let (col_start, col_length) = col.byte_range();
         let file_chunk = self.chunk_reader.get_read(col_start, col_length as usize)?;
-        let page_reader = SerializedPageReader::new(
+        let mut page_reader = SerializedPageReader::new(
             file_chunk,
             col.num_values(),
             col.compression(),
             col.column_descr().physical_type(),
         )?;
+        if self.props.verify_page_checksum() {
+            page_reader =
+                page_reader.with_checksum_verification(col.column_path().clone());
+        }
+        #[cfg(feature = "encryption")]
+        if let Some(crypto_metadata) = col.crypto_metadata() {
+            page_reader = self.with_page_decryptor(page_reader, crypto_metadata, i)?;
+        }
+        #[cfg(not(feature = "encryption"))]
+        if col.crypto_metadata().is_some() {
+            return Err(general_err!(
+                "Column {} is encrypted, which requires the `encryption` feature",
+                col.column_path()
+            ));
+        }
         Ok(Box::new(page_reader))
     }
 
@@ -242,6 +345,16 @@ pub struct SerializedPageReader<T: Read> {
 
     // Column chunk type.
     physical_type: Type,
+
+    // The column whose page checksums are verified, if verification is enabled.
+    checksum_column: Option<ColumnPath>,
+
+    // The number of pages read so far, used to identify corrupt pages.
+    page_index: usize,
+
+    // Decryptor for the pages of an encrypted column chunk.
+    #[cfg(feature = "encryption")]
+    decryptor: Option<PageDecryptor>,
 }
 
 impl<T: Read> SerializedPageReader<T> {
@@ -259,23 +372,99 @@ impl<T: Read> SerializedPageReader<T> {
             seen_num_values: 0,
             decompressor,
             physical_type,
+            checksum_column: None,
+            page_index: 0,
+            #[cfg(feature = "encryption")]
+            decryptor: None,
         };
         Ok(result)
     }
 
+    /// Enables verification of the CRC32 checksum of every page that has one,
+    /// reporting mismatches against the given column.
+    pub fn with_checksum_verification(mut self, column: ColumnPath) -> Self {
+        self.checksum_column = Some(column);
+        self
+    }
+
+    /// Decrypts the pages of an encrypted column chunk with the given decryptor.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn with_decryptor(mut self, decryptor: PageDecryptor) -> Self {
+        self.decryptor = Some(decryptor);
+        self
+    }
+
     /// Reads Page header from Thrift.
     fn read_page_header(&mut self) -> Result<PageHeader> {
+        #[cfg(feature = "encryption")]
+        if let Some(decryptor) = self.decryptor.as_ref() {
+            // Encrypted headers are stored as a length-prefixed module
+            let mut module = vec![0; 4];
+            self.buf.read_exact(&mut module)?;
+            let module_len = LittleEndian::read_u32(&module) as usize;
+            module.resize(4 + module_len, 0);
+            self.buf.read_exact(&mut module[4..])?;
+            let header = decryptor.decrypt_page_header(&module)?;
+            let mut prot = TCompactInputProtocol::new(header.as_slice());
+            return Ok(PageHeader::read_from_in_protocol(&mut prot)?);
+        }
         let mut prot = TCompactInputProtocol::new(&mut self.buf);
         let page_header = PageHeader::read_from_in_protocol(&mut prot)?;
         Ok(page_header)
     }
+
+    /// Reads the header of the next page and skips over its data without
+    /// decompressing or decoding it, e.g. to inspect the layout of a column chunk.
+    /// Returns `None` once all pages of the column chunk have been read.
+    pub fn next_page_header(&mut self) -> Result<Option<PageHeader>> {
+        Ok(self.read_page_data()?.map(|(page_header, _)| page_header))
+    }
+
+    /// Reads the header and the data of the next page, verifying its checksum and
+    /// decrypting it if required. The returned data is still compressed.
+    fn read_page_data(&mut self) -> Result<Option<(PageHeader, Vec<u8>)>> {
+        if self.seen_num_values >= self.total_num_values {
+            return Ok(None);
+        }
+        let page_header = self.read_page_header()?;
+
+        // We still need to read all bytes from buffered stream
+        let mut buffer = vec![0; page_header.compressed_page_size as usize];
+        self.buf.read_exact(&mut buffer)?;
+
+        // The checksum covers the page data as stored, i.e. before decryption and
+        // decompression
+        if let (Some(column), Some(expected)) = (&self.checksum_column, page_header.crc) {
+            let actual = crc32fast::hash(&buffer) as i32;
+            if actual != expected {
+                return Err(general_err!(
+                    "Page checksum mismatch in column {} at page {}: expected {:#010x}, computed {:#010x}",
+                    column,
+                    self.page_index,
+                    expected as u32,
+                    actual as u32
+                ));
+            }
+        }
+        self.page_index += 1;
+
+        #[cfg(feature = "encryption")]
+        if let Some(decryptor) = self.decryptor.as_mut() {
+            buffer = decryptor.decrypt_page(&buffer)?;
+        }
+
+        if let Some(ref header) = page_header.data_page_header {
+            self.seen_num_values += header.num_values as i64;
+        } else if let Some(ref header) = page_header.data_page_header_v2 {
+            self.seen_num_values += header.num_values as i64;
+        }
+        Ok(Some((page_header, buffer)))
+    }
 }
 
 impl<T: Read> PageReader for SerializedPageReader<T> {
     fn get_next_page(&mut self) -> Result<Option<Page>> {
-        while self.seen_num_values < self.total_num_values {
-            let page_header = self.read_page_header()?;
-
+        while let Some((page_header, mut buffer)) = self.read_page_data()? {
             // When processing data page v2, depending on enabled compression for the
             // page, we should account for uncompressed data ('offset') of
             // repetition and definition levels.
@@ -293,11 +482,7 @@ impl<T: Read> PageReader for SerializedPageReader<T> {
                 can_decompress = header_v2.is_compressed.unwrap_or(true);
             }
 
-            let compressed_len = page_header.compressed_page_size as usize - offset;
             let uncompressed_len = page_header.uncompressed_page_size as usize - offset;
-            // We still need to read all bytes from buffered stream
-            let mut buffer = vec![0; offset + compressed_len];
-            self.buf.read_exact(&mut buffer)?;
 
             // TODO: page header could be huge because of statistics. We should set a
             // maximum page header size and abort if that is exceeded.
@@ -339,7 +524,6 @@ impl<T: Read> PageReader for SerializedPageReader<T> {
                 PageType::DataPage => {
                     assert!(page_header.data_page_header.is_some());
                     let header = page_header.data_page_header.unwrap();
-                    self.seen_num_values += header.num_values as i64;
                     Page::DataPage {
                         buf: ByteBufferPtr::new(buffer),
                         num_values: header.num_values as u32,
@@ -360,7 +544,6 @@ impl<T: Read> PageReader for SerializedPageReader<T> {
                     assert!(page_header.data_page_header_v2.is_some());
                     let header = page_header.data_page_header_v2.unwrap();
                     let is_compressed = header.is_compressed.unwrap_or(true);
-                    self.seen_num_values += header.num_values as i64;
                     Page::DataPageV2 {
                         buf: ByteBufferPtr::new(buffer),
                         num_values: header.num_values as u32,
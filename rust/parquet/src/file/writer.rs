@@ -32,7 +32,11 @@ use crate::column::{
     page::{CompressedPage, Page, PageWriteSpec, PageWriter},
     writer::{get_column_writer, ColumnWriter},
 };
+#[cfg(feature = "encryption")]
+use crate::encryption::encrypt::{FileEncryptor, PageEncryptor};
 use crate::errors::{ParquetError, Result};
+#[cfg(feature = "encryption")]
+use crate::file::PARQUET_MAGIC_ENCRYPTED_FOOTER;
 use crate::file::{
     metadata::*, properties::WriterPropertiesPtr,
     statistics::to_thrift as statistics_to_thrift, FOOTER_SIZE, PARQUET_MAGIC,
@@ -134,34 +138,54 @@ pub struct SerializedFileWriter<W: ParquetWriter> {
     row_groups: Vec<RowGroupMetaDataPtr>,
     previous_writer_closed: bool,
     is_closed: bool,
+    #[cfg(feature = "encryption")]
+    file_encryptor: Option<Arc<FileEncryptor>>,
 }
 
 impl<W: ParquetWriter> SerializedFileWriter<W> {
     /// Creates new file writer.
-    pub fn new(
-        mut buf: W,
-        schema: TypePtr,
-        properties: WriterPropertiesPtr,
-    ) -> Result<Self> {
-        Self::start_file(&mut buf)?;
-        Ok(Self {
+    pub fn new(buf: W, schema: TypePtr, properties: WriterPropertiesPtr) -> Result<Self> {
+        let descr = Arc::new(SchemaDescriptor::new(schema.clone()));
+        #[cfg(feature = "encryption")]
+        let file_encryptor = match properties.file_encryption_properties() {
+            Some(encryption) => {
+                Some(Arc::new(FileEncryptor::new(encryption.clone(), &descr)?))
+            }
+            None => None,
+        };
+        let mut writer = Self {
             buf,
-            schema: schema.clone(),
-            descr: Arc::new(SchemaDescriptor::new(schema)),
+            schema,
+            descr,
             props: properties,
             total_num_rows: 0,
             row_groups: Vec::new(),
             previous_writer_closed: true,
             is_closed: false,
-        })
+            #[cfg(feature = "encryption")]
+            file_encryptor,
+        };
+        writer.start_file()?;
+        Ok(writer)
     }
 
     /// Writes magic bytes at the beginning of the file.
-    fn start_file(buf: &mut W) -> Result<()> {
-        buf.write_all(&PARQUET_MAGIC)?;
+    fn start_file(&mut self) -> Result<()> {
+        self.buf.write_all(self.magic())?;
         Ok(())
     }
 
+    /// Returns the magic bytes, which differ for files with an encrypted footer.
+    fn magic(&self) -> &'static [u8] {
+        #[cfg(feature = "encryption")]
+        if let Some(ref encryptor) = self.file_encryptor {
+            if encryptor.encrypted_footer() {
+                return &PARQUET_MAGIC_ENCRYPTED_FOOTER;
+            }
+        }
+        &PARQUET_MAGIC
+    }
+
     /// Finalises active row group writer, otherwise no-op.
     fn finalise_row_group_writer(
         &mut self,
@@ -175,39 +199,108 @@ impl<W: ParquetWriter> SerializedFileWriter<W> {
 
     /// Assembles and writes metadata at the end of the file.
     fn write_metadata(&mut self) -> Result<()> {
+        let row_groups = self
+            .row_groups
+            .iter()
+            .map(|row_group| {
+                let row_group = row_group.to_thrift();
+                #[cfg(feature = "encryption")]
+                if let Some(ref encryptor) = self.file_encryptor {
+                    return encryptor.encrypt_row_group(row_group);
+                }
+                Ok(row_group)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        // A plaintext footer of an encrypted file identifies the encryption algorithm
+        #[cfg(feature = "encryption")]
+        let plaintext_footer_encryptor = self
+            .file_encryptor
+            .as_ref()
+            .filter(|encryptor| !encryptor.encrypted_footer());
         let file_metadata = parquet::FileMetaData {
             version: self.props.writer_version().as_num(),
             schema: types::to_thrift(self.schema.as_ref())?,
             num_rows: self.total_num_rows as i64,
-            row_groups: self
-                .row_groups
-                .as_slice()
-                .iter()
-                .map(|v| v.to_thrift())
-                .collect(),
+            row_groups,
             key_value_metadata: self.props.key_value_metadata().to_owned(),
             created_by: Some(self.props.created_by().to_owned()),
             column_orders: None,
+            #[cfg(feature = "encryption")]
+            encryption_algorithm: plaintext_footer_encryptor
+                .map(|encryptor| encryptor.encryption_algorithm()),
+            #[cfg(not(feature = "encryption"))]
+            encryption_algorithm: None,
+            #[cfg(feature = "encryption")]
+            footer_signing_key_metadata: plaintext_footer_encryptor
+                .and_then(|encryptor| encryptor.footer_key_metadata()),
+            #[cfg(not(feature = "encryption"))]
+            footer_signing_key_metadata: None,
         };
 
         // Write file metadata
         let start_pos = self.buf.seek(SeekFrom::Current(0))?;
-        {
-            let mut protocol = TCompactOutputProtocol::new(&mut self.buf);
-            file_metadata.write_to_out_protocol(&mut protocol)?;
-            protocol.flush()?;
-        }
+        self.write_file_metadata(&file_metadata)?;
         let end_pos = self.buf.seek(SeekFrom::Current(0))?;
 
         // Write footer
         let mut footer_buffer: [u8; FOOTER_SIZE] = [0; FOOTER_SIZE];
         let metadata_len = (end_pos - start_pos) as i32;
         LittleEndian::write_i32(&mut footer_buffer, metadata_len);
-        (&mut footer_buffer[4..]).write_all(&PARQUET_MAGIC)?;
+        (&mut footer_buffer[4..]).write_all(self.magic())?;
         self.buf.write_all(&footer_buffer)?;
         Ok(())
     }
 
+    /// Writes the Thrift encoded file metadata.
+    #[cfg(not(feature = "encryption"))]
+    fn write_file_metadata(
+        &mut self,
+        file_metadata: &parquet::FileMetaData,
+    ) -> Result<()> {
+        let mut protocol = TCompactOutputProtocol::new(&mut self.buf);
+        file_metadata.write_to_out_protocol(&mut protocol)?;
+        protocol.flush()?;
+        Ok(())
+    }
+
+    /// Writes the Thrift encoded file metadata, which is encrypted, or followed by its
+    /// signature, in encrypted files.
+    #[cfg(feature = "encryption")]
+    fn write_file_metadata(
+        &mut self,
+        file_metadata: &parquet::FileMetaData,
+    ) -> Result<()> {
+        match self.file_encryptor {
+            Some(ref encryptor) => {
+                let mut buffer = Vec::new();
+                {
+                    let mut protocol = TCompactOutputProtocol::new(&mut buffer);
+                    file_metadata.write_to_out_protocol(&mut protocol)?;
+                    protocol.flush()?;
+                }
+                if encryptor.encrypted_footer() {
+                    {
+                        let mut protocol = TCompactOutputProtocol::new(&mut self.buf);
+                        encryptor
+                            .file_crypto_metadata()
+                            .write_to_out_protocol(&mut protocol)?;
+                        protocol.flush()?;
+                    }
+                    self.buf.write_all(&encryptor.encrypt_footer(&buffer)?)?;
+                } else {
+                    self.buf.write_all(&buffer)?;
+                    self.buf.write_all(&encryptor.sign_footer(&buffer)?)?;
+                }
+            }
+            None => {
+                let mut protocol = TCompactOutputProtocol::new(&mut self.buf);
+                file_metadata.write_to_out_protocol(&mut protocol)?;
+                protocol.flush()?;
+            }
+        }
+        Ok(())
+    }
+
     #[inline]
     fn assert_closed(&self) -> Result<()> {
         if self.is_closed {
@@ -236,7 +329,11 @@ impl<W: 'static + ParquetWriter> FileWriter for SerializedFileWriter<W> {
             self.descr.clone(),
             self.props.clone(),
             &self.buf,
-        );
+        )
+        .with_ordinal(self.row_groups.len() as i16);
+        #[cfg(feature = "encryption")]
+        let row_group_writer =
+            row_group_writer.with_file_encryptor(self.file_encryptor.clone());
         self.previous_writer_closed = false;
         Ok(Box::new(row_group_writer))
     }
@@ -272,6 +369,9 @@ pub struct SerializedRowGroupWriter<W: ParquetWriter> {
     previous_writer_closed: bool,
     row_group_metadata: Option<RowGroupMetaDataPtr>,
     column_chunks: Vec<ColumnChunkMetaData>,
+    ordinal: Option<i16>,
+    #[cfg(feature = "encryption")]
+    file_encryptor: Option<Arc<FileEncryptor>>,
 }
 
 impl<W: 'static + ParquetWriter> SerializedRowGroupWriter<W> {
@@ -291,9 +391,29 @@ impl<W: 'static + ParquetWriter> SerializedRowGroupWriter<W> {
             previous_writer_closed: true,
             row_group_metadata: None,
             column_chunks: Vec::with_capacity(num_columns),
+            ordinal: None,
+            #[cfg(feature = "encryption")]
+            file_encryptor: None,
         }
     }
 
+    /// Sets the position of this row group in the file.
+    pub(crate) fn with_ordinal(mut self, ordinal: i16) -> Self {
+        self.ordinal = Some(ordinal);
+        self
+    }
+
+    /// Sets the encryptor of an encrypted file, which encrypts the pages of this row
+    /// group's encrypted columns.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn with_file_encryptor(
+        mut self,
+        file_encryptor: Option<Arc<FileEncryptor>>,
+    ) -> Self {
+        self.file_encryptor = file_encryptor;
+        self
+    }
+
     /// Checks and finalises current column writer.
     fn finalise_column_writer(&mut self, writer: ColumnWriter) -> Result<()> {
         let (bytes_written, rows_written, metadata) = match writer {
@@ -354,12 +474,21 @@ impl<W: 'static + ParquetWriter> RowGroupWriter for SerializedRowGroupWriter<W>
             return Ok(None);
         }
         let sink = FileSink::new(&self.buf);
-        let page_writer = Box::new(SerializedPageWriter::new(sink));
-        let column_writer = get_column_writer(
-            self.descr.column(self.column_index),
-            self.props.clone(),
-            page_writer,
-        );
+        let column_descr = self.descr.column(self.column_index);
+        let page_writer = SerializedPageWriter::new(sink)
+            .with_page_checksum(self.props.page_checksum_enabled());
+        #[cfg(feature = "encryption")]
+        let page_writer = page_writer.with_encryptor(match self.file_encryptor {
+            Some(ref encryptor) => encryptor.page_encryptor(
+                column_descr.path(),
+                self.ordinal.unwrap_or(0) as usize,
+                self.column_index,
+            ),
+            None => None,
+        });
+        let page_writer = Box::new(page_writer);
+        let column_writer =
+            get_column_writer(column_descr, self.props.clone(), page_writer);
         self.column_index += 1;
         self.previous_writer_closed = false;
 
@@ -379,11 +508,14 @@ impl<W: 'static + ParquetWriter> RowGroupWriter for SerializedRowGroupWriter<W>
             self.assert_previous_writer_closed()?;
 
             let column_chunks = std::mem::take(&mut self.column_chunks);
-            let row_group_metadata = RowGroupMetaData::builder(self.descr.clone())
+            let mut builder = RowGroupMetaData::builder(self.descr.clone())
                 .set_column_metadata(column_chunks)
                 .set_total_byte_size(self.total_bytes_written as i64)
-                .set_num_rows(self.total_rows_written.unwrap_or(0) as i64)
-                .build()?;
+                .set_num_rows(self.total_rows_written.unwrap_or(0) as i64);
+            if let Some(ordinal) = self.ordinal {
+                builder = builder.set_ordinal(ordinal);
+            }
+            let row_group_metadata = builder.build()?;
 
             self.row_group_metadata = Some(Arc::new(row_group_metadata));
         }
@@ -399,19 +531,57 @@ impl<W: 'static + ParquetWriter> RowGroupWriter for SerializedRowGroupWriter<W>
 /// `SerializedPageWriter` should not be used after calling `close()`.
 pub struct SerializedPageWriter<T: Write + Position> {
     sink: T,
+    // Whether a CRC32 checksum of the page data is written into each page header.
+    page_checksum: bool,
+    // Encryptor for the pages of an encrypted column chunk.
+    #[cfg(feature = "encryption")]
+    encryptor: Option<PageEncryptor>,
 }
 
 impl<T: Write + Position> SerializedPageWriter<T> {
     /// Creates new page writer.
     pub fn new(sink: T) -> Self {
-        Self { sink }
+        Self {
+            sink,
+            page_checksum: false,
+            #[cfg(feature = "encryption")]
+            encryptor: None,
+        }
+    }
+
+    /// Sets whether a CRC32 checksum of the page data is computed and stored in the
+    /// header of each page that is written.
+    pub fn with_page_checksum(mut self, enabled: bool) -> Self {
+        self.page_checksum = enabled;
+        self
     }
 
-    /// Serializes page header into Thrift.
+    /// Sets the encryptor for the pages of an encrypted column chunk.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn with_encryptor(mut self, encryptor: Option<PageEncryptor>) -> Self {
+        self.encryptor = encryptor;
+        self
+    }
+
+    /// Serializes page header into Thrift, encrypting it if the column chunk is
+    /// encrypted.
     /// Returns number of bytes that have been written into the sink.
     #[inline]
     fn serialize_page_header(&mut self, header: parquet::PageHeader) -> Result<usize> {
         let start_pos = self.sink.pos();
+        #[cfg(feature = "encryption")]
+        if let Some(encryptor) = self.encryptor.as_mut() {
+            let page_type = PageType::from(header.type_);
+            let mut buffer = Vec::new();
+            {
+                let mut protocol = TCompactOutputProtocol::new(&mut buffer);
+                header.write_to_out_protocol(&mut protocol)?;
+                protocol.flush()?;
+            }
+            let module = encryptor.encrypt_page_header(page_type, &buffer)?;
+            self.sink.write_all(&module)?;
+            return Ok((self.sink.pos() - start_pos) as usize);
+        }
         {
             let mut protocol = TCompactOutputProtocol::new(&mut self.sink);
             header.write_to_out_protocol(&mut protocol)?;
@@ -434,17 +604,31 @@ impl<T: Write + Position> SerializedPageWriter<T> {
 impl<T: Write + Position> PageWriter for SerializedPageWriter<T> {
     fn write_page(&mut self, page: CompressedPage) -> Result<PageWriteSpec> {
         let uncompressed_size = page.uncompressed_size();
-        let compressed_size = page.compressed_size();
         let num_values = page.num_values();
         let encoding = page.encoding();
         let page_type = page.page_type();
 
+        // Pages of encrypted columns are stored, and checksummed, encrypted
+        #[cfg(feature = "encryption")]
+        let encrypted_data = match self.encryptor {
+            Some(ref encryptor) => Some(encryptor.encrypt_page(page_type, page.data())?),
+            None => None,
+        };
+        #[cfg(feature = "encryption")]
+        let data = encrypted_data.as_deref().unwrap_or_else(|| page.data());
+        #[cfg(not(feature = "encryption"))]
+        let data = page.data();
+        let compressed_size = data.len();
+
         let mut page_header = parquet::PageHeader {
             type_: page_type.into(),
             uncompressed_page_size: uncompressed_size as i32,
             compressed_page_size: compressed_size as i32,
-            // TODO: Add support for crc checksum
-            crc: None,
+            crc: if self.page_checksum {
+                Some(crc32fast::hash(data) as i32)
+            } else {
+                None
+            },
             data_page_header: None,
             index_page_header: None,
             dictionary_page_header: None,
@@ -501,7 +685,7 @@ impl<T: Write + Position> PageWriter for SerializedPageWriter<T> {
         let start_pos = self.sink.pos();
 
         let header_size = self.serialize_page_header(page_header)?;
-        self.sink.write_all(page.data())?;
+        self.sink.write_all(data)?;
 
         let mut spec = PageWriteSpec::new();
         spec.page_type = page_type;
@@ -518,7 +702,13 @@ impl<T: Write + Position> PageWriter for SerializedPageWriter<T> {
     }
 
     fn write_metadata(&mut self, metadata: &ColumnChunkMetaData) -> Result<()> {
-        self.serialize_column_chunk(metadata.to_thrift())
+        let chunk = metadata.to_thrift();
+        #[cfg(feature = "encryption")]
+        let chunk = match self.encryptor {
+            Some(ref encryptor) => encryptor.encrypt_column_chunk(chunk)?,
+            None => chunk,
+        };
+        self.serialize_column_chunk(chunk)
     }
 
     fn close(&mut self) -> Result<()> {
@@ -537,12 +727,15 @@ mod tests {
     use crate::column::page::PageReader;
     use crate::compression::{create_codec, Codec};
     use crate::file::{
-        properties::WriterProperties,
+        properties::{ReaderProperties, WriterProperties},
         reader::{FileReader, SerializedFileReader, SerializedPageReader},
         statistics::{from_thrift, to_thrift, Statistics},
     };
     use crate::record::RowAccessor;
-    use crate::util::{memory::ByteBufferPtr, test_common::get_temp_file};
+    use crate::schema::types::ColumnPath;
+    use crate::util::{
+        cursor::SliceableCursor, memory::ByteBufferPtr, test_common::get_temp_file,
+    };
 
     #[test]
     fn test_file_writer_error_after_close() {
@@ -757,6 +950,241 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_page_writer_checksum() {
+        let make_pages = || {
+            vec![
+                Page::DataPage {
+                    buf: ByteBufferPtr::new(vec![1, 2, 3, 4, 5, 6, 7, 8]),
+                    num_values: 10,
+                    encoding: Encoding::PLAIN,
+                    def_level_encoding: Encoding::RLE,
+                    rep_level_encoding: Encoding::RLE,
+                    statistics: None,
+                },
+                Page::DataPage {
+                    buf: ByteBufferPtr::new(vec![8, 7, 6, 5, 4, 3, 2, 1]),
+                    num_values: 10,
+                    encoding: Encoding::PLAIN,
+                    def_level_encoding: Encoding::RLE,
+                    rep_level_encoding: Encoding::RLE,
+                    statistics: None,
+                },
+            ]
+        };
+        let pages = make_pages();
+
+        let mut buffer: Vec<u8> = vec![];
+        {
+            let cursor = Cursor::new(&mut buffer);
+            let mut page_writer =
+                SerializedPageWriter::new(cursor).with_page_checksum(true);
+            for page in make_pages() {
+                let uncompressed_len = page.buffer().len();
+                let compressed_page = CompressedPage::new(page, uncompressed_len);
+                page_writer.write_page(compressed_page).unwrap();
+            }
+            page_writer.close().unwrap();
+        }
+
+        let read_pages = |buffer: &[u8]| -> Result<Vec<Page>> {
+            let mut page_reader = SerializedPageReader::new(
+                Cursor::new(buffer),
+                20,
+                Compression::UNCOMPRESSED,
+                Type::INT32,
+            )?
+            .with_checksum_verification(ColumnPath::from("col"));
+            let mut result = vec![];
+            while let Some(page) = page_reader.get_next_page()? {
+                result.push(page);
+            }
+            Ok(result)
+        };
+
+        let result_pages = read_pages(&buffer).unwrap();
+        assert_eq!(result_pages.len(), pages.len());
+        for i in 0..result_pages.len() {
+            assert_page(&result_pages[i], &pages[i]);
+        }
+
+        // corrupt the last byte of the second page
+        let last = buffer.len() - 1;
+        buffer[last] ^= 0xFF;
+        let err = read_pages(&buffer).err().unwrap();
+        assert!(
+            format!("{}", err).starts_with(
+                "Parquet error: Page checksum mismatch in column \"col\" at page 1"
+            ),
+            "unexpected error: {}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_page_reader_next_page_header() {
+        let pages = vec![
+            Page::DictionaryPage {
+                buf: ByteBufferPtr::new(vec![1, 2, 3, 4, 5]),
+                num_values: 5,
+                encoding: Encoding::PLAIN,
+                is_sorted: false,
+            },
+            Page::DataPage {
+                buf: ByteBufferPtr::new(vec![1, 2, 3, 4, 5, 6, 7, 8]),
+                num_values: 10,
+                encoding: Encoding::RLE_DICTIONARY,
+                def_level_encoding: Encoding::RLE,
+                rep_level_encoding: Encoding::RLE,
+                statistics: Some(Statistics::int32(Some(1), Some(3), None, 7, true)),
+            },
+            Page::DataPage {
+                buf: ByteBufferPtr::new(vec![8, 7, 6]),
+                num_values: 5,
+                encoding: Encoding::RLE_DICTIONARY,
+                def_level_encoding: Encoding::RLE,
+                rep_level_encoding: Encoding::RLE,
+                statistics: None,
+            },
+        ];
+
+        let mut buffer: Vec<u8> = vec![];
+        {
+            let cursor = Cursor::new(&mut buffer);
+            let mut page_writer = SerializedPageWriter::new(cursor);
+            for page in pages {
+                let uncompressed_len = page.buffer().len();
+                let compressed_page = CompressedPage::new(page, uncompressed_len);
+                page_writer.write_page(compressed_page).unwrap();
+            }
+            page_writer.close().unwrap();
+        }
+
+        let mut page_reader = SerializedPageReader::new(
+            Cursor::new(&buffer),
+            15,
+            Compression::UNCOMPRESSED,
+            Type::INT32,
+        )
+        .unwrap();
+        let mut headers = vec![];
+        while let Some(header) = page_reader.next_page_header().unwrap() {
+            headers.push(header);
+        }
+
+        assert_eq!(headers.len(), 3);
+        assert_eq!(headers[0].type_, parquet_format::PageType::DictionaryPage);
+        assert_eq!(headers[0].compressed_page_size, 5);
+        assert_eq!(
+            headers[0]
+                .dictionary_page_header
+                .as_ref()
+                .unwrap()
+                .num_values,
+            5
+        );
+        assert_eq!(headers[1].type_, parquet_format::PageType::DataPage);
+        assert_eq!(headers[1].uncompressed_page_size, 8);
+        let data_page_header = headers[1].data_page_header.as_ref().unwrap();
+        assert_eq!(data_page_header.num_values, 10);
+        assert_eq!(
+            data_page_header.statistics.as_ref().unwrap().null_count,
+            Some(7)
+        );
+        assert_eq!(headers[2].compressed_page_size, 3);
+        assert!(headers[2]
+            .data_page_header
+            .as_ref()
+            .unwrap()
+            .statistics
+            .is_none());
+    }
+
+    #[test]
+    fn test_file_writer_page_checksum() {
+        let schema = Arc::new(
+            types::Type::group_type_builder("schema")
+                .with_fields(&mut vec![Arc::new(
+                    types::Type::primitive_type_builder("col1", Type::INT32)
+                        .with_repetition(Repetition::REQUIRED)
+                        .build()
+                        .unwrap(),
+                )])
+                .build()
+                .unwrap(),
+        );
+        let props = Arc::new(
+            WriterProperties::builder()
+                .set_page_checksum_enabled(true)
+                .set_dictionary_enabled(false)
+                .build(),
+        );
+        let cursor = InMemoryWriteableCursor::default();
+        {
+            let mut file_writer =
+                SerializedFileWriter::new(cursor.clone(), schema, props).unwrap();
+            let mut row_group_writer = file_writer.next_row_group().unwrap();
+            let mut col_writer = row_group_writer.next_column().unwrap().unwrap();
+            if let ColumnWriter::Int32ColumnWriter(ref mut typed) = col_writer {
+                typed.write_batch(&[1, 2, 3, 4, 5], None, None).unwrap();
+            }
+            row_group_writer.close_column(col_writer).unwrap();
+            file_writer.close_row_group(row_group_writer).unwrap();
+            file_writer.close().unwrap();
+        }
+        let mut buffer = cursor.into_inner().unwrap();
+
+        let verifying_props = || {
+            ReaderProperties::builder()
+                .set_verify_page_checksum(true)
+                .build()
+        };
+
+        let reader = SerializedFileReader::new_with_properties(
+            SliceableCursor::new(buffer.clone()),
+            verifying_props(),
+        )
+        .unwrap();
+        let values: Vec<i32> = reader
+            .get_row_iter(None)
+            .unwrap()
+            .map(|row| row.get_int(0).unwrap())
+            .collect();
+        assert_eq!(values, vec![1, 2, 3, 4, 5]);
+
+        // corrupt the last value of the only data page
+        let (start, len) = reader.metadata().row_group(0).column(0).byte_range();
+        buffer[(start + len - 1) as usize] ^= 0xFF;
+
+        let reader = SerializedFileReader::new_with_properties(
+            SliceableCursor::new(buffer.clone()),
+            verifying_props(),
+        )
+        .unwrap();
+        let mut page_reader = reader
+            .get_row_group(0)
+            .unwrap()
+            .get_column_page_reader(0)
+            .unwrap();
+        let err = page_reader.get_next_page().err().unwrap();
+        assert!(
+            format!("{}", err).starts_with(
+                "Parquet error: Page checksum mismatch in column \"col1\" at page 0"
+            ),
+            "unexpected error: {}",
+            err
+        );
+
+        // without verification the corruption goes unnoticed
+        let reader = SerializedFileReader::new(SliceableCursor::new(buffer)).unwrap();
+        let mut page_reader = reader
+            .get_row_group(0)
+            .unwrap()
+            .get_column_page_reader(0)
+            .unwrap();
+        assert!(page_reader.get_next_page().unwrap().is_some());
+    }
+
     #[test]
     fn test_page_writer_data_pages() {
         let pages = vec![
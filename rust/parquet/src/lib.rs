@@ -52,6 +52,8 @@ pub mod arrow;
 pub mod column;
 pub mod compression;
 mod encodings;
+#[cfg(feature = "encryption")]
+pub mod encryption;
 pub mod file;
 pub mod record;
 pub mod schema;
@@ -509,7 +509,7 @@ pub enum Field {
 
 impl Field {
     /// Get the type name.
-    fn get_type_name(&self) -> &'static str {
+    pub(crate) fn get_type_name(&self) -> &'static str {
         match *self {
             Field::Null => "Null",
             Field::Bool(_) => "Bool",
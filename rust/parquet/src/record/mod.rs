@@ -19,10 +19,12 @@
 
 mod api;
 pub mod reader;
+mod record_reader;
 mod record_writer;
 mod triplet;
 
 pub use self::{
     api::{Field, List, ListAccessor, Map, MapAccessor, Row, RowAccessor},
+    record_reader::{FromField, RecordReader},
     record_writer::RecordWriter,
 };
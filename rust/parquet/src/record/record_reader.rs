@@ -0,0 +1,318 @@
+// Licensed to the Apache Software Foundation (ASF) under one
+// or more contributor license agreements.  See the NOTICE file
+// distributed with this work for additional information
+// regarding copyright ownership.  The ASF licenses this file
+// to you under the Apache License, Version 2.0 (the
+// "License"); you may not use this file except in compliance
+// with the License.  You may obtain a copy of the License at
+//
+//   http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing,
+// software distributed under the License is distributed on an
+// "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY
+// KIND, either express or implied.  See the License for the
+// specific language governing permissions and limitations
+// under the License.
+
+use std::convert::TryFrom;
+
+use chrono::{NaiveDate, NaiveDateTime};
+
+use crate::data_type::ByteArray;
+use crate::errors::{ParquetError, Result};
+use crate::file::reader::RowGroupReader;
+use crate::record::{reader::RowIter, Field, Row};
+use crate::schema::types::Type;
+
+/// Reads records of type `T` from the rows of a row group. Usually implemented for
+/// `Vec<T>` with `#[derive(ParquetRecordReader)]` from the `parquet_derive` crate.
+pub trait RecordReader<T> {
+    /// Returns the names of the top-level columns that are read into a `T`.
+    fn field_names() -> &'static [&'static str];
+
+    /// Reads up to `num_records` records from `rows` and appends them to `self`.
+    /// Returns the number of records read, which is 0 once `rows` is exhausted.
+    fn read_from_rows(
+        &mut self,
+        rows: &mut dyn Iterator<Item = Row>,
+        num_records: usize,
+    ) -> Result<usize>;
+
+    /// Reads the first `num_records` records of a row group and appends them to
+    /// `self`. Use [`RecordReader::row_iter`] with
+    /// [`RecordReader::read_from_rows`] to read a row group in batches.
+    fn read_from_row_group(
+        &mut self,
+        row_group_reader: &dyn RowGroupReader,
+        num_records: usize,
+    ) -> Result<usize> {
+        let mut rows = Self::row_iter(row_group_reader)?;
+        self.read_from_rows(&mut rows, num_records)
+    }
+
+    /// Returns an iterator over the rows of a row group, projected onto the columns
+    /// returned by [`RecordReader::field_names`].
+    fn row_iter(row_group_reader: &dyn RowGroupReader) -> Result<RowIter> {
+        let schema = row_group_reader.metadata().schema_descr().root_schema();
+        let field_names = Self::field_names();
+        let mut fields = schema
+            .get_fields()
+            .iter()
+            .filter(|field| field_names.contains(&field.name()))
+            .cloned()
+            .collect();
+        let projection = Type::group_type_builder(schema.name())
+            .with_fields(&mut fields)
+            .build()?;
+        RowIter::from_row_group(Some(projection), row_group_reader)
+    }
+}
+
+/// Conversion of a [`Field`] of a row into a Rust value.
+///
+/// Integers are converted between widths as long as the value fits, `Vec<T>` is
+/// read from a list or, for `Vec<u8>`, from a byte array and structs that derive
+/// `ParquetRecordReader` are read from a group by matching column names.
+pub trait FromField: Sized {
+    /// Converts `field` into `Self`, returning an error if the types do not match.
+    fn from_field(field: &Field) -> Result<Self>;
+}
+
+fn conversion_err(field: &Field, type_name: &str) -> ParquetError {
+    general_err!("Cannot convert {} to {}", field.get_type_name(), type_name)
+}
+
+macro_rules! integer_from_field {
+    ($($ty:ty),*) => {$(
+        impl FromField for $ty {
+            fn from_field(field: &Field) -> Result<Self> {
+                let value: i128 = match *field {
+                    Field::Byte(value) => value.into(),
+                    Field::Short(value) => value.into(),
+                    Field::Int(value) => value.into(),
+                    Field::Long(value) => value.into(),
+                    Field::UByte(value) => value.into(),
+                    Field::UShort(value) => value.into(),
+                    Field::UInt(value) => value.into(),
+                    Field::ULong(value) => value.into(),
+                    _ => return Err(conversion_err(field, stringify!($ty))),
+                };
+                <$ty>::try_from(value).map_err(|_| {
+                    general_err!("Value {} is out of range for {}", value, stringify!($ty))
+                })
+            }
+        }
+    )*};
+}
+
+integer_from_field!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl FromField for bool {
+    fn from_field(field: &Field) -> Result<Self> {
+        match *field {
+            Field::Bool(value) => Ok(value),
+            _ => Err(conversion_err(field, "bool")),
+        }
+    }
+}
+
+impl FromField for f32 {
+    fn from_field(field: &Field) -> Result<Self> {
+        match *field {
+            Field::Float(value) => Ok(value),
+            _ => Err(conversion_err(field, "f32")),
+        }
+    }
+}
+
+impl FromField for f64 {
+    fn from_field(field: &Field) -> Result<Self> {
+        match *field {
+            Field::Float(value) => Ok(value.into()),
+            Field::Double(value) => Ok(value),
+            _ => Err(conversion_err(field, "f64")),
+        }
+    }
+}
+
+impl FromField for String {
+    fn from_field(field: &Field) -> Result<Self> {
+        match field {
+            Field::Str(value) => Ok(value.clone()),
+            Field::Bytes(value) => Ok(value.as_utf8()?.to_string()),
+            _ => Err(conversion_err(field, "String")),
+        }
+    }
+}
+
+impl FromField for ByteArray {
+    fn from_field(field: &Field) -> Result<Self> {
+        match field {
+            Field::Bytes(value) => Ok(value.clone()),
+            Field::Str(value) => Ok(value.as_str().into()),
+            _ => Err(conversion_err(field, "ByteArray")),
+        }
+    }
+}
+
+impl FromField for NaiveDate {
+    fn from_field(field: &Field) -> Result<Self> {
+        let days = match *field {
+            Field::Date(days) => days as i32,
+            Field::Int(days) => days,
+            _ => return Err(conversion_err(field, "NaiveDate")),
+        };
+        NaiveDate::from_ymd(1970, 1, 1)
+            .checked_add_signed(chrono::Duration::days(days.into()))
+            .ok_or_else(|| general_err!("Date {} is out of range", days))
+    }
+}
+
+impl FromField for NaiveDateTime {
+    fn from_field(field: &Field) -> Result<Self> {
+        let micros = match *field {
+            Field::TimestampMillis(millis) => millis as i64 * 1000,
+            Field::TimestampMicros(micros) => micros as i64,
+            // Written by `#[derive(ParquetRecordWriter)]` as milliseconds
+            Field::Long(millis) => millis * 1000,
+            _ => return Err(conversion_err(field, "NaiveDateTime")),
+        };
+        NaiveDateTime::from_timestamp_opt(
+            micros.div_euclid(1_000_000),
+            (micros.rem_euclid(1_000_000) * 1000) as u32,
+        )
+        .ok_or_else(|| general_err!("Timestamp {} is out of range", micros))
+    }
+}
+
+impl FromField for Row {
+    fn from_field(field: &Field) -> Result<Self> {
+        match field {
+            Field::Group(row) => Ok(row.clone()),
+            _ => Err(conversion_err(field, "Row")),
+        }
+    }
+}
+
+impl<T: FromField> FromField for Option<T> {
+    fn from_field(field: &Field) -> Result<Self> {
+        match field {
+            Field::Null => Ok(None),
+            _ => T::from_field(field).map(Some),
+        }
+    }
+}
+
+impl<T: FromField> FromField for Vec<T> {
+    fn from_field(field: &Field) -> Result<Self> {
+        match field {
+            Field::ListInternal(list) => {
+                list.elements().iter().map(T::from_field).collect()
+            }
+            Field::Bytes(bytes) => bytes
+                .data()
+                .iter()
+                .map(|byte| T::from_field(&Field::UByte(*byte)))
+                .collect(),
+            _ => Err(conversion_err(field, "Vec")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::record::api::make_list;
+
+    #[test]
+    fn test_integers_from_field() {
+        assert_eq!(i32::from_field(&Field::Int(-5)).unwrap(), -5);
+        assert_eq!(i64::from_field(&Field::Int(7)).unwrap(), 7);
+        assert_eq!(u8::from_field(&Field::Int(200)).unwrap(), 200);
+        assert_eq!(usize::from_field(&Field::Long(9)).unwrap(), 9);
+        assert_eq!(u64::from_field(&Field::ULong(u64::MAX)).unwrap(), u64::MAX);
+
+        let res = u8::from_field(&Field::Int(256));
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Parquet error: Value 256 is out of range for u8"
+        );
+        let res = i32::from_field(&Field::Double(1.0));
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Parquet error: Cannot convert Double to i32"
+        );
+    }
+
+    #[test]
+    fn test_primitives_from_field() {
+        assert!(bool::from_field(&Field::Bool(true)).unwrap());
+        assert_eq!(f32::from_field(&Field::Float(1.5)).unwrap(), 1.5);
+        assert_eq!(f64::from_field(&Field::Float(1.5)).unwrap(), 1.5);
+        assert_eq!(f64::from_field(&Field::Double(2.5)).unwrap(), 2.5);
+        assert_eq!(
+            String::from_field(&Field::Str("abc".to_string())).unwrap(),
+            "abc"
+        );
+        assert_eq!(
+            String::from_field(&Field::Bytes("abc".into())).unwrap(),
+            "abc"
+        );
+        assert!(String::from_field(&Field::Bytes(vec![0xff].into())).is_err());
+        assert_eq!(
+            ByteArray::from_field(&Field::Bytes(vec![1, 2].into())).unwrap(),
+            ByteArray::from(vec![1, 2])
+        );
+    }
+
+    #[test]
+    fn test_temporal_from_field() {
+        assert_eq!(
+            NaiveDate::from_field(&Field::Date(14611)).unwrap(),
+            NaiveDate::from_ymd(2010, 1, 2)
+        );
+        assert_eq!(
+            NaiveDate::from_field(&Field::Int(-1)).unwrap(),
+            NaiveDate::from_ymd(1969, 12, 31)
+        );
+
+        let expected = NaiveDate::from_ymd(2010, 1, 2).and_hms_milli(3, 4, 5, 6);
+        assert_eq!(
+            NaiveDateTime::from_field(&Field::TimestampMillis(1262401445006)).unwrap(),
+            expected
+        );
+        assert_eq!(
+            NaiveDateTime::from_field(&Field::TimestampMicros(1262401445006000)).unwrap(),
+            expected
+        );
+        assert_eq!(
+            NaiveDateTime::from_field(&Field::Long(1262401445006)).unwrap(),
+            expected
+        );
+        assert_eq!(
+            NaiveDateTime::from_field(&Field::Long(-1)).unwrap(),
+            NaiveDate::from_ymd(1969, 12, 31).and_hms_milli(23, 59, 59, 999)
+        );
+    }
+
+    #[test]
+    fn test_containers_from_field() {
+        assert_eq!(Option::<i32>::from_field(&Field::Null).unwrap(), None);
+        assert_eq!(Option::<i32>::from_field(&Field::Int(1)).unwrap(), Some(1));
+
+        let list = make_list(vec![Field::Int(1), Field::Null, Field::Int(3)]);
+        assert_eq!(
+            Vec::<Option<i64>>::from_field(&Field::ListInternal(list.clone())).unwrap(),
+            vec![Some(1), None, Some(3)]
+        );
+        assert!(Vec::<i64>::from_field(&Field::ListInternal(list)).is_err());
+
+        assert_eq!(
+            Vec::<u8>::from_field(&Field::Bytes(vec![1, 2, 3].into())).unwrap(),
+            vec![1, 2, 3]
+        );
+        assert!(Vec::<u8>::from_field(&Field::Int(1)).is_err());
+    }
+}
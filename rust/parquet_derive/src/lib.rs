@@ -124,3 +124,113 @@ pub fn parquet_record_writer(input: proc_macro::TokenStream) -> proc_macro::Toke
     }
   }).into()
 }
+
+/// Derive RecordReader implementations for `Vec`s of simple structs. Works by
+/// parsing a struct tagged with `#[derive(ParquetRecordReader)]` and emitting
+/// code that reads each field from the column with the same name, so the order
+/// of the struct fields does not need to match the schema.
+///
+/// Fields can be of any type that implements `parquet::record::FromField`, which
+/// includes primitives, `String`, `Option`, `Vec` (read from lists, or from byte
+/// arrays for `Vec<u8>`), chrono's `NaiveDate` and `NaiveDateTime` and other
+/// structs that derive `ParquetRecordReader`, which are read from groups.
+///
+/// Example:
+///
+/// ```ignore
+/// use parquet::file::reader::{FileReader, SerializedFileReader};
+/// use parquet::record::RecordReader;
+///
+/// #[derive(ParquetRecordReader)]
+/// struct ACompleteRecord {
+///   pub a_bool: bool,
+///   pub a_string: String,
+///   pub maybe_a_number: Option<i32>,
+/// }
+///
+/// pub fn read_some_records(file: File) -> Vec<ACompleteRecord> {
+///   let reader = SerializedFileReader::new(file).unwrap();
+///   let row_group = reader.get_row_group(0).unwrap();
+///   let num_rows = row_group.metadata().num_rows() as usize;
+///
+///   let mut samples: Vec<ACompleteRecord> = Vec::new();
+///   samples.read_from_row_group(&*row_group, num_rows).unwrap();
+///   samples
+/// }
+/// ```
+///
+#[proc_macro_derive(ParquetRecordReader)]
+pub fn parquet_record_reader(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input: DeriveInput = parse_macro_input!(input as DeriveInput);
+    let fields = match input.data {
+        Data::Struct(DataStruct { fields, .. }) => fields,
+        Data::Enum(_) => unimplemented!("Enum currently is not supported"),
+        Data::Union(_) => unimplemented!("Union currently is not supported"),
+    };
+
+    let field_idents: Vec<syn::Ident> = fields
+        .iter()
+        .map(|f: &syn::Field| {
+            f.ident
+                .clone()
+                .expect("Only structs with named fields are currently supported")
+        })
+        .collect();
+    let field_names: Vec<String> = field_idents
+        .iter()
+        .map(|ident| ident.to_string().trim_start_matches("r#").to_string())
+        .collect();
+
+    let derived_for = input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    (quote! {
+    impl #impl_generics parquet::record::FromField for #derived_for #ty_generics #where_clause {
+      fn from_field(__field: &parquet::record::Field) -> Result<Self, parquet::errors::ParquetError> {
+        // Locals are prefixed so they do not clash with the fields of the struct
+        let __row = match __field {
+          parquet::record::Field::Group(__row) => __row,
+          _ => return Err(parquet::errors::ParquetError::General(
+            format!("Cannot read {} from a field that is not a group", stringify!(#derived_for))
+          )),
+        };
+
+        #(let mut #field_idents = None;)*
+        for (__name, __field) in __row.get_column_iter() {
+          match __name.as_str() {
+            #(#field_names => #field_idents = Some(parquet::record::FromField::from_field(__field)?),)*
+            _ => (),
+          }
+        }
+
+        Ok(#derived_for {
+          #(
+            #field_idents: #field_idents.ok_or_else(|| parquet::errors::ParquetError::General(
+              format!("Column {} not found for {}", #field_names, stringify!(#derived_for))
+            ))?,
+          )*
+        })
+      }
+    }
+
+    impl #impl_generics parquet::record::RecordReader<#derived_for #ty_generics> for Vec<#derived_for #ty_generics> #where_clause {
+      fn field_names() -> &'static [&'static str] {
+        &[#(#field_names),*]
+      }
+
+      fn read_from_rows(
+        &mut self,
+        rows: &mut dyn Iterator<Item = parquet::record::Row>,
+        num_records: usize,
+      ) -> Result<usize, parquet::errors::ParquetError> {
+        let mut count = 0;
+        for row in rows.take(num_records) {
+          let field = parquet::record::Field::Group(row);
+          self.push(parquet::record::FromField::from_field(&field)?);
+          count += 1;
+        }
+        Ok(count)
+      }
+    }
+  }).into()
+}
@@ -41,15 +41,47 @@ struct ACompleteRecord<'a> {
     pub borrowed_maybe_a_str: &'a Option<&'a str>,
 }
 
+#[derive(Debug, PartialEq, ParquetRecordWriter, ParquetRecordReader)]
+struct AnOwnedRecord {
+    pub a_bool: bool,
+    pub a_string: String,
+    pub maybe_a_string: Option<String>,
+    pub magic_number: i32,
+    pub big_number: i64,
+    pub low_quality_pi: f32,
+    pub high_quality_pi: f64,
+    pub maybe_pi: Option<f32>,
+    pub a_date: chrono::NaiveDate,
+    pub a_timestamp: chrono::NaiveDateTime,
+}
+
+#[derive(Debug, PartialEq, ParquetRecordReader)]
+struct APoint {
+    pub x: f64,
+    pub y: Option<f64>,
+}
+
+// Fields are deliberately not in schema order, as they are matched by name
+#[derive(Debug, PartialEq, ParquetRecordReader)]
+struct ANestedRecord {
+    pub point: APoint,
+    pub tags: Option<Vec<Option<String>>>,
+    pub id: i32,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use chrono::NaiveDate;
     use parquet::{
+        column::writer::ColumnWriter,
         file::{
             properties::WriterProperties,
+            reader::{FileReader, SerializedFileReader},
             writer::{FileWriter, SerializedFileWriter},
         },
+        record::RecordReader,
         schema::parser::parse_message_type,
     };
     use std::{env, fs, io::Write, sync::Arc};
@@ -105,6 +137,218 @@ mod tests {
         writer.close().unwrap();
     }
 
+    fn owned_records(num_records: usize) -> Vec<AnOwnedRecord> {
+        (0..num_records)
+            .map(|i| AnOwnedRecord {
+                a_bool: i % 2 == 0,
+                a_string: format!("string {}", i),
+                maybe_a_string: if i % 3 == 0 {
+                    None
+                } else {
+                    Some(format!("maybe {}", i))
+                },
+                magic_number: i as i32,
+                big_number: i as i64 * 1_000_000_000_000,
+                low_quality_pi: 3.14 + i as f32,
+                high_quality_pi: 3.1415 + i as f64,
+                maybe_pi: if i % 2 == 0 { Some(3.14) } else { None },
+                a_date: NaiveDate::from_ymd(2021, 1, 1 + i as u32),
+                a_timestamp: NaiveDate::from_ymd(2021, 1, 1)
+                    .and_hms_milli(1, 2, 3, i as u32),
+            })
+            .collect()
+    }
+
+    fn write_owned_records(
+        file_name: &str,
+        row_groups: &[&[AnOwnedRecord]],
+    ) -> SerializedFileReader<fs::File> {
+        let file = get_temp_file(file_name, &[]);
+        let schema_str = "message schema {
+            REQUIRED boolean         a_bool;
+            REQUIRED BINARY          a_string (UTF8);
+            OPTIONAL BINARY          maybe_a_string (UTF8);
+            REQUIRED INT32           magic_number;
+            REQUIRED INT64           big_number;
+            REQUIRED FLOAT           low_quality_pi;
+            REQUIRED DOUBLE          high_quality_pi;
+            OPTIONAL FLOAT           maybe_pi;
+            REQUIRED INT32           a_date (DATE);
+            REQUIRED INT64           a_timestamp (TIMESTAMP_MILLIS);
+        }";
+
+        let schema = Arc::new(parse_message_type(schema_str).unwrap());
+        let props = Arc::new(WriterProperties::builder().build());
+        let mut writer =
+            SerializedFileWriter::new(file.try_clone().unwrap(), schema, props).unwrap();
+        for records in row_groups {
+            let mut row_group = writer.next_row_group().unwrap();
+            records.write_to_row_group(&mut row_group).unwrap();
+            writer.close_row_group(row_group).unwrap();
+        }
+        writer.close().unwrap();
+
+        SerializedFileReader::new(file).unwrap()
+    }
+
+    #[test]
+    fn test_parquet_derive_read_write_round_trip() {
+        let records = owned_records(6);
+        let reader = write_owned_records(
+            "test_parquet_derive_read_write_round_trip",
+            &[&records[..4], &records[4..]],
+        );
+        assert_eq!(reader.num_row_groups(), 2);
+
+        let mut read_records: Vec<AnOwnedRecord> = Vec::new();
+        for i in 0..reader.num_row_groups() {
+            let row_group = reader.get_row_group(i).unwrap();
+            let num_rows = row_group.metadata().num_rows() as usize;
+            let num_read = read_records
+                .read_from_row_group(&*row_group, num_rows)
+                .unwrap();
+            assert_eq!(num_read, num_rows);
+        }
+        assert_eq!(read_records, records);
+    }
+
+    #[test]
+    fn test_parquet_derive_read_in_batches() {
+        let records = owned_records(5);
+        let reader =
+            write_owned_records("test_parquet_derive_read_in_batches", &[&records]);
+
+        let row_group = reader.get_row_group(0).unwrap();
+        let mut rows = Vec::<AnOwnedRecord>::row_iter(&*row_group).unwrap();
+        let mut batches = vec![];
+        loop {
+            let mut batch: Vec<AnOwnedRecord> = Vec::new();
+            if batch.read_from_rows(&mut rows, 2).unwrap() == 0 {
+                break;
+            }
+            batches.push(batch);
+        }
+
+        assert_eq!(
+            batches.iter().map(|batch| batch.len()).collect::<Vec<_>>(),
+            vec![2, 2, 1]
+        );
+        assert_eq!(batches.into_iter().flatten().collect::<Vec<_>>(), records);
+    }
+
+    #[test]
+    fn test_parquet_derive_read_nested() {
+        let file = get_temp_file("test_parquet_derive_read_nested", &[]);
+        let schema_str = "message schema {
+            REQUIRED INT32 id;
+            OPTIONAL group tags (LIST) {
+                REPEATED group list {
+                    OPTIONAL BINARY element (UTF8);
+                }
+            }
+            REQUIRED group point {
+                REQUIRED DOUBLE x;
+                OPTIONAL DOUBLE y;
+            }
+            OPTIONAL INT32 ignored;
+        }";
+
+        let schema = Arc::new(parse_message_type(schema_str).unwrap());
+        let props = Arc::new(WriterProperties::builder().build());
+        let mut writer =
+            SerializedFileWriter::new(file.try_clone().unwrap(), schema, props).unwrap();
+        let mut row_group = writer.next_row_group().unwrap();
+        let mut col_idx = 0;
+        while let Some(mut col_writer) = row_group.next_column().unwrap() {
+            match (col_idx, &mut col_writer) {
+                (0, ColumnWriter::Int32ColumnWriter(typed)) => {
+                    typed.write_batch(&[1, 2, 3], None, None).unwrap();
+                }
+                (1, ColumnWriter::ByteArrayColumnWriter(typed)) => {
+                    // [["a", null, "b"], null, []]
+                    typed
+                        .write_batch(
+                            &["a".into(), "b".into()],
+                            Some(&[3, 2, 3, 0, 1]),
+                            Some(&[0, 1, 1, 0, 0]),
+                        )
+                        .unwrap();
+                }
+                (2, ColumnWriter::DoubleColumnWriter(typed)) => {
+                    typed.write_batch(&[1.0, 2.0, 3.0], None, None).unwrap();
+                }
+                (3, ColumnWriter::DoubleColumnWriter(typed)) => {
+                    typed
+                        .write_batch(&[0.5, 1.5], Some(&[1, 0, 1]), None)
+                        .unwrap();
+                }
+                (4, ColumnWriter::Int32ColumnWriter(typed)) => {
+                    typed.write_batch(&[], Some(&[0, 0, 0]), None).unwrap();
+                }
+                _ => panic!("Unexpected column {}", col_idx),
+            }
+            row_group.close_column(col_writer).unwrap();
+            col_idx += 1;
+        }
+        writer.close_row_group(row_group).unwrap();
+        writer.close().unwrap();
+
+        let reader = SerializedFileReader::new(file).unwrap();
+        let row_group = reader.get_row_group(0).unwrap();
+        let mut records: Vec<ANestedRecord> = Vec::new();
+        records.read_from_row_group(&*row_group, 3).unwrap();
+
+        assert_eq!(
+            records,
+            vec![
+                ANestedRecord {
+                    point: APoint {
+                        x: 1.0,
+                        y: Some(0.5)
+                    },
+                    tags: Some(vec![Some("a".to_string()), None, Some("b".to_string())]),
+                    id: 1,
+                },
+                ANestedRecord {
+                    point: APoint { x: 2.0, y: None },
+                    tags: None,
+                    id: 2,
+                },
+                ANestedRecord {
+                    point: APoint {
+                        x: 3.0,
+                        y: Some(1.5)
+                    },
+                    tags: Some(vec![]),
+                    id: 3,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parquet_derive_read_missing_column() {
+        #[derive(Debug, ParquetRecordReader)]
+        struct AMissingColumnRecord {
+            #[allow(dead_code)]
+            a_bool: bool,
+            #[allow(dead_code)]
+            not_in_file: i32,
+        }
+
+        let records = owned_records(1);
+        let reader =
+            write_owned_records("test_parquet_derive_read_missing_column", &[&records]);
+        let row_group = reader.get_row_group(0).unwrap();
+
+        let mut read_records: Vec<AMissingColumnRecord> = Vec::new();
+        let res = read_records.read_from_row_group(&*row_group, 1);
+        assert_eq!(
+            res.unwrap_err().to_string(),
+            "Parquet error: Column not_in_file not found for AMissingColumnRecord"
+        );
+    }
+
     /// Returns file handle for a temp file in 'target' directory with a provided content
     pub fn get_temp_file(file_name: &str, content: &[u8]) -> fs::File {
         // build tmp path to a file in "target/debug/testdata"